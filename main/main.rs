@@ -2,6 +2,7 @@ use actix_cors::Cors;
 use actix_web::{App, HttpServer, middleware::Logger, web};
 use dotenv::dotenv;
 use teacher_system::infrastructure::api_restful::config::boostrap;
+use teacher_system::infrastructure::api_restful::openapi::docs_routes;
 use teacher_system::infrastructure::api_restful::routes::app_routes;
 
 #[tokio::main]
@@ -26,6 +27,7 @@ async fn main() -> Result<(), String> {
             )
             .app_data(web::Data::new(app_state.clone()))
             .service(app_routes())
+            .service(docs_routes())
     })
     .bind(("127.0.0.1", port.parse().unwrap()))
     .map_err(|e| e.to_string())?
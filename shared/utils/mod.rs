@@ -0,0 +1,102 @@
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+/// Aplica un JSON Merge Patch (RFC 7396) sobre `target`.
+///
+/// Los campos con `null` en el parche se eliminan; los objetos se fusionan
+/// recursivamente y cualquier otro valor reemplaza al original.
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch_map) = patch else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Default::default());
+    }
+
+    if let Value::Object(target_map) = target {
+        for (key, value) in patch_map {
+            if value.is_null() {
+                target_map.remove(key);
+            } else {
+                merge_patch(target_map.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+/// Aplica un JSON Merge Patch sobre una copia serializada de `current`
+/// y devuelve el resultado deserializado.
+pub fn apply_merge_patch<T>(current: &T, patch: &Value) -> Result<T, String>
+where
+    T: Serialize + DeserializeOwned,
+{
+    if !patch.is_object() {
+        return Err("Invalid patch: body must be a JSON object".to_string());
+    }
+
+    let mut document = serde_json::to_value(current).map_err(|e| e.to_string())?;
+    merge_patch(&mut document, patch);
+
+    serde_json::from_value(document).map_err(|e| format!("Invalid patch: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    type Hours = BTreeMap<String, i32>;
+
+    fn hours(pairs: &[(&str, i32)]) -> Hours {
+        pairs.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn null_removes_the_field() {
+        let current = hours(&[("monday", 2), ("friday", 4)]);
+
+        let patched = apply_merge_patch(&current, &json!({ "friday": null })).unwrap();
+
+        assert_eq!(patched, hours(&[("monday", 2)]));
+    }
+
+    #[test]
+    fn nested_objects_are_merged_not_replaced() {
+        let mut current = BTreeMap::new();
+        current.insert("week_1".to_string(), hours(&[("monday", 2), ("friday", 4)]));
+
+        let patched = apply_merge_patch(&current, &json!({ "week_1": { "friday": 6 } })).unwrap();
+
+        assert_eq!(patched["week_1"], hours(&[("monday", 2), ("friday", 6)]));
+    }
+
+    #[test]
+    fn non_object_value_replaces_the_original() {
+        let mut target = json!({ "tags": ["a", "b"], "name": "A-101" });
+
+        merge_patch(&mut target, &json!({ "tags": ["c"] }));
+
+        assert_eq!(target, json!({ "tags": ["c"], "name": "A-101" }));
+    }
+
+    #[test]
+    fn type_mismatch_is_an_invalid_patch() {
+        let current = hours(&[("monday", 2)]);
+
+        let error = apply_merge_patch(&current, &json!({ "monday": "two" })).unwrap_err();
+
+        assert!(error.starts_with("Invalid patch"), "{}", error);
+    }
+
+    #[test]
+    fn patch_must_be_an_object() {
+        let current = hours(&[("monday", 2)]);
+
+        let error = apply_merge_patch(&current, &json!([1, 2])).unwrap_err();
+
+        assert_eq!(error, "Invalid patch: body must be a JSON object");
+    }
+}
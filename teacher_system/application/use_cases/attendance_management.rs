@@ -0,0 +1,237 @@
+use crate::domain::{
+    models::{
+        attendance::{AttendanceFilter, AttendanceRecord, AttendanceSummary},
+        enums::{AttendanceStatus, EnrollmentStatus, Weekday},
+        schedule::Schedule,
+    },
+    repositories::{
+        attendance_repository::AttendanceRepository, course_repository::CourseRepository,
+        enrollment_repository::EnrollmentRepository, schedule_repository::ScheduleRepository,
+        session_exception_repository::SessionExceptionRepository, user_repository::UserRepository,
+    },
+};
+use async_trait::async_trait;
+use chrono::{Datelike, NaiveDate, Utc};
+
+#[async_trait]
+pub trait AttendanceManagementUseCase {
+    async fn take_attendance(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+        entries: Vec<(String, AttendanceStatus)>,
+        actor_id: &str,
+    ) -> Result<Vec<AttendanceRecord>, String>;
+    async fn get_occurrence(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+    ) -> Result<Vec<AttendanceRecord>, String>;
+    async fn get_course_summary(&self, course_id: &str) -> Result<Vec<AttendanceSummary>, String>;
+    async fn get_student_summary(
+        &self,
+        student_id: &str,
+    ) -> Result<Vec<AttendanceSummary>, String>;
+    async fn get_at_risk(
+        &self,
+        course_id: Option<String>,
+        threshold: f64,
+    ) -> Result<Vec<AttendanceSummary>, String>;
+}
+
+pub struct AttendanceManagementUseCaseImpl {
+    attendance_repo: Box<dyn AttendanceRepository + Send + Sync>,
+    schedule_repo: Box<dyn ScheduleRepository + Send + Sync>,
+    course_repo: Box<dyn CourseRepository + Send + Sync>,
+    enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    exception_repo: Box<dyn SessionExceptionRepository + Send + Sync>,
+}
+
+impl AttendanceManagementUseCaseImpl {
+    pub fn new(
+        attendance_repo: Box<dyn AttendanceRepository + Send + Sync>,
+        schedule_repo: Box<dyn ScheduleRepository + Send + Sync>,
+        course_repo: Box<dyn CourseRepository + Send + Sync>,
+        enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        exception_repo: Box<dyn SessionExceptionRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            attendance_repo,
+            schedule_repo,
+            course_repo,
+            enrollment_repo,
+            user_repo,
+            exception_repo,
+        }
+    }
+
+    /// La fecha debe ser una clase que realmente se dictó: el día habitual sin
+    /// cancelar ni mover, o la fecha de una recuperación.
+    async fn check_occurrence(&self, schedule: &Schedule, date: NaiveDate) -> Result<(), String> {
+        if date > Utc::now().date_naive() {
+            return Err("Invalid date: attendance cannot be taken in advance".to_string());
+        }
+
+        let exceptions = self
+            .exception_repo
+            .get_exceptions_by_schedule(&schedule.id)
+            .await?;
+
+        if let Some(exception) = exceptions.iter().find(|e| e.original_date == date) {
+            return Err(format!(
+                "Conflict: the session on {} was {}",
+                date, exception.kind
+            ));
+        }
+
+        let is_regular = Weekday::from(date.weekday()) == schedule.day;
+        let is_make_up = exceptions
+            .iter()
+            .any(|e| e.make_up.as_ref().is_some_and(|slot| slot.date == date));
+
+        if !is_regular && !is_make_up {
+            return Err(format!(
+                "Invalid date: the session does not take place on {}",
+                date
+            ));
+        }
+        Ok(())
+    }
+
+    async fn check_can_record(&self, teacher_id: &str, actor_id: &str) -> Result<(), String> {
+        if teacher_id == actor_id {
+            return Ok(());
+        }
+
+        let actor = self.user_repo.get_user_by_id(actor_id).await?;
+        if actor.is_some_and(|u| u.is_coordinator()) {
+            Ok(())
+        } else {
+            Err("Forbidden: only the course teacher can take attendance".to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl AttendanceManagementUseCase for AttendanceManagementUseCaseImpl {
+    async fn take_attendance(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+        entries: Vec<(String, AttendanceStatus)>,
+        actor_id: &str,
+    ) -> Result<Vec<AttendanceRecord>, String> {
+        let schedule = self
+            .schedule_repo
+            .get_schedule_by_id(schedule_id)
+            .await?
+            .ok_or_else(|| "Schedule not found".to_string())?;
+        let course = self.course_repo.get_course_by_id(&schedule.course_id).await?;
+
+        self.check_can_record(&course.teacher_id, actor_id).await?;
+        self.check_occurrence(&schedule, date).await?;
+
+        let enrolled: Vec<String> = self
+            .enrollment_repo
+            .get_enrollments_by_course(&course.id)
+            .await?
+            .into_iter()
+            .filter(|e| matches!(e.status, EnrollmentStatus::Enrolled))
+            .map(|e| e.student_id)
+            .collect();
+
+        if let Some((student_id, _)) = entries.iter().find(|(id, _)| !enrolled.contains(id)) {
+            return Err(format!(
+                "Invalid student: {} is not enrolled in {}",
+                student_id, course.code
+            ));
+        }
+
+        let records: Vec<AttendanceRecord> = entries
+            .into_iter()
+            .map(|(student_id, status)| AttendanceRecord {
+                id: uuid::Uuid::new_v4().to_string(),
+                schedule_id: schedule.id.clone(),
+                course_id: course.id.clone(),
+                student_id,
+                session_date: date,
+                status,
+                recorded_by: actor_id.to_string(),
+                recorded_at: None,
+            })
+            .collect();
+
+        self.attendance_repo.save_records(&records).await?;
+        self.get_occurrence(schedule_id, date).await
+    }
+
+    async fn get_occurrence(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+    ) -> Result<Vec<AttendanceRecord>, String> {
+        self.attendance_repo
+            .get_records(&AttendanceFilter {
+                schedule_id: Some(schedule_id.to_string()),
+                session_date: Some(date),
+                ..Default::default()
+            })
+            .await
+    }
+
+    async fn get_course_summary(&self, course_id: &str) -> Result<Vec<AttendanceSummary>, String> {
+        let records = self
+            .attendance_repo
+            .get_records(&AttendanceFilter {
+                course_id: Some(course_id.to_string()),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(AttendanceSummary::summarize(&records))
+    }
+
+    async fn get_student_summary(
+        &self,
+        student_id: &str,
+    ) -> Result<Vec<AttendanceSummary>, String> {
+        let records = self
+            .attendance_repo
+            .get_records(&AttendanceFilter {
+                student_id: Some(student_id.to_string()),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(AttendanceSummary::summarize(&records))
+    }
+
+    /// Alumnos bajo el umbral, del más comprometido al menos.
+    async fn get_at_risk(
+        &self,
+        course_id: Option<String>,
+        threshold: f64,
+    ) -> Result<Vec<AttendanceSummary>, String> {
+        if !(0.0..=100.0).contains(&threshold) {
+            return Err("Invalid threshold: must be between 0 and 100".to_string());
+        }
+
+        let records = self
+            .attendance_repo
+            .get_records(&AttendanceFilter {
+                course_id,
+                ..Default::default()
+            })
+            .await?;
+
+        let mut at_risk: Vec<AttendanceSummary> = AttendanceSummary::summarize(&records)
+            .into_iter()
+            .filter(|s| s.is_at_risk(threshold))
+            .collect();
+        at_risk.sort_by(|a, b| a.percentage.total_cmp(&b.percentage));
+
+        Ok(at_risk)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::domain::{
+    models::audit_log::{AuditFilter, AuditLog},
+    repositories::audit_repository::AuditRepository,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AuditManagementUseCase {
+    async fn get_logs(&self, filter: &AuditFilter) -> Result<Vec<AuditLog>, String>;
+}
+
+pub struct AuditManagementUseCaseImpl {
+    audit_repo: Box<dyn AuditRepository + Send + Sync>,
+}
+
+impl AuditManagementUseCaseImpl {
+    pub fn new(audit_repo: Box<dyn AuditRepository + Send + Sync>) -> Self {
+        Self { audit_repo }
+    }
+}
+
+#[async_trait]
+impl AuditManagementUseCase for AuditManagementUseCaseImpl {
+    async fn get_logs(&self, filter: &AuditFilter) -> Result<Vec<AuditLog>, String> {
+        if matches!((filter.from, filter.to), (Some(from), Some(to)) if from > to) {
+            return Err("Invalid time range: from must be before to".to_string());
+        }
+
+        self.audit_repo.get_logs(filter).await
+    }
+}
@@ -0,0 +1,161 @@
+use crate::domain::{
+    models::{
+        booking::{BookingFilter, FacilityBooking},
+        enums::BookingStatus,
+        validation::DomainError,
+    },
+    repositories::{
+        booking_repository::BookingRepository, facility_repository::FacilityRepository,
+        user_repository::UserRepository,
+    },
+    services::validation_service::DefaultValidationService,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+
+#[async_trait]
+pub trait BookingManagementUseCase {
+    async fn get_all(&self, filter: &BookingFilter) -> Result<Vec<FacilityBooking>, String>;
+    async fn get_by_id(&self, id: &str) -> Result<FacilityBooking, String>;
+    async fn request(&self, booking: FacilityBooking) -> Result<FacilityBooking, DomainError>;
+    async fn approve(&self, id: &str, reviewer_id: &str) -> Result<FacilityBooking, String>;
+    async fn reject(&self, id: &str, reviewer_id: &str) -> Result<FacilityBooking, String>;
+    async fn cancel(&self, id: &str, actor_id: &str) -> Result<FacilityBooking, String>;
+}
+
+pub struct BookingManagementUseCaseImpl {
+    booking_repo: Box<dyn BookingRepository + Send + Sync>,
+    facility_repo: Box<dyn FacilityRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    validation_service: DefaultValidationService,
+}
+
+impl BookingManagementUseCaseImpl {
+    pub fn new(
+        booking_repo: Box<dyn BookingRepository + Send + Sync>,
+        facility_repo: Box<dyn FacilityRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        validation_service: DefaultValidationService,
+    ) -> Self {
+        Self {
+            booking_repo,
+            facility_repo,
+            user_repo,
+            validation_service,
+        }
+    }
+
+    async fn is_reviewer(&self, user_id: &str) -> Result<bool, String> {
+        let user = self.user_repo.get_user_by_id(user_id).await?;
+        Ok(user.is_some_and(|u| u.is_coordinator()))
+    }
+
+    async fn pending_booking(&self, id: &str, reviewer_id: &str) -> Result<FacilityBooking, String> {
+        if !self.is_reviewer(reviewer_id).await? {
+            return Err("Forbidden: only coordinators can review bookings".to_string());
+        }
+
+        let booking = self.get_by_id(id).await?;
+        if booking.status != BookingStatus::Pending {
+            return Err(format!("Conflict: booking is already {}", booking.status));
+        }
+
+        Ok(booking)
+    }
+}
+
+#[async_trait]
+impl BookingManagementUseCase for BookingManagementUseCaseImpl {
+    async fn get_all(&self, filter: &BookingFilter) -> Result<Vec<FacilityBooking>, String> {
+        self.booking_repo.get_bookings(filter).await
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<FacilityBooking, String> {
+        self.booking_repo
+            .get_booking_by_id(id)
+            .await?
+            .ok_or_else(|| "Booking not found".to_string())
+    }
+
+    async fn request(&self, mut booking: FacilityBooking) -> Result<FacilityBooking, DomainError> {
+        booking.validate()?;
+
+        if booking.date < Utc::now().date_naive() {
+            return Err("Invalid date: bookings cannot be made for past dates".into());
+        }
+        self.facility_repo.get_facility_by_id(&booking.facility_id).await?;
+
+        let is_available = self
+            .validation_service
+            .check_booking_availability(&booking)
+            .await?;
+
+        if !is_available {
+            return Err("Facility not available at requested time".into());
+        }
+
+        booking.status = BookingStatus::Pending;
+        booking.reviewed_by = None;
+        self.booking_repo.create_booking(&booking).await?;
+        Ok(self.get_by_id(&booking.id).await?)
+    }
+
+    async fn approve(&self, id: &str, reviewer_id: &str) -> Result<FacilityBooking, String> {
+        let booking = self.pending_booking(id, reviewer_id).await?;
+
+        // El horario pudo cambiar desde que se solicitó la reserva
+        let is_available = self
+            .validation_service
+            .check_booking_availability(&booking)
+            .await?;
+
+        if !is_available {
+            return Err("Facility not available at requested time".to_string());
+        }
+
+        self.booking_repo
+            .update_booking_status(
+                id,
+                &[BookingStatus::Pending],
+                BookingStatus::Approved,
+                Some(reviewer_id),
+            )
+            .await?;
+        self.get_by_id(id).await
+    }
+
+    async fn reject(&self, id: &str, reviewer_id: &str) -> Result<FacilityBooking, String> {
+        self.pending_booking(id, reviewer_id).await?;
+
+        self.booking_repo
+            .update_booking_status(
+                id,
+                &[BookingStatus::Pending],
+                BookingStatus::Rejected,
+                Some(reviewer_id),
+            )
+            .await?;
+        self.get_by_id(id).await
+    }
+
+    async fn cancel(&self, id: &str, actor_id: &str) -> Result<FacilityBooking, String> {
+        let booking = self.get_by_id(id).await?;
+
+        if booking.requested_by != actor_id && !self.is_reviewer(actor_id).await? {
+            return Err("Forbidden: only the requester or a coordinator can cancel a booking".to_string());
+        }
+        if !booking.is_active() {
+            return Err(format!("Conflict: booking is already {}", booking.status));
+        }
+
+        self.booking_repo
+            .update_booking_status(
+                id,
+                &[BookingStatus::Pending, BookingStatus::Approved],
+                BookingStatus::Cancelled,
+                None,
+            )
+            .await?;
+        self.get_by_id(id).await
+    }
+}
@@ -1,17 +1,45 @@
 use crate::domain::{
-    models::{course::Course, schedule::Schedule},
-    repositories::course_repository::CourseRepository,
-    services::validation_service::DefaultValidationService,
+    models::{
+        change_set::ChangeSet, course::Course, enums::AuditAction, schedule::Schedule,
+        validation::DomainError,
+    },
+    repositories::{
+        course_repository::CourseRepository, unit_of_work_repository::UnitOfWorkRepository,
+    },
+    services::{audit_service::DefaultAuditService, validation_service::DefaultValidationService},
 };
 use async_trait::async_trait;
+use serde_json::Value;
+use shared::utils::apply_merge_patch;
+
+const AUDIT_ENTITY: &str = "course";
+
 #[async_trait]
 pub trait CourseManagementUseCase {
-    async fn register_extracourse(&self, course: Course, schedule: Schedule) -> Result<(), String>;
+    async fn register_extracourse(
+        &self,
+        course: Course,
+        schedule: Schedule,
+        actor_id: &str,
+    ) -> Result<(), DomainError>;
     async fn get_all(&self) -> Result<Vec<Course>, String>;
     async fn get_by_id(&self, id: &str) -> Result<Course, String>;
-    async fn create(&self, course: Course) -> Result<(), String>;
-    async fn update(&self, course: &Course) -> Result<(), String>;
-    async fn delete(&self, id: &str) -> Result<(), String>;
+    async fn create(&self, course: Course, actor_id: &str) -> Result<Course, DomainError>;
+    async fn update(
+        &self,
+        course: &Course,
+        expected_version: Option<i32>,
+        actor_id: &str,
+    ) -> Result<Course, DomainError>;
+    async fn patch(
+        &self,
+        id: &str,
+        patch: &Value,
+        expected_version: Option<i32>,
+        actor_id: &str,
+    ) -> Result<Course, DomainError>;
+    async fn delete(&self, id: &str, actor_id: &str) -> Result<(), String>;
+    async fn restore(&self, id: &str, actor_id: &str) -> Result<(), String>;
     async fn get_by_user(&self, user_id: &str) -> Result<Vec<Course>, String>;
     async fn get_by_facility(&self, facility_id: &str) -> Result<Vec<Course>, String>;
     async fn get_by_facility_name(&self, name_facility: &str) -> Result<Vec<Course>, String>;
@@ -20,34 +48,59 @@ pub trait CourseManagementUseCase {
 
 pub struct CourseManagementUseCaseImpl {
     course_repo: Box<dyn CourseRepository + Send + Sync>,
+    unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
     validation_service: DefaultValidationService,
+    audit_service: DefaultAuditService,
 }
 
 impl CourseManagementUseCaseImpl {
     pub fn new(
         course_repo: Box<dyn CourseRepository + Send + Sync>,
+        unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
         validation_service: DefaultValidationService,
+        audit_service: DefaultAuditService,
     ) -> Self {
         Self {
             course_repo,
+            unit_of_work,
             validation_service,
+            audit_service,
         }
     }
 }
 
 #[async_trait]
 impl CourseManagementUseCase for CourseManagementUseCaseImpl {
-    async fn register_extracourse(&self, course: Course, schedule: Schedule) -> Result<(), String> {
-        let has_conflict = self
+    async fn register_extracourse(
+        &self,
+        course: Course,
+        schedule: Schedule,
+        actor_id: &str,
+    ) -> Result<(), DomainError> {
+        course.validate()?;
+        schedule.validate()?;
+
+        let teacher_available = self
             .validation_service
-            .check_teacher_availability(&course.teacher_id, &schedule)
+            .check_teacher_availability(&course, &schedule)
             .await?;
 
-        if has_conflict {
-            return Err("El profesor ya tiene un curso en ese horario".into());
+        if !teacher_available {
+            return Err("Conflict: the teacher already teaches at that time".into());
         }
 
-        self.course_repo.create_course(&course).await
+        let mut changes = ChangeSet::new();
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &course.id,
+            AuditAction::Create,
+            None,
+            Some(&course),
+        )?;
+        changes.create_course(course);
+        Ok(self.unit_of_work.commit(changes).await?)
     }
 
     async fn get_all(&self) -> Result<Vec<Course>, String> {
@@ -58,16 +111,143 @@ impl CourseManagementUseCase for CourseManagementUseCaseImpl {
         self.course_repo.get_course_by_id(id).await
     }
 
-    async fn create(&self, course: Course) -> Result<(), String> {
-        self.course_repo.create_course(&course).await
+    async fn create(&self, course: Course, actor_id: &str) -> Result<Course, DomainError> {
+        course.validate()?;
+
+        let mut changes = ChangeSet::new();
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &course.id,
+            AuditAction::Create,
+            None,
+            Some(&course),
+        )?;
+        changes.create_course(course.clone());
+        self.unit_of_work.commit(changes).await?;
+
+        Ok(self.course_repo.get_course_by_id(&course.id).await?)
     }
 
-    async fn update(&self, course: &Course) -> Result<(), String> {
-        self.course_repo.update_course(course).await
+    async fn update(
+        &self,
+        course: &Course,
+        expected_version: Option<i32>,
+        actor_id: &str,
+    ) -> Result<Course, DomainError> {
+        let before = self.course_repo.get_course_by_id(&course.id).await?;
+
+        // Los inscritos, el aula y el estado los gestiona el servidor; sin
+        // programa se conserva el que ya tenía
+        let course = Course {
+            enrolled: before.enrolled,
+            facility_id: before.facility_id.clone(),
+            active: before.active,
+            program: match course.program() {
+                Some(program) => program.to_string(),
+                None => before.program.clone(),
+            },
+            ..course.clone()
+        };
+        course.validate()?;
+
+        // Otro profesor u otro semestre: las sesiones del curso se revisan
+        // con la carga del profesor en ese semestre
+        if course.teacher_id != before.teacher_id || !course.same_term(&before) {
+            self.validation_service
+                .check_course_teacher(&course)
+                .await?;
+        }
+
+        // Sin versión esperada se bloquea contra la leída: así la auditoría
+        // registra la versión que de verdad se escribe
+        let expected_version = expected_version.unwrap_or(before.version);
+        let after = Course {
+            version: expected_version + 1,
+            ..course.clone()
+        };
+
+        let mut changes = ChangeSet::new();
+        changes.update_course(course.clone(), Some(expected_version));
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &course.id,
+            AuditAction::Update,
+            Some(&before),
+            Some(&after),
+        )?;
+        self.unit_of_work.commit(changes).await?;
+
+        Ok(self.course_repo.get_course_by_id(&course.id).await?)
     }
 
-    async fn delete(&self, id: &str) -> Result<(), String> {
-        self.course_repo.delete_course(id).await
+    async fn patch(
+        &self,
+        id: &str,
+        patch: &Value,
+        expected_version: Option<i32>,
+        actor_id: &str,
+    ) -> Result<Course, DomainError> {
+        let current = self.course_repo.get_course_by_id(id).await?;
+
+        let mut merged = apply_merge_patch(&current, patch)?;
+        merged.id = current.id.clone();
+        merged.version = current.version;
+
+        // Bloquear contra la versión leída para no pisar cambios concurrentes
+        self.update(
+            &merged,
+            Some(expected_version.unwrap_or(current.version)),
+            actor_id,
+        )
+        .await
+    }
+
+    async fn delete(&self, id: &str, actor_id: &str) -> Result<(), String> {
+        let before = self.course_repo.get_course_by_id(id).await?;
+
+        let mut changes = ChangeSet::new();
+        changes.archive_course(id);
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            id,
+            AuditAction::Delete,
+            Some(&before),
+            None,
+        )?;
+        self.unit_of_work.commit(changes).await
+    }
+
+    async fn restore(&self, id: &str, actor_id: &str) -> Result<(), String> {
+        let archived = self.course_repo.get_course_including_archived(id).await?;
+        // Mientras estuvo archivado otros cursos pudieron tomar su aula o a
+        // su profesor
+        self.validation_service
+            .check_course_restore(&archived)
+            .await?;
+
+        let after = Course {
+            active: true,
+            ..archived
+        };
+
+        let mut changes = ChangeSet::new();
+        changes.restore_course(id);
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            id,
+            AuditAction::Restore,
+            None,
+            Some(&after),
+        )?;
+        self.unit_of_work.commit(changes).await
     }
 
     async fn get_by_user(&self, user_id: &str) -> Result<Vec<Course>, String> {
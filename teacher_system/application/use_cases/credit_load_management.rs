@@ -0,0 +1,178 @@
+use crate::domain::{
+    models::{
+        credit_load::{CreditLoadPolicy, OverloadFilter, OverloadRequest, TermLoad},
+        enums::OverloadStatus,
+        validation::DomainError,
+    },
+    repositories::{overload_repository::OverloadRepository, user_repository::UserRepository},
+    services::credit_load_service::DefaultCreditLoadService,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreditLoadManagementUseCase {
+    fn get_policy(&self) -> CreditLoadPolicy;
+    async fn get_term_load(
+        &self,
+        student_id: &str,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<TermLoad, String>;
+    async fn get_all(&self, filter: &OverloadFilter) -> Result<Vec<OverloadRequest>, String>;
+    async fn get_by_id(&self, id: &str) -> Result<OverloadRequest, String>;
+    async fn request(&self, request: OverloadRequest) -> Result<OverloadRequest, DomainError>;
+    async fn approve(
+        &self,
+        id: &str,
+        reviewer_id: &str,
+        note: Option<String>,
+    ) -> Result<OverloadRequest, String>;
+    async fn reject(
+        &self,
+        id: &str,
+        reviewer_id: &str,
+        note: Option<String>,
+    ) -> Result<OverloadRequest, String>;
+}
+
+pub struct CreditLoadManagementUseCaseImpl {
+    overload_repo: Box<dyn OverloadRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    credit_load_service: DefaultCreditLoadService,
+}
+
+impl CreditLoadManagementUseCaseImpl {
+    pub fn new(
+        overload_repo: Box<dyn OverloadRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        credit_load_service: DefaultCreditLoadService,
+    ) -> Self {
+        Self {
+            overload_repo,
+            user_repo,
+            credit_load_service,
+        }
+    }
+
+    async fn is_reviewer(&self, user_id: &str) -> Result<bool, String> {
+        let user = self.user_repo.get_user_by_id(user_id).await?;
+        Ok(user.is_some_and(|u| u.is_coordinator()))
+    }
+
+    async fn pending_request(&self, id: &str, reviewer_id: &str) -> Result<OverloadRequest, String> {
+        if !self.is_reviewer(reviewer_id).await? {
+            return Err("Forbidden: only coordinators can review overload requests".to_string());
+        }
+
+        let request = self.get_by_id(id).await?;
+        if request.status != OverloadStatus::Pending {
+            return Err(format!("Conflict: overload request is already {}", request.status));
+        }
+        Ok(request)
+    }
+}
+
+#[async_trait]
+impl CreditLoadManagementUseCase for CreditLoadManagementUseCaseImpl {
+    fn get_policy(&self) -> CreditLoadPolicy {
+        self.credit_load_service.policy().clone()
+    }
+
+    async fn get_term_load(
+        &self,
+        student_id: &str,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<TermLoad, String> {
+        self.credit_load_service
+            .term_load(student_id, academic_year, semester)
+            .await
+    }
+
+    async fn get_all(&self, filter: &OverloadFilter) -> Result<Vec<OverloadRequest>, String> {
+        self.overload_repo.get_requests(filter).await
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<OverloadRequest, String> {
+        self.overload_repo
+            .get_request_by_id(id)
+            .await?
+            .ok_or_else(|| "Overload request not found".to_string())
+    }
+
+    /// Lo pide el propio alumno o un coordinador por él, y solo tiene sentido
+    /// por encima del máximo que ya le corresponde.
+    async fn request(&self, request: OverloadRequest) -> Result<OverloadRequest, DomainError> {
+        request.validate()?;
+
+        if request.requested_by != request.student_id && !self.is_reviewer(&request.requested_by).await? {
+            return Err("Forbidden: students can only request overloads for themselves".into());
+        }
+
+        let load = self
+            .credit_load_service
+            .term_load(&request.student_id, request.academic_year, &request.semester)
+            .await?;
+        match load.max_credits {
+            Some(max) if request.requested_credits <= max => {
+                return Err(format!(
+                    "Invalid request: {} credits are within the maximum of {}",
+                    request.requested_credits, max
+                )
+                .into());
+            }
+            None => {
+                return Err("Invalid request: the student has no credit limit".into());
+            }
+            _ => {}
+        }
+
+        let existing = self
+            .overload_repo
+            .get_requests(&OverloadFilter {
+                student_id: Some(request.student_id.clone()),
+                status: None,
+            })
+            .await?;
+        if existing.iter().any(|r| {
+            r.is_active() && r.academic_year == request.academic_year && r.semester == request.semester
+        }) {
+            return Err(format!(
+                "Conflict: there is already an overload request for {} {}",
+                request.academic_year, request.semester
+            )
+            .into());
+        }
+
+        self.overload_repo.create_request(&request).await?;
+        Ok(self.get_by_id(&request.id).await?)
+    }
+
+    async fn approve(
+        &self,
+        id: &str,
+        reviewer_id: &str,
+        note: Option<String>,
+    ) -> Result<OverloadRequest, String> {
+        self.pending_request(id, reviewer_id).await?;
+
+        self.overload_repo
+            .update_request_status(id, OverloadStatus::Approved, reviewer_id, note.as_deref())
+            .await?;
+        self.get_by_id(id).await
+    }
+
+    async fn reject(
+        &self,
+        id: &str,
+        reviewer_id: &str,
+        note: Option<String>,
+    ) -> Result<OverloadRequest, String> {
+        self.pending_request(id, reviewer_id).await?;
+
+        self.overload_repo
+            .update_request_status(id, OverloadStatus::Rejected, reviewer_id, note.as_deref())
+            .await?;
+        self.get_by_id(id).await
+    }
+}
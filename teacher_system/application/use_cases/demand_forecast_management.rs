@@ -0,0 +1,44 @@
+use crate::domain::{
+    models::demand_forecast::DemandForecast,
+    services::demand_forecast_service::DefaultDemandForecastService,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait DemandForecastManagementUseCase {
+    async fn forecast(
+        &self,
+        academic_year: i32,
+        semester: &str,
+        program: Option<&str>,
+    ) -> Result<DemandForecast, String>;
+}
+
+pub struct DemandForecastManagementUseCaseImpl {
+    demand_forecast_service: DefaultDemandForecastService,
+}
+
+impl DemandForecastManagementUseCaseImpl {
+    pub fn new(demand_forecast_service: DefaultDemandForecastService) -> Self {
+        Self {
+            demand_forecast_service,
+        }
+    }
+}
+
+#[async_trait]
+impl DemandForecastManagementUseCase for DemandForecastManagementUseCaseImpl {
+    async fn forecast(
+        &self,
+        academic_year: i32,
+        semester: &str,
+        program: Option<&str>,
+    ) -> Result<DemandForecast, String> {
+        if semester.trim().is_empty() {
+            return Err("Invalid term: semester is required".to_string());
+        }
+        self.demand_forecast_service
+            .forecast(academic_year, semester, program)
+            .await
+    }
+}
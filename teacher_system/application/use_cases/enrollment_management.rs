@@ -0,0 +1,131 @@
+use crate::domain::{
+    models::{
+        change_set::ChangeSet,
+        course::Course,
+        enrollment::Enrollment,
+        enums::EnrollmentStatus,
+        event::DomainEvent,
+    },
+    repositories::{
+        enrollment_repository::EnrollmentRepository,
+        unit_of_work_repository::UnitOfWorkRepository, user_repository::UserRepository,
+    },
+    services::{
+        credit_load_service::DefaultCreditLoadService,
+        prerequisite_service::DefaultPrerequisiteService,
+    },
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait EnrollmentManagementUseCase {
+    async fn get_by_id(&self, id: &str) -> Result<Enrollment, String>;
+    async fn confirm(&self, id: &str, actor_id: &str) -> Result<Enrollment, String>;
+    async fn missing_prerequisites(
+        &self,
+        student_id: &str,
+        course_id: &str,
+    ) -> Result<Vec<Course>, String>;
+}
+
+pub struct EnrollmentManagementUseCaseImpl {
+    enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+    unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    prerequisite_service: DefaultPrerequisiteService,
+    credit_load_service: DefaultCreditLoadService,
+}
+
+impl EnrollmentManagementUseCaseImpl {
+    pub fn new(
+        enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+        unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        prerequisite_service: DefaultPrerequisiteService,
+        credit_load_service: DefaultCreditLoadService,
+    ) -> Self {
+        Self {
+            enrollment_repo,
+            unit_of_work,
+            user_repo,
+            prerequisite_service,
+            credit_load_service,
+        }
+    }
+
+    /// Solo el propio alumno o un coordinador confirman una matrícula.
+    async fn check_can_confirm(&self, enrollment: &Enrollment, actor_id: &str) -> Result<(), String> {
+        if enrollment.student_id == actor_id {
+            return Ok(());
+        }
+
+        let actor = self.user_repo.get_user_by_id(actor_id).await?;
+        if actor.is_some_and(|u| u.is_coordinator()) {
+            Ok(())
+        } else {
+            Err("Forbidden: only the student or a coordinator can confirm an enrollment".to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl EnrollmentManagementUseCase for EnrollmentManagementUseCaseImpl {
+    async fn get_by_id(&self, id: &str) -> Result<Enrollment, String> {
+        self.enrollment_repo
+            .get_enrollment_by_id(id)
+            .await?
+            .ok_or_else(|| "Enrollment not found".to_string())
+    }
+
+    /// Solo una matrícula pendiente puede confirmarse, y solo si el alumno ya
+    /// aprobó los requisitos del curso y su carga queda entre el mínimo y el
+    /// tope de créditos. La vacante se descuenta del curso en la misma
+    /// transacción, que falla si el curso ya está lleno.
+    async fn confirm(&self, id: &str, actor_id: &str) -> Result<Enrollment, String> {
+        let enrollment = self.get_by_id(id).await?;
+        self.check_can_confirm(&enrollment, actor_id).await?;
+        if !matches!(enrollment.status, EnrollmentStatus::Pending) {
+            return Err(format!(
+                "Conflict: enrollment is already {}",
+                enrollment.status.to_string()
+            ));
+        }
+
+        let missing = self
+            .missing_prerequisites(&enrollment.student_id, &enrollment.course_id)
+            .await?;
+        if !missing.is_empty() {
+            let codes: Vec<String> = missing.into_iter().map(|c| c.code).collect();
+            return Err(format!(
+                "Conflict: missing prerequisites {}",
+                codes.join(", ")
+            ));
+        }
+
+        let max_credits = self
+            .credit_load_service
+            .check_enrollment(&enrollment.student_id, &enrollment.course_id)
+            .await?;
+
+        let mut changes = ChangeSet::new();
+        changes.confirm_enrollment(id, max_credits);
+        changes.publish(DomainEvent::EnrollmentConfirmed {
+            enrollment_id: enrollment.id.clone(),
+            student_id: enrollment.student_id.clone(),
+            course_id: enrollment.course_id.clone(),
+        });
+        self.unit_of_work.commit(changes).await?;
+
+        self.get_by_id(id).await
+    }
+
+    async fn missing_prerequisites(
+        &self,
+        student_id: &str,
+        course_id: &str,
+    ) -> Result<Vec<Course>, String> {
+        self.prerequisite_service
+            .missing_prerequisites(student_id, course_id)
+            .await
+    }
+}
@@ -1,30 +1,83 @@
 // src/application/usecases/facility_management_usecase.rs
 
 use crate::domain::{
-    models::facilitie::Facility, repositories::facility_repository::FacilityRepository,
+    models::{
+        change_set::ChangeSet,
+        enums::AuditAction,
+        facilitie::Facility,
+        facilitie_available::{AvailabilityFilter, FacilityAvailable},
+        schedule::{Schedule, institutional_hours},
+        validation::DomainError,
+    },
+    repositories::{
+        facility_repository::FacilityRepository, schedule_repository::ScheduleRepository,
+        unit_of_work_repository::UnitOfWorkRepository,
+    },
+    services::{
+        audit_service::DefaultAuditService, room_allocation_service::DefaultRoomAllocationService,
+        validation_service::DefaultValidationService,
+    },
 };
 use async_trait::async_trait;
+use chrono::Timelike;
+use serde_json::Value;
+use shared::utils::apply_merge_patch;
+
+const AUDIT_ENTITY: &str = "facility";
+// Las sesiones movidas se auditan como en `ScheduleManagementUseCase`
+const SCHEDULE_AUDIT_ENTITY: &str = "schedule";
 
 #[async_trait]
 pub trait FacilityManagementUseCase {
     async fn get_all(&self) -> Result<Vec<Facility>, String>;
     async fn get_by_id(&self, id: &str) -> Result<Facility, String>;
-    async fn create(&self, facility: Facility) -> Result<(), String>;
-    async fn update(&self, facility: &Facility) -> Result<(), String>;
-    async fn delete(&self, id: &str) -> Result<(), String>;
+    async fn create(&self, facility: Facility, actor_id: &str) -> Result<(), DomainError>;
+    async fn update(&self, facility: &Facility, actor_id: &str) -> Result<(), DomainError>;
+    async fn patch(&self, id: &str, patch: &Value, actor_id: &str)
+    -> Result<Facility, DomainError>;
+    async fn delete(
+        &self,
+        id: &str,
+        move_sessions_to: Option<&str>,
+        actor_id: &str,
+    ) -> Result<(), String>;
+    async fn restore(&self, id: &str, actor_id: &str) -> Result<(), String>;
     async fn get_by_course(&self, course_id: &str) -> Result<Facility, String>;
     async fn get_by_name_course(&self, name_course: &str) -> Result<Vec<Facility>, String>;
     async fn get_by_schedule(&self, schedule_id: &str) -> Result<Facility, String>;
     async fn get_by_user(&self, user_id: &str) -> Result<Vec<Facility>, String>;
+    async fn get_available(
+        &self,
+        filter: &AvailabilityFilter,
+    ) -> Result<Vec<FacilityAvailable>, String>;
 }
 
 pub struct FacilityManagementUseCaseImpl {
     facility_repo: Box<dyn FacilityRepository + Send + Sync>,
+    schedule_repo: Box<dyn ScheduleRepository + Send + Sync>,
+    unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+    validation_service: DefaultValidationService,
+    audit_service: DefaultAuditService,
+    allocation_service: DefaultRoomAllocationService,
 }
 
 impl FacilityManagementUseCaseImpl {
-    pub fn new(facility_repo: Box<dyn FacilityRepository + Send + Sync>) -> Self {
-        Self { facility_repo }
+    pub fn new(
+        facility_repo: Box<dyn FacilityRepository + Send + Sync>,
+        schedule_repo: Box<dyn ScheduleRepository + Send + Sync>,
+        unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+        validation_service: DefaultValidationService,
+        audit_service: DefaultAuditService,
+        allocation_service: DefaultRoomAllocationService,
+    ) -> Self {
+        Self {
+            facility_repo,
+            schedule_repo,
+            unit_of_work,
+            validation_service,
+            audit_service,
+            allocation_service,
+        }
     }
 }
 
@@ -38,16 +91,153 @@ impl FacilityManagementUseCase for FacilityManagementUseCaseImpl {
         self.facility_repo.get_facility_by_id(id).await
     }
 
-    async fn create(&self, facility: Facility) -> Result<(), String> {
-        self.facility_repo.create_facility(&facility).await
+    async fn create(&self, facility: Facility, actor_id: &str) -> Result<(), DomainError> {
+        facility.validate()?;
+
+        let mut changes = ChangeSet::new();
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &facility.id,
+            AuditAction::Create,
+            None,
+            Some(&facility),
+        )?;
+        changes.create_facility(facility);
+        Ok(self.unit_of_work.commit(changes).await?)
     }
 
-    async fn update(&self, facility: &Facility) -> Result<(), String> {
-        self.facility_repo.update_facility(facility).await
+    async fn update(&self, facility: &Facility, actor_id: &str) -> Result<(), DomainError> {
+        facility.validate()?;
+
+        let before = self.facility_repo.get_facility_by_id(&facility.id).await?;
+
+        let mut changes = ChangeSet::new();
+        changes.update_facility(facility.clone());
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &facility.id,
+            AuditAction::Update,
+            Some(&before),
+            Some(facility),
+        )?;
+        Ok(self.unit_of_work.commit(changes).await?)
+    }
+
+    async fn patch(
+        &self,
+        id: &str,
+        patch: &Value,
+        actor_id: &str,
+    ) -> Result<Facility, DomainError> {
+        let current = self.facility_repo.get_facility_by_id(id).await?;
+
+        let mut merged = apply_merge_patch(&current, patch)?;
+        merged.id = current.id.clone();
+
+        self.update(&merged, actor_id).await?;
+        Ok(self.facility_repo.get_facility_by_id(id).await?)
+    }
+
+    async fn delete(
+        &self,
+        id: &str,
+        move_sessions_to: Option<&str>,
+        actor_id: &str,
+    ) -> Result<(), String> {
+        let before = self.facility_repo.get_facility_by_id(id).await?;
+
+        let sessions = self.schedule_repo.get_schedules_by_facility(id).await?;
+
+        // Las sesiones se mueven y el aula se archiva en la misma transacción
+        let mut changes = ChangeSet::new();
+        if !sessions.is_empty() {
+            // Sin destino no se puede archivar un aula con sesiones programadas
+            let target_id = move_sessions_to.ok_or_else(|| {
+                format!(
+                    "Conflict: facility has {} scheduled sessions, move them before archiving it",
+                    sessions.len()
+                )
+            })?;
+
+            if target_id == id {
+                return Err("Conflict: sessions must be moved to a different facility".to_string());
+            }
+            self.facility_repo.get_facility_by_id(target_id).await?;
+
+            // Validar todas las sesiones antes de mover cualquiera: el aula
+            // destino debe estar libre y cumplir el tipo y equipamiento
+            for session in &sessions {
+                self.allocation_service
+                    .check_requirements(&Schedule {
+                        facility_id: target_id.to_string(),
+                        ..session.clone()
+                    })
+                    .await?;
+
+                let is_available = self
+                    .validation_service
+                    .check_facility_availability(target_id, session)
+                    .await?;
+
+                if !is_available {
+                    return Err(format!(
+                        "Conflict: target facility not available for session {}",
+                        session.id
+                    ));
+                }
+            }
+
+            for session in sessions {
+                let moved = Schedule {
+                    facility_id: target_id.to_string(),
+                    version: session.version + 1,
+                    ..session.clone()
+                };
+                self.audit_service.record(
+                    &mut changes,
+                    actor_id,
+                    SCHEDULE_AUDIT_ENTITY,
+                    &session.id,
+                    AuditAction::Update,
+                    Some(&session),
+                    Some(&moved),
+                )?;
+                changes.update_schedule(moved, Some(session.version));
+            }
+        }
+        changes.archive_facility(id);
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            id,
+            AuditAction::Delete,
+            Some(&before),
+            None,
+        )?;
+
+        self.unit_of_work.commit(changes).await
     }
 
-    async fn delete(&self, id: &str) -> Result<(), String> {
-        self.facility_repo.delete_facility(id).await
+    async fn restore(&self, id: &str, actor_id: &str) -> Result<(), String> {
+        let restored = self.facility_repo.get_archived_facility(id).await?;
+
+        let mut changes = ChangeSet::new();
+        changes.restore_facility(id);
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            id,
+            AuditAction::Restore,
+            None,
+            Some(&restored),
+        )?;
+        self.unit_of_work.commit(changes).await
     }
 
     async fn get_by_course(&self, course_id: &str) -> Result<Facility, String> {
@@ -69,4 +259,50 @@ impl FacilityManagementUseCase for FacilityManagementUseCaseImpl {
     async fn get_by_user(&self, user_id: &str) -> Result<Vec<Facility>, String> {
         self.facility_repo.get_facilities_by_user(user_id).await
     }
+
+    async fn get_available(
+        &self,
+        filter: &AvailabilityFilter,
+    ) -> Result<Vec<FacilityAvailable>, String> {
+        let (opening, closing) = institutional_hours();
+        let from = filter.from.unwrap_or(opening.hour());
+        let to = filter.to.unwrap_or(closing.hour());
+
+        if from >= to || to > 24 {
+            return Err(
+                "Invalid hours range: `from` must be before `to` and within 0-24".to_string(),
+            );
+        }
+
+        if filter.semester.trim().is_empty() {
+            return Err("Invalid semester: must not be empty".to_string());
+        }
+
+        let sessions = self
+            .schedule_repo
+            .get_schedules_by_weekday(&filter.day.to_string())
+            .await?;
+        let sessions = self
+            .validation_service
+            .sessions_in_term(sessions, filter.academic_year, &filter.semester)
+            .await?;
+
+        let available = self
+            .facility_repo
+            .get_all_facilities()
+            .await?
+            .into_iter()
+            .filter(|f| filter.min_capacity.is_none_or(|min| f.capacity >= min))
+            .filter(|f| filter.facility_type.is_none_or(|t| f.facility_type == t))
+            .filter(|f| {
+                filter
+                    .wheelchair_accessible
+                    .is_none_or(|required| !required || f.accessibility.wheelchair_accessible)
+            })
+            .map(|f| FacilityAvailable::from_sessions(&f, filter.day, from, to, &sessions))
+            .filter(|a| !a.hours_range.is_empty())
+            .collect();
+
+        Ok(available)
+    }
 }
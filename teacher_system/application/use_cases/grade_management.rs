@@ -0,0 +1,344 @@
+use crate::domain::{
+    models::{
+        change_set::ChangeSet,
+        course::Course,
+        enums::{AuditAction, EnrollmentStatus},
+        grade::{ComponentGrade, EnrollmentGrades, GradingScheme, MAX_GRADE},
+        validation::{DomainError, ValidationError},
+    },
+    repositories::{
+        course_repository::CourseRepository, enrollment_repository::EnrollmentRepository,
+        grade_repository::GradeRepository, unit_of_work_repository::UnitOfWorkRepository,
+        user_repository::UserRepository,
+    },
+    services::{
+        audit_service::DefaultAuditService, student_status_service::DefaultStudentStatusService,
+    },
+};
+use async_trait::async_trait;
+
+const SCHEME_AUDIT_ENTITY: &str = "grading_scheme";
+const GRADE_AUDIT_ENTITY: &str = "component_grade";
+
+#[async_trait]
+pub trait GradeManagementUseCase {
+    async fn get_scheme(&self, course_id: &str) -> Result<GradingScheme, String>;
+    async fn set_scheme(
+        &self,
+        scheme: GradingScheme,
+        actor_id: &str,
+    ) -> Result<GradingScheme, DomainError>;
+    async fn record_grades(
+        &self,
+        course_id: &str,
+        component: &str,
+        entries: Vec<(String, f64)>,
+        actor_id: &str,
+    ) -> Result<Vec<EnrollmentGrades>, String>;
+    async fn get_course_grades(&self, course_id: &str) -> Result<Vec<EnrollmentGrades>, String>;
+    async fn close_course(
+        &self,
+        course_id: &str,
+        actor_id: &str,
+    ) -> Result<Vec<EnrollmentGrades>, DomainError>;
+}
+
+pub struct GradeManagementUseCaseImpl {
+    grade_repo: Box<dyn GradeRepository + Send + Sync>,
+    course_repo: Box<dyn CourseRepository + Send + Sync>,
+    enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+    status_service: DefaultStudentStatusService,
+    audit_service: DefaultAuditService,
+}
+
+impl GradeManagementUseCaseImpl {
+    pub fn new(
+        grade_repo: Box<dyn GradeRepository + Send + Sync>,
+        course_repo: Box<dyn CourseRepository + Send + Sync>,
+        enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+        status_service: DefaultStudentStatusService,
+        audit_service: DefaultAuditService,
+    ) -> Self {
+        Self {
+            grade_repo,
+            course_repo,
+            enrollment_repo,
+            user_repo,
+            unit_of_work,
+            status_service,
+            audit_service,
+        }
+    }
+
+    async fn check_can_grade(&self, course: &Course, actor_id: &str) -> Result<(), String> {
+        if course.teacher_id == actor_id {
+            return Ok(());
+        }
+
+        let actor = self.user_repo.get_user_by_id(actor_id).await?;
+        if actor.is_some_and(|u| u.is_coordinator()) {
+            Ok(())
+        } else {
+            Err("Forbidden: only the course teacher can record grades".to_string())
+        }
+    }
+
+    /// Un curso cerrado ya no admite cambios de notas ni de esquema.
+    async fn check_open(&self, course: &Course) -> Result<(), String> {
+        let closed = self
+            .enrollment_repo
+            .get_enrollments_by_course(&course.id)
+            .await?
+            .iter()
+            .any(|e| matches!(e.status, EnrollmentStatus::Completed | EnrollmentStatus::Failed));
+
+        if closed {
+            Err(format!("Conflict: {} is already closed", course.code))
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn require_scheme(&self, course: &Course) -> Result<GradingScheme, String> {
+        self.grade_repo
+            .get_scheme(&course.id)
+            .await?
+            .ok_or_else(|| format!("Conflict: {} has no grading scheme", course.code))
+    }
+}
+
+#[async_trait]
+impl GradeManagementUseCase for GradeManagementUseCaseImpl {
+    async fn get_scheme(&self, course_id: &str) -> Result<GradingScheme, String> {
+        self.grade_repo
+            .get_scheme(course_id)
+            .await?
+            .ok_or_else(|| "Grading scheme not found".to_string())
+    }
+
+    /// No se puede quitar una evaluación que ya tiene notas registradas.
+    async fn set_scheme(
+        &self,
+        scheme: GradingScheme,
+        actor_id: &str,
+    ) -> Result<GradingScheme, DomainError> {
+        let course = self.course_repo.get_course_by_id(&scheme.course_id).await?;
+        self.check_can_grade(&course, actor_id).await?;
+        self.check_open(&course).await?;
+        scheme.validate()?;
+
+        let grades = self.grade_repo.get_grades_by_course(&course.id).await?;
+        if let Some(grade) = grades.iter().find(|g| scheme.component(&g.component).is_none()) {
+            return Err(format!(
+                "Conflict: {} already has grades recorded",
+                grade.component
+            )
+            .into());
+        }
+
+        let before = self.grade_repo.get_scheme(&course.id).await?;
+        let mut changes = ChangeSet::new();
+        changes.save_grading_scheme(scheme.clone());
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            SCHEME_AUDIT_ENTITY,
+            &course.id,
+            if before.is_some() {
+                AuditAction::Update
+            } else {
+                AuditAction::Create
+            },
+            before.as_ref(),
+            Some(&scheme),
+        )?;
+        self.unit_of_work.commit(changes).await?;
+
+        Ok(self.get_scheme(&course.id).await?)
+    }
+
+    async fn record_grades(
+        &self,
+        course_id: &str,
+        component: &str,
+        entries: Vec<(String, f64)>,
+        actor_id: &str,
+    ) -> Result<Vec<EnrollmentGrades>, String> {
+        let course = self.course_repo.get_course_by_id(course_id).await?;
+        self.check_can_grade(&course, actor_id).await?;
+        self.check_open(&course).await?;
+
+        let scheme = self.require_scheme(&course).await?;
+        let component = scheme
+            .component(component)
+            .ok_or_else(|| format!("Invalid component: {} is not in the grading scheme", component))?;
+
+        if let Some((_, score)) = entries.iter().find(|(_, s)| !(0.0..=MAX_GRADE).contains(s)) {
+            return Err(format!(
+                "Invalid score: {} is not between 0 and {}",
+                score, MAX_GRADE
+            ));
+        }
+
+        let enrolled: Vec<String> = self
+            .enrollment_repo
+            .get_enrollments_by_course(&course.id)
+            .await?
+            .into_iter()
+            .filter(|e| matches!(e.status, EnrollmentStatus::Enrolled))
+            .map(|e| e.id)
+            .collect();
+
+        if let Some((enrollment_id, _)) = entries.iter().find(|(id, _)| !enrolled.contains(id)) {
+            return Err(format!(
+                "Invalid enrollment: {} is not active in {}",
+                enrollment_id, course.code
+            ));
+        }
+
+        let grades: Vec<ComponentGrade> = entries
+            .into_iter()
+            .map(|(enrollment_id, score)| ComponentGrade {
+                enrollment_id,
+                component: component.name.clone(),
+                score,
+                recorded_by: actor_id.to_string(),
+                recorded_at: None,
+            })
+            .collect();
+
+        // Cada nota se audita con la que reemplaza, y todas se guardan juntas
+        let previous = self.grade_repo.get_grades_by_course(&course.id).await?;
+        let mut changes = ChangeSet::new();
+        changes.save_grades(grades.clone());
+        for grade in &grades {
+            let before = previous
+                .iter()
+                .find(|g| g.enrollment_id == grade.enrollment_id && g.component == grade.component);
+            self.audit_service.record(
+                &mut changes,
+                actor_id,
+                GRADE_AUDIT_ENTITY,
+                &format!("{}/{}", grade.enrollment_id, grade.component),
+                if before.is_some() {
+                    AuditAction::Update
+                } else {
+                    AuditAction::Create
+                },
+                before,
+                Some(grade),
+            )?;
+        }
+        self.unit_of_work.commit(changes).await?;
+
+        self.get_course_grades(&course.id).await
+    }
+
+    /// Libreta del curso: sin esquema el promedio acumulado queda en cero.
+    async fn get_course_grades(&self, course_id: &str) -> Result<Vec<EnrollmentGrades>, String> {
+        let scheme = self.grade_repo.get_scheme(course_id).await?;
+        let grades = self.grade_repo.get_grades_by_course(course_id).await?;
+        let enrollments = self.enrollment_repo.get_enrollments_by_course(course_id).await?;
+
+        Ok(enrollments
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    e.status,
+                    EnrollmentStatus::Enrolled | EnrollmentStatus::Completed | EnrollmentStatus::Failed
+                )
+            })
+            .map(|enrollment| {
+                let own: Vec<&ComponentGrade> = grades
+                    .iter()
+                    .filter(|g| g.enrollment_id == enrollment.id)
+                    .collect();
+                let running_grade = scheme.as_ref().map_or(0.0, |s| s.final_grade(&own));
+
+                EnrollmentGrades {
+                    enrollment_id: enrollment.id,
+                    student_id: enrollment.student_id,
+                    status: enrollment.status,
+                    components: own.into_iter().cloned().collect(),
+                    running_grade,
+                    final_grade: enrollment.final_grade,
+                }
+            })
+            .collect())
+    }
+
+    /// Fija la nota final de cada matrícula activa y la pasa a `Completed` o
+    /// `Failed` según la nota aprobatoria del esquema, todas en una sola
+    /// transacción. Si a algún alumno le falta una evaluación no se cierra
+    /// nada. Luego reevalúa el estado académico de los alumnos; si falla, el
+    /// cierre se mantiene y el error queda en el log.
+    async fn close_course(
+        &self,
+        course_id: &str,
+        actor_id: &str,
+    ) -> Result<Vec<EnrollmentGrades>, DomainError> {
+        let course = self.course_repo.get_course_by_id(course_id).await?;
+        self.check_can_grade(&course, actor_id).await?;
+        self.check_open(&course).await?;
+        let scheme = self.require_scheme(&course).await?;
+
+        let enrolled: Vec<_> = self
+            .enrollment_repo
+            .get_enrollments_by_course(&course.id)
+            .await?
+            .into_iter()
+            .filter(|e| matches!(e.status, EnrollmentStatus::Enrolled))
+            .collect();
+
+        if enrolled.is_empty() {
+            return Err(format!(
+                "Conflict: {} has no enrollments in progress",
+                course.code
+            )
+            .into());
+        }
+
+        let grades = self.grade_repo.get_grades_by_course(&course.id).await?;
+        let mut missing = ValidationError::default();
+        let mut changes = ChangeSet::new();
+        for enrollment in &enrolled {
+            let own: Vec<&ComponentGrade> = grades
+                .iter()
+                .filter(|g| g.enrollment_id == enrollment.id)
+                .collect();
+            for component in scheme.missing_components(&own) {
+                missing.add(
+                    "grades",
+                    &format!("{} has no {} grade", enrollment.student_id, component),
+                );
+            }
+
+            let final_grade = scheme.final_grade(&own);
+            let status = if scheme.is_passing(final_grade) {
+                EnrollmentStatus::Completed
+            } else {
+                EnrollmentStatus::Failed
+            };
+            changes.record_enrollment_result(&enrollment.id, status, final_grade);
+        }
+        missing.into_result()?;
+        self.unit_of_work.commit(changes).await?;
+
+        // El cierre ya está guardado; si la evaluación falla se registra y
+        // puede repetirse con la evaluación del semestre
+        let students: Vec<String> = enrolled.iter().map(|e| e.student_id.clone()).collect();
+        if let Err(e) = self.status_service.evaluate_all(&students, actor_id).await {
+            log::error!(
+                "Student status not evaluated after closing {}: {}",
+                course.code,
+                e
+            );
+        }
+
+        Ok(self.get_course_grades(&course.id).await?)
+    }
+}
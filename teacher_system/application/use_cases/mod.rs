@@ -1,4 +1,20 @@
+pub mod attendance_management;
+pub mod audit_management;
+pub mod booking_management;
 pub mod course_management;
+pub mod credit_load_management;
+pub mod demand_forecast_management;
+pub mod enrollment_management;
 pub mod facility_management;
+pub mod grade_management;
+pub mod notification_management;
 pub mod schedule_management;
+pub mod section_management;
+pub mod session_exception_management;
+pub mod student_status_management;
+pub mod study_plan_management;
+pub mod teacher_assignment_management;
+pub mod teaching_load_management;
+pub mod transcript_management;
 pub mod user_management;
+pub mod webhook_management;
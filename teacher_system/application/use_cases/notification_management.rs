@@ -0,0 +1,66 @@
+use crate::domain::{
+    models::{notification::NotificationPreference, validation::DomainError},
+    repositories::{
+        notification_preference_repository::NotificationPreferenceRepository,
+        user_repository::UserRepository,
+    },
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait NotificationManagementUseCase {
+    async fn get_preference(&self, user_id: &str) -> Result<NotificationPreference, String>;
+    async fn update_preference(
+        &self,
+        preference: &NotificationPreference,
+    ) -> Result<NotificationPreference, DomainError>;
+}
+
+pub struct NotificationManagementUseCaseImpl {
+    preference_repo: Box<dyn NotificationPreferenceRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+}
+
+impl NotificationManagementUseCaseImpl {
+    pub fn new(
+        preference_repo: Box<dyn NotificationPreferenceRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            preference_repo,
+            user_repo,
+        }
+    }
+
+    async fn ensure_user(&self, user_id: &str) -> Result<(), String> {
+        self.user_repo
+            .get_user_by_id(user_id)
+            .await?
+            .map(|_| ())
+            .ok_or_else(|| "User not found".to_string())
+    }
+}
+
+#[async_trait]
+impl NotificationManagementUseCase for NotificationManagementUseCaseImpl {
+    async fn get_preference(&self, user_id: &str) -> Result<NotificationPreference, String> {
+        self.ensure_user(user_id).await?;
+
+        Ok(self
+            .preference_repo
+            .get_preference(user_id)
+            .await?
+            .unwrap_or_else(|| NotificationPreference::default_for(user_id)))
+    }
+
+    async fn update_preference(
+        &self,
+        preference: &NotificationPreference,
+    ) -> Result<NotificationPreference, DomainError> {
+        preference.validate()?;
+        self.ensure_user(&preference.user_id).await?;
+
+        self.preference_repo.save_preference(preference).await?;
+        Ok(self.get_preference(&preference.user_id).await?)
+    }
+}
@@ -1,42 +1,137 @@
 use crate::domain::{
-    models::{enums::Weekday, schedule::Schedule},
-    repositories::schedule_repository::ScheduleRepository,
+    models::{
+        change_set::ChangeSet,
+        conflict::{ScheduleConflict, TimetableIssue},
+        enums::{AuditAction, Weekday},
+        event::DomainEvent,
+        facilitie::Facility,
+        schedule::Schedule,
+        weekly_grid::WeeklyGrid,
+        validation::DomainError,
+    },
+    repositories::{
+        course_repository::CourseRepository, schedule_repository::ScheduleRepository,
+        unit_of_work_repository::UnitOfWorkRepository,
+    },
     services::{
+        audit_service::DefaultAuditService, conflict_service::DefaultConflictService,
+        room_allocation_service::DefaultRoomAllocationService,
         scheduling_service::DefaultSchedulingService, validation_service::DefaultValidationService,
     },
 };
 use async_trait::async_trait;
+use serde_json::Value;
+use shared::utils::apply_merge_patch;
+
+const AUDIT_ENTITY: &str = "schedule";
 
 #[async_trait]
 pub trait ScheduleManagementUseCase {
     async fn get_all(&self) -> Result<Vec<Schedule>, String>;
     async fn get_by_id(&self, id: &str) -> Result<Schedule, String>;
-    async fn create(&self, schedule: Schedule) -> Result<(), String>;
-    async fn update(&self, schedule: &Schedule) -> Result<(), String>;
-    async fn delete(&self, id: &str) -> Result<(), String>;
+    async fn create(&self, schedule: Schedule, actor_id: &str) -> Result<Schedule, DomainError>;
+    async fn update(
+        &self,
+        schedule: &Schedule,
+        expected_version: Option<i32>,
+        actor_id: &str,
+    ) -> Result<Schedule, DomainError>;
+    async fn patch(
+        &self,
+        id: &str,
+        patch: &Value,
+        expected_version: Option<i32>,
+        actor_id: &str,
+    ) -> Result<Schedule, DomainError>;
+    async fn delete(&self, id: &str, actor_id: &str) -> Result<(), String>;
     async fn suggest_available_times(
         &self,
         teacher_id: &str,
+        academic_year: i32,
+        semester: &str,
         duration_minutes: i32,
         preferred_days: Vec<Weekday>,
     ) -> Result<Vec<Schedule>, String>;
+    async fn get_course_conflicts(&self, course_id: &str) -> Result<Vec<ScheduleConflict>, String>;
+    async fn get_cycle_conflicts(
+        &self,
+        cycle: i32,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<Vec<ScheduleConflict>, String>;
+    async fn audit_semester(
+        &self,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<Vec<TimetableIssue>, String>;
+    async fn check_curriculum_clashes(
+        &self,
+        schedule: &Schedule,
+    ) -> Result<Vec<ScheduleConflict>, String>;
+    async fn get_cycle_grid(
+        &self,
+        program: &str,
+        cycle: i32,
+        academic_year: i32,
+        semester: &str,
+        section: Option<i32>,
+    ) -> Result<WeeklyGrid, String>;
+    async fn suggest_facilities(&self, schedule: &Schedule) -> Result<Vec<Facility>, String>;
 }
 pub struct ScheduleManagementUseCaseImpl {
     schedule_repo: Box<dyn ScheduleRepository + Send + Sync>,
+    course_repo: Box<dyn CourseRepository + Send + Sync>,
+    unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
     validation_service: DefaultValidationService,
     scheduling_service: DefaultSchedulingService,
+    audit_service: DefaultAuditService,
+    conflict_service: DefaultConflictService,
+    allocation_service: DefaultRoomAllocationService,
 }
 
 impl ScheduleManagementUseCaseImpl {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         schedule_repo: Box<dyn ScheduleRepository + Send + Sync>,
+        course_repo: Box<dyn CourseRepository + Send + Sync>,
+        unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
         validation_service: DefaultValidationService,
         scheduling_service: DefaultSchedulingService,
+        audit_service: DefaultAuditService,
+        conflict_service: DefaultConflictService,
+        allocation_service: DefaultRoomAllocationService,
     ) -> Self {
         Self {
             schedule_repo,
+            course_repo,
+            unit_of_work,
             validation_service,
             scheduling_service,
+            audit_service,
+            conflict_service,
+            allocation_service,
+        }
+    }
+
+    /// El profesor del curso no puede tener otra sesión a esa hora en el
+    /// semestre.
+    async fn check_teacher(&self, schedule: &Schedule) -> Result<(), String> {
+        let course = self
+            .course_repo
+            .get_course_by_id(&schedule.course_id)
+            .await?;
+        if !course.has_teacher() {
+            return Ok(());
+        }
+
+        let teacher_available = self
+            .validation_service
+            .check_teacher_availability(&course, schedule)
+            .await?;
+        if teacher_available {
+            Ok(())
+        } else {
+            Err("Conflict: the teacher already teaches at that time".to_string())
         }
     }
 }
@@ -54,44 +149,233 @@ impl ScheduleManagementUseCase for ScheduleManagementUseCaseImpl {
             .ok_or_else(|| "Schedule not found".to_string())
     }
 
-    async fn create(&self, schedule: Schedule) -> Result<(), String> {
+    async fn create(
+        &self,
+        mut schedule: Schedule,
+        actor_id: &str,
+    ) -> Result<Schedule, DomainError> {
+        // Sin aula indicada se asigna la más ajustada que cumpla los requisitos
+        if schedule.facility_id.trim().is_empty() {
+            let facility = self
+                .allocation_service
+                .candidates(&schedule)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| "Conflict: no facility meets the session requirements".to_string())?;
+            schedule.facility_id = facility.id;
+        }
+
+        schedule.validate()?;
+        self.allocation_service.check_requirements(&schedule).await?;
+
         let is_available = self
             .validation_service
             .check_facility_availability(&schedule.facility_id, &schedule)
             .await?;
 
         if !is_available {
-            return Err("Facility not available at requested time".to_string());
+            return Err("Facility not available at requested time".into());
         }
 
-        self.schedule_repo.create_schedule(&schedule).await
+        self.conflict_service.enforce_clash_policy(&schedule).await?;
+        self.check_teacher(&schedule).await?;
+        self.validation_service.check_teaching_load(&schedule).await?;
+
+        let mut changes = ChangeSet::new();
+        changes.create_schedule(schedule.clone());
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &schedule.id,
+            AuditAction::Create,
+            None,
+            Some(&schedule),
+        )?;
+        changes.publish(DomainEvent::ScheduleChanged {
+            schedule_id: schedule.id.clone(),
+            course_id: schedule.course_id.clone(),
+            action: AuditAction::Create,
+        });
+        self.unit_of_work.commit(changes).await?;
+
+        Ok(self.get_by_id(&schedule.id).await?)
     }
 
-    async fn update(&self, schedule: &Schedule) -> Result<(), String> {
+    async fn update(
+        &self,
+        schedule: &Schedule,
+        expected_version: Option<i32>,
+        actor_id: &str,
+    ) -> Result<Schedule, DomainError> {
+        schedule.validate()?;
+        self.allocation_service.check_requirements(schedule).await?;
+
+        let before = self.get_by_id(&schedule.id).await?;
+
         let is_available = self
             .validation_service
             .check_facility_availability(&schedule.facility_id, schedule)
             .await?;
 
         if !is_available {
-            return Err("Facility not available at requested time".to_string());
+            return Err("Facility not available at requested time".into());
         }
 
-        self.schedule_repo.update_schedule(schedule).await
+        self.conflict_service.enforce_clash_policy(schedule).await?;
+        self.check_teacher(schedule).await?;
+        self.validation_service.check_teaching_load(schedule).await?;
+
+        // Sin versión esperada se bloquea contra la leída: así la auditoría
+        // registra la versión que de verdad se escribe
+        let expected_version = expected_version.unwrap_or(before.version);
+        let mut changes = ChangeSet::new();
+        changes.update_schedule(schedule.clone(), Some(expected_version));
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &schedule.id,
+            AuditAction::Update,
+            Some(&before),
+            Some(&Schedule {
+                version: expected_version + 1,
+                ..schedule.clone()
+            }),
+        )?;
+        changes.publish(DomainEvent::ScheduleChanged {
+            schedule_id: schedule.id.clone(),
+            course_id: schedule.course_id.clone(),
+            action: AuditAction::Update,
+        });
+        self.unit_of_work.commit(changes).await?;
+
+        Ok(self.get_by_id(&schedule.id).await?)
     }
 
-    async fn delete(&self, id: &str) -> Result<(), String> {
-        self.schedule_repo.delete_schedule(id).await
+    async fn patch(
+        &self,
+        id: &str,
+        patch: &Value,
+        expected_version: Option<i32>,
+        actor_id: &str,
+    ) -> Result<Schedule, DomainError> {
+        let current = self.get_by_id(id).await?;
+
+        let mut merged = apply_merge_patch(&current, patch)?;
+        merged.id = current.id.clone();
+        merged.version = current.version;
+
+        // `update` vuelve a validar la disponibilidad del aula con el resultado
+        self.update(
+            &merged,
+            Some(expected_version.unwrap_or(current.version)),
+            actor_id,
+        )
+        .await
+    }
+
+    async fn delete(&self, id: &str, actor_id: &str) -> Result<(), String> {
+        let before = self.get_by_id(id).await?;
+
+        let mut changes = ChangeSet::new();
+        changes.delete_schedule(id);
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            id,
+            AuditAction::Delete,
+            Some(&before),
+            None,
+        )?;
+        changes.publish(DomainEvent::ScheduleChanged {
+            schedule_id: before.id.clone(),
+            course_id: before.course_id.clone(),
+            action: AuditAction::Delete,
+        });
+        self.unit_of_work.commit(changes).await
     }
 
     async fn suggest_available_times(
         &self,
         teacher_id: &str,
+        academic_year: i32,
+        semester: &str,
         duration_minutes: i32,
         preferred_days: Vec<Weekday>,
     ) -> Result<Vec<Schedule>, String> {
         self.scheduling_service
-            .suggest_available_time(teacher_id, duration_minutes, preferred_days)
+            .suggest_available_time(
+                teacher_id,
+                academic_year,
+                semester,
+                duration_minutes,
+                preferred_days,
+            )
             .await
     }
+
+    async fn get_course_conflicts(&self, course_id: &str) -> Result<Vec<ScheduleConflict>, String> {
+        self.conflict_service.course_conflicts(course_id).await
+    }
+
+    async fn get_cycle_conflicts(
+        &self,
+        cycle: i32,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<Vec<ScheduleConflict>, String> {
+        if cycle <= 0 {
+            return Err("Invalid cycle: must be positive".to_string());
+        }
+
+        self.conflict_service.cycle_conflicts(cycle, academic_year, semester).await
+    }
+
+    async fn audit_semester(
+        &self,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<Vec<TimetableIssue>, String> {
+        if semester.trim().is_empty() {
+            return Err("Invalid semester: must not be empty".to_string());
+        }
+
+        self.conflict_service
+            .semester_audit(academic_year, semester)
+            .await
+    }
+
+    async fn check_curriculum_clashes(
+        &self,
+        schedule: &Schedule,
+    ) -> Result<Vec<ScheduleConflict>, String> {
+        self.conflict_service.curriculum_clashes(schedule).await
+    }
+
+    async fn get_cycle_grid(
+        &self,
+        program: &str,
+        cycle: i32,
+        academic_year: i32,
+        semester: &str,
+        section: Option<i32>,
+    ) -> Result<WeeklyGrid, String> {
+        if cycle <= 0 {
+            return Err("Invalid cycle: must be positive".to_string());
+        }
+        if program.trim().is_empty() {
+            return Err("Invalid program: is required".to_string());
+        }
+
+        self.conflict_service
+            .cycle_grid(program.trim(), cycle, academic_year, semester, section)
+            .await
+    }
+
+    async fn suggest_facilities(&self, schedule: &Schedule) -> Result<Vec<Facility>, String> {
+        self.allocation_service.candidates(schedule).await
+    }
 }
@@ -0,0 +1,544 @@
+use crate::domain::{
+    models::{
+        attendance::AttendanceFilter,
+        change_set::ChangeSet,
+        course::Course,
+        enums::{AuditAction, EnrollmentStatus},
+        event::DomainEvent,
+        schedule::Schedule,
+        section::{
+            SectionMerge, SectionMergeResult, SectionSplit, SectionSplitResult, TermClone,
+            TermCloneResult, TermTimetable,
+        },
+        validation::DomainError,
+    },
+    repositories::{
+        attendance_repository::AttendanceRepository, course_repository::CourseRepository,
+        enrollment_repository::EnrollmentRepository,
+        unit_of_work_repository::UnitOfWorkRepository, user_repository::UserRepository,
+    },
+    services::{audit_service::DefaultAuditService, section_service::DefaultSectionService},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const AUDIT_ENTITY: &str = "course";
+const SCHEDULE_AUDIT_ENTITY: &str = "schedule";
+
+#[async_trait]
+pub trait SectionManagementUseCase {
+    async fn clone_term(&self, request: TermClone, actor_id: &str) -> Result<TermCloneResult, String>;
+    async fn split(
+        &self,
+        course_id: &str,
+        split: SectionSplit,
+        actor_id: &str,
+    ) -> Result<SectionSplitResult, DomainError>;
+    async fn merge(
+        &self,
+        course_id: &str,
+        merge: SectionMerge,
+        actor_id: &str,
+    ) -> Result<SectionMergeResult, DomainError>;
+}
+
+pub struct SectionManagementUseCaseImpl {
+    course_repo: Box<dyn CourseRepository + Send + Sync>,
+    enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+    attendance_repo: Box<dyn AttendanceRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+    section_service: DefaultSectionService,
+    audit_service: DefaultAuditService,
+}
+
+impl SectionManagementUseCaseImpl {
+    pub fn new(
+        course_repo: Box<dyn CourseRepository + Send + Sync>,
+        enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+        attendance_repo: Box<dyn AttendanceRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+        section_service: DefaultSectionService,
+        audit_service: DefaultAuditService,
+    ) -> Self {
+        Self {
+            course_repo,
+            enrollment_repo,
+            attendance_repo,
+            user_repo,
+            unit_of_work,
+            section_service,
+            audit_service,
+        }
+    }
+
+    async fn check_supervisor(&self, actor_id: &str) -> Result<(), String> {
+        let actor = self.user_repo.get_user_by_id(actor_id).await?;
+        if actor.is_some_and(|u| u.is_coordinator()) {
+            Ok(())
+        } else {
+            Err("Forbidden: only coordinators can manage sections".to_string())
+        }
+    }
+
+    /// Agrega la sesión nueva al `ChangeSet` con su registro de auditoría y
+    /// el evento que avisa del cambio de horario.
+    fn create_session(
+        &self,
+        changes: &mut ChangeSet,
+        session: &Schedule,
+        actor_id: &str,
+    ) -> Result<(), String> {
+        changes.create_schedule(session.clone());
+        self.audit_service.record(
+            changes,
+            actor_id,
+            SCHEDULE_AUDIT_ENTITY,
+            &session.id,
+            AuditAction::Create,
+            None,
+            Some(session),
+        )?;
+        changes.publish(DomainEvent::ScheduleChanged {
+            schedule_id: session.id.clone(),
+            course_id: session.course_id.clone(),
+            action: AuditAction::Create,
+        });
+        Ok(())
+    }
+
+    /// Aplica la regla del contrato de cada profesor de `course_ids` a sus
+    /// sesiones nuevas, sumadas a las demás que dicta en el semestre.
+    async fn check_teaching_load(
+        &self,
+        timetable: &TermTimetable,
+        course_ids: &[String],
+    ) -> Result<(), String> {
+        let mut teacher_ids: Vec<&str> = course_ids
+            .iter()
+            .filter_map(|id| timetable.courses.get(id))
+            .filter(|c| c.has_teacher())
+            .map(|c| c.teacher_id.as_str())
+            .collect();
+        teacher_ids.sort();
+        teacher_ids.dedup();
+
+        for teacher_id in teacher_ids {
+            let Some(teacher) = self.user_repo.get_user_by_id(teacher_id).await? else {
+                continue;
+            };
+            let Some(rule) = self.section_service.load_policy().rule_for(&teacher) else {
+                continue;
+            };
+            let (sessions, others) = timetable.teacher_sessions(teacher_id, course_ids);
+            rule.check_sessions(&sessions, &others)?;
+        }
+        Ok(())
+    }
+
+    /// Una sección con notas cerradas o asistencia tomada ya no se junta.
+    async fn check_mergeable(&self, course: &Course) -> Result<(), String> {
+        let closed = self
+            .enrollment_repo
+            .get_enrollments_by_course(&course.id)
+            .await?
+            .iter()
+            .any(|e| matches!(e.status, EnrollmentStatus::Completed | EnrollmentStatus::Failed));
+        if closed {
+            return Err(format!(
+                "Conflict: section {} of {} is already closed",
+                course.section, course.code
+            ));
+        }
+
+        let attendance = self
+            .attendance_repo
+            .get_records(&AttendanceFilter {
+                course_id: Some(course.id.clone()),
+                student_id: None,
+                schedule_id: None,
+                session_date: None,
+            })
+            .await?;
+        if !attendance.is_empty() {
+            return Err(format!(
+                "Conflict: section {} of {} already has attendance recorded",
+                course.section, course.code
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SectionManagementUseCase for SectionManagementUseCaseImpl {
+    /// Crea en el semestre destino cada sección que aún no existe, con las
+    /// mismas sesiones, profesor y aula, en una sola transacción. Nada se
+    /// guarda si alguna choca.
+    async fn clone_term(&self, request: TermClone, actor_id: &str) -> Result<TermCloneResult, String> {
+        self.check_supervisor(actor_id).await?;
+
+        if (request.from_academic_year, request.from_semester.as_str())
+            == (request.to_academic_year, request.to_semester.as_str())
+        {
+            return Err("Invalid term: source and target terms are the same".to_string());
+        }
+
+        // Un programa vacío equivale a no filtrar por programa
+        let program = request
+            .program
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty());
+        let source_term = self
+            .section_service
+            .timetable(request.from_academic_year, &request.from_semester)
+            .await?;
+        let mut sources: Vec<&Course> = source_term
+            .courses
+            .values()
+            .filter(|c| program.is_none_or(|p| c.program() == Some(p)))
+            .collect();
+        if sources.is_empty() {
+            return Err(format!(
+                "Term not found: there are no courses in {} {}",
+                request.from_academic_year, request.from_semester
+            ));
+        }
+        sources.sort_by(|a, b| (&a.code, a.section).cmp(&(&b.code, b.section)));
+
+        let mut timetable = self
+            .section_service
+            .timetable(request.to_academic_year, &request.to_semester)
+            .await?;
+
+        let mut planned: Vec<(Course, Vec<Schedule>)> = Vec::new();
+        let mut skipped: Vec<String> = Vec::new();
+        for source in sources {
+            if timetable.find_section(&source.code, source.section).is_some() {
+                skipped.push(format!("{}-{}", source.code, source.section));
+                continue;
+            }
+
+            let (course, sessions) = request.copy(source, source_term.sessions_of(&source.id));
+
+            timetable.add(course.clone(), sessions.clone());
+            planned.push((course, sessions));
+        }
+
+        let new_ids: Vec<String> = planned.iter().map(|(c, _)| c.id.clone()).collect();
+        timetable.check(&new_ids)?;
+        self.check_teaching_load(&timetable, &new_ids).await?;
+
+        let mut changes = ChangeSet::new();
+        let mut sessions_created = 0;
+        for (course, sessions) in &planned {
+            changes.create_course(course.clone());
+            self.audit_service.record(
+                &mut changes,
+                actor_id,
+                AUDIT_ENTITY,
+                &course.id,
+                AuditAction::Create,
+                None,
+                Some(course),
+            )?;
+            for session in sessions {
+                self.create_session(&mut changes, session, actor_id)?;
+                sessions_created += 1;
+            }
+        }
+        self.unit_of_work.commit(changes).await?;
+
+        let mut created = Vec::new();
+        for (course, _) in planned {
+            created.push(self.course_repo.get_course_by_id(&course.id).await?);
+        }
+
+        Ok(TermCloneResult {
+            created,
+            sessions_created,
+            skipped,
+        })
+    }
+
+    /// La sección nueva toma el siguiente número del curso. Sin sesiones
+    /// indicadas copia las de origen; las que no traen aula reciben la más
+    /// chica libre en el semestre. Todo se guarda en una sola transacción.
+    async fn split(
+        &self,
+        course_id: &str,
+        split: SectionSplit,
+        actor_id: &str,
+    ) -> Result<SectionSplitResult, DomainError> {
+        self.check_supervisor(actor_id).await?;
+
+        let source = self.course_repo.get_course_by_id(course_id).await?;
+        let enrollments = self.enrollment_repo.get_enrollments_by_course(&source.id).await?;
+        let active: Vec<_> = enrollments
+            .iter()
+            .filter(|e| matches!(e.status, EnrollmentStatus::Enrolled | EnrollmentStatus::Pending))
+            .collect();
+
+        if let Some(id) = split
+            .enrollment_ids
+            .iter()
+            .find(|id| !active.iter().any(|e| &e.id == *id))
+        {
+            return Err(
+                format!("Invalid enrollment: {} is not active in {}", id, source.code).into(),
+            );
+        }
+        if split.enrollment_ids.len() >= active.len() {
+            return Err(format!(
+                "Invalid split: at least one enrollment must stay in section {}",
+                source.section
+            )
+            .into());
+        }
+
+        let moved_enrolled = active
+            .iter()
+            .filter(|e| {
+                split.enrollment_ids.contains(&e.id) && matches!(e.status, EnrollmentStatus::Enrolled)
+            })
+            .count() as i32;
+
+        if let Some(teacher_id) = &split.teacher_id {
+            let teacher = self.user_repo.get_user_by_id(teacher_id).await?;
+            if !teacher.is_some_and(|u| u.is_teacher()) {
+                return Err(
+                    format!("Invalid teacher: {} is not a registered teacher", teacher_id).into(),
+                );
+            }
+        }
+
+        let mut timetable = self
+            .section_service
+            .timetable(source.academic_year, &source.semester)
+            .await?;
+
+        let section = Course {
+            id: Uuid::new_v4().to_string(),
+            section: timetable.next_section(&source.code),
+            teacher_id: split.teacher_id.clone().unwrap_or_else(|| source.teacher_id.clone()),
+            capacity: split.capacity.unwrap_or(source.capacity),
+            enrolled: moved_enrolled,
+            version: 0,
+            ..source.clone()
+        };
+        section.validate()?;
+
+        let copied = split.sessions.is_empty();
+        let templates = if copied {
+            timetable.sessions_of(&source.id)
+        } else {
+            split.sessions
+        };
+
+        timetable.add(section.clone(), Vec::new());
+        let mut sessions = Vec::new();
+        for template in templates {
+            let mut session = Schedule {
+                id: Uuid::new_v4().to_string(),
+                course_id: section.id.clone(),
+                created_at: None,
+                version: 0,
+                ..template
+            };
+
+            // Las sesiones copiadas no pueden compartir aula con las de origen
+            if copied || session.facility_id.trim().is_empty() {
+                session.facility_id = timetable
+                    .free_facility(&session, section.capacity)
+                    .map(|f| f.id.clone())
+                    .ok_or_else(|| {
+                        format!(
+                            "Conflict: no facility is free on {:?} {}-{} for the new section",
+                            session.day,
+                            session.start_time.format("%H:%M"),
+                            session.end_time.format("%H:%M")
+                        )
+                    })?;
+            }
+            session.validate()?;
+
+            timetable.sessions.push(session.clone());
+            sessions.push(session);
+        }
+        timetable.check(std::slice::from_ref(&section.id))?;
+        self.check_teaching_load(&timetable, std::slice::from_ref(&section.id))
+            .await?;
+
+        let mut updated_source = source.clone();
+        updated_source.enrolled = (source.enrolled - moved_enrolled).max(0);
+
+        let mut changes = ChangeSet::new();
+        changes.create_course(section.clone());
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &section.id,
+            AuditAction::Create,
+            None,
+            Some(&section),
+        )?;
+        for session in &sessions {
+            self.create_session(&mut changes, session, actor_id)?;
+        }
+        changes.move_enrollments(&split.enrollment_ids, &section.id);
+        changes.update_course(updated_source.clone(), Some(source.version));
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &source.id,
+            AuditAction::Update,
+            Some(&source),
+            Some(&Course {
+                version: source.version + 1,
+                ..updated_source
+            }),
+        )?;
+        self.unit_of_work.commit(changes).await?;
+
+        let updated_source = self.course_repo.get_course_by_id(&source.id).await?;
+        let section = self.course_repo.get_course_by_id(&section.id).await?;
+
+        Ok(SectionSplitResult {
+            source: updated_source,
+            section,
+            sessions,
+            moved: split.enrollment_ids,
+        })
+    }
+
+    /// Pasa todas las matrículas de `section_ids` a `course_id` y archiva esas
+    /// secciones junto con sus sesiones, todo en una sola transacción.
+    async fn merge(
+        &self,
+        course_id: &str,
+        merge: SectionMerge,
+        actor_id: &str,
+    ) -> Result<SectionMergeResult, DomainError> {
+        self.check_supervisor(actor_id).await?;
+        merge.validate(course_id)?;
+
+        let target = self.course_repo.get_course_by_id(course_id).await?;
+        self.check_mergeable(&target).await?;
+
+        let mut sources: Vec<Course> = Vec::new();
+        for id in &merge.section_ids {
+            let source = self.course_repo.get_course_by_id(id).await?;
+            if source.id == target.id
+                || source.code != target.code
+                || source.academic_year != target.academic_year
+                || source.semester != target.semester
+            {
+                return Err(format!(
+                    "Invalid section: {} is not another section of {} in {} {}",
+                    id, target.code, target.academic_year, target.semester
+                )
+                .into());
+            }
+            self.check_mergeable(&source).await?;
+            sources.push(source);
+        }
+
+        let mut moved_ids: Vec<String> = Vec::new();
+        for source in &sources {
+            moved_ids.extend(
+                self.enrollment_repo
+                    .get_enrollments_by_course(&source.id)
+                    .await?
+                    .into_iter()
+                    .map(|e| e.id),
+            );
+        }
+
+        let mut merged = target.clone();
+        merged.capacity = merge.capacity.unwrap_or(target.capacity);
+        merged.enrolled = target.enrolled + sources.iter().map(|s| s.enrolled).sum::<i32>();
+        if merged.enrolled > merged.capacity {
+            return Err(format!(
+                "Conflict: {} enrolled students do not fit in a capacity of {}",
+                merged.enrolled, merged.capacity
+            )
+            .into());
+        }
+        merged.validate()?;
+
+        // Con la nueva capacidad las aulas de la sección deben seguir alcanzando
+        let mut timetable = self
+            .section_service
+            .timetable(target.academic_year, &target.semester)
+            .await?;
+        let mut source_sessions: HashMap<String, Vec<Schedule>> = HashMap::new();
+        for source in &sources {
+            source_sessions.insert(source.id.clone(), timetable.sessions_of(&source.id));
+            timetable.remove(&source.id);
+        }
+        timetable.courses.insert(merged.id.clone(), merged.clone());
+        timetable.check(std::slice::from_ref(&merged.id))?;
+
+        let mut changes = ChangeSet::new();
+        changes.move_enrollments(&moved_ids, &merged.id);
+        changes.update_course(merged.clone(), Some(target.version));
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &merged.id,
+            AuditAction::Update,
+            Some(&target),
+            Some(&Course {
+                version: target.version + 1,
+                ..merged.clone()
+            }),
+        )?;
+
+        let mut archived = Vec::new();
+        for source in sources {
+            for session in source_sessions.remove(&source.id).unwrap_or_default() {
+                changes.delete_schedule(&session.id);
+                self.audit_service.record(
+                    &mut changes,
+                    actor_id,
+                    SCHEDULE_AUDIT_ENTITY,
+                    &session.id,
+                    AuditAction::Delete,
+                    Some(&session),
+                    None,
+                )?;
+                changes.publish(DomainEvent::ScheduleChanged {
+                    schedule_id: session.id.clone(),
+                    course_id: session.course_id.clone(),
+                    action: AuditAction::Delete,
+                });
+            }
+            changes.archive_course(&source.id);
+            self.audit_service.record(
+                &mut changes,
+                actor_id,
+                AUDIT_ENTITY,
+                &source.id,
+                AuditAction::Delete,
+                Some(&source),
+                None,
+            )?;
+            archived.push(source.id);
+        }
+        self.unit_of_work.commit(changes).await?;
+
+        let section = self.course_repo.get_course_by_id(&merged.id).await?;
+
+        Ok(SectionMergeResult {
+            section,
+            moved: moved_ids.len() as i32,
+            archived,
+        })
+    }
+}
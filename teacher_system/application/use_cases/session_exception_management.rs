@@ -0,0 +1,282 @@
+use crate::domain::{
+    models::{
+        booking::FacilityBooking,
+        change_set::ChangeSet,
+        course::Course,
+        enums::{AuditAction, BookingStatus, ExceptionKind},
+        event::DomainEvent,
+        schedule::Schedule,
+        session_exception::{MakeUpSlot, SessionException},
+        validation::DomainError,
+    },
+    repositories::{
+        course_repository::CourseRepository, schedule_repository::ScheduleRepository,
+        session_exception_repository::SessionExceptionRepository,
+        unit_of_work_repository::UnitOfWorkRepository, user_repository::UserRepository,
+    },
+    services::{audit_service::DefaultAuditService, validation_service::DefaultValidationService},
+};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+
+const AUDIT_ENTITY: &str = "session_exception";
+
+#[async_trait]
+pub trait SessionExceptionManagementUseCase {
+    async fn get_by_schedule(&self, schedule_id: &str) -> Result<Vec<SessionException>, String>;
+    async fn cancel_occurrence(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+        reason: &str,
+        actor_id: &str,
+    ) -> Result<SessionException, DomainError>;
+    async fn reschedule_occurrence(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+        reason: &str,
+        make_up: MakeUpSlot,
+        actor_id: &str,
+    ) -> Result<SessionException, DomainError>;
+}
+
+pub struct SessionExceptionManagementUseCaseImpl {
+    exception_repo: Box<dyn SessionExceptionRepository + Send + Sync>,
+    schedule_repo: Box<dyn ScheduleRepository + Send + Sync>,
+    course_repo: Box<dyn CourseRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+    validation_service: DefaultValidationService,
+    audit_service: DefaultAuditService,
+}
+
+impl SessionExceptionManagementUseCaseImpl {
+    pub fn new(
+        exception_repo: Box<dyn SessionExceptionRepository + Send + Sync>,
+        schedule_repo: Box<dyn ScheduleRepository + Send + Sync>,
+        course_repo: Box<dyn CourseRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+        validation_service: DefaultValidationService,
+        audit_service: DefaultAuditService,
+    ) -> Self {
+        Self {
+            exception_repo,
+            schedule_repo,
+            course_repo,
+            user_repo,
+            unit_of_work,
+            validation_service,
+            audit_service,
+        }
+    }
+
+    /// Solo el profesor del curso o un coordinador cambian sus sesiones.
+    async fn check_can_change(&self, course: &Course, actor_id: &str) -> Result<(), String> {
+        if course.teacher_id == actor_id {
+            return Ok(());
+        }
+
+        let actor = self.user_repo.get_user_by_id(actor_id).await?;
+        if actor.is_some_and(|u| u.is_coordinator()) {
+            Ok(())
+        } else {
+            Err("Forbidden: only the course teacher can change its sessions".to_string())
+        }
+    }
+
+    /// Valida la excepción sobre una fecha que aún no tiene otra.
+    async fn check_new(
+        &self,
+        schedule: &Schedule,
+        exception: &SessionException,
+    ) -> Result<(), DomainError> {
+        exception.validate(schedule)?;
+
+        let today = Utc::now().date_naive();
+        if exception.original_date < today {
+            return Err("Invalid date: past sessions cannot be changed".into());
+        }
+
+        let existing = self
+            .exception_repo
+            .get_exception_by_date(&schedule.id, exception.original_date)
+            .await?;
+        if existing.is_some() {
+            return Err(format!(
+                "Conflict: the session on {} already has a cancellation or make-up",
+                exception.original_date
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    async fn load_schedule(&self, schedule_id: &str) -> Result<(Schedule, Course), String> {
+        let schedule = self
+            .schedule_repo
+            .get_schedule_by_id(schedule_id)
+            .await?
+            .ok_or_else(|| "Schedule not found".to_string())?;
+        let course = self
+            .course_repo
+            .get_course_by_id(&schedule.course_id)
+            .await?;
+
+        Ok((schedule, course))
+    }
+}
+
+#[async_trait]
+impl SessionExceptionManagementUseCase for SessionExceptionManagementUseCaseImpl {
+    async fn get_by_schedule(&self, schedule_id: &str) -> Result<Vec<SessionException>, String> {
+        self.exception_repo
+            .get_exceptions_by_schedule(schedule_id)
+            .await
+    }
+
+    async fn cancel_occurrence(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+        reason: &str,
+        actor_id: &str,
+    ) -> Result<SessionException, DomainError> {
+        let (schedule, course) = self.load_schedule(schedule_id).await?;
+        self.check_can_change(&course, actor_id).await?;
+
+        let exception = SessionException {
+            id: uuid::Uuid::new_v4().to_string(),
+            schedule_id: schedule.id.clone(),
+            original_date: date,
+            kind: ExceptionKind::Cancelled,
+            reason: reason.to_string(),
+            make_up: None,
+            created_by: actor_id.to_string(),
+            created_at: None,
+        };
+        self.check_new(&schedule, &exception).await?;
+
+        let mut changes = ChangeSet::new();
+        changes.create_session_exception(exception.clone());
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &exception.id,
+            AuditAction::Create,
+            None,
+            Some(&exception),
+        )?;
+        changes.publish(DomainEvent::ClassCancelled {
+            schedule_id: schedule.id.clone(),
+            course_id: course.id.clone(),
+            date,
+            reason: reason.to_string(),
+        });
+        self.unit_of_work.commit(changes).await?;
+
+        Ok(exception)
+    }
+
+    async fn reschedule_occurrence(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+        reason: &str,
+        mut make_up: MakeUpSlot,
+        actor_id: &str,
+    ) -> Result<SessionException, DomainError> {
+        let (schedule, course) = self.load_schedule(schedule_id).await?;
+        self.check_can_change(&course, actor_id).await?;
+
+        if make_up.date < Utc::now().date_naive() {
+            return Err("Invalid date: make-up sessions cannot be in the past".into());
+        }
+        if make_up.facility_id.trim().is_empty() {
+            make_up.facility_id = schedule.facility_id.clone();
+        }
+
+        let mut exception = SessionException {
+            id: uuid::Uuid::new_v4().to_string(),
+            schedule_id: schedule.id.clone(),
+            original_date: date,
+            kind: ExceptionKind::Rescheduled,
+            reason: reason.to_string(),
+            make_up: Some(make_up.clone()),
+            created_by: actor_id.to_string(),
+            created_at: None,
+        };
+        exception.validate(&schedule)?;
+
+        // El profesor no puede tener otra sesión, recuperación o reserva a esa hora
+        let teacher_available = self
+            .validation_service
+            .check_teacher_make_up(&course, &schedule, &exception)
+            .await?;
+
+        if !teacher_available {
+            return Err("Teacher not available at requested time".into());
+        }
+
+        // El aula se ocupa con una reserva para esa fecha; solo un coordinador
+        // la deja aprobada, si no queda pendiente de revisión
+        let actor = self.user_repo.get_user_by_id(actor_id).await?;
+        let reviewed_by = actor.filter(|u| u.is_coordinator()).map(|u| u.id);
+        let booking = FacilityBooking {
+            id: uuid::Uuid::new_v4().to_string(),
+            facility_id: make_up.facility_id.clone(),
+            requested_by: actor_id.to_string(),
+            purpose: format!("Make-up session of {} ({})", course.code, date),
+            date: make_up.date,
+            start_time: make_up.start_time,
+            end_time: make_up.end_time,
+            status: if reviewed_by.is_some() {
+                BookingStatus::Approved
+            } else {
+                BookingStatus::Pending
+            },
+            reviewed_by,
+            created_at: None,
+        };
+        booking.validate()?;
+
+        let facility_available = self
+            .validation_service
+            .check_make_up_availability(&booking, &exception)
+            .await?;
+
+        if !facility_available {
+            return Err("Facility not available at requested time".into());
+        }
+
+        make_up.booking_id = Some(booking.id.clone());
+        exception.make_up = Some(make_up.clone());
+        self.check_new(&schedule, &exception).await?;
+
+        let mut changes = ChangeSet::new();
+        changes.create_booking(booking);
+        changes.create_session_exception(exception.clone());
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &exception.id,
+            AuditAction::Create,
+            None,
+            Some(&exception),
+        )?;
+        changes.publish(DomainEvent::ClassRescheduled {
+            schedule_id: schedule.id.clone(),
+            course_id: course.id.clone(),
+            date,
+            reason: reason.to_string(),
+            make_up,
+        });
+        self.unit_of_work.commit(changes).await?;
+
+        Ok(exception)
+    }
+}
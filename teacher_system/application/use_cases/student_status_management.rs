@@ -0,0 +1,128 @@
+use crate::domain::{
+    models::{
+        enums::EnrollmentStatus,
+        student_status::{StatusChange, StatusRuleSet},
+    },
+    repositories::{
+        course_repository::CourseRepository, enrollment_repository::EnrollmentRepository,
+        student_status_repository::StudentStatusRepository, user_repository::UserRepository,
+    },
+    services::student_status_service::DefaultStudentStatusService,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait StudentStatusManagementUseCase {
+    fn get_rules(&self) -> StatusRuleSet;
+    async fn get_history(&self, student_id: &str) -> Result<Vec<StatusChange>, String>;
+    async fn evaluate_student(
+        &self,
+        student_id: &str,
+        actor_id: &str,
+    ) -> Result<Option<StatusChange>, String>;
+    async fn evaluate_term(
+        &self,
+        academic_year: i32,
+        semester: &str,
+        actor_id: &str,
+    ) -> Result<Vec<StatusChange>, String>;
+}
+
+pub struct StudentStatusManagementUseCaseImpl {
+    status_repo: Box<dyn StudentStatusRepository + Send + Sync>,
+    course_repo: Box<dyn CourseRepository + Send + Sync>,
+    enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    status_service: DefaultStudentStatusService,
+}
+
+impl StudentStatusManagementUseCaseImpl {
+    pub fn new(
+        status_repo: Box<dyn StudentStatusRepository + Send + Sync>,
+        course_repo: Box<dyn CourseRepository + Send + Sync>,
+        enrollment_repo: Box<dyn EnrollmentRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        status_service: DefaultStudentStatusService,
+    ) -> Self {
+        Self {
+            status_repo,
+            course_repo,
+            enrollment_repo,
+            user_repo,
+            status_service,
+        }
+    }
+
+    async fn check_supervisor(&self, actor_id: &str) -> Result<(), String> {
+        let actor = self.user_repo.get_user_by_id(actor_id).await?;
+        if actor.is_some_and(|u| u.is_coordinator()) {
+            Ok(())
+        } else {
+            Err("Forbidden: only coordinators can evaluate student status".to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl StudentStatusManagementUseCase for StudentStatusManagementUseCaseImpl {
+    fn get_rules(&self) -> StatusRuleSet {
+        self.status_service.rules().clone()
+    }
+
+    async fn get_history(&self, student_id: &str) -> Result<Vec<StatusChange>, String> {
+        self.user_repo
+            .get_user_by_id(student_id)
+            .await?
+            .ok_or_else(|| "Student not found".to_string())?;
+
+        self.status_repo.get_history(student_id).await
+    }
+
+    async fn evaluate_student(
+        &self,
+        student_id: &str,
+        actor_id: &str,
+    ) -> Result<Option<StatusChange>, String> {
+        self.check_supervisor(actor_id).await?;
+        self.status_service.evaluate(student_id, actor_id).await
+    }
+
+    /// Cierre de semestre: evalúa a cada alumno con cursos cerrados en él.
+    async fn evaluate_term(
+        &self,
+        academic_year: i32,
+        semester: &str,
+        actor_id: &str,
+    ) -> Result<Vec<StatusChange>, String> {
+        self.check_supervisor(actor_id).await?;
+
+        let courses: Vec<_> = self
+            .course_repo
+            .get_all_courses_including_archived()
+            .await?
+            .into_iter()
+            .filter(|c| c.in_term(academic_year, semester))
+            .collect();
+        if courses.is_empty() {
+            return Err(format!(
+                "Term not found: no courses in {} {}",
+                academic_year, semester
+            ));
+        }
+
+        let mut students: Vec<String> = Vec::new();
+        for course in &courses {
+            for enrollment in self.enrollment_repo.get_enrollments_by_course(&course.id).await? {
+                let closed = matches!(
+                    enrollment.status,
+                    EnrollmentStatus::Completed | EnrollmentStatus::Failed
+                );
+                if closed && !students.contains(&enrollment.student_id) {
+                    students.push(enrollment.student_id);
+                }
+            }
+        }
+
+        self.status_service.evaluate_all(&students, actor_id).await
+    }
+}
@@ -0,0 +1,146 @@
+use crate::domain::{
+    models::{
+        study_plan::{CycleOffering, PlanProgress, StudyPlan},
+        validation::DomainError,
+    },
+    repositories::{study_plan_repository::StudyPlanRepository, user_repository::UserRepository},
+    services::study_plan_service::DefaultStudyPlanService,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+#[async_trait]
+pub trait StudyPlanManagementUseCase {
+    async fn get_all(&self, program: Option<&str>) -> Result<Vec<StudyPlan>, String>;
+    async fn get_by_id(&self, id: &str) -> Result<StudyPlan, String>;
+    async fn get_prerequisites(
+        &self,
+        plan: &StudyPlan,
+    ) -> Result<HashMap<String, Vec<String>>, String>;
+    async fn create(&self, plan: StudyPlan, actor_id: &str) -> Result<StudyPlan, DomainError>;
+    async fn activate(&self, id: &str, actor_id: &str) -> Result<StudyPlan, String>;
+    async fn get_student_progress(
+        &self,
+        student_id: &str,
+        plan_id: Option<&str>,
+    ) -> Result<PlanProgress, String>;
+    async fn get_offering(&self, id: &str) -> Result<Vec<CycleOffering>, String>;
+}
+
+pub struct StudyPlanManagementUseCaseImpl {
+    plan_repo: Box<dyn StudyPlanRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    study_plan_service: DefaultStudyPlanService,
+}
+
+impl StudyPlanManagementUseCaseImpl {
+    pub fn new(
+        plan_repo: Box<dyn StudyPlanRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        study_plan_service: DefaultStudyPlanService,
+    ) -> Self {
+        Self {
+            plan_repo,
+            user_repo,
+            study_plan_service,
+        }
+    }
+
+    async fn check_reviewer(&self, user_id: &str) -> Result<(), String> {
+        let user = self.user_repo.get_user_by_id(user_id).await?;
+        if user.is_some_and(|u| u.is_coordinator()) {
+            Ok(())
+        } else {
+            Err("Forbidden: only coordinators can manage study plans".to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl StudyPlanManagementUseCase for StudyPlanManagementUseCaseImpl {
+    async fn get_all(&self, program: Option<&str>) -> Result<Vec<StudyPlan>, String> {
+        self.plan_repo.get_plans(program).await
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<StudyPlan, String> {
+        self.plan_repo
+            .get_plan_by_id(id)
+            .await?
+            .ok_or_else(|| "Study plan not found".to_string())
+    }
+
+    async fn get_prerequisites(
+        &self,
+        plan: &StudyPlan,
+    ) -> Result<HashMap<String, Vec<String>>, String> {
+        self.study_plan_service.prerequisites(plan).await
+    }
+
+    /// Cada plan nuevo es la siguiente versión de su programa y no queda
+    /// vigente hasta activarlo.
+    async fn create(&self, mut plan: StudyPlan, actor_id: &str) -> Result<StudyPlan, DomainError> {
+        self.check_reviewer(actor_id).await?;
+        self.study_plan_service.check_plan(&plan).await?;
+
+        let latest = self
+            .plan_repo
+            .get_plans(Some(&plan.program))
+            .await?
+            .iter()
+            .map(|p| p.version)
+            .max()
+            .unwrap_or(0);
+        plan.version = latest + 1;
+        plan.active = false;
+
+        self.plan_repo.create_plan(&plan).await?;
+        Ok(self.get_by_id(&plan.id).await?)
+    }
+
+    async fn activate(&self, id: &str, actor_id: &str) -> Result<StudyPlan, String> {
+        self.check_reviewer(actor_id).await?;
+
+        let plan = self.get_by_id(id).await?;
+        if plan.active {
+            return Err(format!(
+                "Conflict: version {} of {} is already active",
+                plan.version, plan.program
+            ));
+        }
+
+        self.plan_repo.activate_plan(id).await?;
+        self.get_by_id(id).await
+    }
+
+    /// Sin `plan_id` se mide contra la versión vigente del programa del alumno.
+    async fn get_student_progress(
+        &self,
+        student_id: &str,
+        plan_id: Option<&str>,
+    ) -> Result<PlanProgress, String> {
+        let student = self
+            .user_repo
+            .get_user_by_id(student_id)
+            .await?
+            .ok_or_else(|| "Student not found".to_string())?;
+        if !student.is_student() {
+            return Err(format!("Invalid user: {} is not a student", student.code));
+        }
+
+        let plan = match plan_id {
+            Some(plan_id) => self.get_by_id(plan_id).await?,
+            None => self
+                .plan_repo
+                .get_active_plan(&student.program)
+                .await?
+                .ok_or_else(|| format!("Study plan not found for {}", student.program))?,
+        };
+
+        self.study_plan_service.progress(&student.id, &plan).await
+    }
+
+    async fn get_offering(&self, id: &str) -> Result<Vec<CycleOffering>, String> {
+        let plan = self.get_by_id(id).await?;
+        self.study_plan_service.offering(&plan).await
+    }
+}
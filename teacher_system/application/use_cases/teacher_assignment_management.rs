@@ -0,0 +1,224 @@
+use crate::domain::{
+    models::{
+        change_set::ChangeSet,
+        course::Course,
+        enums::{AssignmentStatus, AuditAction},
+        teacher_assignment::{AssignmentFilter, AssignmentProposal},
+    },
+    repositories::{
+        course_repository::CourseRepository,
+        teacher_assignment_repository::TeacherAssignmentRepository,
+        unit_of_work_repository::UnitOfWorkRepository, user_repository::UserRepository,
+    },
+    services::{
+        audit_service::DefaultAuditService,
+        teacher_assignment_service::DefaultTeacherAssignmentService,
+    },
+};
+use async_trait::async_trait;
+
+const AUDIT_ENTITY: &str = "course";
+const PROPOSAL_AUDIT_ENTITY: &str = "teacher_assignment";
+
+#[async_trait]
+pub trait TeacherAssignmentManagementUseCase {
+    async fn propose(
+        &self,
+        academic_year: i32,
+        semester: &str,
+        actor_id: &str,
+    ) -> Result<Vec<AssignmentProposal>, String>;
+    async fn get_all(&self, filter: &AssignmentFilter) -> Result<Vec<AssignmentProposal>, String>;
+    async fn get_by_id(&self, id: &str) -> Result<AssignmentProposal, String>;
+    async fn accept(
+        &self,
+        id: &str,
+        actor_id: &str,
+        note: Option<String>,
+    ) -> Result<AssignmentProposal, String>;
+    async fn override_teacher(
+        &self,
+        id: &str,
+        teacher_id: &str,
+        actor_id: &str,
+        note: Option<String>,
+    ) -> Result<AssignmentProposal, String>;
+}
+
+pub struct TeacherAssignmentManagementUseCaseImpl {
+    assignment_repo: Box<dyn TeacherAssignmentRepository + Send + Sync>,
+    course_repo: Box<dyn CourseRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+    assignment_service: DefaultTeacherAssignmentService,
+    audit_service: DefaultAuditService,
+}
+
+impl TeacherAssignmentManagementUseCaseImpl {
+    pub fn new(
+        assignment_repo: Box<dyn TeacherAssignmentRepository + Send + Sync>,
+        course_repo: Box<dyn CourseRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+        assignment_service: DefaultTeacherAssignmentService,
+        audit_service: DefaultAuditService,
+    ) -> Self {
+        Self {
+            assignment_repo,
+            course_repo,
+            user_repo,
+            unit_of_work,
+            assignment_service,
+            audit_service,
+        }
+    }
+
+    async fn check_reviewer(&self, actor_id: &str) -> Result<(), String> {
+        let actor = self.user_repo.get_user_by_id(actor_id).await?;
+        if actor.is_some_and(|u| u.is_coordinator()) {
+            Ok(())
+        } else {
+            Err("Forbidden: only coordinators can assign teachers".to_string())
+        }
+    }
+
+    /// Asigna el profesor al curso, que debe seguir sin profesor, y cierra la
+    /// propuesta con el estado indicado en una sola transacción.
+    async fn assign(
+        &self,
+        id: &str,
+        teacher_id: &str,
+        status: AssignmentStatus,
+        actor_id: &str,
+        note: Option<String>,
+    ) -> Result<AssignmentProposal, String> {
+        self.check_reviewer(actor_id).await?;
+
+        let proposal = self.get_by_id(id).await?;
+        if proposal.status != AssignmentStatus::Proposed {
+            return Err(format!(
+                "Conflict: assignment proposal is already {}",
+                proposal.status
+            ));
+        }
+
+        let before = self
+            .course_repo
+            .get_course_by_id(&proposal.course_id)
+            .await?;
+        if before.has_teacher() {
+            return Err(format!("Conflict: {} already has a teacher", before.code));
+        }
+        self.assignment_service
+            .check_teacher(&before, teacher_id)
+            .await?;
+
+        let course = Course {
+            teacher_id: teacher_id.to_string(),
+            ..before.clone()
+        };
+
+        let mut changes = ChangeSet::new();
+        changes.update_course(course.clone(), Some(before.version));
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &course.id,
+            AuditAction::Update,
+            Some(&before),
+            Some(&Course {
+                version: before.version + 1,
+                ..course.clone()
+            }),
+        )?;
+        changes.decide_proposal(id, status, teacher_id, actor_id, note);
+        self.unit_of_work.commit(changes).await?;
+
+        self.get_by_id(id).await
+    }
+}
+
+#[async_trait]
+impl TeacherAssignmentManagementUseCase for TeacherAssignmentManagementUseCaseImpl {
+    /// Rehace las propuestas pendientes del semestre con el horario actual.
+    async fn propose(
+        &self,
+        academic_year: i32,
+        semester: &str,
+        actor_id: &str,
+    ) -> Result<Vec<AssignmentProposal>, String> {
+        self.check_reviewer(actor_id).await?;
+        if semester.trim().is_empty() {
+            return Err("Invalid term: semester is required".to_string());
+        }
+
+        let proposals = self
+            .assignment_service
+            .propose(academic_year, semester)
+            .await?;
+
+        let filter = AssignmentFilter {
+            academic_year: Some(academic_year),
+            semester: Some(semester.to_string()),
+            status: Some(AssignmentStatus::Proposed),
+        };
+        let previous = self.assignment_repo.get_proposals(&filter).await?;
+
+        // Las propuestas se reemplazan enteras: si falla la escritura quedan
+        // las anteriores
+        let mut changes = ChangeSet::new();
+        changes.delete_pending_proposals(academic_year, semester);
+        changes.create_proposals(proposals.clone());
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            PROPOSAL_AUDIT_ENTITY,
+            &format!("{}-{}", academic_year, semester),
+            AuditAction::Update,
+            Some(&previous),
+            Some(&proposals),
+        )?;
+        self.unit_of_work.commit(changes).await?;
+
+        self.assignment_repo.get_proposals(&filter).await
+    }
+
+    async fn get_all(&self, filter: &AssignmentFilter) -> Result<Vec<AssignmentProposal>, String> {
+        self.assignment_repo.get_proposals(filter).await
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<AssignmentProposal, String> {
+        self.assignment_repo
+            .get_proposal_by_id(id)
+            .await?
+            .ok_or_else(|| "Assignment proposal not found".to_string())
+    }
+
+    async fn accept(
+        &self,
+        id: &str,
+        actor_id: &str,
+        note: Option<String>,
+    ) -> Result<AssignmentProposal, String> {
+        let proposal = self.get_by_id(id).await?;
+        let teacher_id = proposal.proposed_teacher_id.ok_or_else(|| {
+            "Invalid proposal: no teacher was proposed; choose one instead".to_string()
+        })?;
+
+        self.assign(id, &teacher_id, AssignmentStatus::Accepted, actor_id, note)
+            .await
+    }
+
+    /// El coordinador elige otro profesor; igual se revisan choques y horas.
+    async fn override_teacher(
+        &self,
+        id: &str,
+        teacher_id: &str,
+        actor_id: &str,
+        note: Option<String>,
+    ) -> Result<AssignmentProposal, String> {
+        self.assign(id, teacher_id, AssignmentStatus::Overridden, actor_id, note)
+            .await
+    }
+}
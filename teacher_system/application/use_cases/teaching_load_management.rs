@@ -0,0 +1,82 @@
+use crate::domain::{
+    models::teaching_load::{LoadStatus, TeacherTermLoad, TeachingLoadPolicy},
+    repositories::user_repository::UserRepository,
+    services::validation_service::DefaultValidationService,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait TeachingLoadManagementUseCase {
+    fn get_policy(&self) -> TeachingLoadPolicy;
+    async fn get_report(
+        &self,
+        academic_year: i32,
+        semester: &str,
+        status: Option<LoadStatus>,
+    ) -> Result<Vec<TeacherTermLoad>, String>;
+}
+
+pub struct TeachingLoadManagementUseCaseImpl {
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+    validation_service: DefaultValidationService,
+}
+
+impl TeachingLoadManagementUseCaseImpl {
+    pub fn new(
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        validation_service: DefaultValidationService,
+    ) -> Self {
+        Self {
+            user_repo,
+            validation_service,
+        }
+    }
+}
+
+#[async_trait]
+impl TeachingLoadManagementUseCase for TeachingLoadManagementUseCaseImpl {
+    fn get_policy(&self) -> TeachingLoadPolicy {
+        self.validation_service.load_policy().clone()
+    }
+
+    /// Carga de cada profesor en el semestre frente a la regla de su
+    /// contrato; `status` deja solo a los que están debajo, dentro o encima.
+    async fn get_report(
+        &self,
+        academic_year: i32,
+        semester: &str,
+        status: Option<LoadStatus>,
+    ) -> Result<Vec<TeacherTermLoad>, String> {
+        if semester.trim().is_empty() {
+            return Err("Invalid term: semester is required".to_string());
+        }
+
+        let policy = self.validation_service.load_policy();
+        let mut report = Vec::new();
+        for teacher in self
+            .user_repo
+            .get_all_users()
+            .await?
+            .iter()
+            .filter(|u| u.is_teacher())
+        {
+            let sessions = self
+                .validation_service
+                .term_sessions(&teacher.id, academic_year, semester)
+                .await?;
+            let load = TeacherTermLoad::measure(
+                teacher,
+                policy.rule_for(teacher),
+                academic_year,
+                semester,
+                &sessions,
+            );
+            if status.is_none_or(|s| s == load.status) {
+                report.push(load);
+            }
+        }
+
+        report.sort_by(|a, b| a.full_name.cmp(&b.full_name));
+        Ok(report)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::domain::{
+    models::transcript::Transcript, services::transcript_service::DefaultTranscriptService,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait TranscriptManagementUseCase {
+    async fn get_transcript(&self, student_id: &str) -> Result<Transcript, String>;
+}
+
+pub struct TranscriptManagementUseCaseImpl {
+    transcript_service: DefaultTranscriptService,
+}
+
+impl TranscriptManagementUseCaseImpl {
+    pub fn new(transcript_service: DefaultTranscriptService) -> Self {
+        Self { transcript_service }
+    }
+}
+
+#[async_trait]
+impl TranscriptManagementUseCase for TranscriptManagementUseCaseImpl {
+    async fn get_transcript(&self, student_id: &str) -> Result<Transcript, String> {
+        self.transcript_service.build_transcript(student_id).await
+    }
+}
@@ -1,27 +1,49 @@
 // src/application/usecases/user_management_usecase.rs
 
-use crate::domain::{models::user::User, repositories::user_repository::UserRepository};
+use crate::domain::{
+    models::{change_set::ChangeSet, enums::AuditAction, user::User, validation::DomainError},
+    repositories::{
+        unit_of_work_repository::UnitOfWorkRepository, user_repository::UserRepository,
+    },
+    services::audit_service::DefaultAuditService,
+};
 use async_trait::async_trait;
+use serde_json::Value;
+use shared::utils::apply_merge_patch;
+
+const AUDIT_ENTITY: &str = "user";
 
 #[async_trait]
 pub trait UserManagementUseCase {
     async fn get_all(&self) -> Result<Vec<User>, String>;
     async fn get_by_id(&self, id: &str) -> Result<User, String>;
     async fn get_by_email(&self, email: &str) -> Result<User, String>;
-    async fn create(&self, user: User) -> Result<User, String>;
-    async fn update(&self, user: &User) -> Result<(), String>;
-    async fn delete(&self, id: &str) -> Result<(), String>;
+    async fn create(&self, user: User, actor_id: &str) -> Result<User, DomainError>;
+    async fn update(&self, user: &User, actor_id: &str) -> Result<(), DomainError>;
+    async fn patch(&self, id: &str, patch: &Value, actor_id: &str) -> Result<User, DomainError>;
+    async fn delete(&self, id: &str, actor_id: &str) -> Result<(), String>;
+    async fn restore(&self, id: &str, actor_id: &str) -> Result<(), String>;
     async fn get_by_name(&self, name: &str) -> Result<Vec<User>, String>;
     async fn get_by_course(&self, course_id: &str) -> Result<Vec<User>, String>;
     async fn get_by_facility(&self, facility_id: &str) -> Result<Vec<User>, String>;
 }
 pub struct UserManagementUseCaseImpl {
     user_repo: Box<dyn UserRepository + Send + Sync>,
+    unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+    audit_service: DefaultAuditService,
 }
 
 impl UserManagementUseCaseImpl {
-    pub fn new(user_repo: Box<dyn UserRepository + Send + Sync>) -> Self {
-        Self { user_repo }
+    pub fn new(
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+        unit_of_work: Box<dyn UnitOfWorkRepository + Send + Sync>,
+        audit_service: DefaultAuditService,
+    ) -> Self {
+        Self {
+            user_repo,
+            unit_of_work,
+            audit_service,
+        }
     }
 }
 
@@ -45,17 +67,83 @@ impl UserManagementUseCase for UserManagementUseCaseImpl {
             .ok_or_else(|| "User not found".to_string())
     }
 
-    async fn create(&self, user: User) -> Result<User, String> {
-        self.user_repo.create_user(&user).await?;
+    async fn create(&self, user: User, actor_id: &str) -> Result<User, DomainError> {
+        user.validate()?;
+
+        let mut changes = ChangeSet::new();
+        changes.create_user(user.clone());
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &user.id,
+            AuditAction::Create,
+            None,
+            Some(&user),
+        )?;
+        self.unit_of_work.commit(changes).await?;
         Ok(user)
     }
 
-    async fn update(&self, user: &User) -> Result<(), String> {
-        self.user_repo.update_user(user).await
+    async fn update(&self, user: &User, actor_id: &str) -> Result<(), DomainError> {
+        user.validate()?;
+
+        let before = self.get_by_id(&user.id).await?;
+
+        let mut changes = ChangeSet::new();
+        changes.update_user(user.clone());
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            &user.id,
+            AuditAction::Update,
+            Some(&before),
+            Some(user),
+        )?;
+        Ok(self.unit_of_work.commit(changes).await?)
+    }
+
+    async fn patch(&self, id: &str, patch: &Value, actor_id: &str) -> Result<User, DomainError> {
+        let current = self.get_by_id(id).await?;
+
+        let mut merged = apply_merge_patch(&current, patch)?;
+        merged.id = current.id.clone();
+
+        self.update(&merged, actor_id).await?;
+        Ok(self.get_by_id(id).await?)
+    }
+
+    async fn delete(&self, id: &str, actor_id: &str) -> Result<(), String> {
+        let before = self.get_by_id(id).await?;
+
+        let mut changes = ChangeSet::new();
+        changes.archive_user(id);
+        self.audit_service.record(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            id,
+            AuditAction::Delete,
+            Some(&before),
+            None,
+        )?;
+        self.unit_of_work.commit(changes).await
     }
 
-    async fn delete(&self, id: &str) -> Result<(), String> {
-        self.user_repo.delete_user(id).await
+    async fn restore(&self, id: &str, actor_id: &str) -> Result<(), String> {
+        let mut changes = ChangeSet::new();
+        changes.restore_user(id);
+        self.audit_service.record::<User>(
+            &mut changes,
+            actor_id,
+            AUDIT_ENTITY,
+            id,
+            AuditAction::Restore,
+            None,
+            None,
+        )?;
+        self.unit_of_work.commit(changes).await
     }
 
     async fn get_by_name(&self, name: &str) -> Result<Vec<User>, String> {
@@ -0,0 +1,94 @@
+use crate::domain::{
+    models::{
+        validation::DomainError,
+        webhook::{DeliveryAttempt, WebhookSubscription},
+    },
+    repositories::{
+        user_repository::UserRepository,
+        webhook_repository::{OutboxRepository, WebhookRepository},
+    },
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait WebhookManagementUseCase {
+    async fn get_all(&self) -> Result<Vec<WebhookSubscription>, String>;
+    async fn get_by_id(&self, id: &str) -> Result<WebhookSubscription, String>;
+    async fn register(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> Result<WebhookSubscription, DomainError>;
+    async fn deactivate(&self, id: &str, actor_id: &str) -> Result<WebhookSubscription, String>;
+    async fn get_deliveries(&self, id: &str) -> Result<Vec<DeliveryAttempt>, String>;
+}
+
+pub struct WebhookManagementUseCaseImpl {
+    webhook_repo: Box<dyn WebhookRepository + Send + Sync>,
+    outbox_repo: Box<dyn OutboxRepository + Send + Sync>,
+    user_repo: Box<dyn UserRepository + Send + Sync>,
+}
+
+impl WebhookManagementUseCaseImpl {
+    pub fn new(
+        webhook_repo: Box<dyn WebhookRepository + Send + Sync>,
+        outbox_repo: Box<dyn OutboxRepository + Send + Sync>,
+        user_repo: Box<dyn UserRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            outbox_repo,
+            user_repo,
+        }
+    }
+
+    async fn ensure_admin(&self, user_id: &str) -> Result<(), String> {
+        let user = self.user_repo.get_user_by_id(user_id).await?;
+        if user.is_some_and(|u| u.is_admin()) {
+            Ok(())
+        } else {
+            Err("Forbidden: only administrators can manage webhooks".to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookManagementUseCase for WebhookManagementUseCaseImpl {
+    async fn get_all(&self) -> Result<Vec<WebhookSubscription>, String> {
+        self.webhook_repo.get_subscriptions().await
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<WebhookSubscription, String> {
+        self.webhook_repo
+            .get_subscription_by_id(id)
+            .await?
+            .ok_or_else(|| "Webhook not found".to_string())
+    }
+
+    async fn register(
+        &self,
+        subscription: WebhookSubscription,
+    ) -> Result<WebhookSubscription, DomainError> {
+        self.ensure_admin(&subscription.created_by).await?;
+        subscription.validate()?;
+
+        self.webhook_repo.create_subscription(&subscription).await?;
+        Ok(self.get_by_id(&subscription.id).await?)
+    }
+
+    async fn deactivate(&self, id: &str, actor_id: &str) -> Result<WebhookSubscription, String> {
+        self.ensure_admin(actor_id).await?;
+
+        let subscription = self.get_by_id(id).await?;
+        if !subscription.active {
+            return Err("Conflict: webhook is already inactive".to_string());
+        }
+
+        self.webhook_repo.set_subscription_active(id, false).await?;
+        self.get_by_id(id).await
+    }
+
+    async fn get_deliveries(&self, id: &str) -> Result<Vec<DeliveryAttempt>, String> {
+        self.get_by_id(id).await?;
+        self.outbox_repo.get_attempts_by_subscription(id).await
+    }
+}
@@ -0,0 +1,155 @@
+use crate::domain::models::enums::AttendanceStatus;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Umbral de asistencia por defecto bajo el cual un alumno está en riesgo.
+pub const DEFAULT_RISK_THRESHOLD: f64 = 70.0;
+
+/// Asistencia de un alumno a una fecha concreta de una sesión.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttendanceRecord {
+    pub id: String,
+    pub schedule_id: String,
+    pub course_id: String,
+    pub student_id: String,
+    pub session_date: NaiveDate,
+    pub status: AttendanceStatus,
+    pub recorded_by: String,
+    pub recorded_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AttendanceFilter {
+    pub course_id: Option<String>,
+    pub student_id: Option<String>,
+    pub schedule_id: Option<String>,
+    pub session_date: Option<NaiveDate>,
+}
+
+/// Resumen de asistencia de un alumno en un curso.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttendanceSummary {
+    pub student_id: String,
+    pub course_id: String,
+    pub sessions: i32,
+    pub present: i32,
+    pub late: i32,
+    pub absent: i32,
+    pub excused: i32,
+    pub percentage: f64,
+}
+
+impl AttendanceSummary {
+    /// Las tardanzas cuentan como asistencia y las faltas justificadas no
+    /// entran en el cálculo; sin sesiones computables el alumno está al 100 %.
+    pub fn from_records(student_id: &str, course_id: &str, records: &[&AttendanceRecord]) -> Self {
+        let count = |status: AttendanceStatus| {
+            records.iter().filter(|r| r.status == status).count() as i32
+        };
+
+        let present = count(AttendanceStatus::Present);
+        let late = count(AttendanceStatus::Late);
+        let absent = count(AttendanceStatus::Absent);
+        let excused = count(AttendanceStatus::Excused);
+
+        let counted = present + late + absent;
+        let percentage = if counted == 0 {
+            100.0
+        } else {
+            ((present + late) as f64 * 1000.0 / counted as f64).round() / 10.0
+        };
+
+        Self {
+            student_id: student_id.to_string(),
+            course_id: course_id.to_string(),
+            sessions: records.len() as i32,
+            present,
+            late,
+            absent,
+            excused,
+            percentage,
+        }
+    }
+
+    pub fn is_at_risk(&self, threshold: f64) -> bool {
+        self.percentage < threshold
+    }
+
+    /// Agrupa registros por alumno y curso, en el orden en que aparece cada
+    /// par por primera vez.
+    pub fn summarize(records: &[AttendanceRecord]) -> Vec<AttendanceSummary> {
+        let mut order: Vec<(&str, &str)> = Vec::new();
+        let mut groups: HashMap<(&str, &str), Vec<&AttendanceRecord>> = HashMap::new();
+        for record in records {
+            let key = (record.student_id.as_str(), record.course_id.as_str());
+            groups
+                .entry(key)
+                .or_insert_with(|| {
+                    order.push(key);
+                    Vec::new()
+                })
+                .push(record);
+        }
+
+        order
+            .into_iter()
+            .map(|(student_id, course_id)| {
+                Self::from_records(student_id, course_id, &groups[&(student_id, course_id)])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(student_id: &str, course_id: &str, status: AttendanceStatus) -> AttendanceRecord {
+        AttendanceRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            schedule_id: "s1".to_string(),
+            course_id: course_id.to_string(),
+            student_id: student_id.to_string(),
+            session_date: NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(),
+            status,
+            recorded_by: "t1".to_string(),
+            recorded_at: None,
+        }
+    }
+
+    #[test]
+    fn summarize_groups_by_student_and_course_in_first_seen_order() {
+        let records = vec![
+            record("ana", "c1", AttendanceStatus::Present),
+            record("luis", "c1", AttendanceStatus::Absent),
+            record("ana", "c2", AttendanceStatus::Late),
+            record("ana", "c1", AttendanceStatus::Absent),
+            record("luis", "c1", AttendanceStatus::Excused),
+        ];
+
+        let summaries = AttendanceSummary::summarize(&records);
+        let keys: Vec<(&str, &str, i32)> = summaries
+            .iter()
+            .map(|s| (s.student_id.as_str(), s.course_id.as_str(), s.sessions))
+            .collect();
+
+        assert_eq!(keys, vec![("ana", "c1", 2), ("luis", "c1", 2), ("ana", "c2", 1)]);
+        assert_eq!(summaries[0].percentage, 50.0);
+        assert_eq!(summaries[1].percentage, 0.0);
+        assert_eq!(summaries[2].percentage, 100.0);
+    }
+
+    #[test]
+    fn excused_absences_are_left_out_of_the_percentage() {
+        let records = vec![
+            record("ana", "c1", AttendanceStatus::Excused),
+            record("ana", "c1", AttendanceStatus::Excused),
+        ];
+
+        let summary = &AttendanceSummary::summarize(&records)[0];
+        assert_eq!(summary.excused, 2);
+        assert_eq!(summary.percentage, 100.0);
+        assert!(!summary.is_at_risk(DEFAULT_RISK_THRESHOLD));
+    }
+}
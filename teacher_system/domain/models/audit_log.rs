@@ -0,0 +1,24 @@
+use crate::domain::models::enums::AuditAction;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLog {
+    pub id: String,
+    pub actor_id: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub action: AuditAction,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditFilter {
+    pub entity_id: Option<String>,
+    pub actor_id: Option<String>,
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+}
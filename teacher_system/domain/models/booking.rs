@@ -0,0 +1,75 @@
+use crate::domain::models::enums::{BookingStatus, Weekday};
+use crate::domain::models::schedule::{Schedule, institutional_hours};
+use crate::domain::models::validation::ValidationError;
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// Reserva puntual de un aula para una fecha (recuperación, examen, sustentación).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FacilityBooking {
+    pub id: String,
+    pub facility_id: String,
+    pub requested_by: String,
+    pub purpose: String,
+    pub date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub status: BookingStatus,
+    pub reviewed_by: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BookingFilter {
+    pub facility_id: Option<String>,
+    pub requested_by: Option<String>,
+    pub date: Option<NaiveDate>,
+    pub status: Option<BookingStatus>,
+}
+
+impl FacilityBooking {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+        let (opening, closing) = institutional_hours();
+
+        if self.facility_id.trim().is_empty() {
+            errors.add("facility_id", "is required");
+        }
+        if self.purpose.trim().is_empty() {
+            errors.add("purpose", "is required");
+        }
+        if self.end_time <= self.start_time {
+            errors.add("end_time", "must be after start_time");
+        }
+        if self.start_time < opening {
+            errors.add("start_time", "must be within institutional hours (08:00-22:00)");
+        }
+        if self.end_time > closing {
+            errors.add("end_time", "must be within institutional hours (08:00-22:00)");
+        }
+
+        errors.into_result()
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        self.date.weekday().into()
+    }
+
+    /// Solo las reservas pendientes o aprobadas ocupan el aula.
+    pub fn is_active(&self) -> bool {
+        matches!(self.status, BookingStatus::Pending | BookingStatus::Approved)
+    }
+
+    pub fn overlaps(&self, other: &FacilityBooking) -> bool {
+        self.date == other.date
+            && self.start_time < other.end_time
+            && self.end_time > other.start_time
+    }
+
+    /// Choca con una sesión recurrente que cae el mismo día de la semana.
+    pub fn overlaps_session(&self, session: &Schedule) -> bool {
+        self.weekday() == session.day
+            && self.start_time < session.end_time
+            && self.end_time > session.start_time
+    }
+}
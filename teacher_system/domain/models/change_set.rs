@@ -0,0 +1,270 @@
+use crate::domain::models::{
+    audit_log::AuditLog,
+    booking::FacilityBooking,
+    course::Course,
+    enums::{AssignmentStatus, EnrollmentStatus, StudentStatus},
+    event::{DomainEvent, OutboxEvent},
+    facilitie::Facility,
+    grade::{ComponentGrade, GradingScheme},
+    schedule::Schedule,
+    session_exception::SessionException,
+    student_status::StatusChange,
+    teacher_assignment::AssignmentProposal,
+    user::User,
+};
+
+/// Escritura pendiente de un `ChangeSet`.
+#[derive(Debug, Clone)]
+pub enum Change {
+    CreateCourse(Course),
+    UpdateCourse {
+        course: Course,
+        expected_version: Option<i32>,
+    },
+    ArchiveCourse(String),
+    RestoreCourse(String),
+    CreateSchedule(Schedule),
+    UpdateSchedule {
+        schedule: Schedule,
+        expected_version: Option<i32>,
+    },
+    DeleteSchedule(String),
+    CreateFacility(Facility),
+    UpdateFacility(Facility),
+    ArchiveFacility(String),
+    RestoreFacility(String),
+    CreateUser(User),
+    UpdateUser(User),
+    ArchiveUser(String),
+    RestoreUser(String),
+    UpdateStudentStatus {
+        user_id: String,
+        status: StudentStatus,
+    },
+    RecordStatusChange(StatusChange),
+    CreateBooking(FacilityBooking),
+    CreateSessionException(SessionException),
+    /// Pasa una matrícula pendiente a `Enrolled`; con `max_credits` la carga
+    /// del semestre se vuelve a contar dentro de la transacción.
+    ConfirmEnrollment {
+        enrollment_id: String,
+        max_credits: Option<i32>,
+    },
+    MoveEnrollments {
+        enrollment_ids: Vec<String>,
+        course_id: String,
+    },
+    /// Crea el esquema del curso o sobrescribe el que tenía.
+    SaveGradingScheme(GradingScheme),
+    /// Guarda o corrige la nota de cada matrícula en la evaluación.
+    SaveGrades(Vec<ComponentGrade>),
+    RecordEnrollmentResult {
+        enrollment_id: String,
+        status: EnrollmentStatus,
+        final_grade: f64,
+    },
+    /// Borra las propuestas aún sin decidir del semestre.
+    DeletePendingProposals {
+        academic_year: i32,
+        semester: String,
+    },
+    CreateProposals(Vec<AssignmentProposal>),
+    /// Cierra una propuesta de asignación que sigue en `Proposed`.
+    DecideProposal {
+        proposal_id: String,
+        status: AssignmentStatus,
+        assigned_teacher_id: String,
+        decided_by: String,
+        note: Option<String>,
+    },
+    RecordAudit(AuditLog),
+    PublishEvent(OutboxEvent),
+}
+
+/// Cambios de un caso de uso que se guardan en una sola transacción: si uno
+/// falla no se guarda ninguno. Se aplican en el orden en que se agregan.
+#[derive(Debug, Clone, Default)]
+pub struct ChangeSet {
+    changes: Vec<Change>,
+}
+
+impl ChangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn create_course(&mut self, course: Course) {
+        self.changes.push(Change::CreateCourse(course));
+    }
+
+    /// Con `expected_version` solo actualiza si la versión almacenada coincide.
+    pub fn update_course(&mut self, course: Course, expected_version: Option<i32>) {
+        self.changes.push(Change::UpdateCourse {
+            course,
+            expected_version,
+        });
+    }
+
+    pub fn archive_course(&mut self, course_id: &str) {
+        self.changes
+            .push(Change::ArchiveCourse(course_id.to_string()));
+    }
+
+    pub fn restore_course(&mut self, course_id: &str) {
+        self.changes
+            .push(Change::RestoreCourse(course_id.to_string()));
+    }
+
+    pub fn create_schedule(&mut self, schedule: Schedule) {
+        self.changes.push(Change::CreateSchedule(schedule));
+    }
+
+    /// Con `expected_version` solo actualiza si la versión almacenada coincide.
+    pub fn update_schedule(&mut self, schedule: Schedule, expected_version: Option<i32>) {
+        self.changes.push(Change::UpdateSchedule {
+            schedule,
+            expected_version,
+        });
+    }
+
+    pub fn delete_schedule(&mut self, schedule_id: &str) {
+        self.changes
+            .push(Change::DeleteSchedule(schedule_id.to_string()));
+    }
+
+    pub fn create_facility(&mut self, facility: Facility) {
+        self.changes.push(Change::CreateFacility(facility));
+    }
+
+    pub fn update_facility(&mut self, facility: Facility) {
+        self.changes.push(Change::UpdateFacility(facility));
+    }
+
+    pub fn archive_facility(&mut self, facility_id: &str) {
+        self.changes
+            .push(Change::ArchiveFacility(facility_id.to_string()));
+    }
+
+    pub fn restore_facility(&mut self, facility_id: &str) {
+        self.changes
+            .push(Change::RestoreFacility(facility_id.to_string()));
+    }
+
+    pub fn create_user(&mut self, user: User) {
+        self.changes.push(Change::CreateUser(user));
+    }
+
+    pub fn update_user(&mut self, user: User) {
+        self.changes.push(Change::UpdateUser(user));
+    }
+
+    pub fn archive_user(&mut self, user_id: &str) {
+        self.changes.push(Change::ArchiveUser(user_id.to_string()));
+    }
+
+    pub fn restore_user(&mut self, user_id: &str) {
+        self.changes.push(Change::RestoreUser(user_id.to_string()));
+    }
+
+    pub fn update_student_status(&mut self, user_id: &str, status: StudentStatus) {
+        self.changes.push(Change::UpdateStudentStatus {
+            user_id: user_id.to_string(),
+            status,
+        });
+    }
+
+    pub fn record_status_change(&mut self, change: StatusChange) {
+        self.changes.push(Change::RecordStatusChange(change));
+    }
+
+    pub fn create_booking(&mut self, booking: FacilityBooking) {
+        self.changes.push(Change::CreateBooking(booking));
+    }
+
+    pub fn create_session_exception(&mut self, exception: SessionException) {
+        self.changes.push(Change::CreateSessionException(exception));
+    }
+
+    pub fn confirm_enrollment(&mut self, enrollment_id: &str, max_credits: Option<i32>) {
+        self.changes.push(Change::ConfirmEnrollment {
+            enrollment_id: enrollment_id.to_string(),
+            max_credits,
+        });
+    }
+
+    pub fn move_enrollments(&mut self, enrollment_ids: &[String], course_id: &str) {
+        self.changes.push(Change::MoveEnrollments {
+            enrollment_ids: enrollment_ids.to_vec(),
+            course_id: course_id.to_string(),
+        });
+    }
+
+    pub fn save_grading_scheme(&mut self, scheme: GradingScheme) {
+        self.changes.push(Change::SaveGradingScheme(scheme));
+    }
+
+    pub fn save_grades(&mut self, grades: Vec<ComponentGrade>) {
+        self.changes.push(Change::SaveGrades(grades));
+    }
+
+    pub fn record_enrollment_result(
+        &mut self,
+        enrollment_id: &str,
+        status: EnrollmentStatus,
+        final_grade: f64,
+    ) {
+        self.changes.push(Change::RecordEnrollmentResult {
+            enrollment_id: enrollment_id.to_string(),
+            status,
+            final_grade,
+        });
+    }
+
+    pub fn delete_pending_proposals(&mut self, academic_year: i32, semester: &str) {
+        self.changes.push(Change::DeletePendingProposals {
+            academic_year,
+            semester: semester.to_string(),
+        });
+    }
+
+    pub fn create_proposals(&mut self, proposals: Vec<AssignmentProposal>) {
+        self.changes.push(Change::CreateProposals(proposals));
+    }
+
+    pub fn decide_proposal(
+        &mut self,
+        proposal_id: &str,
+        status: AssignmentStatus,
+        assigned_teacher_id: &str,
+        decided_by: &str,
+        note: Option<String>,
+    ) {
+        self.changes.push(Change::DecideProposal {
+            proposal_id: proposal_id.to_string(),
+            status,
+            assigned_teacher_id: assigned_teacher_id.to_string(),
+            decided_by: decided_by.to_string(),
+            note,
+        });
+    }
+
+    /// El registro de auditoría se guarda junto con el cambio que describe.
+    pub fn record_audit(&mut self, log: AuditLog) {
+        self.changes.push(Change::RecordAudit(log));
+    }
+
+    /// El evento queda en la bandeja de salida solo si el cambio se guarda;
+    /// los manejadores lo reciben después, fuera de la petición.
+    pub fn publish(&mut self, event: DomainEvent) {
+        self.changes
+            .push(Change::PublishEvent(OutboxEvent::new(event)));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn into_changes(self) -> Vec<Change> {
+        self.changes
+    }
+}
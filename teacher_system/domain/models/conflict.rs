@@ -0,0 +1,213 @@
+use crate::domain::models::{course::Course, facilitie::Facility, schedule::Schedule};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictKind {
+    /// Dos sesiones del mismo curso se solapan.
+    SameCourse,
+    /// Se solapan cursos distintos que la misma sección del ciclo lleva en conjunto.
+    CrossSection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleConflict {
+    pub kind: ConflictKind,
+    pub first: Schedule,
+    pub second: Schedule,
+}
+
+/// Sesiones que se solapan con `schedule` en el semestre de su curso, sin
+/// contarla a ella. Es la regla de choque de todo el sistema: una sesión de
+/// otro semestre nunca choca, aunque repita día, hora, aula y profesor.
+pub fn term_overlaps<'a>(
+    schedule: &Schedule,
+    course: &Course,
+    courses: &HashMap<String, Course>,
+    sessions: &'a [Schedule],
+) -> Vec<&'a Schedule> {
+    sessions
+        .iter()
+        .filter(|s| {
+            s.id != schedule.id
+                && courses
+                    .get(&s.course_id)
+                    .is_some_and(|c| c.same_term(course))
+                && s.conflicts_with(schedule)
+        })
+        .collect()
+}
+
+/// Busca solapamientos entre cada par de sesiones sin repetir pares.
+pub fn find_overlaps(sessions: &[Schedule], kind: ConflictKind) -> Vec<ScheduleConflict> {
+    let mut conflicts = Vec::new();
+
+    for (i, first) in sessions.iter().enumerate() {
+        for second in &sessions[i + 1..] {
+            let counts = match kind {
+                ConflictKind::SameCourse => first.course_id == second.course_id,
+                ConflictKind::CrossSection => first.course_id != second.course_id,
+            };
+
+            if counts && first.conflicts_with(second) {
+                conflicts.push(ScheduleConflict {
+                    kind,
+                    first: first.clone(),
+                    second: second.clone(),
+                });
+            }
+        }
+    }
+
+    conflicts
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimetableIssueKind {
+    TeacherDoubleBooking,
+    FacilityDoubleBooking,
+    OverCapacity,
+    SessionTypeMismatch,
+}
+
+impl fmt::Display for TimetableIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            TimetableIssueKind::TeacherDoubleBooking => "teacher_double_booking",
+            TimetableIssueKind::FacilityDoubleBooking => "facility_double_booking",
+            TimetableIssueKind::OverCapacity => "over_capacity",
+            TimetableIssueKind::SessionTypeMismatch => "session_type_mismatch",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+/// Hallazgo del reporte de auditoría del horario de un semestre.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimetableIssue {
+    pub kind: TimetableIssueKind,
+    pub schedule_ids: Vec<String>,
+    pub course_ids: Vec<String>,
+    pub teacher_id: Option<String>,
+    pub facility_id: Option<String>,
+    pub detail: String,
+}
+
+/// Choques de profesor y de aula, sobrecupo y tipo de aula entre las sesiones
+/// de un semestre. Las sesiones de cursos ausentes en `courses` se ignoran y,
+/// como en `term_overlaps`, dos sesiones de semestres distintos no chocan.
+pub fn audit_timetable(
+    courses: &HashMap<String, Course>,
+    facilities: &HashMap<String, Facility>,
+    sessions: &[Schedule],
+) -> Vec<TimetableIssue> {
+    let sessions: Vec<&Schedule> = sessions
+        .iter()
+        .filter(|s| courses.contains_key(&s.course_id))
+        .collect();
+
+    let mut issues = Vec::new();
+
+    for (i, first) in sessions.iter().enumerate() {
+        let first_course = &courses[&first.course_id];
+
+        for second in sessions[i + 1..].iter().filter(|s| first.conflicts_with(s)) {
+            let second_course = &courses[&second.course_id];
+            if !first_course.same_term(second_course) {
+                continue;
+            }
+
+            if first_course.has_teacher() && first_course.teacher_id == second_course.teacher_id {
+                issues.push(TimetableIssue {
+                    kind: TimetableIssueKind::TeacherDoubleBooking,
+                    schedule_ids: vec![first.id.clone(), second.id.clone()],
+                    course_ids: vec![first_course.id.clone(), second_course.id.clone()],
+                    teacher_id: Some(first_course.teacher_id.clone()),
+                    facility_id: None,
+                    detail: format!(
+                        "Teacher has {} and {} at the same time on {}",
+                        first_course.code,
+                        second_course.code,
+                        first.day.to_string()
+                    ),
+                });
+            }
+
+            if !first.facility_id.is_empty() && first.facility_id == second.facility_id {
+                issues.push(TimetableIssue {
+                    kind: TimetableIssueKind::FacilityDoubleBooking,
+                    schedule_ids: vec![first.id.clone(), second.id.clone()],
+                    course_ids: vec![first_course.id.clone(), second_course.id.clone()],
+                    teacher_id: None,
+                    facility_id: Some(first.facility_id.clone()),
+                    detail: format!(
+                        "Facility hosts {} and {} at the same time on {}",
+                        first_course.code,
+                        second_course.code,
+                        first.day.to_string()
+                    ),
+                });
+            }
+        }
+
+        let Some(facility) = facilities.get(&first.facility_id) else {
+            continue;
+        };
+
+        if first_course.capacity > facility.capacity {
+            issues.push(TimetableIssue {
+                kind: TimetableIssueKind::OverCapacity,
+                schedule_ids: vec![first.id.clone()],
+                course_ids: vec![first_course.id.clone()],
+                teacher_id: None,
+                facility_id: Some(facility.id.clone()),
+                detail: format!(
+                    "Course capacity {} exceeds facility {} capacity {}",
+                    first_course.capacity, facility.name, facility.capacity
+                ),
+            });
+        }
+
+        if !first.session_type.fits_facility_type(&facility.facility_type) {
+            issues.push(TimetableIssue {
+                kind: TimetableIssueKind::SessionTypeMismatch,
+                schedule_ids: vec![first.id.clone()],
+                course_ids: vec![first_course.id.clone()],
+                teacher_id: None,
+                facility_id: Some(facility.id.clone()),
+                detail: format!(
+                    "A {} session cannot take place in a {} facility",
+                    first.session_type.to_string(),
+                    facility.facility_type
+                ),
+            });
+        }
+    }
+
+    issues
+}
+
+/// Qué hacer cuando una sesión choca con otro curso obligatorio del mismo ciclo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClashPolicy {
+    #[default]
+    Block,
+    Warn,
+}
+
+impl FromStr for ClashPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "block" => Ok(ClashPolicy::Block),
+            "warn" => Ok(ClashPolicy::Warn),
+            _ => Err(format!("Unknown clash policy: {}", s)),
+        }
+    }
+}
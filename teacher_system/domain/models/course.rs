@@ -1,4 +1,5 @@
-use crate::domain::models::enums::Curriculum;
+use crate::domain::models::enums::{Curriculum, Semester};
+use crate::domain::models::validation::ValidationError;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,11 +18,132 @@ pub struct Course {
     pub enrolled: i32,
     pub semester: String,
     pub academic_year: i32,
+    #[serde(default)]
+    pub program: String,
     pub active: bool,
+    #[serde(default)]
+    pub version: i32,
+}
+
+/// Fila de `course_prerequisites` expresada por códigos de curso, para
+/// aplicarla a todas las secciones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrerequisiteLink {
+    pub course_code: String,
+    pub prerequisite_code: String,
 }
 
 impl Course {
     pub fn available_spots(&self) -> i32 {
         self.capacity - self.enrolled
     }
+
+    /// Programa al que pertenece; vacío equivale a no tener ninguno.
+    pub fn program(&self) -> Option<&str> {
+        Some(self.program.trim()).filter(|p| !p.is_empty())
+    }
+
+    /// Dos cursos del mismo año y semestre comparten horario.
+    pub fn same_term(&self, other: &Course) -> bool {
+        self.in_term(other.academic_year, &other.semester)
+    }
+
+    /// Compara el semestre ya interpretado: "II", "ii" y "2" son el mismo.
+    pub fn in_term(&self, academic_year: i32, semester: &str) -> bool {
+        self.academic_year == academic_year
+            && match (self.semester.parse::<Semester>(), semester.parse::<Semester>()) {
+                (Ok(own), Ok(other)) => own == other,
+                _ => self.semester == semester,
+            }
+    }
+
+    /// Un curso sin profesor espera una propuesta de asignación.
+    pub fn has_teacher(&self) -> bool {
+        !self.teacher_id.trim().is_empty()
+    }
+
+    /// Verifica las invariantes del curso antes de persistirlo.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.code.trim().is_empty() {
+            errors.add("code", "is required");
+        }
+        if self.name.trim().is_empty() {
+            errors.add("name", "is required");
+        }
+        // Solo el código canónico, así cada semestre se guarda de una sola forma
+        match self.semester.parse::<Semester>() {
+            Ok(semester) if semester.code() == self.semester => {}
+            _ if self.semester.trim().is_empty() => errors.add("semester", "is required"),
+            _ => errors.add("semester", "must be 0, I or II"),
+        }
+        if self.section <= 0 {
+            errors.add("section", "must be positive");
+        }
+        if self.capacity <= 0 {
+            errors.add("capacity", "must be positive");
+        }
+        if self.credits <= 0 {
+            errors.add("credits", "must be positive");
+        }
+        if self.hours_per_week <= 0 {
+            errors.add("hours_per_week", "must be positive");
+        }
+        if self.cycle <= 0 {
+            errors.add("cycle", "must be positive");
+        }
+        if self.enrolled < 0 {
+            errors.add("enrolled", "must not be negative");
+        }
+        if self.enrolled > self.capacity {
+            errors.add("enrolled", "must not exceed capacity");
+        }
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course(semester: &str) -> Course {
+        Course {
+            id: "c1".to_string(),
+            code: "MAT1".to_string(),
+            name: "Matemática I".to_string(),
+            section: 1,
+            curriculum: Curriculum::Obligatory,
+            capacity: 30,
+            credits: 4,
+            hours_per_week: 4,
+            cycle: 1,
+            teacher_id: String::new(),
+            facility_id: String::new(),
+            enrolled: 0,
+            semester: semester.to_string(),
+            academic_year: 2025,
+            program: "SIS".to_string(),
+            active: true,
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn only_canonical_semesters_are_valid() {
+        assert!(course("II").validate().is_ok());
+        assert!(course("0").validate().is_ok());
+        assert!(course("ii").validate().is_err());
+        assert!(course("2").validate().is_err());
+        assert!(course("").validate().is_err());
+    }
+
+    #[test]
+    fn in_term_reads_stored_semesters_in_any_form() {
+        assert!(course("2").in_term(2025, "II"));
+        assert!(course("ii").in_term(2025, "II"));
+        assert!(!course("II").in_term(2025, "I"));
+        assert!(!course("II").in_term(2024, "II"));
+    }
 }
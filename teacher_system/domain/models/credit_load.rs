@@ -0,0 +1,198 @@
+use crate::domain::models::{
+    enums::{OverloadStatus, StudentStatus},
+    validation::ValidationError,
+};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Créditos mínimos y máximos por semestre para un estado académico.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditLimit {
+    pub status: StudentStatus,
+    pub min_credits: i32,
+    pub max_credits: i32,
+}
+
+/// Límites vigentes. Se configuran con `CREDIT_LOAD_LIMITS` como una lista
+/// JSON, por ejemplo `[{"status":"Observation","min_credits":8,"max_credits":12}]`.
+/// Un estado sin límite no tiene tope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditLoadPolicy {
+    pub limits: Vec<CreditLimit>,
+}
+
+impl Default for CreditLoadPolicy {
+    fn default() -> Self {
+        Self {
+            limits: vec![
+                CreditLimit {
+                    status: StudentStatus::Regular,
+                    min_credits: 12,
+                    max_credits: 22,
+                },
+                CreditLimit {
+                    status: StudentStatus::Observation,
+                    min_credits: 8,
+                    max_credits: 14,
+                },
+            ],
+        }
+    }
+}
+
+impl FromStr for CreditLoadPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let limits: Vec<CreditLimit> =
+            serde_json::from_str(s).map_err(|e| format!("Invalid credit load limits: {}", e))?;
+
+        for (i, limit) in limits.iter().enumerate() {
+            if limit.min_credits < 0 || limit.max_credits < limit.min_credits {
+                return Err(format!(
+                    "Invalid credit load limits: {} must satisfy 0 <= min <= max",
+                    limit.status.to_string()
+                ));
+            }
+            if limits[..i].iter().any(|l| l.status == limit.status) {
+                return Err(format!(
+                    "Invalid credit load limits: {} is repeated",
+                    limit.status.to_string()
+                ));
+            }
+        }
+
+        Ok(Self { limits })
+    }
+}
+
+impl CreditLoadPolicy {
+    /// Un alumno sin estado registrado se trata como regular.
+    pub fn limit_for(&self, status: Option<&StudentStatus>) -> Option<&CreditLimit> {
+        let status = status.unwrap_or(&StudentStatus::Regular);
+        self.limits.iter().find(|l| &l.status == status)
+    }
+}
+
+/// Carga de un alumno en un semestre frente a su límite. El mínimo se mide
+/// contra lo pedido (matriculado más pendiente), porque al confirmar curso por
+/// curso lo matriculado siempre empieza por debajo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TermLoad {
+    pub student_id: String,
+    pub academic_year: i32,
+    pub semester: String,
+    pub student_status: Option<StudentStatus>,
+    pub enrolled_credits: i32,
+    /// Créditos de las matrículas aún por confirmar.
+    pub pending_credits: i32,
+    pub min_credits: Option<i32>,
+    pub max_credits: Option<i32>,
+    /// Tope aprobado por sobrecarga, si lo hay; reemplaza a `max_credits`.
+    pub approved_credits: Option<i32>,
+}
+
+impl TermLoad {
+    pub fn effective_max(&self) -> Option<i32> {
+        self.approved_credits.or(self.max_credits)
+    }
+
+    pub fn requested_credits(&self) -> i32 {
+        self.enrolled_credits + self.pending_credits
+    }
+
+    pub fn below_minimum(&self) -> bool {
+        self.min_credits
+            .is_some_and(|min| self.requested_credits() < min)
+    }
+}
+
+/// Pedido de un alumno para llevar más créditos que su máximo en un semestre.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverloadRequest {
+    pub id: String,
+    pub student_id: String,
+    pub academic_year: i32,
+    pub semester: String,
+    pub requested_credits: i32,
+    pub reason: String,
+    pub status: OverloadStatus,
+    pub requested_by: String,
+    pub reviewed_by: Option<String>,
+    pub review_note: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OverloadFilter {
+    pub student_id: Option<String>,
+    pub status: Option<OverloadStatus>,
+}
+
+impl OverloadRequest {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.student_id.trim().is_empty() {
+            errors.add("student_id", "is required");
+        }
+        if self.semester.trim().is_empty() {
+            errors.add("semester", "is required");
+        }
+        if self.reason.trim().is_empty() {
+            errors.add("reason", "is required");
+        }
+        if self.requested_credits <= 0 {
+            errors.add("requested_credits", "must be greater than 0");
+        }
+
+        errors.into_result()
+    }
+
+    /// Pendientes y aprobados bloquean un nuevo pedido para el mismo semestre.
+    pub fn is_active(&self) -> bool {
+        matches!(self.status, OverloadStatus::Pending | OverloadStatus::Approved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(enrolled_credits: i32, pending_credits: i32) -> TermLoad {
+        TermLoad {
+            student_id: "s1".to_string(),
+            academic_year: 2025,
+            semester: "I".to_string(),
+            student_status: None,
+            enrolled_credits,
+            pending_credits,
+            min_credits: Some(12),
+            max_credits: Some(22),
+            approved_credits: None,
+        }
+    }
+
+    #[test]
+    fn minimum_counts_pending_enrollments() {
+        assert!(load(4, 4).below_minimum());
+        assert!(!load(4, 8).below_minimum());
+    }
+
+    #[test]
+    fn approved_overload_replaces_the_maximum() {
+        let mut load = load(20, 0);
+        assert_eq!(load.effective_max(), Some(22));
+
+        load.approved_credits = Some(26);
+        assert_eq!(load.effective_max(), Some(26));
+    }
+
+    #[test]
+    fn rejects_a_minimum_above_the_maximum() {
+        let result: Result<CreditLoadPolicy, _> =
+            r#"[{"status":"Regular","min_credits":20,"max_credits":12}]"#.parse();
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::domain::models::enums::Curriculum;
+use serde::{Deserialize, Serialize};
+
+/// Capacidad por sección cuando el curso nunca se ha dictado.
+pub const DEFAULT_SECTION_CAPACITY: i32 = 40;
+
+/// Semestres anteriores que se promedian para el histórico.
+pub const HISTORY_TERMS: usize = 3;
+
+/// Demanda estimada de un curso del plan para el próximo semestre.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CourseDemand {
+    pub code: String,
+    pub name: String,
+    pub program: String,
+    pub cycle: i32,
+    pub curriculum: Curriculum,
+    pub elective_group: Option<String>,
+    /// Alumnos que podrán llevarlo según su avance en el plan.
+    pub eligible_students: i32,
+    /// Matriculados por semestre en los últimos semestres dictados.
+    pub historical_average: Option<f64>,
+    pub estimated_demand: i32,
+    /// Aforo del aula apta más grande; `None` si ninguna sirve.
+    pub max_room_capacity: Option<i32>,
+    pub suggested_sections: i32,
+    pub suggested_capacity: i32,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemandForecast {
+    pub academic_year: i32,
+    pub semester: String,
+    pub courses: Vec<CourseDemand>,
+    pub total_sections: i32,
+}
+
+/// Mezcla a partes iguales la demanda del plan y el histórico, si lo hay.
+/// En los electivos los alumnos elegibles se reparten entre los cursos del
+/// grupo que pueden llevar.
+pub fn estimate_demand(eligible: i32, group_size: i32, history: &[i32]) -> (Option<f64>, i32) {
+    let base = if group_size > 1 {
+        (eligible + group_size - 1) / group_size
+    } else {
+        eligible
+    };
+
+    if history.is_empty() {
+        return (None, base);
+    }
+    let average = history.iter().sum::<i32>() as f64 / history.len() as f64;
+    let average = (average * 10.0).round() / 10.0;
+    (Some(average), ((base as f64 + average) / 2.0).ceil() as i32)
+}
+
+/// Secciones necesarias y capacidad pareja para cada una. La capacidad no
+/// pasa de la preferida ni del aula más grande que sirva.
+pub fn size_sections(demand: i32, preferred_capacity: i32, max_room_capacity: Option<i32>) -> (i32, i32) {
+    if demand <= 0 {
+        return (0, 0);
+    }
+    let limit = match max_room_capacity {
+        Some(room) => preferred_capacity.min(room),
+        None => preferred_capacity,
+    }
+    .max(1);
+
+    let sections = (demand + limit - 1) / limit;
+    let capacity = (demand + sections - 1) / sections;
+    (sections, capacity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn without_history_the_plan_demand_is_used() {
+        assert_eq!(estimate_demand(45, 1, &[]), (None, 45));
+    }
+
+    #[test]
+    fn history_is_averaged_with_the_plan_demand() {
+        // Promedio 37.5; (45 + 37.5) / 2 = 41.25
+        assert_eq!(estimate_demand(45, 1, &[40, 35]), (Some(37.5), 42));
+    }
+
+    #[test]
+    fn electives_split_eligible_students_across_the_group() {
+        assert_eq!(estimate_demand(50, 3, &[]), (None, 17));
+    }
+
+    #[test]
+    fn sections_share_the_demand_evenly() {
+        assert_eq!(size_sections(90, 40, None), (3, 30));
+        assert_eq!(size_sections(40, 40, None), (1, 40));
+    }
+
+    #[test]
+    fn capacity_is_limited_by_the_largest_room() {
+        assert_eq!(size_sections(60, 40, Some(25)), (3, 20));
+    }
+
+    #[test]
+    fn no_demand_opens_no_sections() {
+        assert_eq!(size_sections(0, 40, Some(30)), (0, 0));
+    }
+}
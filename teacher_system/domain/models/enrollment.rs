@@ -7,4 +7,7 @@ pub struct Enrollment {
     pub student_id: String,
     pub course_id: String,
     pub status: EnrollmentStatus,
+    /// Se fija al cerrar el curso.
+    #[serde(default)]
+    pub final_grade: Option<f64>,
 }
@@ -1,4 +1,6 @@
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,14 +39,14 @@ pub enum SessionType {
     Practice,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum StudentStatus {
     Regular,
     Observation,
     Graduated,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ContractType {
     Contracted,
     Principal,
@@ -128,6 +130,18 @@ impl ToString for SessionType {
     }
 }
 
+impl SessionType {
+    /// Los laboratorios exigen un aula de tipo laboratorio y la teoría y los
+    /// seminarios no deben ocuparlas; la práctica puede ir en cualquiera.
+    pub fn fits_facility_type(&self, facility_type: &FacilityType) -> bool {
+        match self {
+            SessionType::Laboratory => facility_type.is_lab(),
+            SessionType::Theory | SessionType::Seminar => !facility_type.is_lab(),
+            SessionType::Practice => true,
+        }
+    }
+}
+
 impl FromStr for Weekday {
     type Err = String;
 
@@ -192,6 +206,17 @@ impl ToString for ContractType {
     }
 }
 
+impl ContractType {
+    /// Orden de preferencia al proponer profesores: primero los principales.
+    pub fn assignment_priority(&self) -> u8 {
+        match self {
+            ContractType::Principal => 0,
+            ContractType::Associate => 1,
+            ContractType::Contracted => 2,
+        }
+    }
+}
+
 impl FromStr for ContractType {
     type Err = ();
 
@@ -204,3 +229,451 @@ impl FromStr for ContractType {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Restore,
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditAction::Create => write!(f, "create"),
+            AuditAction::Update => write!(f, "update"),
+            AuditAction::Delete => write!(f, "delete"),
+            AuditAction::Restore => write!(f, "restore"),
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create" => Ok(AuditAction::Create),
+            "update" => Ok(AuditAction::Update),
+            "delete" => Ok(AuditAction::Delete),
+            "restore" => Ok(AuditAction::Restore),
+            _ => Err(format!("Unknown audit action: {}", s)),
+        }
+    }
+}
+
+impl From<chrono::Weekday> for Weekday {
+    fn from(day: chrono::Weekday) -> Self {
+        match day {
+            chrono::Weekday::Mon => Weekday::Monday,
+            chrono::Weekday::Tue => Weekday::Tuesday,
+            chrono::Weekday::Wed => Weekday::Wednesday,
+            chrono::Weekday::Thu => Weekday::Thursday,
+            chrono::Weekday::Fri => Weekday::Friday,
+            chrono::Weekday::Sat => Weekday::Saturday,
+            chrono::Weekday::Sun => Weekday::Sunday,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BookingStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+impl fmt::Display for BookingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BookingStatus::Pending => write!(f, "pending"),
+            BookingStatus::Approved => write!(f, "approved"),
+            BookingStatus::Rejected => write!(f, "rejected"),
+            BookingStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl FromStr for BookingStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(BookingStatus::Pending),
+            "approved" => Ok(BookingStatus::Approved),
+            "rejected" => Ok(BookingStatus::Rejected),
+            "cancelled" => Ok(BookingStatus::Cancelled),
+            _ => Err(format!("Unknown booking status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FacilityType {
+    #[default]
+    Classroom,
+    Laboratory,
+    ComputerLab,
+    Auditorium,
+    SeminarRoom,
+    Workshop,
+    Other,
+}
+
+impl FacilityType {
+    pub fn is_lab(&self) -> bool {
+        matches!(self, FacilityType::Laboratory | FacilityType::ComputerLab)
+    }
+}
+
+impl fmt::Display for FacilityType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FacilityType::Classroom => write!(f, "classroom"),
+            FacilityType::Laboratory => write!(f, "laboratory"),
+            FacilityType::ComputerLab => write!(f, "computer_lab"),
+            FacilityType::Auditorium => write!(f, "auditorium"),
+            FacilityType::SeminarRoom => write!(f, "seminar_room"),
+            FacilityType::Workshop => write!(f, "workshop"),
+            FacilityType::Other => write!(f, "other"),
+        }
+    }
+}
+
+impl FromStr for FacilityType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "classroom" => Ok(FacilityType::Classroom),
+            "laboratory" | "lab" => Ok(FacilityType::Laboratory),
+            "computer_lab" => Ok(FacilityType::ComputerLab),
+            "auditorium" => Ok(FacilityType::Auditorium),
+            "seminar_room" => Ok(FacilityType::SeminarRoom),
+            "workshop" => Ok(FacilityType::Workshop),
+            "other" => Ok(FacilityType::Other),
+            _ => Err(format!("Unknown facility type: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EquipmentKind {
+    Projector,
+    Computers,
+    LabBenches,
+    Whiteboard,
+    SoundSystem,
+    VideoConference,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExceptionKind {
+    Cancelled,
+    Rescheduled,
+}
+
+impl fmt::Display for ExceptionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExceptionKind::Cancelled => write!(f, "cancelled"),
+            ExceptionKind::Rescheduled => write!(f, "rescheduled"),
+        }
+    }
+}
+
+impl FromStr for ExceptionKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cancelled" => Ok(ExceptionKind::Cancelled),
+            "rescheduled" => Ok(ExceptionKind::Rescheduled),
+            _ => Err(format!("Unknown exception kind: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    ScheduleChanged,
+    EnrollmentConfirmed,
+    ClassCancelled,
+    ClassRescheduled,
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventKind::ScheduleChanged => write!(f, "schedule_changed"),
+            EventKind::EnrollmentConfirmed => write!(f, "enrollment_confirmed"),
+            EventKind::ClassCancelled => write!(f, "class_cancelled"),
+            EventKind::ClassRescheduled => write!(f, "class_rescheduled"),
+        }
+    }
+}
+
+impl FromStr for EventKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "schedule_changed" => Ok(EventKind::ScheduleChanged),
+            "enrollment_confirmed" => Ok(EventKind::EnrollmentConfirmed),
+            "class_cancelled" => Ok(EventKind::ClassCancelled),
+            "class_rescheduled" => Ok(EventKind::ClassRescheduled),
+            _ => Err(format!("Unknown event kind: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationChannel {
+    Email,
+    Webhook,
+    Log,
+}
+
+impl fmt::Display for NotificationChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotificationChannel::Email => write!(f, "email"),
+            NotificationChannel::Webhook => write!(f, "webhook"),
+            NotificationChannel::Log => write!(f, "log"),
+        }
+    }
+}
+
+impl FromStr for NotificationChannel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "email" => Ok(NotificationChannel::Email),
+            "webhook" => Ok(NotificationChannel::Webhook),
+            "log" => Ok(NotificationChannel::Log),
+            _ => Err(format!("Unknown notification channel: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeliveryStatus::Pending => write!(f, "pending"),
+            DeliveryStatus::Delivered => write!(f, "delivered"),
+            DeliveryStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl FromStr for DeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(DeliveryStatus::Pending),
+            "delivered" => Ok(DeliveryStatus::Delivered),
+            "failed" => Ok(DeliveryStatus::Failed),
+            _ => Err(format!("Unknown delivery status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AttendanceStatus {
+    Present,
+    Absent,
+    Late,
+    Excused,
+}
+
+impl fmt::Display for AttendanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttendanceStatus::Present => write!(f, "present"),
+            AttendanceStatus::Absent => write!(f, "absent"),
+            AttendanceStatus::Late => write!(f, "late"),
+            AttendanceStatus::Excused => write!(f, "excused"),
+        }
+    }
+}
+
+impl FromStr for AttendanceStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "present" => Ok(AttendanceStatus::Present),
+            "absent" => Ok(AttendanceStatus::Absent),
+            "late" => Ok(AttendanceStatus::Late),
+            "excused" => Ok(AttendanceStatus::Excused),
+            _ => Err(format!("Unknown attendance status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverloadStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl fmt::Display for OverloadStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverloadStatus::Pending => write!(f, "pending"),
+            OverloadStatus::Approved => write!(f, "approved"),
+            OverloadStatus::Rejected => write!(f, "rejected"),
+        }
+    }
+}
+
+impl FromStr for OverloadStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(OverloadStatus::Pending),
+            "approved" => Ok(OverloadStatus::Approved),
+            "rejected" => Ok(OverloadStatus::Rejected),
+            _ => Err(format!("Unknown overload status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssignmentStatus {
+    Proposed,
+    Accepted,
+    Overridden,
+}
+
+impl fmt::Display for AssignmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssignmentStatus::Proposed => write!(f, "proposed"),
+            AssignmentStatus::Accepted => write!(f, "accepted"),
+            AssignmentStatus::Overridden => write!(f, "overridden"),
+        }
+    }
+}
+
+impl FromStr for AssignmentStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "proposed" => Ok(AssignmentStatus::Proposed),
+            "accepted" => Ok(AssignmentStatus::Accepted),
+            "overridden" => Ok(AssignmentStatus::Overridden),
+            _ => Err(format!("Unknown assignment status: {}", s)),
+        }
+    }
+}
+
+/// Periodo del año académico. El orden de las variantes es el del calendario:
+/// el verano (`0`) va antes del primer (`I`) y del segundo semestre (`II`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Semester {
+    Summer,
+    First,
+    Second,
+}
+
+impl FromStr for Semester {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "0" => Ok(Semester::Summer),
+            "I" | "1" => Ok(Semester::First),
+            "II" | "2" => Ok(Semester::Second),
+            _ => Err(format!("Invalid semester: {} (expected 0, I or II)", s)),
+        }
+    }
+}
+
+impl Semester {
+    /// Periodo en que cae `date`: el verano de enero a marzo, el primer
+    /// semestre de abril a julio y el segundo de agosto a diciembre.
+    pub fn containing(date: NaiveDate) -> Semester {
+        match date.month() {
+            1..=3 => Semester::Summer,
+            4..=7 => Semester::First,
+            _ => Semester::Second,
+        }
+    }
+
+    /// Código con el que los cursos guardan el semestre.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Semester::Summer => "0",
+            Semester::First => "I",
+            Semester::Second => "II",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn semesters_follow_the_calendar() {
+        let mut terms: Vec<(i32, Semester)> = ["II", "0", "I"]
+            .iter()
+            .map(|s| (2025, s.parse().unwrap()))
+            .collect();
+        terms.push((2024, Semester::Second));
+        terms.sort();
+
+        assert_eq!(
+            terms,
+            vec![
+                (2024, Semester::Second),
+                (2025, Semester::Summer),
+                (2025, Semester::First),
+                (2025, Semester::Second),
+            ]
+        );
+    }
+
+    #[test]
+    fn dates_fall_in_their_semester() {
+        let date = |m| NaiveDate::from_ymd_opt(2025, m, 15).unwrap();
+
+        assert_eq!(Semester::containing(date(2)).code(), "0");
+        assert_eq!(Semester::containing(date(4)).code(), "I");
+        assert_eq!(Semester::containing(date(7)).code(), "I");
+        assert_eq!(Semester::containing(date(8)).code(), "II");
+        assert_eq!(Semester::containing(date(12)), Semester::Second);
+    }
+
+    #[test]
+    fn rejects_unknown_semesters() {
+        assert_eq!("ii".parse(), Ok(Semester::Second));
+        assert!("2025-1".parse::<Semester>().is_err());
+        assert!("".parse::<Semester>().is_err());
+    }
+}
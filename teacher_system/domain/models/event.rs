@@ -0,0 +1,124 @@
+use crate::domain::models::enums::{AuditAction, DeliveryStatus, EventKind};
+use crate::domain::models::session_exception::MakeUpSlot;
+use crate::domain::models::webhook::OutboxMessage;
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Hechos del dominio que interesan a usuarios y sistemas externos.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum DomainEvent {
+    ScheduleChanged {
+        schedule_id: String,
+        course_id: String,
+        action: AuditAction,
+    },
+    EnrollmentConfirmed {
+        enrollment_id: String,
+        student_id: String,
+        course_id: String,
+    },
+    ClassCancelled {
+        schedule_id: String,
+        course_id: String,
+        date: NaiveDate,
+        reason: String,
+    },
+    ClassRescheduled {
+        schedule_id: String,
+        course_id: String,
+        date: NaiveDate,
+        reason: String,
+        make_up: MakeUpSlot,
+    },
+}
+
+impl DomainEvent {
+    pub fn kind(&self) -> EventKind {
+        match self {
+            DomainEvent::ScheduleChanged { .. } => EventKind::ScheduleChanged,
+            DomainEvent::EnrollmentConfirmed { .. } => EventKind::EnrollmentConfirmed,
+            DomainEvent::ClassCancelled { .. } => EventKind::ClassCancelled,
+            DomainEvent::ClassRescheduled { .. } => EventKind::ClassRescheduled,
+        }
+    }
+
+    pub fn course_id(&self) -> &str {
+        match self {
+            DomainEvent::ScheduleChanged { course_id, .. }
+            | DomainEvent::EnrollmentConfirmed { course_id, .. }
+            | DomainEvent::ClassCancelled { course_id, .. }
+            | DomainEvent::ClassRescheduled { course_id, .. } => course_id,
+        }
+    }
+}
+
+/// Evento publicado con su identificador y momento; es lo que reciben los
+/// manejadores y el cuerpo que se envía a los webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    pub id: String,
+    pub occurred_at: NaiveDateTime,
+    #[serde(flatten)]
+    pub event: DomainEvent,
+}
+
+impl EventEnvelope {
+    pub fn new(event: DomainEvent) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            occurred_at: Utc::now().naive_utc(),
+            event,
+        }
+    }
+}
+
+/// Evento guardado en la bandeja de salida en la misma transacción que el
+/// cambio que lo produjo; un proceso en segundo plano lo reparte después.
+#[derive(Debug, Clone)]
+pub struct OutboxEvent {
+    pub envelope: EventEnvelope,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub last_error: Option<String>,
+    /// Manejadores que ya lo procesaron; un reintento no vuelve a pasar por
+    /// ellos.
+    pub handled_by: Vec<String>,
+}
+
+impl OutboxEvent {
+    pub fn new(event: DomainEvent) -> Self {
+        let envelope = EventEnvelope::new(event);
+        Self {
+            next_attempt_at: envelope.occurred_at,
+            envelope,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            handled_by: Vec::new(),
+        }
+    }
+
+    pub fn was_handled_by(&self, handler: &str) -> bool {
+        self.handled_by.iter().any(|h| h == handler)
+    }
+
+    /// Reintenta con la misma espera que los webhooks hasta darlo por fallido.
+    pub fn record_failure(&mut self, error: &str, now: NaiveDateTime) {
+        self.attempts += 1;
+        self.last_error = Some(error.to_string());
+
+        if self.attempts >= OutboxMessage::MAX_ATTEMPTS {
+            self.status = DeliveryStatus::Failed;
+        } else {
+            self.next_attempt_at = now + OutboxMessage::backoff(self.attempts);
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Delivered;
+        self.last_error = None;
+    }
+}
@@ -1,10 +1,60 @@
+use crate::domain::models::enums::{EquipmentKind, FacilityType};
+use crate::domain::models::validation::ValidationError;
 use serde::{Deserialize, Serialize};
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EquipmentItem {
+    pub kind: EquipmentKind,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Accessibility {
+    pub wheelchair_accessible: bool,
+    pub step_free_access: bool,
+    pub hearing_loop: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Facility {
     pub id: String,
     pub name: String,
     pub capacity: i32,
-    pub facility_type: String,
+    pub facility_type: FacilityType,
+    pub building: Option<String>,
+    pub floor: Option<i32>,
+    pub location: Option<String>,
+    #[serde(default)]
+    pub equipment: Vec<EquipmentItem>,
+    #[serde(default)]
+    pub accessibility: Accessibility,
     pub created_at: Option<String>,
 }
+
+impl Facility {
+    /// Verifica las invariantes del aula antes de persistirla.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.name.trim().is_empty() {
+            errors.add("name", "is required");
+        }
+        if self.capacity <= 0 {
+            errors.add("capacity", "must be positive");
+        }
+        if self.equipment.iter().any(|item| item.quantity <= 0) {
+            errors.add("equipment", "quantities must be positive");
+        }
+
+        errors.into_result()
+    }
+
+    /// Cuenta con cada equipo requerido en al menos la cantidad pedida.
+    pub fn has_equipment(&self, required: &[EquipmentItem]) -> bool {
+        required.iter().all(|req| {
+            self.equipment
+                .iter()
+                .any(|item| item.kind == req.kind && item.quantity >= req.quantity)
+        })
+    }
+}
@@ -1,4 +1,7 @@
-use crate::domain::models::enums::Weekday;
+use crate::domain::models::enums::{FacilityType, Weekday};
+use crate::domain::models::facilitie::Facility;
+use crate::domain::models::schedule::Schedule;
+use chrono::Timelike;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,7 +9,151 @@ pub struct FacilityAvailable {
     pub id: String,
     pub name: String,
     pub capacity: i32,
-    pub facility_type: String,
+    pub facility_type: FacilityType,
     pub day: Weekday,
     pub hours_range: Vec<(u32, u32)>,
 }
+
+/// Filtros de `/facilities/available`; las horas son enteras (0-24). Solo
+/// ocupan el aula las sesiones del semestre indicado.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvailabilityFilter {
+    pub day: Weekday,
+    pub from: Option<u32>,
+    pub to: Option<u32>,
+    pub min_capacity: Option<i32>,
+    #[serde(rename = "type")]
+    pub facility_type: Option<FacilityType>,
+    pub wheelchair_accessible: Option<bool>,
+    pub academic_year: i32,
+    pub semester: String,
+}
+
+impl FacilityAvailable {
+    /// Rangos libres en horas enteras dentro de `[from, to)`. Una hora ocupada
+    /// parcialmente por una sesión no se ofrece como libre.
+    pub fn from_sessions(
+        facility: &Facility,
+        day: Weekday,
+        from: u32,
+        to: u32,
+        sessions: &[Schedule],
+    ) -> Self {
+        let mut busy: Vec<(u32, u32)> = sessions
+            .iter()
+            .filter(|s| s.facility_id == facility.id && s.day == day)
+            .map(|s| {
+                let end = s.end_time.hour() + u32::from(s.end_time.minute() > 0);
+                (s.start_time.hour(), end)
+            })
+            .collect();
+        busy.sort_unstable();
+
+        let mut hours_range = Vec::new();
+        let mut cursor = from;
+        for (start, end) in busy {
+            if start > cursor {
+                hours_range.push((cursor, start.min(to)));
+            }
+            cursor = cursor.max(end);
+            if cursor >= to {
+                break;
+            }
+        }
+        if cursor < to {
+            hours_range.push((cursor, to));
+        }
+
+        Self {
+            id: facility.id.clone(),
+            name: facility.name.clone(),
+            capacity: facility.capacity,
+            facility_type: facility.facility_type,
+            day,
+            hours_range,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::enums::SessionType;
+    use chrono::NaiveTime;
+
+    fn facility() -> Facility {
+        Facility {
+            id: "A-101".to_string(),
+            name: "A-101".to_string(),
+            capacity: 40,
+            facility_type: FacilityType::Classroom,
+            building: None,
+            floor: None,
+            location: None,
+            equipment: Vec::new(),
+            accessibility: Default::default(),
+            created_at: None,
+        }
+    }
+
+    fn session(facility_id: &str, day: Weekday, start: (u32, u32), end: (u32, u32)) -> Schedule {
+        Schedule {
+            id: format!("{}-{}", start.0, end.0),
+            course_id: "c1".to_string(),
+            day,
+            start_time: NaiveTime::from_hms_opt(start.0, start.1, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end.0, end.1, 0).unwrap(),
+            session_type: SessionType::Theory,
+            location_detail: None,
+            created_at: None,
+            facility_id: facility_id.to_string(),
+            required_equipment: Vec::new(),
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn free_ranges_skip_busy_hours() {
+        let sessions = vec![
+            session("A-101", Weekday::Monday, (14, 0), (16, 0)),
+            session("A-101", Weekday::Monday, (10, 0), (12, 0)),
+        ];
+
+        let available =
+            FacilityAvailable::from_sessions(&facility(), Weekday::Monday, 8, 22, &sessions);
+        assert_eq!(available.hours_range, vec![(8, 10), (12, 14), (16, 22)]);
+    }
+
+    #[test]
+    fn a_partly_used_hour_is_busy() {
+        let sessions = vec![session("A-101", Weekday::Monday, (9, 0), (10, 30))];
+
+        let available =
+            FacilityAvailable::from_sessions(&facility(), Weekday::Monday, 8, 12, &sessions);
+        assert_eq!(available.hours_range, vec![(8, 9), (11, 12)]);
+    }
+
+    #[test]
+    fn ignores_other_days_and_facilities() {
+        let sessions = vec![
+            session("A-101", Weekday::Tuesday, (8, 0), (22, 0)),
+            session("B-201", Weekday::Monday, (8, 0), (22, 0)),
+        ];
+
+        let available =
+            FacilityAvailable::from_sessions(&facility(), Weekday::Monday, 8, 22, &sessions);
+        assert_eq!(available.hours_range, vec![(8, 22)]);
+    }
+
+    #[test]
+    fn a_fully_booked_day_has_no_ranges() {
+        let sessions = vec![
+            session("A-101", Weekday::Monday, (8, 0), (13, 0)),
+            session("A-101", Weekday::Monday, (12, 0), (22, 0)),
+        ];
+
+        let available =
+            FacilityAvailable::from_sessions(&facility(), Weekday::Monday, 8, 22, &sessions);
+        assert!(available.hours_range.is_empty());
+    }
+}
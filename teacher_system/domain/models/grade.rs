@@ -0,0 +1,197 @@
+use crate::domain::models::{enums::EnrollmentStatus, validation::ValidationError};
+use serde::{Deserialize, Serialize};
+
+/// Nota máxima de la escala vigesimal.
+pub const MAX_GRADE: f64 = 20.0;
+/// Nota mínima aprobatoria si el curso no define otra.
+pub const DEFAULT_PASSING_GRADE: f64 = 10.5;
+
+/// Evaluación del curso y su peso porcentual en la nota final.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GradeComponent {
+    pub name: String,
+    pub weight: f64,
+}
+
+/// Esquema de calificación configurado por curso.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GradingScheme {
+    pub course_id: String,
+    pub components: Vec<GradeComponent>,
+    pub passing_grade: f64,
+    pub updated_at: Option<String>,
+}
+
+impl GradingScheme {
+    /// Los pesos deben sumar 100 y cada evaluación tener un nombre único.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.components.is_empty() {
+            errors.add("components", "must include at least one evaluation");
+        }
+
+        let mut seen: Vec<String> = Vec::new();
+        for component in &self.components {
+            let name = component.name.trim().to_lowercase();
+            if name.is_empty() {
+                errors.add("components", "name is required");
+            } else if seen.contains(&name) {
+                errors.add("components", &format!("{} is repeated", component.name));
+            }
+            if component.weight <= 0.0 {
+                errors.add("components", &format!("{} must weigh more than 0", component.name));
+            }
+            seen.push(name);
+        }
+
+        let total: f64 = self.components.iter().map(|c| c.weight).sum();
+        if !self.components.is_empty() && (total - 100.0).abs() > 0.01 {
+            errors.add("components", &format!("weights must add up to 100, got {}", total));
+        }
+        if !(0.0..=MAX_GRADE).contains(&self.passing_grade) {
+            errors.add("passing_grade", &format!("must be between 0 and {}", MAX_GRADE));
+        }
+
+        errors.into_result()
+    }
+
+    pub fn component(&self, name: &str) -> Option<&GradeComponent> {
+        self.components
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Evaluaciones del esquema que aún no tienen nota.
+    pub fn missing_components(&self, grades: &[&ComponentGrade]) -> Vec<&str> {
+        self.components
+            .iter()
+            .filter(|component| {
+                !grades
+                    .iter()
+                    .any(|g| g.component.eq_ignore_ascii_case(&component.name))
+            })
+            .map(|component| component.name.as_str())
+            .collect()
+    }
+
+    /// Promedio ponderado con dos decimales; una evaluación sin nota cuenta
+    /// como cero (así se muestra el promedio acumulado antes del cierre).
+    pub fn final_grade(&self, grades: &[&ComponentGrade]) -> f64 {
+        let total: f64 = self
+            .components
+            .iter()
+            .map(|component| {
+                grades
+                    .iter()
+                    .find(|g| g.component.eq_ignore_ascii_case(&component.name))
+                    .map_or(0.0, |g| g.score * component.weight / 100.0)
+            })
+            .sum();
+        (total * 100.0).round() / 100.0
+    }
+
+    pub fn is_passing(&self, grade: f64) -> bool {
+        grade >= self.passing_grade
+    }
+}
+
+/// Nota de una matrícula en una evaluación del esquema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentGrade {
+    pub enrollment_id: String,
+    pub component: String,
+    pub score: f64,
+    pub recorded_by: String,
+    pub recorded_at: Option<String>,
+}
+
+/// Libreta de un alumno en el curso: notas parciales, promedio acumulado y
+/// nota final una vez cerrado.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnrollmentGrades {
+    pub enrollment_id: String,
+    pub student_id: String,
+    pub status: EnrollmentStatus,
+    pub components: Vec<ComponentGrade>,
+    pub running_grade: f64,
+    pub final_grade: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheme() -> GradingScheme {
+        GradingScheme {
+            course_id: "c1".to_string(),
+            components: vec![
+                GradeComponent {
+                    name: "Parcial".to_string(),
+                    weight: 30.0,
+                },
+                GradeComponent {
+                    name: "Final".to_string(),
+                    weight: 50.0,
+                },
+                GradeComponent {
+                    name: "Tareas".to_string(),
+                    weight: 20.0,
+                },
+            ],
+            passing_grade: DEFAULT_PASSING_GRADE,
+            updated_at: None,
+        }
+    }
+
+    fn grade(component: &str, score: f64) -> ComponentGrade {
+        ComponentGrade {
+            enrollment_id: "e1".to_string(),
+            component: component.to_string(),
+            score,
+            recorded_by: "t1".to_string(),
+            recorded_at: None,
+        }
+    }
+
+    #[test]
+    fn final_grade_is_the_weighted_average_rounded_to_two_decimals() {
+        let grades = [grade("Parcial", 13.0), grade("final", 11.5), grade("Tareas", 17.0)];
+        let grades: Vec<&ComponentGrade> = grades.iter().collect();
+
+        // 3.9 + 5.75 + 3.4
+        assert_eq!(scheme().final_grade(&grades), 13.05);
+    }
+
+    #[test]
+    fn final_grade_counts_missing_components_as_zero() {
+        let grades = [grade("Parcial", 20.0)];
+        let grades: Vec<&ComponentGrade> = grades.iter().collect();
+
+        assert_eq!(scheme().final_grade(&grades), 6.0);
+    }
+
+    #[test]
+    fn final_grade_ignores_grades_outside_the_scheme() {
+        let grades = [grade("Parcial", 10.0), grade("Extra", 20.0)];
+        let grades: Vec<&ComponentGrade> = grades.iter().collect();
+
+        assert_eq!(scheme().final_grade(&grades), 3.0);
+    }
+
+    #[test]
+    fn missing_components_lists_evaluations_without_grade() {
+        let grades = [grade("parcial", 12.0)];
+        let grades: Vec<&ComponentGrade> = grades.iter().collect();
+
+        assert_eq!(scheme().missing_components(&grades), vec!["Final", "Tareas"]);
+    }
+
+    #[test]
+    fn passing_grade_is_inclusive() {
+        let scheme = scheme();
+
+        assert!(scheme.is_passing(10.5));
+        assert!(!scheme.is_passing(10.49));
+    }
+}
@@ -1,6 +1,27 @@
+pub mod attendance;
+pub mod audit_log;
+pub mod booking;
+pub mod change_set;
+pub mod conflict;
 pub mod course;
+pub mod credit_load;
+pub mod demand_forecast;
 pub mod enrollment;
 pub mod enums;
+pub mod event;
 pub mod facilitie;
+pub mod facilitie_available;
+pub mod grade;
+pub mod notification;
 pub mod schedule;
+pub mod section;
+pub mod session_exception;
+pub mod student_status;
+pub mod study_plan;
+pub mod teaching_load;
+pub mod teacher_assignment;
+pub mod transcript;
 pub mod user;
+pub mod validation;
+pub mod webhook;
+pub mod weekly_grid;
@@ -0,0 +1,52 @@
+use crate::domain::models::enums::{EventKind, NotificationChannel};
+use crate::domain::models::validation::ValidationError;
+use serde::{Deserialize, Serialize};
+
+/// Mensaje dirigido a un usuario, independiente del canal de envío.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub recipient_id: String,
+    pub recipient_email: Option<String>,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Canales por los que un usuario quiere recibir avisos y eventos silenciados.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationPreference {
+    pub user_id: String,
+    pub channels: Vec<NotificationChannel>,
+    #[serde(default)]
+    pub muted_events: Vec<EventKind>,
+    pub updated_at: Option<String>,
+}
+
+impl NotificationPreference {
+    /// Sin preferencias guardadas se avisa por correo de todos los eventos.
+    pub fn default_for(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            channels: vec![NotificationChannel::Email],
+            muted_events: Vec::new(),
+            updated_at: None,
+        }
+    }
+
+    pub fn wants(&self, kind: EventKind) -> bool {
+        !self.muted_events.contains(&kind)
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        let mut seen = Vec::new();
+        for channel in &self.channels {
+            if seen.contains(channel) {
+                errors.add("channels", &format!("{} is repeated", channel));
+            }
+            seen.push(*channel);
+        }
+
+        errors.into_result()
+    }
+}
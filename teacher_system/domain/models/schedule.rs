@@ -1,4 +1,6 @@
 use crate::domain::models::enums::{SessionType, Weekday};
+use crate::domain::models::facilitie::EquipmentItem;
+use crate::domain::models::validation::ValidationError;
 use chrono::NaiveTime;
 use serde::{Deserialize, Serialize};
 
@@ -13,9 +15,61 @@ pub struct Schedule {
     pub location_detail: Option<String>,
     pub created_at: Option<String>,
     pub facility_id: String,
+    #[serde(default)]
+    pub required_equipment: Vec<EquipmentItem>,
+    #[serde(default)]
+    pub version: i32,
+}
+
+/// Horario institucional en el que se pueden programar sesiones.
+pub fn institutional_hours() -> (NaiveTime, NaiveTime) {
+    (
+        NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+        NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+    )
 }
 
 impl Schedule {
+    /// Verifica las invariantes de la sesión antes de persistirla.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = self.request_errors();
+
+        if self.facility_id.trim().is_empty() {
+            errors.add("facility_id", "is required");
+        }
+
+        errors.into_result()
+    }
+
+    /// Reglas que ya se pueden comprobar en la petición, antes de que el
+    /// servidor asigne el aula.
+    pub fn validate_request(&self) -> Result<(), ValidationError> {
+        self.request_errors().into_result()
+    }
+
+    fn request_errors(&self) -> ValidationError {
+        let mut errors = ValidationError::default();
+        let (opening, closing) = institutional_hours();
+
+        if self.course_id.trim().is_empty() {
+            errors.add("course_id", "is required");
+        }
+        if self.end_time <= self.start_time {
+            errors.add("end_time", "must be after start_time");
+        }
+        if self.start_time < opening {
+            errors.add("start_time", "must be within institutional hours (08:00-22:00)");
+        }
+        if self.end_time > closing {
+            errors.add("end_time", "must be within institutional hours (08:00-22:00)");
+        }
+        if self.required_equipment.iter().any(|item| item.quantity <= 0) {
+            errors.add("required_equipment", "quantities must be positive");
+        }
+
+        errors
+    }
+
     pub fn conflicts_with(&self, other: &Schedule) -> bool {
         self.day == other.day
             && self.start_time < other.end_time
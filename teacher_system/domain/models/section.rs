@@ -0,0 +1,447 @@
+use crate::domain::models::{
+    conflict::{TimetableIssue, audit_timetable},
+    course::Course,
+    facilitie::Facility,
+    schedule::Schedule,
+    validation::ValidationError,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Copia los cursos de un semestre, con sus sesiones, a otro.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TermClone {
+    pub from_academic_year: i32,
+    pub from_semester: String,
+    pub to_academic_year: i32,
+    pub to_semester: String,
+    /// Solo los cursos de este programa; todos si falta.
+    pub program: Option<String>,
+}
+
+impl TermClone {
+    /// Copia de la sección en el semestre destino, sin matrículas y con las
+    /// mismas sesiones, profesor y aula.
+    pub fn copy(&self, source: &Course, sessions: Vec<Schedule>) -> (Course, Vec<Schedule>) {
+        let course = Course {
+            id: Uuid::new_v4().to_string(),
+            academic_year: self.to_academic_year,
+            semester: self.to_semester.clone(),
+            enrolled: 0,
+            active: true,
+            version: 0,
+            ..source.clone()
+        };
+        let sessions = sessions
+            .into_iter()
+            .map(|s| Schedule {
+                id: Uuid::new_v4().to_string(),
+                course_id: course.id.clone(),
+                created_at: None,
+                version: 0,
+                ..s
+            })
+            .collect();
+        (course, sessions)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TermCloneResult {
+    pub created: Vec<Course>,
+    pub sessions_created: i32,
+    /// Secciones (`código-sección`) que ya existían en el semestre destino.
+    pub skipped: Vec<String>,
+}
+
+/// Abre una sección nueva con parte de las matrículas de otra.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionSplit {
+    pub enrollment_ids: Vec<String>,
+    /// Por defecto, el mismo profesor.
+    pub teacher_id: Option<String>,
+    /// Por defecto, la misma capacidad.
+    pub capacity: Option<i32>,
+    /// Sesiones de la nueva sección; si está vacío se copian las de origen.
+    pub sessions: Vec<Schedule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionSplitResult {
+    pub source: Course,
+    pub section: Course,
+    pub sessions: Vec<Schedule>,
+    pub moved: Vec<String>,
+}
+
+/// Junta otras secciones del mismo curso y semestre en una.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionMerge {
+    pub section_ids: Vec<String>,
+    /// Nueva capacidad de la sección que queda, si hace falta ampliarla.
+    pub capacity: Option<i32>,
+}
+
+impl SectionMerge {
+    /// Cada sección se junta una sola vez y nunca consigo misma.
+    pub fn validate(&self, course_id: &str) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.section_ids.is_empty() {
+            errors.add("section_ids", "must not be empty");
+        }
+        if self.section_ids.iter().any(|id| id == course_id) {
+            errors.add("section_ids", "must not include the section that remains");
+        }
+        let mut unique = self.section_ids.clone();
+        unique.sort();
+        unique.dedup();
+        if unique.len() != self.section_ids.len() {
+            errors.add("section_ids", "must not repeat a section");
+        }
+
+        errors.into_result()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionMergeResult {
+    pub section: Course,
+    pub moved: i32,
+    pub archived: Vec<String>,
+}
+
+/// Horario completo de un semestre sobre el que se ensayan los cambios de
+/// secciones antes de guardarlos.
+#[derive(Debug, Clone)]
+pub struct TermTimetable {
+    pub courses: HashMap<String, Course>,
+    pub sessions: Vec<Schedule>,
+    pub facilities: HashMap<String, Facility>,
+}
+
+impl TermTimetable {
+    pub fn find_section(&self, code: &str, section: i32) -> Option<&Course> {
+        self.courses
+            .values()
+            .find(|c| c.code == code && c.section == section)
+    }
+
+    pub fn next_section(&self, code: &str) -> i32 {
+        self.courses
+            .values()
+            .filter(|c| c.code == code)
+            .map(|c| c.section)
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+
+    pub fn sessions_of(&self, course_id: &str) -> Vec<Schedule> {
+        self.sessions
+            .iter()
+            .filter(|s| s.course_id == course_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Sesiones del semestre que dicta el profesor: primero las de
+    /// `course_ids` y luego las demás, como las recibe `check_sessions`.
+    pub fn teacher_sessions(
+        &self,
+        teacher_id: &str,
+        course_ids: &[String],
+    ) -> (Vec<Schedule>, Vec<Schedule>) {
+        self.sessions
+            .iter()
+            .filter(|s| {
+                self.courses
+                    .get(&s.course_id)
+                    .is_some_and(|c| c.teacher_id == teacher_id)
+            })
+            .cloned()
+            .partition(|s| course_ids.contains(&s.course_id))
+    }
+
+    pub fn add(&mut self, course: Course, sessions: Vec<Schedule>) {
+        self.courses.insert(course.id.clone(), course);
+        self.sessions.extend(sessions);
+    }
+
+    pub fn remove(&mut self, course_id: &str) {
+        self.courses.remove(course_id);
+        self.sessions.retain(|s| s.course_id != course_id);
+    }
+
+    /// Aula más chica que admite la sesión y está libre en ese horario.
+    pub fn free_facility(&self, session: &Schedule, capacity: i32) -> Option<&Facility> {
+        let mut candidates: Vec<&Facility> = self
+            .facilities
+            .values()
+            .filter(|f| {
+                f.capacity >= capacity
+                    && session.session_type.fits_facility_type(&f.facility_type)
+                    && f.has_equipment(&session.required_equipment)
+                    && !self
+                        .sessions
+                        .iter()
+                        .any(|s| s.facility_id == f.id && s.conflicts_with(session))
+            })
+            .collect();
+        candidates.sort_by(|a, b| (a.capacity, &a.id).cmp(&(b.capacity, &b.id)));
+        candidates.into_iter().next()
+    }
+
+    /// Problemas del horario que involucran a los cursos modificados.
+    pub fn issues_for(&self, course_ids: &[String]) -> Vec<TimetableIssue> {
+        audit_timetable(&self.courses, &self.facilities, &self.sessions)
+            .into_iter()
+            .filter(|issue| issue.course_ids.iter().any(|id| course_ids.contains(id)))
+            .collect()
+    }
+
+    /// Falla si los cursos modificados chocan en profesor o aula, o no caben.
+    pub fn check(&self, course_ids: &[String]) -> Result<(), String> {
+        let issues = self.issues_for(course_ids);
+        if issues.is_empty() {
+            return Ok(());
+        }
+
+        let details: Vec<&str> = issues.iter().map(|i| i.detail.as_str()).collect();
+        Err(format!("Conflict: {}", details.join("; ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{
+        conflict::term_overlaps,
+        enums::{Curriculum, FacilityType, SessionType, Weekday},
+    };
+    use chrono::NaiveTime;
+
+    fn course(id: &str, code: &str, section: i32, teacher_id: &str) -> Course {
+        Course {
+            id: id.to_string(),
+            code: code.to_string(),
+            name: code.to_string(),
+            section,
+            curriculum: Curriculum::Obligatory,
+            capacity: 30,
+            credits: 4,
+            hours_per_week: 4,
+            cycle: 1,
+            teacher_id: teacher_id.to_string(),
+            facility_id: String::new(),
+            enrolled: 0,
+            semester: "I".to_string(),
+            academic_year: 2025,
+            program: "SIS".to_string(),
+            active: true,
+            version: 1,
+        }
+    }
+
+    fn session(id: &str, course_id: &str, facility_id: &str, start: u32, end: u32) -> Schedule {
+        Schedule {
+            id: id.to_string(),
+            course_id: course_id.to_string(),
+            day: Weekday::Monday,
+            start_time: NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end, 0, 0).unwrap(),
+            session_type: SessionType::Theory,
+            location_detail: None,
+            created_at: None,
+            facility_id: facility_id.to_string(),
+            required_equipment: Vec::new(),
+            version: 1,
+        }
+    }
+
+    fn facility(id: &str, capacity: i32) -> Facility {
+        Facility {
+            id: id.to_string(),
+            name: id.to_string(),
+            capacity,
+            facility_type: FacilityType::Classroom,
+            building: None,
+            floor: None,
+            location: None,
+            equipment: Vec::new(),
+            accessibility: Default::default(),
+            created_at: None,
+        }
+    }
+
+    fn timetable(courses: Vec<Course>, sessions: Vec<Schedule>) -> TermTimetable {
+        TermTimetable {
+            courses: courses.into_iter().map(|c| (c.id.clone(), c)).collect(),
+            sessions,
+            facilities: [
+                facility("A-101", 40),
+                facility("A-102", 30),
+                facility("B-201", 80),
+            ]
+            .into_iter()
+            .map(|f| (f.id.clone(), f))
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn next_section_follows_the_highest_one() {
+        let timetable = timetable(
+            vec![course("c1", "MAT1", 1, "t1"), course("c3", "MAT1", 3, "t1")],
+            Vec::new(),
+        );
+
+        assert_eq!(timetable.next_section("MAT1"), 4);
+        assert_eq!(timetable.next_section("FIS1"), 1);
+        assert_eq!(
+            timetable.find_section("MAT1", 3).map(|c| c.id.as_str()),
+            Some("c3")
+        );
+    }
+
+    #[test]
+    fn free_facility_picks_the_smallest_room_left() {
+        let timetable = timetable(
+            vec![course("c1", "MAT1", 1, "t1")],
+            vec![session("s1", "c1", "A-102", 8, 10)],
+        );
+
+        let candidate = session("new", "c2", "", 9, 11);
+        assert_eq!(
+            timetable
+                .free_facility(&candidate, 30)
+                .map(|f| f.id.as_str()),
+            Some("A-101")
+        );
+        assert_eq!(
+            timetable
+                .free_facility(&candidate, 50)
+                .map(|f| f.id.as_str()),
+            Some("B-201")
+        );
+        assert!(timetable.free_facility(&candidate, 100).is_none());
+    }
+
+    #[test]
+    fn check_reports_teacher_clashes_of_changed_courses_only() {
+        let mut timetable = timetable(
+            vec![course("c1", "MAT1", 1, "t1"), course("c2", "FIS1", 1, "t1")],
+            vec![
+                session("s1", "c1", "A-101", 8, 10),
+                session("s2", "c2", "A-102", 9, 11),
+            ],
+        );
+
+        let error = timetable.check(&["c2".to_string()]).unwrap_err();
+        assert!(error.starts_with("Conflict: Teacher has"), "{}", error);
+        assert!(timetable.check(&["c3".to_string()]).is_ok());
+
+        timetable.remove("c2");
+        assert!(timetable.check(&["c1".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn sessions_of_another_year_do_not_double_book() {
+        let next_year = Course {
+            academic_year: 2026,
+            ..course("c2", "FIS1", 1, "t1")
+        };
+        let timetable = timetable(
+            vec![course("c1", "MAT1", 1, "t1"), next_year],
+            vec![
+                session("s1", "c1", "A-101", 8, 10),
+                session("s2", "c2", "A-101", 8, 10),
+            ],
+        );
+
+        assert!(timetable.issues_for(&["c1".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn merge_rejects_repeated_sections_and_the_target() {
+        let merge = |ids: &[&str]| SectionMerge {
+            section_ids: ids.iter().map(|id| id.to_string()).collect(),
+            capacity: None,
+        };
+
+        assert!(merge(&["c2", "c3"]).validate("c1").is_ok());
+        assert!(merge(&["c2", "c2"]).validate("c1").is_err());
+        assert!(merge(&["c1", "c2"]).validate("c1").is_err());
+        assert!(merge(&[]).validate("c1").is_err());
+    }
+
+    #[test]
+    fn teacher_sessions_split_the_new_sections_from_the_rest() {
+        let timetable = timetable(
+            vec![
+                course("c1", "MAT1", 1, "t1"),
+                course("c2", "MAT1", 2, "t1"),
+                course("c3", "FIS1", 1, "t2"),
+            ],
+            vec![
+                session("s1", "c1", "A-101", 8, 10),
+                session("s2", "c2", "A-101", 10, 12),
+                session("s3", "c3", "A-102", 8, 10),
+            ],
+        );
+
+        let (new, others) = timetable.teacher_sessions("t1", &["c2".to_string()]);
+        assert_eq!(new.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(), ["s2"]);
+        assert_eq!(
+            others.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(),
+            ["s1"]
+        );
+    }
+
+    #[test]
+    fn a_cloned_term_does_not_clash_with_its_source() {
+        let source = course("c1", "MAT1", 1, "t1");
+        let sessions = vec![session("s1", "c1", "A-101", 8, 10)];
+        let request = TermClone {
+            from_academic_year: 2025,
+            from_semester: "I".to_string(),
+            to_academic_year: 2025,
+            to_semester: "II".to_string(),
+            program: None,
+        };
+
+        let (copy, copied) = request.copy(&source, sessions.clone());
+        assert_eq!(copied[0].course_id, copy.id);
+        assert_eq!(copied[0].facility_id, "A-101");
+
+        // Se edita la sesión de origen sin moverla de aula ni de profesor
+        let mut edited = sessions[0].clone();
+        edited.end_time = NaiveTime::from_hms_opt(11, 0, 0).unwrap();
+
+        let courses: HashMap<String, Course> = [source.clone(), copy.clone()]
+            .into_iter()
+            .map(|c| (c.id.clone(), c))
+            .collect();
+        let all: Vec<Schedule> = sessions.iter().chain(&copied).cloned().collect();
+
+        assert!(term_overlaps(&edited, &source, &courses, &all).is_empty());
+        assert!(term_overlaps(&copied[0], &copy, &courses, &all).is_empty());
+
+        // En el semestre destino la copia sí ocupa su horario
+        let other = course("c2", "FIS1", 1, "t1");
+        let mut courses = courses;
+        courses.insert(
+            other.id.clone(),
+            Course {
+                semester: "II".to_string(),
+                ..other.clone()
+            },
+        );
+        let clash = session("s2", "c2", "B-201", 9, 10);
+        assert_eq!(
+            term_overlaps(&clash, &courses["c2"], &courses, &all).len(),
+            1
+        );
+    }
+}
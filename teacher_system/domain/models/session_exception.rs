@@ -0,0 +1,78 @@
+use crate::domain::models::enums::{ExceptionKind, Weekday};
+use crate::domain::models::schedule::Schedule;
+use crate::domain::models::validation::ValidationError;
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// Nueva fecha, hora y aula de una sesión reprogramada.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MakeUpSlot {
+    pub date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub facility_id: String,
+    /// Reserva del aula que respalda la recuperación
+    pub booking_id: Option<String>,
+}
+
+impl MakeUpSlot {
+    /// Se cruza con otra ocupación del profesor o del aula en la misma fecha.
+    pub fn overlaps(&self, date: NaiveDate, start_time: NaiveTime, end_time: NaiveTime) -> bool {
+        self.date == date && self.start_time < end_time && self.end_time > start_time
+    }
+}
+
+/// Excepción sobre una fecha concreta de una sesión recurrente.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionException {
+    pub id: String,
+    pub schedule_id: String,
+    pub original_date: NaiveDate,
+    pub kind: ExceptionKind,
+    pub reason: String,
+    pub make_up: Option<MakeUpSlot>,
+    pub created_by: String,
+    pub created_at: Option<String>,
+}
+
+impl SessionException {
+    pub fn validate(&self, schedule: &Schedule) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.reason.trim().is_empty() {
+            errors.add("reason", "is required");
+        }
+        if Weekday::from(self.original_date.weekday()) != schedule.day {
+            errors.add("date", "the session does not take place on that date");
+        }
+
+        match (&self.kind, &self.make_up) {
+            (ExceptionKind::Rescheduled, None) => {
+                errors.add("make_up", "is required to reschedule a session");
+            }
+            (ExceptionKind::Cancelled, Some(_)) => {
+                errors.add("make_up", "must be empty when cancelling a session");
+            }
+            (_, Some(slot)) if slot.end_time <= slot.start_time => {
+                errors.add("make_up.end_time", "must be after start_time");
+            }
+            _ => {}
+        }
+
+        errors.into_result()
+    }
+
+    /// Sesión equivalente a la recuperación, para reutilizar las validaciones
+    /// de disponibilidad. Lleva el ID de la excepción: la sesión original sigue
+    /// dictándose las demás semanas y debe poder chocar con su recuperación.
+    pub fn make_up_session(&self, schedule: &Schedule) -> Option<Schedule> {
+        self.make_up.as_ref().map(|slot| Schedule {
+            id: self.id.clone(),
+            day: slot.date.weekday().into(),
+            start_time: slot.start_time,
+            end_time: slot.end_time,
+            facility_id: slot.facility_id.clone(),
+            ..schedule.clone()
+        })
+    }
+}
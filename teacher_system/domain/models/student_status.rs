@@ -0,0 +1,285 @@
+use crate::domain::models::{enums::StudentStatus, grade::MAX_GRADE, transcript::Transcript};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Regla que se evalúa sobre el historial del alumno al cerrar el semestre.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum StatusRule {
+    /// Desaprobó el mismo curso `times` veces y aún no lo aprueba.
+    RepeatedFailure { times: i32 },
+    /// Promedio ponderado acumulado por debajo de `below`.
+    LowAverage { below: f64 },
+    /// Aprobó todos los créditos obligatorios del programa.
+    ProgramCompleted,
+}
+
+impl StatusRule {
+    pub fn outcome(&self) -> StudentStatus {
+        match self {
+            StatusRule::RepeatedFailure { .. } | StatusRule::LowAverage { .. } => {
+                StudentStatus::Observation
+            }
+            StatusRule::ProgramCompleted => StudentStatus::Graduated,
+        }
+    }
+
+    /// Motivo legible si la regla se cumple.
+    pub fn check(&self, transcript: &Transcript) -> Option<String> {
+        match self {
+            StatusRule::RepeatedFailure { times } => {
+                let entries: Vec<_> = transcript.terms.iter().flat_map(|t| &t.entries).collect();
+                let mut repeated: Vec<&str> = Vec::new();
+                for entry in &entries {
+                    let failures = entries
+                        .iter()
+                        .filter(|e| e.code == entry.code && !e.passed)
+                        .count() as i32;
+                    let passed = entries.iter().any(|e| e.code == entry.code && e.passed);
+                    if failures >= *times && !passed && !repeated.contains(&entry.code.as_str()) {
+                        repeated.push(&entry.code);
+                    }
+                }
+                (!repeated.is_empty()).then(|| {
+                    format!("failed {} {} times or more", repeated.join(", "), times)
+                })
+            }
+            StatusRule::LowAverage { below } => {
+                let graded = transcript.credits_attempted > 0;
+                (graded && transcript.weighted_average < *below).then(|| {
+                    format!(
+                        "weighted average {:.2} is below {}",
+                        transcript.weighted_average, below
+                    )
+                })
+            }
+            StatusRule::ProgramCompleted => {
+                let progress = &transcript.progress;
+                let completed = progress.required_credits > 0
+                    && progress.pending_courses.is_empty()
+                    && progress.pending_elective_groups.is_empty();
+                completed.then(|| {
+                    format!(
+                        "completed {} required credits of {}",
+                        progress.required_credits, progress.program
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// Reglas activas. Se configuran con `STUDENT_STATUS_RULES` como una lista
+/// JSON, por ejemplo `[{"rule":"repeated_failure","times":2}]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusRuleSet {
+    pub rules: Vec<StatusRule>,
+}
+
+impl Default for StatusRuleSet {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                StatusRule::RepeatedFailure { times: 2 },
+                StatusRule::ProgramCompleted,
+            ],
+        }
+    }
+}
+
+impl FromStr for StatusRuleSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rules: Vec<StatusRule> =
+            serde_json::from_str(s).map_err(|e| format!("Invalid student status rules: {}", e))?;
+
+        for rule in &rules {
+            match rule {
+                StatusRule::RepeatedFailure { times } if *times < 1 => {
+                    return Err("Invalid student status rules: times must be at least 1".to_string());
+                }
+                StatusRule::LowAverage { below } if !(0.0..=MAX_GRADE).contains(below) => {
+                    return Err(format!(
+                        "Invalid student status rules: below must be between 0 and {}",
+                        MAX_GRADE
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self { rules })
+    }
+}
+
+/// Estado que corresponde al alumno y las reglas que lo justifican.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusDecision {
+    pub status: StudentStatus,
+    pub reasons: Vec<String>,
+}
+
+impl StatusRuleSet {
+    /// Egresar tiene prioridad sobre quedar observado, y un egresado ya no
+    /// vuelve a evaluarse. Si ninguna regla aplica el alumno es regular.
+    pub fn evaluate(&self, current: Option<&StudentStatus>, transcript: &Transcript) -> StatusDecision {
+        if current == Some(&StudentStatus::Graduated) {
+            return StatusDecision {
+                status: StudentStatus::Graduated,
+                reasons: Vec::new(),
+            };
+        }
+
+        for status in [StudentStatus::Graduated, StudentStatus::Observation] {
+            let reasons: Vec<String> = self
+                .rules
+                .iter()
+                .filter(|rule| rule.outcome() == status)
+                .filter_map(|rule| rule.check(transcript))
+                .collect();
+            if !reasons.is_empty() {
+                return StatusDecision { status, reasons };
+            }
+        }
+
+        StatusDecision {
+            status: StudentStatus::Regular,
+            reasons: Vec::new(),
+        }
+    }
+}
+
+/// Cambio de estado registrado en el historial del alumno.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub id: String,
+    pub student_id: String,
+    pub previous_status: Option<StudentStatus>,
+    pub new_status: StudentStatus,
+    pub reasons: Vec<String>,
+    pub changed_by: String,
+    pub changed_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::transcript::{ProgramProgress, TranscriptEntry, TranscriptTerm};
+
+    fn entry(code: &str, final_grade: f64) -> TranscriptEntry {
+        TranscriptEntry {
+            course_id: code.to_string(),
+            code: code.to_string(),
+            name: code.to_string(),
+            credits: 4,
+            final_grade,
+            passed: final_grade >= 10.5,
+        }
+    }
+
+    fn transcript(entries: Vec<TranscriptEntry>, pending_courses: &[&str]) -> Transcript {
+        let credits_attempted = entries.iter().map(|e| e.credits).sum();
+        let credits_earned = entries.iter().filter(|e| e.passed).map(|e| e.credits).sum();
+        let weighted_average = if entries.is_empty() {
+            0.0
+        } else {
+            entries.iter().map(|e| e.final_grade).sum::<f64>() / entries.len() as f64
+        };
+        Transcript {
+            student_id: "s1".to_string(),
+            student_code: "2021001".to_string(),
+            full_name: "Ana Pérez".to_string(),
+            student_status: None,
+            admission_date: None,
+            terms: vec![TranscriptTerm {
+                academic_year: 2024,
+                semester: "I".to_string(),
+                entries,
+                credits_attempted,
+                credits_earned,
+                weighted_average,
+            }],
+            credits_attempted,
+            credits_earned,
+            weighted_average,
+            progress: ProgramProgress {
+                program: "SIS".to_string(),
+                plan_version: None,
+                required_credits: 8,
+                earned_required_credits: credits_earned,
+                earned_elective_credits: 0,
+                percentage: 0.0,
+                pending_courses: pending_courses.iter().map(|c| c.to_string()).collect(),
+                pending_elective_groups: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn no_matching_rule_falls_back_to_regular() {
+        let rules = StatusRuleSet::default();
+        let decision = rules.evaluate(
+            Some(&StudentStatus::Observation),
+            &transcript(vec![entry("MAT1", 14.0), entry("FIS1", 8.0)], &["FIS1"]),
+        );
+
+        assert_eq!(decision.status, StudentStatus::Regular);
+        assert!(decision.reasons.is_empty());
+    }
+
+    #[test]
+    fn repeated_failures_put_the_student_under_observation() {
+        let rules = StatusRuleSet::default();
+        let decision = rules.evaluate(
+            None,
+            &transcript(vec![entry("FIS1", 8.0), entry("FIS1", 9.0)], &["MAT1", "FIS1"]),
+        );
+
+        assert_eq!(decision.status, StudentStatus::Observation);
+        assert_eq!(decision.reasons, vec!["failed FIS1 2 times or more".to_string()]);
+    }
+
+    #[test]
+    fn a_failure_later_passed_is_not_repeated() {
+        let rules = StatusRuleSet::default();
+        let decision = rules.evaluate(
+            None,
+            &transcript(
+                vec![entry("FIS1", 8.0), entry("FIS1", 9.0), entry("FIS1", 12.0)],
+                &["MAT1"],
+            ),
+        );
+
+        assert_eq!(decision.status, StudentStatus::Regular);
+    }
+
+    #[test]
+    fn graduation_wins_over_observation() {
+        let rules: StatusRuleSet =
+            r#"[{"rule":"low_average","below":15},{"rule":"program_completed"}]"#
+                .parse()
+                .unwrap();
+        let decision = rules.evaluate(None, &transcript(vec![entry("MAT1", 12.0)], &[]));
+
+        assert_eq!(decision.status, StudentStatus::Graduated);
+    }
+
+    #[test]
+    fn graduates_are_not_evaluated_again() {
+        let rules = StatusRuleSet::default();
+        let decision = rules.evaluate(
+            Some(&StudentStatus::Graduated),
+            &transcript(vec![entry("FIS1", 8.0), entry("FIS1", 9.0)], &["FIS1"]),
+        );
+
+        assert_eq!(decision.status, StudentStatus::Graduated);
+        assert!(decision.reasons.is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_rule_parameters() {
+        assert!(r#"[{"rule":"repeated_failure","times":0}]"#.parse::<StatusRuleSet>().is_err());
+        assert!(r#"[{"rule":"low_average","below":25}]"#.parse::<StatusRuleSet>().is_err());
+    }
+}
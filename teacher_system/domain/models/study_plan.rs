@@ -0,0 +1,428 @@
+use crate::domain::models::{enums::Curriculum, validation::ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Curso del plan, identificado por código para abarcar todas sus secciones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanCourse {
+    pub code: String,
+    pub name: String,
+    pub cycle: i32,
+    pub credits: i32,
+    pub curriculum: Curriculum,
+    /// Solo los electivos pertenecen a un grupo.
+    pub elective_group: Option<String>,
+}
+
+/// Bolsa de electivos de la que el alumno debe aprobar `min_credits`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElectiveGroup {
+    pub name: String,
+    pub min_credits: i32,
+}
+
+/// Versión del plan de estudios de un programa. Solo una versión por
+/// programa está vigente.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyPlan {
+    pub id: String,
+    pub program: String,
+    /// Facultad a la que pertenece el programa.
+    pub faculty: String,
+    pub version: i32,
+    pub name: String,
+    pub active: bool,
+    pub courses: Vec<PlanCourse>,
+    pub elective_groups: Vec<ElectiveGroup>,
+    pub created_at: Option<String>,
+}
+
+impl StudyPlan {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.program.trim().is_empty() {
+            errors.add("program", "is required");
+        }
+        if self.faculty.trim().is_empty() {
+            errors.add("faculty", "is required");
+        }
+        if self.name.trim().is_empty() {
+            errors.add("name", "is required");
+        }
+        if self.courses.is_empty() {
+            errors.add("courses", "must include at least one course");
+        }
+
+        for (i, group) in self.elective_groups.iter().enumerate() {
+            if self.elective_groups[..i].iter().any(|g| g.name == group.name) {
+                errors.add("elective_groups", &format!("{} is repeated", group.name));
+            }
+            let offered: i32 = self
+                .courses
+                .iter()
+                .filter(|c| c.elective_group.as_deref() == Some(group.name.as_str()))
+                .map(|c| c.credits)
+                .sum();
+            if group.min_credits <= 0 || group.min_credits > offered {
+                errors.add(
+                    "elective_groups",
+                    &format!("{} must require between 1 and {} credits", group.name, offered),
+                );
+            }
+        }
+
+        for (i, course) in self.courses.iter().enumerate() {
+            if self.courses[..i].iter().any(|c| c.code == course.code) {
+                errors.add("courses", &format!("{} is repeated", course.code));
+            }
+            if course.cycle < 1 {
+                errors.add("courses", &format!("{} must be in cycle 1 or later", course.code));
+            }
+            if course.credits <= 0 {
+                errors.add("courses", &format!("{} must have credits", course.code));
+            }
+            match (&course.curriculum, &course.elective_group) {
+                (Curriculum::Elective, None) => {
+                    errors.add("courses", &format!("{} is elective but has no group", course.code))
+                }
+                (Curriculum::Elective, Some(group)) => {
+                    if !self.elective_groups.iter().any(|g| &g.name == group) {
+                        errors.add("courses", &format!("{} uses unknown group {}", course.code, group));
+                    }
+                }
+                (_, Some(_)) => errors.add(
+                    "courses",
+                    &format!("{} is not elective and cannot belong to a group", course.code),
+                ),
+                (_, None) => {}
+            }
+        }
+
+        errors.into_result()
+    }
+
+    pub fn course(&self, code: &str) -> Option<&PlanCourse> {
+        self.courses.iter().find(|c| c.code == code)
+    }
+
+    /// Todo curso debe dictarse en un ciclo posterior a sus requisitos.
+    pub fn check_prerequisites(
+        &self,
+        prerequisites: &HashMap<String, Vec<String>>,
+    ) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        for course in &self.courses {
+            for required in prerequisites.get(&course.code).into_iter().flatten() {
+                match self.course(required) {
+                    Some(p) if p.cycle >= course.cycle => errors.add(
+                        "courses",
+                        &format!(
+                            "{} (cycle {}) requires {} which is in cycle {}",
+                            course.code, course.cycle, p.code, p.cycle
+                        ),
+                    ),
+                    None => errors.add(
+                        "courses",
+                        &format!("{} requires {} which is not in the plan", course.code, required),
+                    ),
+                    _ => {}
+                }
+            }
+        }
+
+        errors.into_result()
+    }
+
+    /// Créditos aprobados dentro de un grupo de electivos, sin tope.
+    pub fn group_credits(&self, group: &str, passed: &[String]) -> i32 {
+        self.courses
+            .iter()
+            .filter(|c| c.elective_group.as_deref() == Some(group) && passed.contains(&c.code))
+            .map(|c| c.credits)
+            .sum()
+    }
+
+    /// Créditos aprobados que cuentan para egresar: cada grupo aporta hasta
+    /// su mínimo.
+    pub fn earned_credits(&self, passed: &[String]) -> i32 {
+        let obligatory: i32 = self
+            .courses
+            .iter()
+            .filter(|c| !matches!(c.curriculum, Curriculum::Elective) && passed.contains(&c.code))
+            .map(|c| c.credits)
+            .sum();
+        obligatory
+            + self
+                .elective_groups
+                .iter()
+                .map(|g| self.group_credits(&g.name, passed).min(g.min_credits))
+                .sum::<i32>()
+    }
+
+    /// Créditos para egresar: los obligatorios más el mínimo de cada grupo.
+    pub fn required_credits(&self) -> i32 {
+        let obligatory: i32 = self
+            .courses
+            .iter()
+            .filter(|c| !matches!(c.curriculum, Curriculum::Elective))
+            .map(|c| c.credits)
+            .sum();
+        obligatory + self.elective_groups.iter().map(|g| g.min_credits).sum::<i32>()
+    }
+
+    /// Cursos que el alumno puede llevar: no los aprobó y ya cumple sus
+    /// requisitos. Los electivos de un grupo completo no se ofrecen.
+    pub fn eligible_courses(
+        &self,
+        passed: &[String],
+        prerequisites: &HashMap<String, Vec<String>>,
+    ) -> Vec<&PlanCourse> {
+        let group_done = |name: &str| {
+            self.elective_groups
+                .iter()
+                .any(|g| g.name == name && self.group_credits(name, passed) >= g.min_credits)
+        };
+
+        self.courses
+            .iter()
+            .filter(|c| !passed.contains(&c.code))
+            .filter(|c| c.elective_group.as_deref().is_none_or(|g| !group_done(g)))
+            .filter(|c| {
+                prerequisites
+                    .get(&c.code)
+                    .into_iter()
+                    .flatten()
+                    .all(|p| passed.contains(p))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CycleProgress {
+    pub cycle: i32,
+    pub required_credits: i32,
+    pub earned_credits: i32,
+    pub pending_courses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElectiveGroupProgress {
+    pub name: String,
+    pub min_credits: i32,
+    pub earned_credits: i32,
+    pub satisfied: bool,
+}
+
+/// Avance de un alumno sobre una versión del plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanProgress {
+    pub student_id: String,
+    pub plan_id: String,
+    pub program: String,
+    pub version: i32,
+    pub cycles: Vec<CycleProgress>,
+    pub elective_groups: Vec<ElectiveGroupProgress>,
+    pub required_credits: i32,
+    pub earned_credits: i32,
+    pub percentage: f64,
+    pub eligible_courses: Vec<String>,
+}
+
+impl PlanProgress {
+    pub fn measure(
+        student_id: &str,
+        plan: &StudyPlan,
+        passed: &[String],
+        prerequisites: &HashMap<String, Vec<String>>,
+    ) -> Self {
+        let mut cycles: Vec<CycleProgress> = Vec::new();
+        for course in plan
+            .courses
+            .iter()
+            .filter(|c| !matches!(c.curriculum, Curriculum::Elective))
+        {
+            let cycle = match cycles.iter_mut().find(|c| c.cycle == course.cycle) {
+                Some(cycle) => cycle,
+                None => {
+                    cycles.push(CycleProgress {
+                        cycle: course.cycle,
+                        required_credits: 0,
+                        earned_credits: 0,
+                        pending_courses: Vec::new(),
+                    });
+                    cycles.last_mut().expect("just pushed")
+                }
+            };
+            cycle.required_credits += course.credits;
+            if passed.contains(&course.code) {
+                cycle.earned_credits += course.credits;
+            } else {
+                cycle.pending_courses.push(course.code.clone());
+            }
+        }
+        cycles.sort_by_key(|c| c.cycle);
+
+        let elective_groups: Vec<ElectiveGroupProgress> = plan
+            .elective_groups
+            .iter()
+            .map(|group| {
+                let earned_credits = plan.group_credits(&group.name, passed);
+                ElectiveGroupProgress {
+                    name: group.name.clone(),
+                    min_credits: group.min_credits,
+                    earned_credits,
+                    satisfied: earned_credits >= group.min_credits,
+                }
+            })
+            .collect();
+
+        let required_credits = plan.required_credits();
+        let earned_credits = plan.earned_credits(passed);
+        let percentage = if required_credits == 0 {
+            0.0
+        } else {
+            (earned_credits as f64 * 1000.0 / required_credits as f64).round() / 10.0
+        };
+
+        Self {
+            student_id: student_id.to_string(),
+            plan_id: plan.id.clone(),
+            program: plan.program.clone(),
+            version: plan.version,
+            cycles,
+            elective_groups,
+            required_credits,
+            earned_credits,
+            percentage,
+            eligible_courses: plan
+                .eligible_courses(passed, prerequisites)
+                .into_iter()
+                .map(|c| c.code.clone())
+                .collect(),
+        }
+    }
+}
+
+/// Curso que un ciclo debe abrir y cuántos alumnos del programa pueden llevarlo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfferedCourse {
+    pub code: String,
+    pub name: String,
+    pub curriculum: Curriculum,
+    pub elective_group: Option<String>,
+    pub eligible_students: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CycleOffering {
+    pub cycle: i32,
+    pub courses: Vec<OfferedCourse>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan_course(code: &str, cycle: i32, credits: i32, group: Option<&str>) -> PlanCourse {
+        PlanCourse {
+            code: code.to_string(),
+            name: code.to_string(),
+            cycle,
+            credits,
+            curriculum: if group.is_some() {
+                Curriculum::Elective
+            } else {
+                Curriculum::Obligatory
+            },
+            elective_group: group.map(|g| g.to_string()),
+        }
+    }
+
+    fn plan() -> StudyPlan {
+        StudyPlan {
+            id: "p1".to_string(),
+            program: "SIS".to_string(),
+            faculty: "FIIS".to_string(),
+            version: 1,
+            name: "Plan 2024".to_string(),
+            active: true,
+            courses: vec![
+                plan_course("MAT1", 1, 4, None),
+                plan_course("FIS1", 1, 4, None),
+                plan_course("MAT2", 2, 4, None),
+                plan_course("ELE1", 2, 3, Some("Humanidades")),
+                plan_course("ELE2", 2, 3, Some("Humanidades")),
+            ],
+            elective_groups: vec![ElectiveGroup {
+                name: "Humanidades".to_string(),
+                min_credits: 3,
+            }],
+            created_at: None,
+        }
+    }
+
+    fn codes(codes: &[&str]) -> Vec<String> {
+        codes.iter().map(|c| c.to_string()).collect()
+    }
+
+    fn prerequisites() -> HashMap<String, Vec<String>> {
+        HashMap::from([("MAT2".to_string(), codes(&["MAT1"]))])
+    }
+
+    #[test]
+    fn electives_count_only_up_to_their_group_minimum() {
+        let plan = plan();
+
+        assert_eq!(plan.required_credits(), 15);
+        assert_eq!(plan.earned_credits(&codes(&["MAT1", "ELE1", "ELE2"])), 7);
+    }
+
+    #[test]
+    fn progress_is_measured_per_cycle() {
+        let progress =
+            PlanProgress::measure("s1", &plan(), &codes(&["MAT1", "ELE1"]), &prerequisites());
+
+        let cycles: Vec<(i32, i32, i32)> = progress
+            .cycles
+            .iter()
+            .map(|c| (c.cycle, c.required_credits, c.earned_credits))
+            .collect();
+        assert_eq!(cycles, vec![(1, 8, 4), (2, 4, 0)]);
+        assert!(progress.elective_groups[0].satisfied);
+        assert_eq!(progress.earned_credits, 7);
+        assert_eq!(progress.percentage, 46.7);
+    }
+
+    #[test]
+    fn eligible_courses_need_their_prerequisites_and_an_open_group() {
+        let plan = plan();
+
+        let eligible: Vec<&str> = plan
+            .eligible_courses(&codes(&["FIS1"]), &prerequisites())
+            .into_iter()
+            .map(|c| c.code.as_str())
+            .collect();
+        assert_eq!(eligible, vec!["MAT1", "ELE1", "ELE2"]);
+
+        let eligible: Vec<&str> = plan
+            .eligible_courses(&codes(&["MAT1", "FIS1", "ELE1"]), &prerequisites())
+            .into_iter()
+            .map(|c| c.code.as_str())
+            .collect();
+        assert_eq!(eligible, vec!["MAT2"]);
+    }
+
+    #[test]
+    fn prerequisites_must_come_in_an_earlier_cycle() {
+        let plan = plan();
+        let same_cycle = HashMap::from([("FIS1".to_string(), codes(&["MAT1"]))]);
+        let outside = HashMap::from([("MAT2".to_string(), codes(&["QUI1"]))]);
+
+        assert!(plan.check_prerequisites(&prerequisites()).is_ok());
+        assert!(plan.check_prerequisites(&same_cycle).is_err());
+        assert!(plan.check_prerequisites(&outside).is_err());
+    }
+}
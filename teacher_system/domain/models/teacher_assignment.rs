@@ -0,0 +1,227 @@
+use crate::domain::models::{
+    course::Course,
+    enums::{AssignmentStatus, ContractType},
+    schedule::Schedule,
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Candidatos que se guardan con cada propuesta para que el coordinador
+/// pueda elegir otro.
+pub const MAX_CANDIDATES: usize = 5;
+
+/// Profesor que puede dictar un curso sin chocar ni pasarse de horas.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeacherCandidate {
+    pub teacher_id: String,
+    pub full_name: String,
+    pub contract_type: ContractType,
+    pub specialty_match: bool,
+    pub same_faculty: bool,
+    pub taught_before: bool,
+    /// Horas semanales en el semestre antes de sumar este curso.
+    pub current_hours: i32,
+    pub max_hours_per_week: Option<i32>,
+}
+
+impl TeacherCandidate {
+    /// Especialidad, facultad, tipo de contrato, experiencia con el curso y,
+    /// al final, quien tiene menos horas.
+    fn rank(&self) -> (bool, bool, u8, bool, i32) {
+        (
+            !self.specialty_match,
+            !self.same_faculty,
+            self.contract_type.assignment_priority(),
+            !self.taught_before,
+            self.current_hours,
+        )
+    }
+
+    pub fn reasons(&self) -> Vec<String> {
+        let mut reasons = Vec::new();
+        if self.specialty_match {
+            reasons.push("specialty matches the course".to_string());
+        }
+        if self.same_faculty {
+            reasons.push("same faculty as the program".to_string());
+        }
+        reasons.push(format!("{} contract", self.contract_type.to_string()));
+        if self.taught_before {
+            reasons.push("has taught this course before".to_string());
+        }
+        reasons.push(match self.max_hours_per_week {
+            Some(max) => format!("{} of {} weekly hours in use", self.current_hours, max),
+            None => format!("{} weekly hours in use", self.current_hours),
+        });
+        reasons
+    }
+}
+
+/// Propuesta de profesor para un curso sin asignar, que el coordinador
+/// acepta o reemplaza por otro.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentProposal {
+    pub id: String,
+    pub course_id: String,
+    pub academic_year: i32,
+    pub semester: String,
+    /// `None` si ningún profesor puede tomarlo.
+    pub proposed_teacher_id: Option<String>,
+    pub candidates: Vec<TeacherCandidate>,
+    pub reasons: Vec<String>,
+    pub status: AssignmentStatus,
+    pub assigned_teacher_id: Option<String>,
+    pub decided_by: Option<String>,
+    pub note: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssignmentFilter {
+    pub academic_year: Option<i32>,
+    pub semester: Option<String>,
+    pub status: Option<AssignmentStatus>,
+}
+
+/// Carga de un profesor en el semestre sobre la que se van sumando las
+/// propuestas.
+#[derive(Debug, Clone)]
+pub struct TeacherLoad {
+    pub teacher: User,
+    pub hours: i32,
+    pub sessions: Vec<Schedule>,
+    /// Códigos de cursos que dictó en cualquier semestre.
+    pub taught_codes: HashSet<String>,
+}
+
+impl TeacherLoad {
+    pub fn has_room(&self, hours: i32) -> bool {
+        self.teacher
+            .max_hours_per_week
+            .is_none_or(|max| self.hours + hours <= max)
+    }
+
+    pub fn clashes(&self, sessions: &[Schedule]) -> bool {
+        sessions
+            .iter()
+            .any(|s| self.sessions.iter().any(|busy| busy.conflicts_with(s)))
+    }
+
+    pub fn take(&mut self, course: &Course, sessions: &[Schedule]) {
+        self.hours += course.hours_per_week;
+        self.sessions.extend_from_slice(sessions);
+        self.taught_codes.insert(course.code.clone());
+    }
+
+    pub fn candidate(&self, course: &Course, program_faculty: Option<&str>) -> TeacherCandidate {
+        TeacherCandidate {
+            teacher_id: self.teacher.id.clone(),
+            full_name: self.teacher.full_name.clone(),
+            contract_type: self
+                .teacher
+                .contract_type
+                .clone()
+                .unwrap_or(ContractType::Contracted),
+            specialty_match: specialty_matches(&self.teacher.specialty, &course.name),
+            same_faculty: program_faculty
+                .is_some_and(|f| f.eq_ignore_ascii_case(self.teacher.faculty.trim())),
+            taught_before: self.taught_codes.contains(&course.code),
+            current_hours: self.hours,
+            max_hours_per_week: self.teacher.max_hours_per_week,
+        }
+    }
+}
+
+/// Ordena los candidatos de mejor a peor.
+pub fn rank_candidates(candidates: &mut [TeacherCandidate]) {
+    candidates.sort_by(|a, b| {
+        a.rank()
+            .cmp(&b.rank())
+            .then_with(|| a.teacher_id.cmp(&b.teacher_id))
+    });
+}
+
+/// La especialidad coincide si alguna de sus palabras comparte raíz con una
+/// del nombre del curso ("Matemáticas" con "Matemática Discreta").
+pub fn specialty_matches(specialty: &str, course_name: &str) -> bool {
+    let stems = |text: &str| -> HashSet<String> {
+        text.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| w.chars().count() >= 4)
+            .map(|w| w.chars().take(6).collect())
+            .collect()
+    };
+
+    let course = stems(course_name);
+    stems(specialty).iter().any(|s| course.contains(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(teacher_id: &str, contract_type: ContractType, current_hours: i32) -> TeacherCandidate {
+        TeacherCandidate {
+            teacher_id: teacher_id.to_string(),
+            full_name: teacher_id.to_string(),
+            contract_type,
+            specialty_match: false,
+            same_faculty: false,
+            taught_before: false,
+            current_hours,
+            max_hours_per_week: Some(20),
+        }
+    }
+
+    fn ranked(mut candidates: Vec<TeacherCandidate>) -> Vec<String> {
+        rank_candidates(&mut candidates);
+        candidates.into_iter().map(|c| c.teacher_id).collect()
+    }
+
+    #[test]
+    fn specialty_comes_before_contract_and_hours() {
+        let principal = candidate("t1", ContractType::Principal, 0);
+        let specialist = TeacherCandidate {
+            specialty_match: true,
+            ..candidate("t2", ContractType::Contracted, 18)
+        };
+
+        assert_eq!(ranked(vec![principal, specialist]), vec!["t2", "t1"]);
+    }
+
+    #[test]
+    fn contract_type_comes_before_experience() {
+        let associate = TeacherCandidate {
+            taught_before: true,
+            ..candidate("t1", ContractType::Associate, 0)
+        };
+        let principal = candidate("t2", ContractType::Principal, 10);
+
+        assert_eq!(ranked(vec![associate, principal]), vec!["t2", "t1"]);
+    }
+
+    #[test]
+    fn ties_go_to_the_lighter_load_then_the_id() {
+        let candidates = vec![
+            candidate("t3", ContractType::Contracted, 12),
+            candidate("t2", ContractType::Contracted, 6),
+            candidate("t1", ContractType::Contracted, 6),
+        ];
+
+        assert_eq!(ranked(candidates), vec!["t1", "t2", "t3"]);
+    }
+
+    #[test]
+    fn specialty_matches_words_sharing_a_stem() {
+        assert!(specialty_matches("Matemáticas", "Matemática Discreta"));
+        assert!(specialty_matches("Ingeniería de Software", "Ingeniería de Requisitos"));
+        assert!(!specialty_matches("Física", "Química General"));
+    }
+
+    #[test]
+    fn short_words_do_not_match() {
+        // Solo comparten "de" y "la"
+        assert!(!specialty_matches("Historia de la Ciencia", "Teoría de la Música"));
+    }
+}
@@ -0,0 +1,387 @@
+use crate::domain::models::{
+    enums::{ContractType, Weekday},
+    schedule::Schedule,
+    user::User,
+};
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Hora desde la que una sesión cuenta como nocturna.
+pub fn evening_start() -> NaiveTime {
+    NaiveTime::from_hms_opt(18, 0, 0).unwrap()
+}
+
+/// Una sesión es nocturna si empieza de noche; una de la tarde que se
+/// alarga pasadas las 18:00 no cuenta.
+pub fn is_evening(session: &Schedule) -> bool {
+    session.start_time >= evening_start()
+}
+
+/// Minutos semanales de clase de un conjunto de sesiones.
+pub fn weekly_minutes(sessions: &[Schedule]) -> i64 {
+    sessions
+        .iter()
+        .map(|s| (s.end_time - s.start_time).num_minutes())
+        .sum()
+}
+
+/// Carga permitida para un tipo de contrato.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractLoadRule {
+    pub contract_type: ContractType,
+    pub min_hours: i32,
+    pub max_hours: i32,
+    /// Vacío si puede dictar cualquier día.
+    #[serde(default)]
+    pub allowed_days: Vec<Weekday>,
+    /// `None` si no hay tope de sesiones nocturnas.
+    #[serde(default)]
+    pub max_evening_sessions: Option<i32>,
+}
+
+impl ContractLoadRule {
+    /// Falla si la sesión, sumada a `others`, rompe la regla. `others` son
+    /// las demás sesiones del profesor en el semestre, sin la propia.
+    pub fn check_session(&self, session: &Schedule, others: &[Schedule]) -> Result<(), String> {
+        let contract = self.contract_type.to_string();
+
+        if !self.allowed_days.is_empty() && !self.allowed_days.contains(&session.day) {
+            return Err(format!(
+                "Conflict: {} teachers cannot teach on {}",
+                contract,
+                session.day.to_string()
+            ));
+        }
+
+        if let Some(max) = self.max_evening_sessions {
+            let evenings = others.iter().filter(|s| is_evening(s)).count() as i32;
+            if is_evening(session) && evenings + 1 > max {
+                return Err(format!(
+                    "Conflict: {} teachers are limited to {} evening sessions",
+                    contract, max
+                ));
+            }
+        }
+
+        let minutes =
+            weekly_minutes(others) + (session.end_time - session.start_time).num_minutes();
+        if minutes > self.max_hours as i64 * 60 {
+            return Err(format!(
+                "Conflict: {} teachers are limited to {} weekly hours",
+                contract, self.max_hours
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Revisa varias sesiones nuevas, sumando cada una a las anteriores.
+    pub fn check_sessions(&self, sessions: &[Schedule], others: &[Schedule]) -> Result<(), String> {
+        let mut taken = others.to_vec();
+        for session in sessions {
+            self.check_session(session, &taken)?;
+            taken.push(session.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Reglas vigentes. Se configuran con `TEACHING_LOAD_RULES` como una lista
+/// JSON, por ejemplo `[{"contract_type":"Contracted","min_hours":4,"max_hours":20}]`.
+/// Un tipo de contrato sin regla no tiene límites, así que sin la variable
+/// no se aplica ninguno.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TeachingLoadPolicy {
+    pub rules: Vec<ContractLoadRule>,
+}
+
+impl FromStr for TeachingLoadPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rules: Vec<ContractLoadRule> =
+            serde_json::from_str(s).map_err(|e| format!("Invalid teaching load rules: {}", e))?;
+
+        for (i, rule) in rules.iter().enumerate() {
+            if rule.min_hours < 0 || rule.max_hours < rule.min_hours {
+                return Err(format!(
+                    "Invalid teaching load rules: {} must satisfy 0 <= min <= max",
+                    rule.contract_type.to_string()
+                ));
+            }
+            if rule.max_evening_sessions.is_some_and(|max| max < 0) {
+                return Err(format!(
+                    "Invalid teaching load rules: {} evening sessions must not be negative",
+                    rule.contract_type.to_string()
+                ));
+            }
+            if rules[..i]
+                .iter()
+                .any(|r| r.contract_type == rule.contract_type)
+            {
+                return Err(format!(
+                    "Invalid teaching load rules: {} is repeated",
+                    rule.contract_type.to_string()
+                ));
+            }
+        }
+
+        Ok(Self { rules })
+    }
+}
+
+impl TeachingLoadPolicy {
+    /// Regla del profesor; los usuarios sin contrato no tienen ninguna.
+    pub fn rule_for(&self, teacher: &User) -> Option<&ContractLoadRule> {
+        let contract_type = teacher.contract_type.as_ref()?;
+        self.rules
+            .iter()
+            .find(|r| &r.contract_type == contract_type)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadStatus {
+    Under,
+    Within,
+    Over,
+}
+
+/// Carga de un profesor en un semestre frente a la regla de su contrato.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeacherTermLoad {
+    pub teacher_id: String,
+    pub full_name: String,
+    pub contract_type: Option<ContractType>,
+    pub academic_year: i32,
+    pub semester: String,
+    pub weekly_hours: f64,
+    pub evening_sessions: i32,
+    pub min_hours: Option<i32>,
+    pub max_hours: Option<i32>,
+    pub max_evening_sessions: Option<i32>,
+    pub status: LoadStatus,
+    /// Incumplimientos de la regla, además de las horas.
+    pub flags: Vec<String>,
+}
+
+impl TeacherTermLoad {
+    pub fn measure(
+        teacher: &User,
+        rule: Option<&ContractLoadRule>,
+        academic_year: i32,
+        semester: &str,
+        sessions: &[Schedule],
+    ) -> Self {
+        let minutes = weekly_minutes(sessions);
+        let evening_sessions = sessions.iter().filter(|s| is_evening(s)).count() as i32;
+
+        let status = match rule {
+            Some(rule) if minutes < rule.min_hours as i64 * 60 => LoadStatus::Under,
+            Some(rule) if minutes > rule.max_hours as i64 * 60 => LoadStatus::Over,
+            _ => LoadStatus::Within,
+        };
+
+        let mut flags = Vec::new();
+        if let Some(rule) = rule {
+            let mut days: Vec<Weekday> = Vec::new();
+            for session in sessions {
+                if !rule.allowed_days.is_empty()
+                    && !rule.allowed_days.contains(&session.day)
+                    && !days.contains(&session.day)
+                {
+                    days.push(session.day);
+                }
+            }
+            for day in days {
+                flags.push(format!(
+                    "teaches on {}, outside the allowed days",
+                    day.to_string()
+                ));
+            }
+            if let Some(max) = rule
+                .max_evening_sessions
+                .filter(|max| evening_sessions > *max)
+            {
+                flags.push(format!(
+                    "{} evening sessions, above the limit of {}",
+                    evening_sessions, max
+                ));
+            }
+        }
+
+        Self {
+            teacher_id: teacher.id.clone(),
+            full_name: teacher.full_name.clone(),
+            contract_type: teacher.contract_type.clone(),
+            academic_year,
+            semester: semester.to_string(),
+            weekly_hours: (minutes as f64 / 60.0 * 10.0).round() / 10.0,
+            evening_sessions,
+            min_hours: rule.map(|r| r.min_hours),
+            max_hours: rule.map(|r| r.max_hours),
+            max_evening_sessions: rule.and_then(|r| r.max_evening_sessions),
+            status,
+            flags,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::enums::SessionType;
+
+    fn session(day: Weekday, start: (u32, u32), end: (u32, u32)) -> Schedule {
+        Schedule {
+            id: format!("{}-{}-{}", day.to_string(), start.0, end.0),
+            course_id: "c1".to_string(),
+            day,
+            start_time: NaiveTime::from_hms_opt(start.0, start.1, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end.0, end.1, 0).unwrap(),
+            session_type: SessionType::Theory,
+            location_detail: None,
+            created_at: None,
+            facility_id: "A-101".to_string(),
+            required_equipment: Vec::new(),
+            version: 1,
+        }
+    }
+
+    fn teacher(contract_type: Option<ContractType>) -> User {
+        User {
+            id: "t1".to_string(),
+            code: "T001".to_string(),
+            email: None,
+            phone: None,
+            faculty: "Ingeniería".to_string(),
+            program: String::new(),
+            specialty: String::new(),
+            role: "teacher".to_string(),
+            student_status: None,
+            admission_date: None,
+            contract_type,
+            max_hours_per_week: None,
+            hire_date: None,
+            full_name: "Ana Pérez".to_string(),
+        }
+    }
+
+    fn rule() -> ContractLoadRule {
+        ContractLoadRule {
+            contract_type: ContractType::Principal,
+            min_hours: 4,
+            max_hours: 6,
+            allowed_days: vec![Weekday::Monday, Weekday::Tuesday],
+            max_evening_sessions: Some(1),
+        }
+    }
+
+    #[test]
+    fn the_default_policy_has_no_rules() {
+        let policy = TeachingLoadPolicy::default();
+
+        assert!(policy.rules.is_empty());
+        assert!(
+            policy
+                .rule_for(&teacher(Some(ContractType::Principal)))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn only_sessions_starting_at_night_are_evening() {
+        assert!(!is_evening(&session(Weekday::Monday, (16, 0), (18, 30))));
+        assert!(is_evening(&session(Weekday::Monday, (18, 0), (20, 0))));
+    }
+
+    #[test]
+    fn parses_rules_and_finds_the_teacher_rule() {
+        let policy: TeachingLoadPolicy =
+            r#"[{"contract_type":"Contracted","min_hours":4,"max_hours":20}]"#
+                .parse()
+                .unwrap();
+
+        let rule = policy
+            .rule_for(&teacher(Some(ContractType::Contracted)))
+            .unwrap();
+        assert_eq!(rule.max_hours, 20);
+        assert!(rule.allowed_days.is_empty());
+        assert!(
+            policy
+                .rule_for(&teacher(Some(ContractType::Associate)))
+                .is_none()
+        );
+        assert!(policy.rule_for(&teacher(None)).is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        let inverted = r#"[{"contract_type":"Contracted","min_hours":10,"max_hours":4}]"#;
+        let repeated = r#"[
+            {"contract_type":"Contracted","min_hours":0,"max_hours":4},
+            {"contract_type":"Contracted","min_hours":0,"max_hours":8}
+        ]"#;
+        let negative = r#"[{"contract_type":"Contracted","min_hours":0,"max_hours":4,"max_evening_sessions":-1}]"#;
+
+        assert!(inverted.parse::<TeachingLoadPolicy>().is_err());
+        assert!(repeated.parse::<TeachingLoadPolicy>().is_err());
+        assert!(negative.parse::<TeachingLoadPolicy>().is_err());
+    }
+
+    #[test]
+    fn rejects_days_outside_the_contract() {
+        let result = rule().check_session(&session(Weekday::Friday, (8, 0), (10, 0)), &[]);
+
+        assert!(result.unwrap_err().contains("cannot teach on"));
+    }
+
+    #[test]
+    fn limits_evening_sessions() {
+        let others = vec![session(Weekday::Monday, (18, 0), (19, 0))];
+
+        let result = rule().check_session(&session(Weekday::Tuesday, (19, 0), (20, 0)), &others);
+        assert!(result.unwrap_err().contains("evening sessions"));
+        assert!(
+            rule()
+                .check_session(&session(Weekday::Tuesday, (16, 0), (18, 30)), &others)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn adds_each_new_session_to_the_weekly_hours() {
+        let others = vec![session(Weekday::Monday, (8, 0), (11, 0))];
+        let new = vec![
+            session(Weekday::Tuesday, (8, 0), (10, 0)),
+            session(Weekday::Tuesday, (10, 0), (12, 0)),
+        ];
+
+        let result = rule().check_sessions(&new, &others);
+        assert!(result.unwrap_err().contains("6 weekly hours"));
+        assert!(rule().check_sessions(&new[..1], &others).is_ok());
+    }
+
+    #[test]
+    fn measures_the_term_load_against_the_rule() {
+        let sessions = vec![
+            session(Weekday::Monday, (8, 0), (9, 30)),
+            session(Weekday::Friday, (18, 0), (19, 0)),
+            session(Weekday::Friday, (19, 0), (20, 0)),
+        ];
+
+        let load = TeacherTermLoad::measure(
+            &teacher(Some(ContractType::Principal)),
+            Some(&rule()),
+            2025,
+            "I",
+            &sessions,
+        );
+        assert_eq!(load.weekly_hours, 3.5);
+        assert_eq!(load.evening_sessions, 2);
+        assert_eq!(load.status, LoadStatus::Under);
+        assert_eq!(load.flags.len(), 2);
+    }
+}
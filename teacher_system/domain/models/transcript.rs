@@ -0,0 +1,376 @@
+use crate::domain::models::{
+    course::Course,
+    enrollment::Enrollment,
+    enums::{Curriculum, EnrollmentStatus, StudentStatus},
+    study_plan::StudyPlan,
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Curso calificado en el historial del alumno.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptEntry {
+    pub course_id: String,
+    pub code: String,
+    pub name: String,
+    pub credits: i32,
+    pub final_grade: f64,
+    pub passed: bool,
+}
+
+/// Cursos calificados de un semestre con su promedio ponderado.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptTerm {
+    pub academic_year: i32,
+    pub semester: String,
+    pub entries: Vec<TranscriptEntry>,
+    pub credits_attempted: i32,
+    pub credits_earned: i32,
+    pub weighted_average: f64,
+}
+
+/// Avance sobre el plan de estudios del programa.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgramProgress {
+    pub program: String,
+    /// Versión del plan medida; `None` si el programa aún no tiene plan.
+    pub plan_version: Option<i32>,
+    pub required_credits: i32,
+    pub earned_required_credits: i32,
+    pub earned_elective_credits: i32,
+    pub percentage: f64,
+    pub pending_courses: Vec<String>,
+    pub pending_elective_groups: Vec<String>,
+}
+
+/// Historial académico de un alumno.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transcript {
+    pub student_id: String,
+    pub student_code: String,
+    pub full_name: String,
+    pub student_status: Option<StudentStatus>,
+    pub admission_date: Option<String>,
+    pub terms: Vec<TranscriptTerm>,
+    pub credits_attempted: i32,
+    pub credits_earned: i32,
+    pub weighted_average: f64,
+    pub progress: ProgramProgress,
+}
+
+/// Promedio ponderado por créditos con dos decimales; cero si no hay cursos.
+fn weighted_average(entries: &[&TranscriptEntry]) -> f64 {
+    let credits: i32 = entries.iter().map(|e| e.credits).sum();
+    if credits == 0 {
+        return 0.0;
+    }
+    let points: f64 = entries.iter().map(|e| e.final_grade * e.credits as f64).sum();
+    (points * 100.0 / credits as f64).round() / 100.0
+}
+
+impl Transcript {
+    /// Arma el historial con las matrículas cerradas del alumno. El avance se
+    /// mide contra el plan vigente del programa o, si no hay, contra los
+    /// cursos obligatorios del catálogo.
+    pub fn build(
+        student: &User,
+        graded: Vec<(Enrollment, Course)>,
+        plan: Option<&StudyPlan>,
+        catalog: &[Course],
+    ) -> Self {
+        let mut terms: Vec<TranscriptTerm> = Vec::new();
+        let mut passed: Vec<Course> = Vec::new();
+
+        for (enrollment, course) in graded {
+            // Solo cuentan los cursos ya cerrados
+            let final_grade = match (&enrollment.status, enrollment.final_grade) {
+                (EnrollmentStatus::Completed | EnrollmentStatus::Failed, Some(grade)) => grade,
+                _ => continue,
+            };
+            let is_passed = matches!(enrollment.status, EnrollmentStatus::Completed);
+
+            let entry = TranscriptEntry {
+                course_id: course.id.clone(),
+                code: course.code.clone(),
+                name: course.name.clone(),
+                credits: course.credits,
+                final_grade,
+                passed: is_passed,
+            };
+
+            match terms
+                .iter_mut()
+                .find(|t| t.academic_year == course.academic_year && t.semester == course.semester)
+            {
+                Some(term) => term.entries.push(entry),
+                None => terms.push(TranscriptTerm {
+                    academic_year: course.academic_year,
+                    semester: course.semester.clone(),
+                    entries: vec![entry],
+                    credits_attempted: 0,
+                    credits_earned: 0,
+                    weighted_average: 0.0,
+                }),
+            }
+            if is_passed {
+                passed.push(course);
+            }
+        }
+
+        terms.sort_by(|a, b| {
+            (a.academic_year, &a.semester).cmp(&(b.academic_year, &b.semester))
+        });
+        for term in &mut terms {
+            term.entries.sort_by(|a, b| a.code.cmp(&b.code));
+            let entries: Vec<&TranscriptEntry> = term.entries.iter().collect();
+            term.credits_attempted = entries.iter().map(|e| e.credits).sum();
+            term.credits_earned = entries.iter().filter(|e| e.passed).map(|e| e.credits).sum();
+            term.weighted_average = weighted_average(&entries);
+        }
+
+        let all: Vec<&TranscriptEntry> = terms.iter().flat_map(|t| t.entries.iter()).collect();
+
+        Self {
+            student_id: student.id.clone(),
+            student_code: student.code.clone(),
+            full_name: student.full_name.clone(),
+            student_status: student.student_status.clone(),
+            admission_date: student.admission_date.clone(),
+            credits_attempted: terms.iter().map(|t| t.credits_attempted).sum(),
+            credits_earned: terms.iter().map(|t| t.credits_earned).sum(),
+            weighted_average: weighted_average(&all),
+            progress: match plan {
+                Some(plan) => ProgramProgress::from_plan(plan, &passed),
+                None => ProgramProgress::measure(&student.program, &passed, catalog),
+            },
+            terms,
+        }
+    }
+}
+
+impl ProgramProgress {
+    /// Un curso aprobado dos veces (por ejemplo, en otra sección) cuenta una
+    /// sola vez.
+    pub fn measure(program: &str, passed: &[Course], catalog: &[Course]) -> Self {
+        let mut plan: Vec<&Course> = Vec::new();
+        for course in catalog
+            .iter()
+            .filter(|c| c.program() == Some(program) && matches!(c.curriculum, Curriculum::Obligatory))
+        {
+            if !plan.iter().any(|p| p.code == course.code) {
+                plan.push(course);
+            }
+        }
+
+        let mut counted: Vec<&str> = Vec::new();
+        let mut earned_required_credits = 0;
+        let mut earned_elective_credits = 0;
+        for course in passed {
+            if counted.contains(&course.code.as_str()) {
+                continue;
+            }
+            counted.push(&course.code);
+            if plan.iter().any(|p| p.code == course.code) {
+                earned_required_credits += course.credits;
+            } else {
+                earned_elective_credits += course.credits;
+            }
+        }
+
+        let required_credits: i32 = plan.iter().map(|c| c.credits).sum();
+        let percentage = if required_credits == 0 {
+            0.0
+        } else {
+            (earned_required_credits as f64 * 1000.0 / required_credits as f64).round() / 10.0
+        };
+
+        Self {
+            program: program.to_string(),
+            plan_version: None,
+            required_credits,
+            earned_required_credits,
+            earned_elective_credits,
+            percentage,
+            pending_courses: plan
+                .iter()
+                .filter(|p| !counted.contains(&p.code.as_str()))
+                .map(|p| p.code.clone())
+                .collect(),
+            pending_elective_groups: Vec::new(),
+        }
+    }
+
+    /// Los créditos que exceden lo que pide el plan se informan como
+    /// electivos adicionales.
+    pub fn from_plan(plan: &StudyPlan, passed: &[Course]) -> Self {
+        let mut codes: Vec<String> = Vec::new();
+        let mut passed_credits = 0;
+        for course in passed {
+            if !codes.contains(&course.code) {
+                codes.push(course.code.clone());
+                passed_credits += course.credits;
+            }
+        }
+
+        let required_credits = plan.required_credits();
+        let earned_required_credits = plan.earned_credits(&codes);
+        let percentage = if required_credits == 0 {
+            0.0
+        } else {
+            (earned_required_credits as f64 * 1000.0 / required_credits as f64).round() / 10.0
+        };
+
+        Self {
+            program: plan.program.clone(),
+            plan_version: Some(plan.version),
+            required_credits,
+            earned_required_credits,
+            earned_elective_credits: passed_credits - earned_required_credits,
+            percentage,
+            pending_courses: plan
+                .courses
+                .iter()
+                .filter(|c| !matches!(c.curriculum, Curriculum::Elective) && !codes.contains(&c.code))
+                .map(|c| c.code.clone())
+                .collect(),
+            pending_elective_groups: plan
+                .elective_groups
+                .iter()
+                .filter(|g| plan.group_credits(&g.name, &codes) < g.min_credits)
+                .map(|g| g.name.clone())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn student() -> User {
+        User {
+            id: "s1".to_string(),
+            code: "2021001".to_string(),
+            email: None,
+            phone: None,
+            faculty: "FIIS".to_string(),
+            program: "SIS".to_string(),
+            specialty: String::new(),
+            role: "student".to_string(),
+            student_status: Some(StudentStatus::Regular),
+            admission_date: None,
+            contract_type: None,
+            max_hours_per_week: None,
+            hire_date: None,
+            full_name: "Ana Pérez".to_string(),
+        }
+    }
+
+    fn course(code: &str, credits: i32, academic_year: i32, semester: &str) -> Course {
+        Course {
+            id: format!("{}-{}-{}", code, academic_year, semester),
+            code: code.to_string(),
+            name: code.to_string(),
+            section: 1,
+            curriculum: Curriculum::Obligatory,
+            capacity: 30,
+            credits,
+            hours_per_week: 4,
+            cycle: 1,
+            teacher_id: String::new(),
+            facility_id: String::new(),
+            enrolled: 0,
+            semester: semester.to_string(),
+            academic_year,
+            program: "SIS".to_string(),
+            active: true,
+            version: 1,
+        }
+    }
+
+    fn graded(course: Course, status: EnrollmentStatus, grade: Option<f64>) -> (Enrollment, Course) {
+        let enrollment = Enrollment {
+            id: format!("e-{}", course.id),
+            student_id: "s1".to_string(),
+            course_id: course.id.clone(),
+            status,
+            final_grade: grade,
+        };
+        (enrollment, course)
+    }
+
+    #[test]
+    fn average_is_weighted_by_credits() {
+        let transcript = Transcript::build(
+            &student(),
+            vec![
+                graded(course("MAT1", 4, 2024, "I"), EnrollmentStatus::Completed, Some(15.0)),
+                graded(course("FIS1", 3, 2024, "I"), EnrollmentStatus::Completed, Some(12.0)),
+            ],
+            None,
+            &[],
+        );
+
+        // (15 * 4 + 12 * 3) / 7 = 13.714...
+        assert_eq!(transcript.weighted_average, 13.71);
+        assert_eq!(transcript.terms[0].weighted_average, 13.71);
+    }
+
+    #[test]
+    fn failed_courses_count_as_attempted_but_not_earned() {
+        let transcript = Transcript::build(
+            &student(),
+            vec![
+                graded(course("MAT1", 4, 2024, "I"), EnrollmentStatus::Completed, Some(14.0)),
+                graded(course("FIS1", 3, 2024, "I"), EnrollmentStatus::Failed, Some(8.0)),
+                graded(course("QUI1", 3, 2024, "II"), EnrollmentStatus::Enrolled, None),
+            ],
+            None,
+            &[],
+        );
+
+        assert_eq!(transcript.credits_attempted, 7);
+        assert_eq!(transcript.credits_earned, 4);
+        assert_eq!(transcript.terms.len(), 1);
+    }
+
+    #[test]
+    fn terms_are_listed_in_order_with_their_own_credits() {
+        let transcript = Transcript::build(
+            &student(),
+            vec![
+                graded(course("MAT2", 4, 2024, "II"), EnrollmentStatus::Completed, Some(16.0)),
+                graded(course("MAT1", 4, 2024, "I"), EnrollmentStatus::Completed, Some(12.0)),
+                graded(course("FIS1", 3, 2024, "I"), EnrollmentStatus::Completed, Some(13.0)),
+            ],
+            None,
+            &[],
+        );
+
+        let terms: Vec<(&str, i32)> = transcript
+            .terms
+            .iter()
+            .map(|t| (t.semester.as_str(), t.credits_earned))
+            .collect();
+        assert_eq!(terms, vec![("I", 7), ("II", 4)]);
+        assert_eq!(transcript.credits_earned, 11);
+    }
+
+    #[test]
+    fn progress_counts_a_course_passed_twice_once() {
+        let catalog = vec![course("MAT1", 4, 2024, "I"), course("FIS1", 4, 2024, "I")];
+        let mut retaken = course("MAT1", 4, 2024, "II");
+        retaken.section = 2;
+
+        let progress = ProgramProgress::measure(
+            "SIS",
+            &[course("MAT1", 4, 2024, "I"), retaken],
+            &catalog,
+        );
+
+        assert_eq!(progress.required_credits, 8);
+        assert_eq!(progress.earned_required_credits, 4);
+        assert_eq!(progress.percentage, 50.0);
+        assert_eq!(progress.pending_courses, vec!["FIS1".to_string()]);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::models::enums::{ContractType, StudentStatus};
+use crate::domain::models::validation::ValidationError;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,7 +21,43 @@ pub struct User {
 }
 
 impl User {
+    /// Verifica las invariantes del usuario antes de persistirlo.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.code.trim().is_empty() {
+            errors.add("code", "is required");
+        }
+        if self.full_name.trim().is_empty() {
+            errors.add("full_name", "is required");
+        }
+        if self.faculty.trim().is_empty() {
+            errors.add("faculty", "is required");
+        }
+        if self.role.trim().is_empty() {
+            errors.add("role", "is required");
+        }
+        if self.max_hours_per_week.is_some_and(|hours| hours <= 0) {
+            errors.add("max_hours_per_week", "must be positive");
+        }
+
+        errors.into_result()
+    }
+
+    pub fn is_student(&self) -> bool {
+        self.role.eq_ignore_ascii_case("student")
+    }
+
     pub fn is_teacher(&self) -> bool {
         self.contract_type.is_some()
     }
+
+    /// Coordinadores y administradores revisan y aprueban lo que piden los demás.
+    pub fn is_coordinator(&self) -> bool {
+        matches!(self.role.to_lowercase().as_str(), "coordinator" | "admin")
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.role.eq_ignore_ascii_case("admin")
+    }
 }
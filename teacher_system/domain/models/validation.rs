@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// Lista de errores por campo al violar una invariante del dominio.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValidationError {
+    pub errors: Vec<FieldError>,
+}
+
+impl ValidationError {
+    pub fn add(&mut self, field: &str, message: &str) {
+        self.errors.push(FieldError {
+            field: field.to_string(),
+            message: message.to_string(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn into_result(self) -> Result<(), ValidationError> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+/// Error de una operación que verifica invariantes del dominio: la lista de
+/// campos llega tipada al controlador y el resto de fallos sigue siendo un
+/// mensaje, como en los demás casos de uso.
+#[derive(Debug, Clone)]
+pub enum DomainError {
+    Validation(ValidationError),
+    Other(String),
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DomainError::Validation(error) => {
+                let fields: Vec<String> = error
+                    .errors
+                    .iter()
+                    .map(|e| format!("{} {}", e.field, e.message))
+                    .collect();
+                write!(f, "Validation failed: {}", fields.join(", "))
+            }
+            DomainError::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<ValidationError> for DomainError {
+    fn from(error: ValidationError) -> Self {
+        DomainError::Validation(error)
+    }
+}
+
+impl From<String> for DomainError {
+    fn from(message: String) -> Self {
+        DomainError::Other(message)
+    }
+}
+
+impl From<&str> for DomainError {
+    fn from(message: &str) -> Self {
+        DomainError::Other(message.to_string())
+    }
+}
@@ -0,0 +1,194 @@
+use crate::domain::models::enums::{DeliveryStatus, EventKind};
+use crate::domain::models::validation::ValidationError;
+use chrono::{Duration, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Largo mínimo del secreto con el que se firman los envíos.
+const MIN_SECRET_LEN: usize = 16;
+
+/// Sistema externo que recibe por HTTP los eventos a los que se suscribe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSubscription {
+    pub id: String,
+    pub target_url: String,
+    pub secret: String,
+    pub events: Vec<EventKind>,
+    pub active: bool,
+    pub created_by: String,
+    pub created_at: Option<String>,
+}
+
+impl WebhookSubscription {
+    pub fn listens_to(&self, kind: EventKind) -> bool {
+        self.active && self.events.contains(&kind)
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if !(self.target_url.starts_with("https://") || self.target_url.starts_with("http://")) {
+            errors.add("target_url", "must be an http or https URL");
+        }
+        if self.secret.len() < MIN_SECRET_LEN {
+            errors.add("secret", "must have at least 16 characters");
+        }
+        if self.events.is_empty() {
+            errors.add("events", "must include at least one event");
+        }
+
+        errors.into_result()
+    }
+}
+
+/// Envío pendiente en la bandeja de salida; sobrevive a reinicios del servidor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxMessage {
+    pub id: String,
+    pub subscription_id: String,
+    pub event_id: String,
+    pub event_kind: EventKind,
+    pub payload: Value,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub last_error: Option<String>,
+    pub created_at: Option<String>,
+    pub delivered_at: Option<String>,
+}
+
+impl OutboxMessage {
+    /// Intentos antes de dar el envío por fallido.
+    pub const MAX_ATTEMPTS: i32 = 8;
+
+    /// Identificador del envío de un evento a una suscripción. Es siempre el
+    /// mismo para el par, así un evento reintentado no se encola dos veces ni
+    /// llega con otro `X-Webhook-Delivery`.
+    pub fn delivery_id(event_id: &str, subscription_id: &str) -> String {
+        let name = format!("{}:{}", event_id, subscription_id);
+        uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, name.as_bytes()).to_string()
+    }
+
+    /// Espera exponencial desde 30 segundos, con tope de una hora.
+    pub fn backoff(attempts: i32) -> Duration {
+        let exponent = attempts.clamp(1, 8) as u32 - 1;
+        Duration::seconds((30 * 2_i64.pow(exponent)).min(3600))
+    }
+
+    /// Registra un intento fallido: reprograma el envío o lo descarta.
+    pub fn record_failure(&mut self, error: &str, now: NaiveDateTime) {
+        self.attempts += 1;
+        self.last_error = Some(error.to_string());
+
+        if self.attempts >= Self::MAX_ATTEMPTS {
+            self.status = DeliveryStatus::Failed;
+        } else {
+            self.next_attempt_at = now + Self::backoff(self.attempts);
+        }
+    }
+
+    pub fn record_success(&mut self, now: NaiveDateTime) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Delivered;
+        self.last_error = None;
+        self.delivered_at = Some(now.to_string());
+    }
+}
+
+/// Entrada del registro de entregas: una por cada intento HTTP.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryAttempt {
+    pub id: String,
+    pub outbox_id: String,
+    pub subscription_id: String,
+    pub event_kind: EventKind,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub duration_ms: i64,
+    pub attempted_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn message() -> OutboxMessage {
+        OutboxMessage {
+            id: "m1".to_string(),
+            subscription_id: "s1".to_string(),
+            event_id: "e1".to_string(),
+            event_kind: EventKind::ScheduleChanged,
+            payload: Value::Null,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now(),
+            last_error: None,
+            created_at: None,
+            delivered_at: None,
+        }
+    }
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 3, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn delivery_id_is_stable_per_event_and_subscription() {
+        let id = OutboxMessage::delivery_id("e1", "s1");
+
+        assert_eq!(id, OutboxMessage::delivery_id("e1", "s1"));
+        assert_ne!(id, OutboxMessage::delivery_id("e1", "s2"));
+        assert_ne!(id, OutboxMessage::delivery_id("e2", "s1"));
+    }
+
+    #[test]
+    fn backoff_doubles_from_thirty_seconds() {
+        assert_eq!(OutboxMessage::backoff(1), Duration::seconds(30));
+        assert_eq!(OutboxMessage::backoff(2), Duration::seconds(60));
+        assert_eq!(OutboxMessage::backoff(4), Duration::seconds(240));
+        assert_eq!(OutboxMessage::backoff(7), Duration::seconds(1920));
+    }
+
+    #[test]
+    fn backoff_is_capped_at_one_hour() {
+        assert_eq!(OutboxMessage::backoff(8), Duration::seconds(3600));
+        assert_eq!(OutboxMessage::backoff(50), Duration::seconds(3600));
+    }
+
+    #[test]
+    fn backoff_treats_non_positive_attempts_as_the_first() {
+        assert_eq!(OutboxMessage::backoff(0), Duration::seconds(30));
+        assert_eq!(OutboxMessage::backoff(-3), Duration::seconds(30));
+    }
+
+    #[test]
+    fn failure_reschedules_until_the_last_attempt() {
+        let mut message = message();
+
+        message.record_failure("timeout", now());
+        assert_eq!(message.status, DeliveryStatus::Pending);
+        assert_eq!(message.attempts, 1);
+        assert_eq!(message.next_attempt_at, now() + Duration::seconds(30));
+        assert_eq!(message.last_error.as_deref(), Some("timeout"));
+
+        message.attempts = OutboxMessage::MAX_ATTEMPTS - 1;
+        message.record_failure("timeout", now());
+        assert_eq!(message.status, DeliveryStatus::Failed);
+    }
+
+    #[test]
+    fn success_clears_the_last_error() {
+        let mut message = message();
+        message.record_failure("timeout", now());
+
+        message.record_success(now());
+        assert_eq!(message.status, DeliveryStatus::Delivered);
+        assert_eq!(message.attempts, 2);
+        assert_eq!(message.last_error, None);
+    }
+}
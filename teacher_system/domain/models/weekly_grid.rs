@@ -0,0 +1,34 @@
+use crate::domain::models::enums::{SessionType, Weekday};
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridSession {
+    pub schedule_id: String,
+    pub course_id: String,
+    pub course_code: String,
+    pub course_name: String,
+    pub section: i32,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub session_type: SessionType,
+    pub facility_id: String,
+    /// Se solapa con otro curso obligatorio de la misma sección
+    pub clashes: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridDay {
+    pub day: Weekday,
+    pub sessions: Vec<GridSession>,
+}
+
+/// Horario semanal de un ciclo de un programa, ordenado por día y hora.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyGrid {
+    pub program: String,
+    pub cycle: i32,
+    pub academic_year: i32,
+    pub semester: String,
+    pub days: Vec<GridDay>,
+}
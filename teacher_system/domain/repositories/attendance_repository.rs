@@ -0,0 +1,12 @@
+use crate::domain::models::attendance::{AttendanceFilter, AttendanceRecord};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AttendanceRepository: Send + Sync {
+    /// Guarda o corrige la asistencia de cada alumno en esa fecha de la sesión.
+    async fn save_records(&self, records: &[AttendanceRecord]) -> Result<(), String>;
+    async fn get_records(
+        &self,
+        filter: &AttendanceFilter,
+    ) -> Result<Vec<AttendanceRecord>, String>;
+}
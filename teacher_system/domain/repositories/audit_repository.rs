@@ -0,0 +1,9 @@
+use crate::domain::models::audit_log::{AuditFilter, AuditLog};
+use async_trait::async_trait;
+
+/// Registro de auditoría de solo inserción: no expone update ni delete.
+#[async_trait]
+pub trait AuditRepository: Send + Sync {
+    async fn create_log(&self, log: &AuditLog) -> Result<(), String>;
+    async fn get_logs(&self, filter: &AuditFilter) -> Result<Vec<AuditLog>, String>;
+}
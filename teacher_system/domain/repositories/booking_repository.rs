@@ -0,0 +1,18 @@
+use crate::domain::models::booking::{BookingFilter, FacilityBooking};
+use crate::domain::models::enums::BookingStatus;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait BookingRepository: Send + Sync {
+    async fn create_booking(&self, booking: &FacilityBooking) -> Result<(), String>;
+    /// Solo cambia el estado si el actual es uno de `from`.
+    async fn update_booking_status(
+        &self,
+        booking_id: &str,
+        from: &[BookingStatus],
+        status: BookingStatus,
+        reviewed_by: Option<&str>,
+    ) -> Result<(), String>;
+    async fn get_booking_by_id(&self, booking_id: &str) -> Result<Option<FacilityBooking>, String>;
+    async fn get_bookings(&self, filter: &BookingFilter) -> Result<Vec<FacilityBooking>, String>;
+}
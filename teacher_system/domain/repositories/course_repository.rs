@@ -1,12 +1,24 @@
-use crate::domain::models::course::Course;
+use crate::domain::models::course::{Course, PrerequisiteLink};
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait CourseRepository: Send + Sync {
     async fn create_course(&self, course: &Course) -> Result<(), String>;
-    async fn update_course(&self, course: &Course) -> Result<(), String>;
+    /// Con `expected_version` solo actualiza si la versión almacenada coincide.
+    async fn update_course(
+        &self,
+        course: &Course,
+        expected_version: Option<i32>,
+    ) -> Result<(), String>;
     async fn get_course_by_id(&self, id: &str) -> Result<Course, String>;
+    /// Incluye los cursos archivados: es la que debe usarse para leer el
+    /// historial académico (notas, créditos, requisitos aprobados).
+    async fn get_course_including_archived(&self, id: &str) -> Result<Course, String>;
+    /// Los cursos indicados en una sola consulta, con los archivados.
+    async fn get_courses_including_archived(&self, ids: &[String]) -> Result<Vec<Course>, String>;
     async fn get_all_courses(&self) -> Result<Vec<Course>, String>;
+    /// Catálogo completo, con los cursos archivados.
+    async fn get_all_courses_including_archived(&self) -> Result<Vec<Course>, String>;
     async fn get_courses_by_user(&self, user_id: &str) -> Result<Vec<Course>, String>;
     async fn get_courses_by_facility(&self, facility_id: &str) -> Result<Vec<Course>, String>;
     async fn get_courses_by_facility_name(
@@ -14,5 +26,10 @@ pub trait CourseRepository: Send + Sync {
         name_facility: &str,
     ) -> Result<Vec<Course>, String>;
     async fn get_courses_by_schedule(&self, schedule_id: &str) -> Result<Course, String>;
+    /// Cursos que deben aprobarse antes de llevar `course_id`.
+    async fn get_prerequisites(&self, course_id: &str) -> Result<Vec<Course>, String>;
+    /// Todos los requisitos del catálogo, sin repetir pares de códigos.
+    async fn get_prerequisite_links(&self) -> Result<Vec<PrerequisiteLink>, String>;
     async fn delete_course(&self, id: &str) -> Result<(), String>;
+    async fn restore_course(&self, id: &str) -> Result<(), String>;
 }
@@ -0,0 +1,35 @@
+use crate::domain::models::{enrollment::Enrollment, enums::EnrollmentStatus};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+#[async_trait]
+pub trait EnrollmentRepository: Send + Sync {
+    async fn get_enrollment_by_id(&self, id: &str) -> Result<Option<Enrollment>, String>;
+    async fn get_enrollments_by_course(&self, course_id: &str) -> Result<Vec<Enrollment>, String>;
+    async fn get_enrollments_by_student(
+        &self,
+        student_id: &str,
+    ) -> Result<Vec<Enrollment>, String>;
+    /// Códigos de los cursos que aprobó cada alumno, en una sola consulta y
+    /// contando los cursos archivados. Con `include_in_progress` también
+    /// cuentan los que lleva ahora, como si fuera a aprobarlos.
+    async fn get_passed_codes(
+        &self,
+        student_ids: &[String],
+        include_in_progress: bool,
+    ) -> Result<HashMap<String, Vec<String>>, String>;
+    async fn update_enrollment_status(
+        &self,
+        id: &str,
+        status: &EnrollmentStatus,
+    ) -> Result<(), String>;
+    /// Guarda el resultado del curso: `Completed` o `Failed` con su nota final.
+    async fn record_result(
+        &self,
+        id: &str,
+        status: &EnrollmentStatus,
+        final_grade: f64,
+    ) -> Result<(), String>;
+    /// Pasa las matrículas indicadas a otra sección del mismo curso.
+    async fn move_enrollments(&self, enrollment_ids: &[String], course_id: &str) -> Result<(), String>;
+}
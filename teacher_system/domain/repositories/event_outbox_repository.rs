@@ -0,0 +1,17 @@
+use crate::domain::models::event::OutboxEvent;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+/// Bandeja de salida de los eventos del dominio. Los eventos se guardan con
+/// el `ChangeSet` del cambio que los produce.
+#[async_trait]
+pub trait EventOutboxRepository: Send + Sync {
+    /// Toma los eventos pendientes que ya tocan y los aparta un rato para que
+    /// otro proceso no los reparta a la vez.
+    async fn claim_due_events(
+        &self,
+        now: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<OutboxEvent>, String>;
+    async fn update_event(&self, event: &OutboxEvent) -> Result<(), String>;
+}
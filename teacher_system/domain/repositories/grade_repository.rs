@@ -0,0 +1,11 @@
+use crate::domain::models::grade::{ComponentGrade, GradingScheme};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait GradeRepository: Send + Sync {
+    async fn get_scheme(&self, course_id: &str) -> Result<Option<GradingScheme>, String>;
+    async fn save_scheme(&self, scheme: &GradingScheme) -> Result<(), String>;
+    /// Guarda o corrige la nota de cada matrícula en la evaluación.
+    async fn save_grades(&self, grades: &[ComponentGrade]) -> Result<(), String>;
+    async fn get_grades_by_course(&self, course_id: &str) -> Result<Vec<ComponentGrade>, String>;
+}
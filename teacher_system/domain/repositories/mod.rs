@@ -1,4 +1,18 @@
+pub mod attendance_repository;
+pub mod audit_repository;
+pub mod booking_repository;
 pub mod course_repository;
+pub mod enrollment_repository;
+pub mod event_outbox_repository;
 pub mod facility_repository;
+pub mod grade_repository;
+pub mod notification_preference_repository;
+pub mod overload_repository;
 pub mod schedule_repository;
+pub mod session_exception_repository;
+pub mod student_status_repository;
+pub mod study_plan_repository;
+pub mod teacher_assignment_repository;
+pub mod unit_of_work_repository;
 pub mod user_repository;
+pub mod webhook_repository;
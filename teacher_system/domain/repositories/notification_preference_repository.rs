@@ -0,0 +1,11 @@
+use crate::domain::models::notification::NotificationPreference;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait NotificationPreferenceRepository: Send + Sync {
+    async fn get_preference(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<NotificationPreference>, String>;
+    async fn save_preference(&self, preference: &NotificationPreference) -> Result<(), String>;
+}
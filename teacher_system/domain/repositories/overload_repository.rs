@@ -0,0 +1,18 @@
+use crate::domain::models::credit_load::{OverloadFilter, OverloadRequest};
+use crate::domain::models::enums::OverloadStatus;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OverloadRepository: Send + Sync {
+    async fn create_request(&self, request: &OverloadRequest) -> Result<(), String>;
+    /// Solo revisa pedidos que siguen pendientes.
+    async fn update_request_status(
+        &self,
+        request_id: &str,
+        status: OverloadStatus,
+        reviewed_by: &str,
+        review_note: Option<&str>,
+    ) -> Result<(), String>;
+    async fn get_request_by_id(&self, request_id: &str) -> Result<Option<OverloadRequest>, String>;
+    async fn get_requests(&self, filter: &OverloadFilter) -> Result<Vec<OverloadRequest>, String>;
+}
@@ -4,10 +4,15 @@ use async_trait::async_trait;
 #[async_trait]
 pub trait ScheduleRepository: Send + Sync {
     async fn create_schedule(&self, schedule: &Schedule) -> Result<(), String>;
-    async fn update_schedule(&self, schedule: &Schedule) -> Result<(), String>;
+    /// Con `expected_version` solo actualiza si la versión almacenada coincide.
+    async fn update_schedule(
+        &self,
+        schedule: &Schedule,
+        expected_version: Option<i32>,
+    ) -> Result<(), String>;
     async fn get_schedule_by_id(&self, schedule_id: &str) -> Result<Option<Schedule>, String>;
     async fn get_all_schedules(&self) -> Result<Vec<Schedule>, String>;
-    async fn get_schedules_by_course(&self, course_id: &str) -> Result<Option<Schedule>, String>;
+    async fn get_schedules_by_course(&self, course_id: &str) -> Result<Vec<Schedule>, String>;
     async fn get_schedules_by_course_name(
         &self,
         name_course: &str,
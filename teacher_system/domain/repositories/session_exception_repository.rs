@@ -0,0 +1,23 @@
+use crate::domain::models::session_exception::SessionException;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+#[async_trait]
+pub trait SessionExceptionRepository: Send + Sync {
+    async fn create_exception(&self, exception: &SessionException) -> Result<(), String>;
+    async fn get_exceptions_by_schedule(
+        &self,
+        schedule_id: &str,
+    ) -> Result<Vec<SessionException>, String>;
+    async fn get_exception_by_date(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+    ) -> Result<Option<SessionException>, String>;
+    /// Recuperaciones de `schedule_ids` que caen en `date`.
+    async fn get_make_ups_by_date(
+        &self,
+        schedule_ids: &[String],
+        date: NaiveDate,
+    ) -> Result<Vec<SessionException>, String>;
+}
@@ -0,0 +1,9 @@
+use crate::domain::models::student_status::StatusChange;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait StudentStatusRepository: Send + Sync {
+    async fn record_change(&self, change: &StatusChange) -> Result<(), String>;
+    /// Historial del alumno, del cambio más reciente al más antiguo.
+    async fn get_history(&self, student_id: &str) -> Result<Vec<StatusChange>, String>;
+}
@@ -0,0 +1,13 @@
+use crate::domain::models::study_plan::StudyPlan;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait StudyPlanRepository: Send + Sync {
+    async fn create_plan(&self, plan: &StudyPlan) -> Result<(), String>;
+    async fn get_plan_by_id(&self, plan_id: &str) -> Result<Option<StudyPlan>, String>;
+    /// Versiones del programa, de la más reciente a la más antigua.
+    async fn get_plans(&self, program: Option<&str>) -> Result<Vec<StudyPlan>, String>;
+    async fn get_active_plan(&self, program: &str) -> Result<Option<StudyPlan>, String>;
+    /// Deja vigente solo esta versión dentro de su programa.
+    async fn activate_plan(&self, plan_id: &str) -> Result<(), String>;
+}
@@ -0,0 +1,26 @@
+use crate::domain::models::enums::AssignmentStatus;
+use crate::domain::models::teacher_assignment::{AssignmentFilter, AssignmentProposal};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait TeacherAssignmentRepository: Send + Sync {
+    async fn create_proposals(&self, proposals: &[AssignmentProposal]) -> Result<(), String>;
+    /// Borra las propuestas aún sin decidir del semestre.
+    async fn delete_pending(&self, academic_year: i32, semester: &str) -> Result<(), String>;
+    async fn decide_proposal(
+        &self,
+        proposal_id: &str,
+        status: AssignmentStatus,
+        assigned_teacher_id: &str,
+        decided_by: &str,
+        note: Option<&str>,
+    ) -> Result<(), String>;
+    async fn get_proposal_by_id(
+        &self,
+        proposal_id: &str,
+    ) -> Result<Option<AssignmentProposal>, String>;
+    async fn get_proposals(
+        &self,
+        filter: &AssignmentFilter,
+    ) -> Result<Vec<AssignmentProposal>, String>;
+}
@@ -0,0 +1,9 @@
+use crate::domain::models::change_set::ChangeSet;
+use async_trait::async_trait;
+
+/// Guarda de una vez los cambios que un caso de uso reparte entre varias
+/// tablas.
+#[async_trait]
+pub trait UnitOfWorkRepository: Send + Sync {
+    async fn commit(&self, changes: ChangeSet) -> Result<(), String>;
+}
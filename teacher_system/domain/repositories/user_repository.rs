@@ -1,4 +1,4 @@
-use crate::domain::models::user::User;
+use crate::domain::models::{enums::StudentStatus, user::User};
 use async_trait::async_trait;
 
 #[async_trait]
@@ -8,7 +8,14 @@ pub trait UserRepository: Send + Sync {
     async fn get_all_users(&self) -> Result<Vec<User>, String>;
     async fn create_user(&self, user: &User) -> Result<(), String>;
     async fn update_user(&self, user: &User) -> Result<(), String>;
+    /// Cambia solo el estado académico, sin tocar el resto del usuario.
+    async fn update_student_status(
+        &self,
+        user_id: &str,
+        status: &StudentStatus,
+    ) -> Result<(), String>;
     async fn delete_user(&self, user_id: &str) -> Result<(), String>;
+    async fn restore_user(&self, user_id: &str) -> Result<(), String>;
     async fn get_users_by_course(&self, course_id: &str) -> Result<Vec<User>, String>;
     async fn get_users_by_course_name(&self, name_course: &str) -> Result<Vec<User>, String>;
     async fn get_users_by_facility(&self, facility_id: &str) -> Result<Vec<User>, String>;
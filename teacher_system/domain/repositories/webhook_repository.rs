@@ -0,0 +1,34 @@
+use crate::domain::models::webhook::{DeliveryAttempt, OutboxMessage, WebhookSubscription};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn create_subscription(&self, subscription: &WebhookSubscription) -> Result<(), String>;
+    async fn get_subscriptions(&self) -> Result<Vec<WebhookSubscription>, String>;
+    async fn get_subscription_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<WebhookSubscription>, String>;
+    async fn set_subscription_active(&self, id: &str, active: bool) -> Result<(), String>;
+}
+
+/// Bandeja de salida y registro de entregas de los webhooks.
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Un envío con el mismo `id` ya encolado se deja como está.
+    async fn enqueue_message(&self, message: &OutboxMessage) -> Result<(), String>;
+    /// Toma los envíos pendientes que ya tocan y los aparta un rato para que
+    /// otro despachador no los mande a la vez.
+    async fn claim_due_messages(
+        &self,
+        now: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<OutboxMessage>, String>;
+    async fn update_message(&self, message: &OutboxMessage) -> Result<(), String>;
+    async fn record_attempt(&self, attempt: &DeliveryAttempt) -> Result<(), String>;
+    async fn get_attempts_by_subscription(
+        &self,
+        subscription_id: &str,
+    ) -> Result<Vec<DeliveryAttempt>, String>;
+}
@@ -0,0 +1,65 @@
+use crate::domain::models::audit_log::AuditLog;
+use crate::domain::models::change_set::ChangeSet;
+use crate::domain::models::enums::AuditAction;
+use serde::Serialize;
+
+#[derive(Clone, Default)]
+pub struct DefaultAuditService;
+
+impl DefaultAuditService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Agrega el registro al `ChangeSet` del cambio, para que ambos se guarden
+    /// en la misma transacción.
+    #[allow(clippy::too_many_arguments)]
+    pub fn record<T: Serialize>(
+        &self,
+        changes: &mut ChangeSet,
+        actor_id: &str,
+        entity_type: &str,
+        entity_id: &str,
+        action: AuditAction,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> Result<(), String> {
+        changes.record_audit(build_log(
+            actor_id,
+            entity_type,
+            entity_id,
+            action,
+            before,
+            after,
+        )?);
+        Ok(())
+    }
+}
+
+fn build_log<T: Serialize>(
+    actor_id: &str,
+    entity_type: &str,
+    entity_id: &str,
+    action: AuditAction,
+    before: Option<&T>,
+    after: Option<&T>,
+) -> Result<AuditLog, String> {
+    // Guardar el estado antes y después del cambio como JSON
+    let to_json = |value: Option<&T>| {
+        value
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| e.to_string())
+    };
+
+    Ok(AuditLog {
+        id: uuid::Uuid::new_v4().to_string(),
+        actor_id: actor_id.to_string(),
+        entity_type: entity_type.to_string(),
+        entity_id: entity_id.to_string(),
+        action,
+        before: to_json(before)?,
+        after: to_json(after)?,
+        created_at: None,
+    })
+}
@@ -0,0 +1,301 @@
+use crate::domain::{
+    models::{
+        conflict::{
+            ClashPolicy, ConflictKind, ScheduleConflict, TimetableIssue, audit_timetable,
+            find_overlaps,
+        },
+        course::Course,
+        enums::{Curriculum, Weekday},
+        facilitie::Facility,
+        schedule::Schedule,
+        weekly_grid::{GridDay, GridSession, WeeklyGrid},
+    },
+    repositories::{
+        course_repository::CourseRepository, facility_repository::FacilityRepository,
+        schedule_repository::ScheduleRepository,
+    },
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DefaultConflictService {
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+    facility_repo: Arc<dyn FacilityRepository + Send + Sync>,
+    clash_policy: ClashPolicy,
+}
+
+impl DefaultConflictService {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+        facility_repo: Arc<dyn FacilityRepository + Send + Sync>,
+        clash_policy: ClashPolicy,
+    ) -> Self {
+        Self {
+            course_repo,
+            schedule_repo,
+            facility_repo,
+            clash_policy,
+        }
+    }
+
+    /// Aplica la política de choques entre cursos obligatorios del mismo ciclo.
+    pub async fn enforce_clash_policy(&self, schedule: &Schedule) -> Result<(), String> {
+        let clashes = self.curriculum_clashes(schedule).await?;
+        if clashes.is_empty() {
+            return Ok(());
+        }
+
+        let courses: Vec<&str> = clashes.iter().map(|c| c.second.course_id.as_str()).collect();
+        match self.clash_policy {
+            ClashPolicy::Block => Err(format!(
+                "Conflict: session overlaps obligatory courses of the same cycle ({})",
+                courses.join(", ")
+            )),
+            ClashPolicy::Warn => {
+                eprintln!(
+                    "Warning: session {} overlaps obligatory courses of the same cycle ({})",
+                    schedule.id,
+                    courses.join(", ")
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Cursos obligatorios que una misma sección del ciclo lleva en el semestre.
+/// Sin programa no hay cohorte: dos cursos sin programa no se comparan.
+fn same_cohort(a: &Course, b: &Course) -> bool {
+    matches!(a.curriculum, Curriculum::Obligatory)
+        && matches!(b.curriculum, Curriculum::Obligatory)
+        && a.program().is_some()
+        && a.program() == b.program()
+        && a.cycle == b.cycle
+        && a.section == b.section
+        && a.same_term(b)
+}
+
+impl DefaultConflictService {
+    /// Solapamientos del curso consigo mismo y con el resto de su sección.
+    pub async fn course_conflicts(&self, course_id: &str) -> Result<Vec<ScheduleConflict>, String> {
+        let course = self.course_repo.get_course_by_id(course_id).await?;
+        let own_sessions = self.schedule_repo.get_schedules_by_course(course_id).await?;
+
+        let mut conflicts = find_overlaps(&own_sessions, ConflictKind::SameCourse);
+
+        let cohort: Vec<Course> = self
+            .course_repo
+            .get_all_courses()
+            .await?
+            .into_iter()
+            .filter(|c| c.id != course.id && same_cohort(&course, c))
+            .collect();
+
+        for other in cohort {
+            let other_sessions = self.schedule_repo.get_schedules_by_course(&other.id).await?;
+            for first in &own_sessions {
+                for second in other_sessions.iter().filter(|s| first.conflicts_with(s)) {
+                    conflicts.push(ScheduleConflict {
+                        kind: ConflictKind::CrossSection,
+                        first: first.clone(),
+                        second: second.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(conflicts)
+    }
+
+    /// Reporte de todo un ciclo en un semestre, agrupado por sección.
+    pub async fn cycle_conflicts(
+        &self,
+        cycle: i32,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<Vec<ScheduleConflict>, String> {
+        let courses: Vec<Course> = self
+            .course_repo
+            .get_all_courses()
+            .await?
+            .into_iter()
+            .filter(|c| c.cycle == cycle && c.in_term(academic_year, semester))
+            .collect();
+
+        let mut conflicts = Vec::new();
+        let mut cohort_sessions: Vec<(Course, Vec<Schedule>)> = Vec::new();
+
+        for course in courses {
+            let sessions = self.schedule_repo.get_schedules_by_course(&course.id).await?;
+            conflicts.extend(find_overlaps(&sessions, ConflictKind::SameCourse));
+            cohort_sessions.push((course, sessions));
+        }
+
+        for (i, (first_course, first_sessions)) in cohort_sessions.iter().enumerate() {
+            for (second_course, second_sessions) in &cohort_sessions[i + 1..] {
+                if !same_cohort(first_course, second_course) {
+                    continue;
+                }
+
+                let sessions: Vec<Schedule> = first_sessions
+                    .iter()
+                    .chain(second_sessions.iter())
+                    .cloned()
+                    .collect();
+                conflicts.extend(find_overlaps(&sessions, ConflictKind::CrossSection));
+            }
+        }
+
+        Ok(conflicts)
+    }
+
+    /// Revisa todas las sesiones del semestre y reporta los choques existentes.
+    pub async fn semester_audit(
+        &self,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<Vec<TimetableIssue>, String> {
+        let courses: HashMap<String, Course> = self
+            .course_repo
+            .get_all_courses()
+            .await?
+            .into_iter()
+            .filter(|c| c.in_term(academic_year, semester))
+            .map(|c| (c.id.clone(), c))
+            .collect();
+
+        let facilities: HashMap<String, Facility> = self
+            .facility_repo
+            .get_all_facilities()
+            .await?
+            .into_iter()
+            .map(|f| (f.id.clone(), f))
+            .collect();
+
+        // Solo las sesiones de cursos vigentes del semestre
+        let sessions: Vec<Schedule> = self
+            .schedule_repo
+            .get_all_schedules()
+            .await?
+            .into_iter()
+            .filter(|s| courses.contains_key(&s.course_id))
+            .collect();
+
+        Ok(audit_timetable(&courses, &facilities, &sessions))
+    }
+
+    /// Sesiones de otros cursos obligatorios del mismo ciclo y programa que
+    /// se solapan con `schedule`; vacío si el curso no es obligatorio.
+    pub async fn curriculum_clashes(
+        &self,
+        schedule: &Schedule,
+    ) -> Result<Vec<ScheduleConflict>, String> {
+        let course = self.course_repo.get_course_by_id(&schedule.course_id).await?;
+        if !matches!(course.curriculum, Curriculum::Obligatory) {
+            return Ok(Vec::new());
+        }
+
+        let cohort: Vec<Course> = self
+            .course_repo
+            .get_all_courses()
+            .await?
+            .into_iter()
+            .filter(|c| c.id != course.id && same_cohort(&course, c))
+            .collect();
+
+        let mut clashes = Vec::new();
+        for other in cohort {
+            let sessions = self.schedule_repo.get_schedules_by_course(&other.id).await?;
+            for session in sessions.into_iter().filter(|s| s.conflicts_with(schedule)) {
+                clashes.push(ScheduleConflict {
+                    kind: ConflictKind::CrossSection,
+                    first: schedule.clone(),
+                    second: session,
+                });
+            }
+        }
+
+        Ok(clashes)
+    }
+
+    /// Vista semanal de un ciclo; con `section` solo muestra esa sección.
+    pub async fn cycle_grid(
+        &self,
+        program: &str,
+        cycle: i32,
+        academic_year: i32,
+        semester: &str,
+        section: Option<i32>,
+    ) -> Result<WeeklyGrid, String> {
+        let courses: Vec<Course> = self
+            .course_repo
+            .get_all_courses()
+            .await?
+            .into_iter()
+            .filter(|c| {
+                c.program() == Some(program)
+                    && c.cycle == cycle
+                    && c.in_term(academic_year, semester)
+            })
+            .filter(|c| section.is_none_or(|section| c.section == section))
+            .collect();
+
+        let mut entries: Vec<(Course, Schedule)> = Vec::new();
+        for course in courses {
+            let sessions = self.schedule_repo.get_schedules_by_course(&course.id).await?;
+            entries.extend(sessions.into_iter().map(|s| (course.clone(), s)));
+        }
+
+        let week = [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+        ];
+
+        let mut days = Vec::new();
+        for day in week {
+            let mut sessions: Vec<GridSession> = entries
+                .iter()
+                .filter(|(_, schedule)| schedule.day == day)
+                .map(|(course, schedule)| GridSession {
+                    schedule_id: schedule.id.clone(),
+                    course_id: course.id.clone(),
+                    course_code: course.code.clone(),
+                    course_name: course.name.clone(),
+                    section: course.section,
+                    start_time: schedule.start_time,
+                    end_time: schedule.end_time,
+                    session_type: schedule.session_type.clone(),
+                    facility_id: schedule.facility_id.clone(),
+                    clashes: entries.iter().any(|(other_course, other)| {
+                        other_course.id != course.id
+                            && same_cohort(course, other_course)
+                            && other.conflicts_with(schedule)
+                    }),
+                })
+                .collect();
+
+            if sessions.is_empty() {
+                continue;
+            }
+
+            sessions.sort_by_key(|s| s.start_time);
+            days.push(GridDay { day, sessions });
+        }
+
+        Ok(WeeklyGrid {
+            program: program.to_string(),
+            cycle,
+            academic_year,
+            semester: semester.to_string(),
+            days,
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use crate::domain::{
+    models::{
+        course::Course,
+        credit_load::{CreditLoadPolicy, OverloadFilter, TermLoad},
+        enums::{EnrollmentStatus, OverloadStatus},
+    },
+    repositories::{
+        course_repository::CourseRepository, enrollment_repository::EnrollmentRepository,
+        overload_repository::OverloadRepository, user_repository::UserRepository,
+    },
+};
+use std::{collections::HashMap, sync::Arc};
+
+#[derive(Clone)]
+pub struct DefaultCreditLoadService {
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    enrollment_repo: Arc<dyn EnrollmentRepository + Send + Sync>,
+    user_repo: Arc<dyn UserRepository + Send + Sync>,
+    overload_repo: Arc<dyn OverloadRepository + Send + Sync>,
+    policy: CreditLoadPolicy,
+}
+
+impl DefaultCreditLoadService {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        enrollment_repo: Arc<dyn EnrollmentRepository + Send + Sync>,
+        user_repo: Arc<dyn UserRepository + Send + Sync>,
+        overload_repo: Arc<dyn OverloadRepository + Send + Sync>,
+        policy: CreditLoadPolicy,
+    ) -> Self {
+        Self {
+            course_repo,
+            enrollment_repo,
+            user_repo,
+            overload_repo,
+            policy,
+        }
+    }
+
+    pub fn policy(&self) -> &CreditLoadPolicy {
+        &self.policy
+    }
+
+    /// Créditos matriculados del alumno en el semestre y el tope que le toca.
+    pub async fn term_load(
+        &self,
+        student_id: &str,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<TermLoad, String> {
+        let student = self
+            .user_repo
+            .get_user_by_id(student_id)
+            .await?
+            .ok_or_else(|| "Student not found".to_string())?;
+
+        let enrollments: Vec<_> = self
+            .enrollment_repo
+            .get_enrollments_by_student(student_id)
+            .await?
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    e.status,
+                    EnrollmentStatus::Enrolled | EnrollmentStatus::Pending
+                )
+            })
+            .collect();
+        let course_ids: Vec<String> = enrollments.iter().map(|e| e.course_id.clone()).collect();
+        let courses: HashMap<String, Course> = self
+            .course_repo
+            .get_courses_including_archived(&course_ids)
+            .await?
+            .into_iter()
+            .map(|c| (c.id.clone(), c))
+            .collect();
+
+        let mut enrolled_credits = 0;
+        let mut pending_credits = 0;
+        for enrollment in &enrollments {
+            let Some(course) = courses.get(&enrollment.course_id) else {
+                continue;
+            };
+            if course.academic_year != academic_year || course.semester != semester {
+                continue;
+            }
+            match enrollment.status {
+                EnrollmentStatus::Enrolled => enrolled_credits += course.credits,
+                _ => pending_credits += course.credits,
+            }
+        }
+
+        let approved_credits = self
+            .overload_repo
+            .get_requests(&OverloadFilter {
+                student_id: Some(student_id.to_string()),
+                status: Some(OverloadStatus::Approved),
+            })
+            .await?
+            .into_iter()
+            .filter(|r| r.academic_year == academic_year && r.semester == semester)
+            .map(|r| r.requested_credits)
+            .max();
+
+        let limit = self.policy.limit_for(student.student_status.as_ref());
+
+        Ok(TermLoad {
+            student_id: student.id,
+            academic_year,
+            semester: semester.to_string(),
+            student_status: student.student_status,
+            enrolled_credits,
+            pending_credits,
+            min_credits: limit.map(|l| l.min_credits),
+            max_credits: limit.map(|l| l.max_credits),
+            approved_credits,
+        })
+    }
+
+    /// Rechaza la matrícula si con el curso se pasa del tope del semestre o si
+    /// lo pedido en el semestre no llega al mínimo. Devuelve el tope, que se
+    /// vuelve a comprobar al confirmar dentro de la transacción.
+    pub async fn check_enrollment(
+        &self,
+        student_id: &str,
+        course_id: &str,
+    ) -> Result<Option<i32>, String> {
+        let course = self.course_repo.get_course_by_id(course_id).await?;
+        let load = self
+            .term_load(student_id, course.academic_year, &course.semester)
+            .await?;
+
+        if let Some(min) = load.min_credits.filter(|_| load.below_minimum()) {
+            return Err(format!(
+                "Conflict: {} credits requested for {} {} are below the minimum of {}; more courses must be requested first",
+                load.requested_credits(),
+                course.academic_year,
+                course.semester,
+                min
+            ));
+        }
+
+        let Some(max) = load.effective_max() else {
+            return Ok(None);
+        };
+        let total = load.enrolled_credits + course.credits;
+        if total > max {
+            return Err(format!(
+                "Conflict: {} credits would exceed the maximum of {} for {} {}; an overload request must be approved first",
+                total, max, course.academic_year, course.semester
+            ));
+        }
+        Ok(Some(max))
+    }
+}
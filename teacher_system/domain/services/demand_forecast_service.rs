@@ -0,0 +1,229 @@
+use crate::domain::{
+    models::{
+        course::Course,
+        demand_forecast::{
+            CourseDemand, DEFAULT_SECTION_CAPACITY, DemandForecast, HISTORY_TERMS,
+            estimate_demand, size_sections,
+        },
+        enums::{Semester, SessionType},
+        facilitie::Facility,
+        study_plan::StudyPlan,
+    },
+    repositories::{
+        course_repository::CourseRepository, facility_repository::FacilityRepository,
+        schedule_repository::ScheduleRepository, study_plan_repository::StudyPlanRepository,
+    },
+    services::study_plan_service::DefaultStudyPlanService,
+};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DefaultDemandForecastService {
+    plan_repo: Arc<dyn StudyPlanRepository + Send + Sync>,
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    facility_repo: Arc<dyn FacilityRepository + Send + Sync>,
+    schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+    study_plan_service: DefaultStudyPlanService,
+}
+
+impl DefaultDemandForecastService {
+    pub fn new(
+        plan_repo: Arc<dyn StudyPlanRepository + Send + Sync>,
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        facility_repo: Arc<dyn FacilityRepository + Send + Sync>,
+        schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+        study_plan_service: DefaultStudyPlanService,
+    ) -> Self {
+        Self {
+            plan_repo,
+            course_repo,
+            facility_repo,
+            schedule_repo,
+            study_plan_service,
+        }
+    }
+
+    /// Estima la demanda del semestre para los cursos de los planes vigentes.
+    /// Los cursos que los alumnos llevan ahora se dan por aprobados.
+    pub async fn forecast(
+        &self,
+        academic_year: i32,
+        semester: &str,
+        program: Option<&str>,
+    ) -> Result<DemandForecast, String> {
+        let plans: Vec<StudyPlan> = self
+            .plan_repo
+            .get_plans(program)
+            .await?
+            .into_iter()
+            .filter(|p| p.active)
+            .collect();
+        if plans.is_empty() {
+            return Err("Study plan not found: there is no active plan to forecast".to_string());
+        }
+
+        let term = (academic_year, semester.parse::<Semester>()?);
+
+        // Las secciones archivadas también cuentan como historial de matrícula
+        let catalog = self.course_repo.get_all_courses_including_archived().await?;
+        let catalog: Vec<(&Course, (i32, Semester))> = catalog
+            .iter()
+            .filter_map(|c| course_term(c).map(|t| (c, t)))
+            .collect();
+        let facilities = self.facility_repo.get_all_facilities().await?;
+
+        let mut courses: Vec<CourseDemand> = Vec::new();
+        for plan in &plans {
+            let eligible = self.study_plan_service.eligible_counts(plan, true).await?;
+
+            for course in &plan.courses {
+                let eligible_students = eligible.get(&course.code).copied().unwrap_or(0);
+                let group_size = match &course.elective_group {
+                    Some(group) => plan
+                        .courses
+                        .iter()
+                        .filter(|c| {
+                            c.elective_group.as_ref() == Some(group)
+                                && eligible.get(&c.code).is_some_and(|n| *n > 0)
+                        })
+                        .count() as i32,
+                    None => 1,
+                };
+
+                let sections: Vec<(&Course, (i32, Semester))> = catalog
+                    .iter()
+                    .filter(|(c, _)| c.code == course.code)
+                    .copied()
+                    .collect();
+                let history = past_enrollments(&sections, term);
+                let (historical_average, estimated_demand) =
+                    estimate_demand(eligible_students, group_size, &history);
+                if estimated_demand == 0 {
+                    continue;
+                }
+
+                let mut notes: Vec<String> = Vec::new();
+                if history.is_empty() {
+                    notes.push("no enrollment history; estimated from the study plan only".to_string());
+                }
+
+                let latest = sections
+                    .iter()
+                    .filter(|(_, t)| *t < term)
+                    .max_by_key(|(c, t)| (*t, c.section))
+                    .map(|(c, _)| *c);
+                let preferred_capacity = latest.map_or(DEFAULT_SECTION_CAPACITY, |c| c.capacity);
+                let session_types = match latest {
+                    Some(latest) => self
+                        .schedule_repo
+                        .get_schedules_by_course(&latest.id)
+                        .await?
+                        .into_iter()
+                        .map(|s| s.session_type)
+                        .collect(),
+                    None => Vec::new(),
+                };
+
+                let max_room_capacity = max_room_capacity(&facilities, &session_types);
+                match max_room_capacity {
+                    None => notes.push("no facility fits its sessions".to_string()),
+                    Some(room) if room < preferred_capacity => notes.push(format!(
+                        "capacity limited by the largest suitable facility ({})",
+                        room
+                    )),
+                    _ => {}
+                }
+
+                let opened = sections.iter().filter(|(_, t)| *t == term).count();
+                if opened > 0 {
+                    notes.push(format!(
+                        "{} section(s) already open for {} {}",
+                        opened, academic_year, semester
+                    ));
+                }
+
+                let (suggested_sections, suggested_capacity) =
+                    size_sections(estimated_demand, preferred_capacity, max_room_capacity);
+
+                courses.push(CourseDemand {
+                    code: course.code.clone(),
+                    name: course.name.clone(),
+                    program: plan.program.clone(),
+                    cycle: course.cycle,
+                    curriculum: course.curriculum.clone(),
+                    elective_group: course.elective_group.clone(),
+                    eligible_students,
+                    historical_average,
+                    estimated_demand,
+                    max_room_capacity,
+                    suggested_sections,
+                    suggested_capacity,
+                    notes,
+                });
+            }
+        }
+
+        courses.sort_by(|a, b| (&a.program, a.cycle, &a.code).cmp(&(&b.program, b.cycle, &b.code)));
+        Ok(DemandForecast {
+            academic_year,
+            semester: semester.to_string(),
+            total_sections: courses.iter().map(|c| c.suggested_sections).sum(),
+            courses,
+        })
+    }
+}
+
+/// Año y semestre del curso. Un semestre que no se reconoce deja la sección
+/// fuera del historial y queda en el log.
+fn course_term(course: &Course) -> Option<(i32, Semester)> {
+    match course.semester.parse() {
+        Ok(semester) => Some((course.academic_year, semester)),
+        Err(e) => {
+            log::warn!("Course {} left out of the forecast: {}", course.id, e);
+            None
+        }
+    }
+}
+
+/// Matriculados por semestre en los últimos semestres anteriores al pedido,
+/// sumando todas las secciones.
+fn past_enrollments(sections: &[(&Course, (i32, Semester))], term: (i32, Semester)) -> Vec<i32> {
+    let mut terms: Vec<((i32, Semester), i32)> = Vec::new();
+    for (course, course_term) in sections.iter().filter(|(_, t)| *t < term) {
+        match terms.iter_mut().find(|(t, _)| t == course_term) {
+            Some(past) => past.1 += course.enrolled,
+            None => terms.push((*course_term, course.enrolled)),
+        }
+    }
+
+    terms.sort_by_key(|(t, _)| std::cmp::Reverse(*t));
+    terms
+        .into_iter()
+        .take(HISTORY_TERMS)
+        .map(|(_, enrolled)| enrolled)
+        .collect()
+}
+
+/// Aforo que admite todas las sesiones del curso: para cada tipo de sesión
+/// la mayor aula apta, y de ellas la menor. Sin horarios se asume teoría.
+fn max_room_capacity(facilities: &[Facility], session_types: &[SessionType]) -> Option<i32> {
+    let theory = [SessionType::Theory];
+    let session_types = if session_types.is_empty() {
+        &theory[..]
+    } else {
+        session_types
+    };
+
+    session_types
+        .iter()
+        .map(|session_type| {
+            facilities
+                .iter()
+                .filter(|f| session_type.fits_facility_type(&f.facility_type))
+                .map(|f| f.capacity)
+                .max()
+        })
+        .collect::<Option<Vec<i32>>>()?
+        .into_iter()
+        .min()
+}
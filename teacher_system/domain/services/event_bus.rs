@@ -0,0 +1,111 @@
+use crate::domain::models::event::{EventEnvelope, OutboxEvent};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Reacción a un evento del dominio (avisos, webhooks, ...).
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    /// Nombre estable con el que el evento recuerda que ya pasó por aquí.
+    fn name(&self) -> &'static str;
+    async fn handle(&self, envelope: &EventEnvelope) -> Result<(), String>;
+}
+
+/// Reparte cada evento de la bandeja de salida entre los manejadores
+/// registrados.
+#[derive(Clone, Default)]
+pub struct DefaultEventBus {
+    handlers: Vec<Arc<dyn EventHandler>>,
+}
+
+impl DefaultEventBus {
+    pub fn new(handlers: Vec<Arc<dyn EventHandler>>) -> Self {
+        Self { handlers }
+    }
+
+    /// Entrega el evento a los manejadores que aún no lo procesaron, aunque
+    /// alguno falle; si hubo errores se devuelven juntos para reintentar el
+    /// evento más tarde, solo con los que fallaron.
+    pub async fn dispatch(&self, event: &mut OutboxEvent) -> Result<(), String> {
+        let mut errors = Vec::new();
+        for handler in &self.handlers {
+            if event.was_handled_by(handler.name()) {
+                continue;
+            }
+            match handler.handle(&event.envelope).await {
+                Ok(()) => event.handled_by.push(handler.name().to_string()),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::enums::AuditAction;
+    use crate::domain::models::event::DomainEvent;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Counting(AtomicUsize);
+
+    #[async_trait]
+    impl EventHandler for Counting {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        async fn handle(&self, _: &EventEnvelope) -> Result<(), String> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    struct Failing;
+
+    #[async_trait]
+    impl EventHandler for Failing {
+        fn name(&self) -> &'static str {
+            "failing"
+        }
+
+        async fn handle(&self, _: &EventEnvelope) -> Result<(), String> {
+            Err("down".to_string())
+        }
+    }
+
+    fn event() -> OutboxEvent {
+        OutboxEvent::new(DomainEvent::ScheduleChanged {
+            schedule_id: "s1".to_string(),
+            course_id: "c1".to_string(),
+            action: AuditAction::Create,
+        })
+    }
+
+    #[tokio::test]
+    async fn a_failing_handler_does_not_stop_the_others() {
+        let counting = Arc::new(Counting(AtomicUsize::new(0)));
+        let bus = DefaultEventBus::new(vec![Arc::new(Failing), counting.clone()]);
+        let mut event = event();
+
+        assert_eq!(bus.dispatch(&mut event).await, Err("down".to_string()));
+        assert_eq!(counting.0.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn a_retry_skips_the_handlers_that_already_succeeded() {
+        let counting = Arc::new(Counting(AtomicUsize::new(0)));
+        let bus = DefaultEventBus::new(vec![Arc::new(Failing), counting.clone()]);
+        let mut event = event();
+
+        assert!(bus.dispatch(&mut event).await.is_err());
+        assert!(bus.dispatch(&mut event).await.is_err());
+        assert_eq!(counting.0.load(Ordering::SeqCst), 1);
+        assert_eq!(event.handled_by, vec!["counting".to_string()]);
+    }
+}
@@ -1,2 +1,17 @@
+pub mod audit_service;
+pub mod conflict_service;
+pub mod credit_load_service;
+pub mod demand_forecast_service;
+pub mod event_bus;
+pub mod notification_service;
+pub mod notifier;
+pub mod prerequisite_service;
+pub mod room_allocation_service;
 pub mod scheduling_service;
+pub mod section_service;
+pub mod student_status_service;
+pub mod study_plan_service;
+pub mod teacher_assignment_service;
+pub mod transcript_service;
 pub mod validation_service;
+pub mod webhook_service;
@@ -0,0 +1,170 @@
+use crate::domain::{
+    models::{
+        course::Course,
+        enums::{AuditAction, NotificationChannel},
+        event::{DomainEvent, EventEnvelope},
+        notification::{Notification, NotificationPreference},
+        user::User,
+    },
+    repositories::{
+        course_repository::CourseRepository,
+        notification_preference_repository::NotificationPreferenceRepository,
+        user_repository::UserRepository,
+    },
+    services::{event_bus::EventHandler, notifier::Notifier},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Convierte los eventos en avisos para los usuarios afectados, por los
+/// canales que cada uno eligió.
+#[derive(Clone)]
+pub struct DefaultNotificationService {
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    user_repo: Arc<dyn UserRepository + Send + Sync>,
+    preference_repo: Arc<dyn NotificationPreferenceRepository + Send + Sync>,
+    channels: HashMap<NotificationChannel, Arc<dyn Notifier>>,
+}
+
+impl DefaultNotificationService {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        user_repo: Arc<dyn UserRepository + Send + Sync>,
+        preference_repo: Arc<dyn NotificationPreferenceRepository + Send + Sync>,
+        channels: HashMap<NotificationChannel, Arc<dyn Notifier>>,
+    ) -> Self {
+        Self {
+            course_repo,
+            user_repo,
+            preference_repo,
+            channels,
+        }
+    }
+
+    /// Alumnos del curso y, si cambia el horario, también el profesor.
+    async fn recipients(&self, event: &DomainEvent, course: &Course) -> Result<Vec<User>, String> {
+        match event {
+            DomainEvent::EnrollmentConfirmed { student_id, .. } => Ok(self
+                .user_repo
+                .get_user_by_id(student_id)
+                .await?
+                .into_iter()
+                .collect()),
+            DomainEvent::ScheduleChanged { .. } => {
+                let mut users = self.user_repo.get_users_by_course(&course.id).await?;
+                if let Some(teacher) = self.user_repo.get_user_by_id(&course.teacher_id).await? {
+                    users.push(teacher);
+                }
+                Ok(users)
+            }
+            DomainEvent::ClassCancelled { .. } | DomainEvent::ClassRescheduled { .. } => {
+                self.user_repo.get_users_by_course(&course.id).await
+            }
+        }
+    }
+
+    async fn preference(&self, user_id: &str) -> Result<NotificationPreference, String> {
+        Ok(self
+            .preference_repo
+            .get_preference(user_id)
+            .await?
+            .unwrap_or_else(|| NotificationPreference::default_for(user_id)))
+    }
+}
+
+/// Asunto y cuerpo del aviso para cada tipo de evento.
+fn message(event: &DomainEvent, course: &Course) -> (String, String) {
+    match event {
+        DomainEvent::ScheduleChanged { action, .. } => {
+            let change = match action {
+                AuditAction::Create => "added",
+                AuditAction::Update => "updated",
+                AuditAction::Delete => "removed",
+                AuditAction::Restore => "restored",
+            };
+            (
+                format!("Schedule of {} changed", course.name),
+                format!(
+                    "A session of {} ({}) was {}. Check the updated timetable.",
+                    course.name, course.code, change
+                ),
+            )
+        }
+        DomainEvent::EnrollmentConfirmed { .. } => (
+            format!("Enrollment in {} confirmed", course.name),
+            format!(
+                "Your enrollment in {} ({}), section {}, is confirmed.",
+                course.name, course.code, course.section
+            ),
+        ),
+        DomainEvent::ClassCancelled { date, reason, .. } => (
+            format!("{} cancelled on {}", course.name, date),
+            format!(
+                "The session of {} on {} is cancelled. Reason: {}",
+                course.name, date, reason
+            ),
+        ),
+        DomainEvent::ClassRescheduled {
+            date,
+            reason,
+            make_up,
+            ..
+        } => (
+            format!("{} moved from {}", course.name, date),
+            format!(
+                "The session of {} on {} moves to {} {}-{}. Reason: {}",
+                course.name,
+                date,
+                make_up.date,
+                make_up.start_time.format("%H:%M"),
+                make_up.end_time.format("%H:%M"),
+                reason
+            ),
+        ),
+    }
+}
+
+#[async_trait]
+impl EventHandler for DefaultNotificationService {
+    fn name(&self) -> &'static str {
+        "notifications"
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> Result<(), String> {
+        let event = &envelope.event;
+        let kind = event.kind();
+        // El curso pudo archivarse después del evento; igual se avisa
+        let course = self
+            .course_repo
+            .get_course_including_archived(event.course_id())
+            .await?;
+        let (subject, body) = message(event, &course);
+
+        for user in self.recipients(event, &course).await? {
+            let preference = self.preference(&user.id).await?;
+            if !preference.wants(kind) {
+                continue;
+            }
+
+            let notification = Notification {
+                recipient_id: user.id.clone(),
+                recipient_email: user.email.clone(),
+                subject: subject.clone(),
+                body: body.clone(),
+            };
+
+            // Un canal caído no impide avisar por los demás
+            for channel in &preference.channels {
+                let Some(notifier) = self.channels.get(channel) else {
+                    continue;
+                };
+                if let Err(e) = notifier.notify(&notification).await {
+                    eprintln!("Error notifying {} by {}: {}", user.id, channel, e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+use crate::domain::models::notification::Notification;
+use async_trait::async_trait;
+
+/// Canal de envío de notificaciones; las implementaciones viven en infraestructura.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, notification: &Notification) -> Result<(), String>;
+}
@@ -0,0 +1,50 @@
+use crate::domain::{
+    models::course::Course,
+    repositories::{
+        course_repository::CourseRepository, enrollment_repository::EnrollmentRepository,
+    },
+};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DefaultPrerequisiteService {
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    enrollment_repo: Arc<dyn EnrollmentRepository + Send + Sync>,
+}
+
+impl DefaultPrerequisiteService {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        enrollment_repo: Arc<dyn EnrollmentRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            course_repo,
+            enrollment_repo,
+        }
+    }
+
+    /// Requisitos de `course_id` que el alumno aún no aprobó. Se comparan por
+    /// código: aprobar cualquier sección o semestre del curso basta.
+    pub async fn missing_prerequisites(
+        &self,
+        student_id: &str,
+        course_id: &str,
+    ) -> Result<Vec<Course>, String> {
+        let prerequisites = self.course_repo.get_prerequisites(course_id).await?;
+        if prerequisites.is_empty() {
+            return Ok(prerequisites);
+        }
+
+        let passed_codes = self
+            .enrollment_repo
+            .get_passed_codes(&[student_id.to_string()], false)
+            .await?
+            .remove(student_id)
+            .unwrap_or_default();
+
+        Ok(prerequisites
+            .into_iter()
+            .filter(|p| !passed_codes.contains(&p.code))
+            .collect())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::domain::{
+    models::{facilitie::Facility, schedule::Schedule},
+    repositories::{course_repository::CourseRepository, facility_repository::FacilityRepository},
+    services::validation_service::DefaultValidationService,
+};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DefaultRoomAllocationService {
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    facility_repo: Arc<dyn FacilityRepository + Send + Sync>,
+    validation_service: DefaultValidationService,
+}
+
+impl DefaultRoomAllocationService {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        facility_repo: Arc<dyn FacilityRepository + Send + Sync>,
+        validation_service: DefaultValidationService,
+    ) -> Self {
+        Self {
+            course_repo,
+            facility_repo,
+            validation_service,
+        }
+    }
+}
+
+impl DefaultRoomAllocationService {
+    /// El aula admite el tipo de sesión y tiene el equipo que la sesión requiere.
+    pub fn meets_requirements(facility: &Facility, schedule: &Schedule) -> bool {
+        schedule.session_type.fits_facility_type(&facility.facility_type)
+            && facility.has_equipment(&schedule.required_equipment)
+    }
+
+    /// Aulas libres que cumplen capacidad, tipo y equipo, de la más ajustada a la más grande.
+    pub async fn candidates(&self, schedule: &Schedule) -> Result<Vec<Facility>, String> {
+        let course = self.course_repo.get_course_by_id(&schedule.course_id).await?;
+
+        let mut candidates = Vec::new();
+        for facility in self.facility_repo.get_all_facilities().await? {
+            if facility.capacity < course.capacity || !Self::meets_requirements(&facility, schedule) {
+                continue;
+            }
+
+            let is_available = self
+                .validation_service
+                .check_facility_availability(&facility.id, schedule)
+                .await?;
+
+            if is_available {
+                candidates.push(facility);
+            }
+        }
+
+        candidates.sort_by_key(|f| f.capacity);
+        Ok(candidates)
+    }
+
+    /// Verifica que el aula elegida cumpla los requisitos de la sesión.
+    pub async fn check_requirements(&self, schedule: &Schedule) -> Result<(), String> {
+        let facility = self
+            .facility_repo
+            .get_facility_by_id(&schedule.facility_id)
+            .await?;
+
+        if !Self::meets_requirements(&facility, schedule) {
+            return Err(format!(
+                "Conflict: facility {} does not meet the session type or equipment requirements",
+                facility.name
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -1,48 +1,52 @@
 use crate::domain::{
     models::enums::{SessionType, Weekday},
-    models::schedule::Schedule,
-    repositories::{course_repository::CourseRepository, schedule_repository::ScheduleRepository},
+    models::schedule::{Schedule, institutional_hours},
+    repositories::user_repository::UserRepository,
+    services::validation_service::DefaultValidationService,
 };
-use chrono::NaiveTime;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct DefaultSchedulingService {
-    course_repo: Arc<dyn CourseRepository + Send + Sync>,
-    schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+    user_repo: Arc<dyn UserRepository + Send + Sync>,
+    validation_service: DefaultValidationService,
 }
 
 impl DefaultSchedulingService {
     pub fn new(
-        course_repo: Arc<dyn CourseRepository + Send + Sync>,
-        schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+        user_repo: Arc<dyn UserRepository + Send + Sync>,
+        validation_service: DefaultValidationService,
     ) -> Self {
         Self {
-            course_repo,
-            schedule_repo,
+            user_repo,
+            validation_service,
         }
     }
 }
 
 impl DefaultSchedulingService {
+    /// Horarios libres del profesor en el semestre indicado; solo ocupan al
+    /// profesor sus sesiones de ese semestre, las mismas que mide la regla de
+    /// su contrato.
     pub async fn suggest_available_time(
         &self,
         teacher_id: &str,
+        academic_year: i32,
+        semester: &str,
         duration_minutes: i32,
         preferred_days: Vec<Weekday>,
     ) -> Result<Vec<Schedule>, String> {
-        let teacher_courses = self.course_repo.get_courses_by_user(&teacher_id).await?;
+        let term_schedules = self
+            .validation_service
+            .term_sessions(teacher_id, academic_year, semester)
+            .await?;
 
-        let mut busy_schedules = Vec::new();
-        for course in teacher_courses {
-            match self.schedule_repo.get_schedules_by_course(&course.id).await {
-                Ok(schedule) => busy_schedules.extend(schedule),
-                Err(e) => return Err(e),
-            }
-        }
+        let teacher = self.user_repo.get_user_by_id(teacher_id).await?;
+        let rule = teacher
+            .as_ref()
+            .and_then(|t| self.validation_service.load_policy().rule_for(t));
 
-        let work_start = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
-        let work_end = NaiveTime::from_hms_opt(22, 0, 0).unwrap();
+        let (work_start, work_end) = institutional_hours();
         let duration = chrono::Duration::minutes(duration_minutes as i64);
         let interval = chrono::Duration::minutes(30);
 
@@ -59,6 +63,12 @@ impl DefaultSchedulingService {
         } else {
             preferred_days
         };
+        let days_to_check: Vec<Weekday> = days_to_check
+            .into_iter()
+            .filter(|day| {
+                rule.is_none_or(|r| r.allowed_days.is_empty() || r.allowed_days.contains(day))
+            })
+            .collect();
 
         let mut available_slots = Vec::new();
 
@@ -77,11 +87,15 @@ impl DefaultSchedulingService {
                     location_detail: None,
                     created_at: None,
                     course_id: "temp_course".to_string(),
+                    required_equipment: Vec::new(),
+                    version: 0,
                 };
 
-                let is_available = !busy_schedules
+                let is_available = !term_schedules
                     .iter()
-                    .any(|busy| busy.conflicts_with(&proposed_slot));
+                    .any(|busy| busy.conflicts_with(&proposed_slot))
+                    && rule
+                        .is_none_or(|r| r.check_session(&proposed_slot, &term_schedules).is_ok());
 
                 if is_available {
                     available_slots.push(proposed_slot);
@@ -94,26 +108,19 @@ impl DefaultSchedulingService {
         Ok(available_slots)
     }
 
+    /// Si el profesor está libre para `schedule` en el semestre indicado.
     pub async fn validate_schedule(
         &self,
         teacher_id: &str,
+        academic_year: i32,
+        semester: &str,
         schedule: &Schedule,
     ) -> Result<bool, String> {
-        let teacher_courses = self.course_repo.get_courses_by_user(&teacher_id).await?;
-
-        for course in teacher_courses {
-            let existing_schedule = self
-                .schedule_repo
-                .get_schedules_by_course(&course.id)
-                .await?
-                .iter()
-                .any(|s| s.conflicts_with(schedule));
-
-            if existing_schedule {
-                return Ok(false);
-            }
-        }
+        let term_schedules = self
+            .validation_service
+            .term_sessions(teacher_id, academic_year, semester)
+            .await?;
 
-        Ok(true)
+        Ok(!term_schedules.iter().any(|s| s.conflicts_with(schedule)))
     }
 }
@@ -0,0 +1,80 @@
+use crate::domain::{
+    models::{
+        course::Course, facilitie::Facility, schedule::Schedule, section::TermTimetable,
+        teaching_load::TeachingLoadPolicy,
+    },
+    repositories::{
+        course_repository::CourseRepository, facility_repository::FacilityRepository,
+        schedule_repository::ScheduleRepository,
+    },
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DefaultSectionService {
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+    facility_repo: Arc<dyn FacilityRepository + Send + Sync>,
+    load_policy: TeachingLoadPolicy,
+}
+
+impl DefaultSectionService {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+        facility_repo: Arc<dyn FacilityRepository + Send + Sync>,
+        load_policy: TeachingLoadPolicy,
+    ) -> Self {
+        Self {
+            course_repo,
+            schedule_repo,
+            facility_repo,
+            load_policy,
+        }
+    }
+
+    /// Las mismas reglas de carga que `DefaultValidationService`.
+    pub fn load_policy(&self) -> &TeachingLoadPolicy {
+        &self.load_policy
+    }
+
+    /// Cursos vigentes del semestre con sus sesiones y todas las aulas. Las
+    /// sesiones de otros semestres no chocan con las de este.
+    pub async fn timetable(
+        &self,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<TermTimetable, String> {
+        let courses: HashMap<String, Course> = self
+            .course_repo
+            .get_all_courses()
+            .await?
+            .into_iter()
+            .filter(|c| c.in_term(academic_year, semester))
+            .map(|c| (c.id.clone(), c))
+            .collect();
+
+        let sessions: Vec<Schedule> = self
+            .schedule_repo
+            .get_all_schedules()
+            .await?
+            .into_iter()
+            .filter(|s| courses.contains_key(&s.course_id))
+            .collect();
+
+        let facilities: HashMap<String, Facility> = self
+            .facility_repo
+            .get_all_facilities()
+            .await?
+            .into_iter()
+            .map(|f| (f.id.clone(), f))
+            .collect();
+
+        Ok(TermTimetable {
+            courses,
+            sessions,
+            facilities,
+        })
+    }
+}
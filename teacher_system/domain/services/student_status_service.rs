@@ -0,0 +1,132 @@
+use crate::domain::{
+    models::{
+        change_set::ChangeSet,
+        enums::AuditAction,
+        student_status::{StatusChange, StatusRuleSet},
+        user::User,
+    },
+    repositories::{unit_of_work_repository::UnitOfWorkRepository, user_repository::UserRepository},
+    services::{audit_service::DefaultAuditService, transcript_service::DefaultTranscriptService},
+};
+use std::sync::Arc;
+
+const AUDIT_ENTITY: &str = "user";
+
+#[derive(Clone)]
+pub struct DefaultStudentStatusService {
+    transcript_service: DefaultTranscriptService,
+    user_repo: Arc<dyn UserRepository + Send + Sync>,
+    unit_of_work: Arc<dyn UnitOfWorkRepository + Send + Sync>,
+    audit_service: DefaultAuditService,
+    rules: StatusRuleSet,
+}
+
+impl DefaultStudentStatusService {
+    pub fn new(
+        transcript_service: DefaultTranscriptService,
+        user_repo: Arc<dyn UserRepository + Send + Sync>,
+        unit_of_work: Arc<dyn UnitOfWorkRepository + Send + Sync>,
+        audit_service: DefaultAuditService,
+        rules: StatusRuleSet,
+    ) -> Self {
+        Self {
+            transcript_service,
+            user_repo,
+            unit_of_work,
+            audit_service,
+            rules,
+        }
+    }
+
+    pub fn rules(&self) -> &StatusRuleSet {
+        &self.rules
+    }
+
+    /// Aplica las reglas al historial del alumno. Solo si el estado cambia se
+    /// actualiza el usuario y se deja constancia en el historial.
+    pub async fn evaluate(
+        &self,
+        student_id: &str,
+        actor_id: &str,
+    ) -> Result<Option<StatusChange>, String> {
+        let student = self
+            .user_repo
+            .get_user_by_id(student_id)
+            .await?
+            .ok_or_else(|| format!("Student not found: {}", student_id))?;
+        if !student.is_student() {
+            return Err(format!("Invalid user: {} is not a student", student.code));
+        }
+
+        Ok(self
+            .evaluate_all(&[student_id.to_string()], actor_id)
+            .await?
+            .pop())
+    }
+
+    /// Evalúa a varios alumnos armando sus historiales de una vez; devuelve
+    /// solo los cambios de estado. Los usuarios que no son alumnos se omiten.
+    /// Cada cambio, su historial y su auditoría se guardan juntos.
+    pub async fn evaluate_all(
+        &self,
+        student_ids: &[String],
+        actor_id: &str,
+    ) -> Result<Vec<StatusChange>, String> {
+        let mut students = Vec::with_capacity(student_ids.len());
+        for student_id in student_ids {
+            let student = self
+                .user_repo
+                .get_user_by_id(student_id)
+                .await?
+                .ok_or_else(|| format!("Student not found: {}", student_id))?;
+            if student.is_student() {
+                students.push(student);
+            }
+        }
+        let transcripts = self
+            .transcript_service
+            .build_transcripts(students.clone())
+            .await?;
+
+        let mut changes = Vec::new();
+        for (student, transcript) in students.into_iter().zip(transcripts) {
+            let decision = self
+                .rules
+                .evaluate(student.student_status.as_ref(), &transcript);
+            if student.student_status.as_ref() == Some(&decision.status) {
+                continue;
+            }
+
+            let change = StatusChange {
+                id: uuid::Uuid::new_v4().to_string(),
+                student_id: student.id.clone(),
+                previous_status: student.student_status.clone(),
+                new_status: decision.status,
+                reasons: decision.reasons,
+                changed_by: actor_id.to_string(),
+                changed_at: None,
+            };
+
+            let after = User {
+                student_status: Some(change.new_status.clone()),
+                ..student.clone()
+            };
+            let mut change_set = ChangeSet::new();
+            change_set.update_student_status(&student.id, change.new_status.clone());
+            change_set.record_status_change(change.clone());
+            self.audit_service.record(
+                &mut change_set,
+                actor_id,
+                AUDIT_ENTITY,
+                &student.id,
+                AuditAction::Update,
+                Some(&student),
+                Some(&after),
+            )?;
+            self.unit_of_work.commit(change_set).await?;
+            changes.push(change);
+        }
+
+        Ok(changes)
+    }
+}
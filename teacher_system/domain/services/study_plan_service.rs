@@ -0,0 +1,143 @@
+use crate::domain::{
+    models::{
+        enums::StudentStatus,
+        study_plan::{CycleOffering, OfferedCourse, PlanProgress, StudyPlan},
+        validation::DomainError,
+    },
+    repositories::{
+        course_repository::CourseRepository, enrollment_repository::EnrollmentRepository,
+        user_repository::UserRepository,
+    },
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DefaultStudyPlanService {
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    enrollment_repo: Arc<dyn EnrollmentRepository + Send + Sync>,
+    user_repo: Arc<dyn UserRepository + Send + Sync>,
+}
+
+impl DefaultStudyPlanService {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        enrollment_repo: Arc<dyn EnrollmentRepository + Send + Sync>,
+        user_repo: Arc<dyn UserRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            course_repo,
+            enrollment_repo,
+            user_repo,
+        }
+    }
+
+    /// Requisitos de `course_prerequisites` de los cursos del plan, por código.
+    pub async fn prerequisites(
+        &self,
+        plan: &StudyPlan,
+    ) -> Result<HashMap<String, Vec<String>>, String> {
+        let mut prerequisites: HashMap<String, Vec<String>> = HashMap::new();
+        for link in self.course_repo.get_prerequisite_links().await? {
+            if plan.course(&link.course_code).is_some() {
+                prerequisites
+                    .entry(link.course_code)
+                    .or_default()
+                    .push(link.prerequisite_code);
+            }
+        }
+        Ok(prerequisites)
+    }
+
+    /// Valida el plan y que sus requisitos se dicten en ciclos anteriores.
+    pub async fn check_plan(&self, plan: &StudyPlan) -> Result<(), DomainError> {
+        plan.validate()?;
+        let prerequisites = self.prerequisites(plan).await?;
+        plan.check_prerequisites(&prerequisites)?;
+        Ok(())
+    }
+
+    pub async fn progress(
+        &self,
+        student_id: &str,
+        plan: &StudyPlan,
+    ) -> Result<PlanProgress, String> {
+        let passed = self
+            .enrollment_repo
+            .get_passed_codes(&[student_id.to_string()], false)
+            .await?
+            .remove(student_id)
+            .unwrap_or_default();
+        let prerequisites = self.prerequisites(plan).await?;
+        Ok(PlanProgress::measure(
+            student_id,
+            plan,
+            &passed,
+            &prerequisites,
+        ))
+    }
+
+    /// Alumnos activos del programa que pueden llevar cada curso del plan.
+    pub async fn eligible_counts(
+        &self,
+        plan: &StudyPlan,
+        include_in_progress: bool,
+    ) -> Result<HashMap<String, i32>, String> {
+        let prerequisites = self.prerequisites(plan).await?;
+
+        let students: Vec<String> = self
+            .user_repo
+            .get_all_users()
+            .await?
+            .into_iter()
+            .filter(|s| {
+                s.is_student()
+                    && s.program == plan.program
+                    && s.student_status != Some(StudentStatus::Graduated)
+            })
+            .map(|s| s.id)
+            .collect();
+        let mut passed = self
+            .enrollment_repo
+            .get_passed_codes(&students, include_in_progress)
+            .await?;
+
+        let mut eligible: HashMap<String, i32> = HashMap::new();
+        for student_id in &students {
+            let passed = passed.remove(student_id).unwrap_or_default();
+            for course in plan.eligible_courses(&passed, &prerequisites) {
+                *eligible.entry(course.code.clone()).or_default() += 1;
+            }
+        }
+        Ok(eligible)
+    }
+
+    /// Cursos que cada ciclo debe abrir: los que al menos un alumno activo
+    /// del programa aún no aprueba y ya puede llevar.
+    pub async fn offering(&self, plan: &StudyPlan) -> Result<Vec<CycleOffering>, String> {
+        let eligible = self.eligible_counts(plan, false).await?;
+
+        let mut cycles: Vec<CycleOffering> = Vec::new();
+        for course in &plan.courses {
+            let Some(&eligible_students) = eligible.get(&course.code) else {
+                continue;
+            };
+            let offered = OfferedCourse {
+                code: course.code.clone(),
+                name: course.name.clone(),
+                curriculum: course.curriculum.clone(),
+                elective_group: course.elective_group.clone(),
+                eligible_students,
+            };
+            match cycles.iter_mut().find(|c| c.cycle == course.cycle) {
+                Some(cycle) => cycle.courses.push(offered),
+                None => cycles.push(CycleOffering {
+                    cycle: course.cycle,
+                    courses: vec![offered],
+                }),
+            }
+        }
+        cycles.sort_by_key(|c| c.cycle);
+        Ok(cycles)
+    }
+}
@@ -0,0 +1,236 @@
+use crate::domain::{
+    models::{
+        course::Course,
+        enums::AssignmentStatus,
+        schedule::Schedule,
+        teacher_assignment::{
+            AssignmentProposal, MAX_CANDIDATES, TeacherCandidate, TeacherLoad, rank_candidates,
+        },
+        teaching_load::TeachingLoadPolicy,
+    },
+    repositories::{
+        course_repository::CourseRepository, schedule_repository::ScheduleRepository,
+        study_plan_repository::StudyPlanRepository, user_repository::UserRepository,
+    },
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Estado del semestre sobre el que se calculan las propuestas.
+struct TermBoard {
+    courses: Vec<Course>,
+    sessions: HashMap<String, Vec<Schedule>>,
+    loads: Vec<TeacherLoad>,
+    /// Facultad de cada programa, según su plan vigente.
+    faculties: HashMap<String, String>,
+}
+
+#[derive(Clone)]
+pub struct DefaultTeacherAssignmentService {
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+    user_repo: Arc<dyn UserRepository + Send + Sync>,
+    plan_repo: Arc<dyn StudyPlanRepository + Send + Sync>,
+    load_policy: TeachingLoadPolicy,
+}
+
+impl DefaultTeacherAssignmentService {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+        user_repo: Arc<dyn UserRepository + Send + Sync>,
+        plan_repo: Arc<dyn StudyPlanRepository + Send + Sync>,
+        load_policy: TeachingLoadPolicy,
+    ) -> Self {
+        Self {
+            course_repo,
+            schedule_repo,
+            user_repo,
+            plan_repo,
+            load_policy,
+        }
+    }
+
+    /// Días, noches y horas que permite el contrato del profesor.
+    fn check_contract(&self, load: &TeacherLoad, sessions: &[Schedule]) -> Result<(), String> {
+        match self.load_policy.rule_for(&load.teacher) {
+            Some(rule) => rule.check_sessions(sessions, &load.sessions),
+            None => Ok(()),
+        }
+    }
+
+    async fn board(&self, academic_year: i32, semester: &str) -> Result<TermBoard, String> {
+        let catalog = self.course_repo.get_all_courses().await?;
+        let courses: Vec<Course> = catalog
+            .iter()
+            .filter(|c| c.in_term(academic_year, semester))
+            .cloned()
+            .collect();
+
+        let mut sessions: HashMap<String, Vec<Schedule>> = HashMap::new();
+        for schedule in self.schedule_repo.get_all_schedules().await? {
+            if courses.iter().any(|c| c.id == schedule.course_id) {
+                sessions
+                    .entry(schedule.course_id.clone())
+                    .or_default()
+                    .push(schedule);
+            }
+        }
+
+        let faculties: HashMap<String, String> = self
+            .plan_repo
+            .get_plans(None)
+            .await?
+            .into_iter()
+            .filter(|p| p.active)
+            .map(|p| (p.program, p.faculty))
+            .collect();
+
+        let users = self.user_repo.get_all_users().await?;
+
+        let loads = users
+            .into_iter()
+            .filter(|u| u.is_teacher())
+            .map(|teacher| {
+                let term: Vec<&Course> = courses
+                    .iter()
+                    .filter(|c| c.teacher_id == teacher.id)
+                    .collect();
+                TeacherLoad {
+                    hours: term.iter().map(|c| c.hours_per_week).sum(),
+                    sessions: term
+                        .iter()
+                        .flat_map(|c| sessions.get(&c.id).cloned().unwrap_or_default())
+                        .collect(),
+                    taught_codes: catalog
+                        .iter()
+                        .filter(|c| c.teacher_id == teacher.id)
+                        .map(|c| c.code.clone())
+                        .collect::<HashSet<String>>(),
+                    teacher,
+                }
+            })
+            .collect();
+
+        Ok(TermBoard {
+            courses,
+            sessions,
+            loads,
+            faculties,
+        })
+    }
+
+    /// Propone un profesor para cada curso sin asignar del semestre. Cada
+    /// propuesta cuenta las horas y sesiones de las anteriores.
+    pub async fn propose(
+        &self,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<Vec<AssignmentProposal>, String> {
+        let mut board = self.board(academic_year, semester).await?;
+
+        let mut pending: Vec<Course> = board
+            .courses
+            .iter()
+            .filter(|c| c.active && !c.has_teacher())
+            .cloned()
+            .collect();
+        pending.sort_by(|a, b| (a.cycle, &a.code, a.section).cmp(&(b.cycle, &b.code, b.section)));
+
+        let mut proposals = Vec::new();
+        for course in pending {
+            let sessions = board.sessions.get(&course.id).cloned().unwrap_or_default();
+            let faculty = board.faculties.get(&course.program).map(|f| f.as_str());
+
+            let mut candidates: Vec<TeacherCandidate> = board
+                .loads
+                .iter()
+                .filter(|l| {
+                    l.has_room(course.hours_per_week)
+                        && !l.clashes(&sessions)
+                        && self.check_contract(l, &sessions).is_ok()
+                })
+                .map(|l| l.candidate(&course, faculty))
+                .collect();
+            rank_candidates(&mut candidates);
+            candidates.truncate(MAX_CANDIDATES);
+
+            let reasons = match candidates.first() {
+                Some(best) => best.reasons(),
+                None => {
+                    let busy = board.loads.iter().filter(|l| l.clashes(&sessions)).count();
+                    let full = board
+                        .loads
+                        .iter()
+                        .filter(|l| !l.clashes(&sessions) && !l.has_room(course.hours_per_week))
+                        .count();
+                    let outside = board.loads.len() - busy - full;
+                    vec![format!(
+                        "no teacher available: {} with a schedule conflict, {} without free hours, {} outside their contract rules",
+                        busy, full, outside
+                    )]
+                }
+            };
+
+            let proposed_teacher_id = candidates.first().map(|c| c.teacher_id.clone());
+            if let Some(load) = board
+                .loads
+                .iter_mut()
+                .find(|l| Some(&l.teacher.id) == proposed_teacher_id.as_ref())
+            {
+                load.take(&course, &sessions);
+            }
+
+            proposals.push(AssignmentProposal {
+                id: uuid::Uuid::new_v4().to_string(),
+                course_id: course.id.clone(),
+                academic_year,
+                semester: semester.to_string(),
+                proposed_teacher_id,
+                candidates,
+                reasons,
+                status: AssignmentStatus::Proposed,
+                assigned_teacher_id: None,
+                decided_by: None,
+                note: None,
+                created_at: None,
+            });
+        }
+
+        Ok(proposals)
+    }
+
+    /// Revisa con el horario actual que el profesor pueda tomar el curso.
+    pub async fn check_teacher(
+        &self,
+        course: &Course,
+        teacher_id: &str,
+    ) -> Result<TeacherCandidate, String> {
+        let board = self.board(course.academic_year, &course.semester).await?;
+        let load = board
+            .loads
+            .iter()
+            .find(|l| l.teacher.id == teacher_id)
+            .ok_or_else(|| format!("Invalid teacher: {} is not a teacher", teacher_id))?;
+
+        let sessions = board.sessions.get(&course.id).cloned().unwrap_or_default();
+        if load.clashes(&sessions) {
+            return Err(format!(
+                "Conflict: {} already teaches at the time of {}",
+                load.teacher.full_name, course.code
+            ));
+        }
+        if !load.has_room(course.hours_per_week) {
+            return Err(format!(
+                "Conflict: {} would exceed {} weekly hours",
+                load.teacher.full_name,
+                load.teacher.max_hours_per_week.unwrap_or_default()
+            ));
+        }
+
+        self.check_contract(load, &sessions)?;
+
+        let faculty = board.faculties.get(&course.program).map(|f| f.as_str());
+        Ok(load.candidate(course, faculty))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::domain::{
+    models::{
+        course::Course, enums::EnrollmentStatus, study_plan::StudyPlan, transcript::Transcript,
+        user::User,
+    },
+    repositories::{
+        course_repository::CourseRepository, enrollment_repository::EnrollmentRepository,
+        study_plan_repository::StudyPlanRepository, user_repository::UserRepository,
+    },
+};
+use std::{collections::HashMap, sync::Arc};
+
+#[derive(Clone)]
+pub struct DefaultTranscriptService {
+    user_repo: Arc<dyn UserRepository + Send + Sync>,
+    enrollment_repo: Arc<dyn EnrollmentRepository + Send + Sync>,
+    course_repo: Arc<dyn CourseRepository + Send + Sync>,
+    plan_repo: Arc<dyn StudyPlanRepository + Send + Sync>,
+}
+
+impl DefaultTranscriptService {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository + Send + Sync>,
+        enrollment_repo: Arc<dyn EnrollmentRepository + Send + Sync>,
+        course_repo: Arc<dyn CourseRepository + Send + Sync>,
+        plan_repo: Arc<dyn StudyPlanRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            user_repo,
+            enrollment_repo,
+            course_repo,
+            plan_repo,
+        }
+    }
+
+    pub async fn build_transcript(&self, student_id: &str) -> Result<Transcript, String> {
+        let student = self
+            .user_repo
+            .get_user_by_id(student_id)
+            .await?
+            .ok_or_else(|| "Student not found".to_string())?;
+
+        let mut transcripts = self.build_transcripts(vec![student]).await?;
+        Ok(transcripts.remove(0))
+    }
+
+    /// Arma el historial de varios alumnos leyendo el catálogo (con los cursos
+    /// archivados) y el plan de cada programa una sola vez.
+    pub async fn build_transcripts(&self, students: Vec<User>) -> Result<Vec<Transcript>, String> {
+        let catalog = self
+            .course_repo
+            .get_all_courses_including_archived()
+            .await?;
+        let courses: HashMap<&str, &Course> = catalog.iter().map(|c| (c.id.as_str(), c)).collect();
+        let mut plans: HashMap<String, Option<StudyPlan>> = HashMap::new();
+
+        let mut transcripts = Vec::with_capacity(students.len());
+        for student in students {
+            if !student.is_student() {
+                return Err(format!("Invalid user: {} is not a student", student.code));
+            }
+
+            let mut graded = Vec::new();
+            for enrollment in self
+                .enrollment_repo
+                .get_enrollments_by_student(&student.id)
+                .await?
+            {
+                if !matches!(
+                    enrollment.status,
+                    EnrollmentStatus::Completed | EnrollmentStatus::Failed
+                ) {
+                    continue;
+                }
+                let course = courses
+                    .get(enrollment.course_id.as_str())
+                    .ok_or_else(|| format!("Course {} not found", enrollment.course_id))?;
+                graded.push((enrollment, (*course).clone()));
+            }
+
+            if !plans.contains_key(&student.program) {
+                let plan = self.plan_repo.get_active_plan(&student.program).await?;
+                plans.insert(student.program.clone(), plan);
+            }
+            let plan = plans[&student.program].as_ref();
+            transcripts.push(Transcript::build(&student, graded, plan, &catalog));
+        }
+        Ok(transcripts)
+    }
+}
@@ -1,30 +1,184 @@
+use crate::domain::models::booking::{BookingFilter, FacilityBooking};
+use crate::domain::models::conflict::term_overlaps;
+use crate::domain::models::course::Course;
+use crate::domain::models::enums::{BookingStatus, Semester};
 use crate::domain::models::schedule::Schedule;
+use crate::domain::models::session_exception::SessionException;
+use crate::domain::models::teaching_load::TeachingLoadPolicy;
+use crate::domain::repositories::booking_repository::BookingRepository;
+use crate::domain::repositories::course_repository::CourseRepository;
 use crate::domain::repositories::schedule_repository::ScheduleRepository;
+use crate::domain::repositories::session_exception_repository::SessionExceptionRepository;
+use crate::domain::repositories::user_repository::UserRepository;
+use chrono::{Datelike, Utc};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct DefaultValidationService {
     schedule_repo: Arc<dyn ScheduleRepository>,
+    booking_repo: Arc<dyn BookingRepository>,
+    exception_repo: Arc<dyn SessionExceptionRepository>,
+    course_repo: Arc<dyn CourseRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    load_policy: TeachingLoadPolicy,
 }
 
 impl DefaultValidationService {
-    pub fn new(schedule_repo: Arc<dyn ScheduleRepository>) -> Self {
-        Self { schedule_repo }
+    pub fn new(
+        schedule_repo: Arc<dyn ScheduleRepository>,
+        booking_repo: Arc<dyn BookingRepository>,
+        exception_repo: Arc<dyn SessionExceptionRepository>,
+        course_repo: Arc<dyn CourseRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        load_policy: TeachingLoadPolicy,
+    ) -> Self {
+        Self {
+            schedule_repo,
+            booking_repo,
+            exception_repo,
+            course_repo,
+            user_repo,
+            load_policy,
+        }
     }
 
+    pub fn load_policy(&self) -> &TeachingLoadPolicy {
+        &self.load_policy
+    }
+
+    /// Si alguna de `sessions` choca con `schedule` en el semestre de
+    /// `course`; la misma regla que el horario del semestre y la copia entre
+    /// semestres. El curso lo pasa quien llama, así sirve aunque aún no esté
+    /// guardado o siga archivado.
+    async fn overlaps_in_term(
+        &self,
+        course: &Course,
+        schedule: &Schedule,
+        sessions: &[Schedule],
+    ) -> Result<bool, String> {
+        let course_ids: Vec<String> = sessions.iter().map(|s| s.course_id.clone()).collect();
+        let courses: HashMap<String, Course> = self
+            .course_repo
+            .get_courses_including_archived(&course_ids)
+            .await?
+            .into_iter()
+            .map(|c| (c.id.clone(), c))
+            .collect();
+
+        Ok(!term_overlaps(schedule, course, &courses, sessions).is_empty())
+    }
+
+    /// Solo las sesiones de cursos del semestre indicado.
+    pub async fn sessions_in_term(
+        &self,
+        sessions: Vec<Schedule>,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<Vec<Schedule>, String> {
+        let course_ids: Vec<String> = sessions.iter().map(|s| s.course_id.clone()).collect();
+        let term_ids: Vec<String> = self
+            .course_repo
+            .get_courses_including_archived(&course_ids)
+            .await?
+            .into_iter()
+            .filter(|c| c.in_term(academic_year, semester))
+            .map(|c| c.id)
+            .collect();
+
+        Ok(sessions
+            .into_iter()
+            .filter(|s| term_ids.contains(&s.course_id))
+            .collect())
+    }
+
+    /// Si el profesor de `course` puede dictar `schedule`.
     pub async fn check_teacher_availability(
         &self,
-        teacher_id: &str,
+        course: &Course,
         schedule: &Schedule,
     ) -> Result<bool, String> {
         // Obtener todos los horarios del profesor
-        let teacher_schedules = self.schedule_repo.get_schedules_by_user(teacher_id).await?;
+        let teacher_schedules = self
+            .schedule_repo
+            .get_schedules_by_user(&course.teacher_id)
+            .await?;
 
-        // Verificar conflictos con horarios existentes
-        let has_conflict = teacher_schedules.iter().any(|s| s.conflicts_with(schedule));
+        // Si hay conflicto en el semestre, el profesor no está disponible
+        Ok(!self
+            .overlaps_in_term(course, schedule, &teacher_schedules)
+            .await?)
+    }
 
-        // Si hay conflicto, el profesor no está disponible
-        Ok(!has_conflict)
+    /// Si el profesor de `course` puede dictar la recuperación de `exception`
+    /// en su fecha: sin choques con sus sesiones del semestre que ese día no
+    /// se suspenden (la misma sesión incluida), con otras recuperaciones de
+    /// esa fecha ni con las reservas que él pidió.
+    pub async fn check_teacher_make_up(
+        &self,
+        course: &Course,
+        schedule: &Schedule,
+        exception: &SessionException,
+    ) -> Result<bool, String> {
+        let (Some(slot), Some(session)) =
+            (&exception.make_up, exception.make_up_session(schedule))
+        else {
+            return Ok(true);
+        };
+        if !course.has_teacher() {
+            return Ok(true);
+        }
+
+        let teacher_schedules = self
+            .term_sessions(&course.teacher_id, course.academic_year, &course.semester)
+            .await?;
+
+        for other in teacher_schedules
+            .iter()
+            .filter(|s| s.conflicts_with(&session))
+        {
+            // La ocurrencia que se suspende o ya se movió ese día no cuenta
+            let freed = if other.id == exception.schedule_id
+                && exception.original_date == slot.date
+            {
+                true
+            } else {
+                self.exception_repo
+                    .get_exception_by_date(&other.id, slot.date)
+                    .await?
+                    .is_some()
+            };
+            if !freed {
+                return Ok(false);
+            }
+        }
+
+        let schedule_ids: Vec<String> = teacher_schedules.iter().map(|s| s.id.clone()).collect();
+        let make_ups = self
+            .exception_repo
+            .get_make_ups_by_date(&schedule_ids, slot.date)
+            .await?;
+        if make_ups
+            .iter()
+            .filter(|e| e.id != exception.id)
+            .filter_map(|e| e.make_up.as_ref())
+            .any(|other| slot.overlaps(other.date, other.start_time, other.end_time))
+        {
+            return Ok(false);
+        }
+
+        let bookings = self
+            .booking_repo
+            .get_bookings(&BookingFilter {
+                requested_by: Some(course.teacher_id.clone()),
+                date: Some(slot.date),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(!bookings
+            .iter()
+            .any(|b| b.is_active() && slot.overlaps(b.date, b.start_time, b.end_time)))
     }
 
     pub async fn check_facility_availability(
@@ -32,18 +186,215 @@ impl DefaultValidationService {
         facility_id: &str,
         schedule: &Schedule,
     ) -> Result<bool, String> {
+        // El curso puede estar archivado, como al restaurarlo
+        let course = self
+            .course_repo
+            .get_course_including_archived(&schedule.course_id)
+            .await?;
+
         // Obtener todos los horarios de la instalación
         let facility_schedules = self
             .schedule_repo
             .get_schedules_by_facility(facility_id)
             .await?;
 
-        // Verificar conflictos con horarios existentes
-        let has_conflict = facility_schedules
+        // Verificar conflictos con las sesiones del semestre (sin contar la propia)
+        if self
+            .overlaps_in_term(&course, schedule, &facility_schedules)
+            .await?
+        {
+            return Ok(false);
+        }
+
+        // Las reservas aprobadas que aún no pasan también ocupan el aula
+        let today = Utc::now().date_naive();
+        let bookings = self
+            .booking_repo
+            .get_bookings(&BookingFilter {
+                facility_id: Some(facility_id.to_string()),
+                status: Some(BookingStatus::Approved),
+                ..Default::default()
+            })
+            .await?;
+
+        let has_booking = bookings
             .iter()
-            .any(|s| s.conflicts_with(schedule));
+            .any(|b| b.date >= today && b.overlaps_session(schedule));
+
+        Ok(!has_booking)
+    }
+
+    /// Una reserva puntual no puede chocar con sesiones recurrentes del aula
+    /// en el semestre de su fecha (salvo las suspendidas ese día) ni con otras
+    /// reservas pendientes o aprobadas de la misma fecha.
+    pub async fn check_booking_availability(
+        &self,
+        booking: &FacilityBooking,
+    ) -> Result<bool, String> {
+        self.booking_available(booking, None).await
+    }
+
+    /// Como `check_booking_availability`, pero la sesión que se recupera deja
+    /// libre el aula el día en que se suspende.
+    pub async fn check_make_up_availability(
+        &self,
+        booking: &FacilityBooking,
+        exception: &SessionException,
+    ) -> Result<bool, String> {
+        self.booking_available(booking, Some(exception)).await
+    }
+
+    async fn booking_available(
+        &self,
+        booking: &FacilityBooking,
+        pending: Option<&SessionException>,
+    ) -> Result<bool, String> {
+        // Solo se dictan ese día las sesiones del semestre que lo contiene
+        let facility_schedules = self
+            .schedule_repo
+            .get_schedules_by_facility(&booking.facility_id)
+            .await?;
+        let facility_schedules = self
+            .sessions_in_term(
+                facility_schedules,
+                booking.date.year(),
+                Semester::containing(booking.date).code(),
+            )
+            .await?;
+
+        for session in facility_schedules
+            .iter()
+            .filter(|s| booking.overlaps_session(s))
+        {
+            // Una sesión suspendida o movida ese día no ocupa el aula
+            let freed = match pending {
+                Some(exception) if exception.schedule_id == session.id => {
+                    exception.original_date == booking.date
+                }
+                _ => self
+                    .exception_repo
+                    .get_exception_by_date(&session.id, booking.date)
+                    .await?
+                    .is_some(),
+            };
+            if !freed {
+                return Ok(false);
+            }
+        }
+
+        let same_day = self
+            .booking_repo
+            .get_bookings(&BookingFilter {
+                facility_id: Some(booking.facility_id.clone()),
+                date: Some(booking.date),
+                ..Default::default()
+            })
+            .await?;
+
+        let has_conflict = same_day
+            .iter()
+            .any(|b| b.id != booking.id && b.is_active() && b.overlaps(booking));
 
-        // Si hay conflicto, la instalación no está disponible
         Ok(!has_conflict)
     }
+
+    /// Sesiones de los cursos que el profesor dicta en el semestre; las
+    /// mismas que revisa `check_teacher_availability`.
+    pub async fn term_sessions(
+        &self,
+        teacher_id: &str,
+        academic_year: i32,
+        semester: &str,
+    ) -> Result<Vec<Schedule>, String> {
+        let sessions = self.schedule_repo.get_schedules_by_user(teacher_id).await?;
+        self.sessions_in_term(sessions, academic_year, semester)
+            .await
+    }
+
+    /// Aplica a la sesión la regla del contrato del profesor del curso, con
+    /// sus demás sesiones del mismo semestre.
+    pub async fn check_teaching_load(&self, schedule: &Schedule) -> Result<(), String> {
+        let course = self
+            .course_repo
+            .get_course_by_id(&schedule.course_id)
+            .await?;
+        if !course.has_teacher() {
+            return Ok(());
+        }
+        let Some(teacher) = self.user_repo.get_user_by_id(&course.teacher_id).await? else {
+            return Ok(());
+        };
+        let Some(rule) = self.load_policy.rule_for(&teacher) else {
+            return Ok(());
+        };
+
+        let others: Vec<Schedule> = self
+            .term_sessions(&teacher.id, course.academic_year, &course.semester)
+            .await?
+            .into_iter()
+            .filter(|s| s.id != schedule.id)
+            .collect();
+        rule.check_session(schedule, &others)
+    }
+
+    /// El profesor del curso debe poder tomar todas sus sesiones: sin choques
+    /// con sus otros cursos del semestre y dentro de la regla de su contrato.
+    pub async fn check_course_teacher(&self, course: &Course) -> Result<(), String> {
+        if !course.has_teacher() {
+            return Ok(());
+        }
+        let sessions = self
+            .schedule_repo
+            .get_schedules_by_course(&course.id)
+            .await?;
+        if sessions.is_empty() {
+            return Ok(());
+        }
+
+        let others: Vec<Schedule> = self
+            .term_sessions(&course.teacher_id, course.academic_year, &course.semester)
+            .await?
+            .into_iter()
+            .filter(|s| s.course_id != course.id)
+            .collect();
+        if sessions
+            .iter()
+            .any(|s| others.iter().any(|o| o.conflicts_with(s)))
+        {
+            return Err(format!(
+                "Conflict: the teacher already teaches at the time of {}",
+                course.code
+            ));
+        }
+
+        let Some(teacher) = self.user_repo.get_user_by_id(&course.teacher_id).await? else {
+            return Ok(());
+        };
+        match self.load_policy.rule_for(&teacher) {
+            Some(rule) => rule.check_sessions(&sessions, &others),
+            None => Ok(()),
+        }
+    }
+
+    /// Las sesiones de un curso archivado no ocupan su aula ni a su profesor;
+    /// al restaurarlo, ambos deben seguir libres en su semestre.
+    pub async fn check_course_restore(&self, course: &Course) -> Result<(), String> {
+        for session in self
+            .schedule_repo
+            .get_schedules_by_course(&course.id)
+            .await?
+        {
+            let is_available = self
+                .check_facility_availability(&session.facility_id, &session)
+                .await?;
+            if !is_available {
+                return Err(format!(
+                    "Conflict: facility {} is no longer free for session {}",
+                    session.facility_id, session.id
+                ));
+            }
+        }
+
+        self.check_course_teacher(course).await
+    }
 }
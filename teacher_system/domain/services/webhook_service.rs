@@ -0,0 +1,61 @@
+use crate::domain::{
+    models::{enums::DeliveryStatus, event::EventEnvelope, webhook::OutboxMessage},
+    repositories::webhook_repository::{OutboxRepository, WebhookRepository},
+    services::event_bus::EventHandler,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// Deja en la bandeja de salida un envío por cada suscripción interesada;
+/// la entrega HTTP la hace el despachador de infraestructura.
+#[derive(Clone)]
+pub struct DefaultWebhookService {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+}
+
+impl DefaultWebhookService {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            outbox_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for DefaultWebhookService {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> Result<(), String> {
+        let kind = envelope.event.kind();
+        let payload = serde_json::to_value(envelope).map_err(|e| e.to_string())?;
+
+        let subscriptions = self.webhook_repo.get_subscriptions().await?;
+        for subscription in subscriptions.iter().filter(|s| s.listens_to(kind)) {
+            let message = OutboxMessage {
+                id: OutboxMessage::delivery_id(&envelope.id, &subscription.id),
+                subscription_id: subscription.id.clone(),
+                event_id: envelope.id.clone(),
+                event_kind: kind,
+                payload: payload.clone(),
+                status: DeliveryStatus::Pending,
+                attempts: 0,
+                next_attempt_at: Utc::now().naive_utc(),
+                last_error: None,
+                created_at: None,
+                delivered_at: None,
+            };
+
+            self.outbox_repo.enqueue_message(&message).await?;
+        }
+
+        Ok(())
+    }
+}
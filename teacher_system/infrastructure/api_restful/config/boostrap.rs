@@ -1,57 +1,409 @@
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
+use crate::domain::models::conflict::ClashPolicy;
+use crate::domain::models::credit_load::CreditLoadPolicy;
+use crate::domain::models::enums::NotificationChannel;
+use crate::domain::models::student_status::StatusRuleSet;
+use crate::domain::models::teaching_load::TeachingLoadPolicy;
+use crate::domain::services::audit_service::DefaultAuditService;
+use crate::domain::services::conflict_service::DefaultConflictService;
+use crate::domain::services::credit_load_service::DefaultCreditLoadService;
+use crate::domain::services::demand_forecast_service::DefaultDemandForecastService;
+use crate::domain::services::event_bus::{DefaultEventBus, EventHandler};
+use crate::domain::services::notification_service::DefaultNotificationService;
+use crate::domain::services::notifier::Notifier;
+use crate::domain::services::prerequisite_service::DefaultPrerequisiteService;
+use crate::domain::services::room_allocation_service::DefaultRoomAllocationService;
+use crate::domain::services::section_service::DefaultSectionService;
+use crate::domain::services::student_status_service::DefaultStudentStatusService;
+use crate::domain::services::study_plan_service::DefaultStudyPlanService;
+use crate::domain::services::teacher_assignment_service::DefaultTeacherAssignmentService;
+use crate::domain::services::transcript_service::DefaultTranscriptService;
 use crate::domain::services::validation_service::DefaultValidationService;
+use crate::domain::services::webhook_service::DefaultWebhookService;
+use crate::infrastructure::database::queries::attendance_query::SupabaseAttendanceRepository;
+use crate::infrastructure::database::queries::audit_query::SupabaseAuditRepository;
+use crate::infrastructure::database::queries::booking_query::SupabaseBookingRepository;
 use crate::infrastructure::database::queries::course_query::SupabaseCourseRepository;
+use crate::infrastructure::database::queries::enrollment_query::SupabaseEnrollmentRepository;
+use crate::infrastructure::database::queries::event_outbox_query::SupabaseEventOutboxRepository;
 use crate::infrastructure::database::queries::facility_query::SupabaseFacilityRepository;
+use crate::infrastructure::database::queries::grade_query::SupabaseGradeRepository;
+use crate::infrastructure::database::queries::notification_preference_query::SupabaseNotificationPreferenceRepository;
+use crate::infrastructure::database::queries::overload_query::SupabaseOverloadRepository;
 use crate::infrastructure::database::queries::schedule_query::SupabaseScheduleRepository;
+use crate::infrastructure::database::queries::session_exception_query::SupabaseSessionExceptionRepository;
+use crate::infrastructure::database::queries::student_status_query::SupabaseStudentStatusRepository;
+use crate::infrastructure::database::queries::study_plan_query::SupabaseStudyPlanRepository;
+use crate::infrastructure::database::queries::teacher_assignment_query::SupabaseTeacherAssignmentRepository;
+use crate::infrastructure::database::queries::unit_of_work_query::SupabaseUnitOfWorkRepository;
 use crate::infrastructure::database::queries::user_query::SupabaseUserRepository;
+use crate::infrastructure::database::queries::webhook_query::SupabaseWebhookRepository;
+use crate::infrastructure::notifications::file_notifier::FileNotifier;
+use crate::infrastructure::notifications::log_notifier::LogNotifier;
+use crate::infrastructure::notifications::smtp_notifier::SmtpNotifier;
+use crate::infrastructure::notifications::event_dispatcher::EventDispatcher;
+use crate::infrastructure::notifications::webhook_dispatcher::WebhookDispatcher;
+use crate::infrastructure::notifications::webhook_notifier::WebhookNotifier;
 use crate::{
     application::use_cases::{
-        course_management::CourseManagementUseCaseImpl, facility_management::FacilityManagementUseCaseImpl, schedule_management::ScheduleManagementUseCaseImpl,
-        user_management::UserManagementUseCaseImpl,
+        attendance_management::AttendanceManagementUseCaseImpl, audit_management::AuditManagementUseCaseImpl, booking_management::BookingManagementUseCaseImpl, course_management::CourseManagementUseCaseImpl, credit_load_management::CreditLoadManagementUseCaseImpl, demand_forecast_management::DemandForecastManagementUseCaseImpl, enrollment_management::EnrollmentManagementUseCaseImpl, facility_management::FacilityManagementUseCaseImpl, grade_management::GradeManagementUseCaseImpl,
+        notification_management::NotificationManagementUseCaseImpl, schedule_management::ScheduleManagementUseCaseImpl, section_management::SectionManagementUseCaseImpl, session_exception_management::SessionExceptionManagementUseCaseImpl, student_status_management::StudentStatusManagementUseCaseImpl, study_plan_management::StudyPlanManagementUseCaseImpl, teacher_assignment_management::TeacherAssignmentManagementUseCaseImpl, teaching_load_management::TeachingLoadManagementUseCaseImpl, transcript_management::TranscriptManagementUseCaseImpl,
+        user_management::UserManagementUseCaseImpl, webhook_management::WebhookManagementUseCaseImpl,
     },
     domain::services::scheduling_service::DefaultSchedulingService,
 };
 
 #[derive(Clone)]
 pub struct AppState {
+    pub attendance_use_case: Arc<AttendanceManagementUseCaseImpl>,
+    pub audit_use_case: Arc<AuditManagementUseCaseImpl>,
+    pub booking_use_case: Arc<BookingManagementUseCaseImpl>,
     pub course_use_case: Arc<CourseManagementUseCaseImpl>,
+    pub credit_load_use_case: Arc<CreditLoadManagementUseCaseImpl>,
+    pub demand_forecast_use_case: Arc<DemandForecastManagementUseCaseImpl>,
+    pub enrollment_use_case: Arc<EnrollmentManagementUseCaseImpl>,
     pub facility_use_case: Arc<FacilityManagementUseCaseImpl>,
+    pub grade_use_case: Arc<GradeManagementUseCaseImpl>,
+    pub notification_use_case: Arc<NotificationManagementUseCaseImpl>,
     pub schedule_use_case: Arc<ScheduleManagementUseCaseImpl>,
+    pub section_use_case: Arc<SectionManagementUseCaseImpl>,
+    pub session_exception_use_case: Arc<SessionExceptionManagementUseCaseImpl>,
+    pub student_status_use_case: Arc<StudentStatusManagementUseCaseImpl>,
+    pub study_plan_use_case: Arc<StudyPlanManagementUseCaseImpl>,
+    pub teacher_assignment_use_case: Arc<TeacherAssignmentManagementUseCaseImpl>,
+    pub teaching_load_use_case: Arc<TeachingLoadManagementUseCaseImpl>,
+    pub transcript_use_case: Arc<TranscriptManagementUseCaseImpl>,
     pub user_use_case: Arc<UserManagementUseCaseImpl>,
+    pub webhook_use_case: Arc<WebhookManagementUseCaseImpl>,
+}
+
+/// Canales de aviso según el entorno. Sin SMTP configurado el correo va al
+/// log (o a `NOTIFICATION_LOG_FILE`) para poder probar en local.
+fn notification_channels() -> Result<HashMap<NotificationChannel, Arc<dyn Notifier>>, String> {
+    let sink: Arc<dyn Notifier> = match std::env::var("NOTIFICATION_LOG_FILE") {
+        Ok(path) => Arc::new(FileNotifier::new(path)),
+        Err(_) => Arc::new(LogNotifier),
+    };
+    let email: Arc<dyn Notifier> = match SmtpNotifier::from_env()? {
+        Some(smtp) => Arc::new(smtp),
+        None => sink.clone(),
+    };
+
+    let mut channels = HashMap::new();
+    channels.insert(NotificationChannel::Email, email);
+    channels.insert(NotificationChannel::Log, sink);
+    if let Ok(url) = std::env::var("NOTIFICATION_WEBHOOK_URL") {
+        let webhook: Arc<dyn Notifier> = Arc::new(WebhookNotifier::new(&url)?);
+        channels.insert(NotificationChannel::Webhook, webhook);
+    }
+
+    Ok(channels)
 }
 
 pub async fn bootstrap_teacher() -> Result<AppState, String> {
     // Course_case
     let course_repo = SupabaseCourseRepository::new().await?;
     let schedule_repo = SupabaseScheduleRepository::new().await?;
-    let validation_service = DefaultValidationService::new(Arc::new(schedule_repo.clone()));
+    let booking_repo = SupabaseBookingRepository::new().await?;
+    let user_repo = SupabaseUserRepository::new().await?;
+    let unit_of_work = SupabaseUnitOfWorkRepository::new().await?;
+    let exception_repo = SupabaseSessionExceptionRepository::new().await?;
+
+    // Teaching_load: sin `TEACHING_LOAD_RULES` no hay límites
+    let load_policy = match std::env::var("TEACHING_LOAD_RULES") {
+        Ok(rules) => rules.parse::<TeachingLoadPolicy>()?,
+        Err(_) => TeachingLoadPolicy::default(),
+    };
+
+    let validation_service = DefaultValidationService::new(
+        Arc::new(schedule_repo.clone()),
+        Arc::new(booking_repo.clone()),
+        Arc::new(exception_repo.clone()),
+        Arc::new(course_repo.clone()),
+        Arc::new(user_repo.clone()),
+        load_policy.clone(),
+    );
 
     // Facility_case
     let facility_repo = SupabaseFacilityRepository::new().await?;
 
     // Schedule_case
     let schedule_repo = SupabaseScheduleRepository::new().await?;
-    let scheduling_service = DefaultSchedulingService::new(Arc::new(course_repo.clone()), Arc::new(schedule_repo.clone()));
+    let scheduling_service = DefaultSchedulingService::new(
+        Arc::new(user_repo.clone()),
+        validation_service.clone(),
+    );
 
-    // User_case
-    let user_repo = SupabaseUserRepository::new().await?;
+    // `block` por defecto; `warn` solo registra el choque
+    let clash_policy = match std::env::var("CURRICULUM_CLASH_POLICY") {
+        Ok(policy) => policy.parse::<ClashPolicy>()?,
+        Err(_) => ClashPolicy::default(),
+    };
+
+    let conflict_service = DefaultConflictService::new(
+        Arc::new(course_repo.clone()),
+        Arc::new(schedule_repo.clone()),
+        Arc::new(facility_repo.clone()),
+        clash_policy,
+    );
+
+    let allocation_service = DefaultRoomAllocationService::new(
+        Arc::new(course_repo.clone()),
+        Arc::new(facility_repo.clone()),
+        validation_service.clone(),
+    );
+
+    // Audit_case
+    let audit_repo = SupabaseAuditRepository::new().await?;
+    let audit_service = DefaultAuditService::new();
+
+    // Session_exception_case
+
+    // Enrollment_case
+    let enrollment_repo = SupabaseEnrollmentRepository::new().await?;
+    let prerequisite_service = DefaultPrerequisiteService::new(Arc::new(course_repo.clone()), Arc::new(enrollment_repo.clone()));
+
+    // Credit_load_case: límites por defecto salvo `CREDIT_LOAD_LIMITS`
+    let overload_repo = SupabaseOverloadRepository::new().await?;
+    let credit_policy = match std::env::var("CREDIT_LOAD_LIMITS") {
+        Ok(limits) => limits.parse::<CreditLoadPolicy>()?,
+        Err(_) => CreditLoadPolicy::default(),
+    };
+    let credit_load_service = DefaultCreditLoadService::new(
+        Arc::new(course_repo.clone()),
+        Arc::new(enrollment_repo.clone()),
+        Arc::new(user_repo.clone()),
+        Arc::new(overload_repo.clone()),
+        credit_policy,
+    );
+
+    // Grade_case
+    let grade_repo = SupabaseGradeRepository::new().await?;
+
+    // Study_plan_case
+    let plan_repo = SupabaseStudyPlanRepository::new().await?;
+    let study_plan_service = DefaultStudyPlanService::new(
+        Arc::new(course_repo.clone()),
+        Arc::new(enrollment_repo.clone()),
+        Arc::new(user_repo.clone()),
+    );
+    let demand_forecast_service = DefaultDemandForecastService::new(
+        Arc::new(plan_repo.clone()),
+        Arc::new(course_repo.clone()),
+        Arc::new(facility_repo.clone()),
+        Arc::new(schedule_repo.clone()),
+        study_plan_service.clone(),
+    );
+
+    // Student_status_case: reglas por defecto salvo `STUDENT_STATUS_RULES`
+    let status_repo = SupabaseStudentStatusRepository::new().await?;
+    let status_rules = match std::env::var("STUDENT_STATUS_RULES") {
+        Ok(rules) => rules.parse::<StatusRuleSet>()?,
+        Err(_) => StatusRuleSet::default(),
+    };
+    let transcript_service = DefaultTranscriptService::new(
+        Arc::new(user_repo.clone()),
+        Arc::new(enrollment_repo.clone()),
+        Arc::new(course_repo.clone()),
+        Arc::new(plan_repo.clone()),
+    );
+    let status_service = DefaultStudentStatusService::new(
+        transcript_service.clone(),
+        Arc::new(user_repo.clone()),
+        Arc::new(unit_of_work.clone()),
+        audit_service.clone(),
+        status_rules,
+    );
+
+    // Teacher_assignment_case
+    let assignment_repo = SupabaseTeacherAssignmentRepository::new().await?;
+    let assignment_service = DefaultTeacherAssignmentService::new(
+        Arc::new(course_repo.clone()),
+        Arc::new(schedule_repo.clone()),
+        Arc::new(user_repo.clone()),
+        Arc::new(plan_repo.clone()),
+        load_policy.clone(),
+    );
+
+    // Attendance_case
+    let attendance_repo = SupabaseAttendanceRepository::new().await?;
+
+    // Section_case
+    let section_service = DefaultSectionService::new(
+        Arc::new(course_repo.clone()),
+        Arc::new(schedule_repo.clone()),
+        Arc::new(facility_repo.clone()),
+        load_policy.clone(),
+    );
+
+    // Notification_case: los eventos que los casos de uso dejan en la bandeja
+    // de salida se avisan a los usuarios y se pasan a la de los webhooks, todo
+    // en segundo plano
+    let preference_repo = SupabaseNotificationPreferenceRepository::new().await?;
+    let webhook_repo = SupabaseWebhookRepository::new().await?;
+
+    let notification_service = DefaultNotificationService::new(
+        Arc::new(course_repo.clone()),
+        Arc::new(user_repo.clone()),
+        Arc::new(preference_repo.clone()),
+        notification_channels()?,
+    );
+    let webhook_service = DefaultWebhookService::new(Arc::new(webhook_repo.clone()), Arc::new(webhook_repo.clone()));
+    let handlers: Vec<Arc<dyn EventHandler>> = vec![Arc::new(notification_service), Arc::new(webhook_service)];
+    let event_bus = DefaultEventBus::new(handlers);
+
+    let poll_seconds = std::env::var("WEBHOOK_POLL_SECONDS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(10);
+    WebhookDispatcher::new(Arc::new(webhook_repo.clone()), Arc::new(webhook_repo.clone()))?.spawn(Duration::from_secs(poll_seconds));
+    let event_outbox_repo = SupabaseEventOutboxRepository::new().await?;
+    EventDispatcher::new(Arc::new(event_outbox_repo), event_bus).spawn(Duration::from_secs(poll_seconds));
 
     //// Instanciar los use_Case
-    let course_use_case = Arc::new(CourseManagementUseCaseImpl::new(Box::new(course_repo.clone()), validation_service.clone()));
-    let facility_use_case = Arc::new(FacilityManagementUseCaseImpl::new(Box::new(facility_repo.clone())));
+    let course_use_case = Arc::new(CourseManagementUseCaseImpl::new(
+        Box::new(course_repo.clone()),
+        Box::new(unit_of_work.clone()),
+        validation_service.clone(),
+        audit_service.clone(),
+    ));
+    let facility_use_case = Arc::new(FacilityManagementUseCaseImpl::new(
+        Box::new(facility_repo.clone()),
+        Box::new(schedule_repo.clone()),
+        Box::new(unit_of_work.clone()),
+        validation_service.clone(),
+        audit_service.clone(),
+        allocation_service.clone(),
+    ));
     let schedule_use_case = Arc::new(ScheduleManagementUseCaseImpl::new(
         Box::new(schedule_repo.clone()),
+        Box::new(course_repo.clone()),
+        Box::new(unit_of_work.clone()),
         validation_service.clone(),
         scheduling_service.clone(),
+        audit_service.clone(),
+        conflict_service.clone(),
+        allocation_service.clone(),
+    ));
+    let user_use_case = Arc::new(UserManagementUseCaseImpl::new(
+        Box::new(user_repo.clone()),
+        Box::new(unit_of_work.clone()),
+        audit_service.clone(),
+    ));
+    let audit_use_case = Arc::new(AuditManagementUseCaseImpl::new(Box::new(audit_repo.clone())));
+    let booking_use_case = Arc::new(BookingManagementUseCaseImpl::new(
+        Box::new(booking_repo.clone()),
+        Box::new(facility_repo.clone()),
+        Box::new(user_repo.clone()),
+        validation_service.clone(),
+    ));
+    let session_exception_use_case = Arc::new(SessionExceptionManagementUseCaseImpl::new(
+        Box::new(exception_repo.clone()),
+        Box::new(schedule_repo.clone()),
+        Box::new(course_repo.clone()),
+        Box::new(user_repo.clone()),
+        Box::new(unit_of_work.clone()),
+        validation_service.clone(),
+        audit_service.clone(),
+    ));
+    let enrollment_use_case = Arc::new(EnrollmentManagementUseCaseImpl::new(
+        Box::new(enrollment_repo.clone()),
+        Box::new(unit_of_work.clone()),
+        Box::new(user_repo.clone()),
+        prerequisite_service.clone(),
+        credit_load_service.clone(),
+    ));
+    let credit_load_use_case = Arc::new(CreditLoadManagementUseCaseImpl::new(
+        Box::new(overload_repo.clone()),
+        Box::new(user_repo.clone()),
+        credit_load_service.clone(),
+    ));
+    let grade_use_case = Arc::new(GradeManagementUseCaseImpl::new(
+        Box::new(grade_repo.clone()),
+        Box::new(course_repo.clone()),
+        Box::new(enrollment_repo.clone()),
+        Box::new(user_repo.clone()),
+        Box::new(unit_of_work.clone()),
+        status_service.clone(),
+        audit_service.clone(),
+    ));
+    let attendance_use_case = Arc::new(AttendanceManagementUseCaseImpl::new(
+        Box::new(attendance_repo.clone()),
+        Box::new(schedule_repo.clone()),
+        Box::new(course_repo.clone()),
+        Box::new(enrollment_repo.clone()),
+        Box::new(user_repo.clone()),
+        Box::new(exception_repo.clone()),
+    ));
+    let transcript_use_case = Arc::new(TranscriptManagementUseCaseImpl::new(transcript_service.clone()));
+    let student_status_use_case = Arc::new(StudentStatusManagementUseCaseImpl::new(
+        Box::new(status_repo.clone()),
+        Box::new(course_repo.clone()),
+        Box::new(enrollment_repo.clone()),
+        Box::new(user_repo.clone()),
+        status_service.clone(),
+    ));
+    let study_plan_use_case = Arc::new(StudyPlanManagementUseCaseImpl::new(
+        Box::new(plan_repo.clone()),
+        Box::new(user_repo.clone()),
+        study_plan_service.clone(),
+    ));
+    let section_use_case = Arc::new(SectionManagementUseCaseImpl::new(
+        Box::new(course_repo.clone()),
+        Box::new(enrollment_repo.clone()),
+        Box::new(attendance_repo.clone()),
+        Box::new(user_repo.clone()),
+        Box::new(unit_of_work.clone()),
+        section_service.clone(),
+        audit_service.clone(),
+    ));
+    let teacher_assignment_use_case = Arc::new(TeacherAssignmentManagementUseCaseImpl::new(
+        Box::new(assignment_repo.clone()),
+        Box::new(course_repo.clone()),
+        Box::new(user_repo.clone()),
+        Box::new(unit_of_work.clone()),
+        assignment_service.clone(),
+        audit_service.clone(),
+    ));
+    let teaching_load_use_case = Arc::new(TeachingLoadManagementUseCaseImpl::new(
+        Box::new(user_repo.clone()),
+        validation_service.clone(),
+    ));
+    let demand_forecast_use_case = Arc::new(DemandForecastManagementUseCaseImpl::new(demand_forecast_service.clone()));
+    let notification_use_case = Arc::new(NotificationManagementUseCaseImpl::new(
+        Box::new(preference_repo.clone()),
+        Box::new(user_repo.clone()),
+    ));
+    let webhook_use_case = Arc::new(WebhookManagementUseCaseImpl::new(
+        Box::new(webhook_repo.clone()),
+        Box::new(webhook_repo.clone()),
+        Box::new(user_repo.clone()),
     ));
-    let user_use_case = Arc::new(UserManagementUseCaseImpl::new(Box::new(user_repo.clone())));
 
     // Crear estado de aplicación
     Ok(AppState {
+        attendance_use_case,
+        audit_use_case,
+        booking_use_case,
         course_use_case,
+        credit_load_use_case,
+        demand_forecast_use_case,
+        enrollment_use_case,
         facility_use_case,
+        grade_use_case,
+        notification_use_case,
         schedule_use_case,
+        section_use_case,
+        session_exception_use_case,
+        student_status_use_case,
+        study_plan_use_case,
+        teacher_assignment_use_case,
+        teaching_load_use_case,
+        transcript_use_case,
         user_use_case,
+        webhook_use_case,
     })
 }
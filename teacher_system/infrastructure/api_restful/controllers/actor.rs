@@ -0,0 +1,14 @@
+use actix_web::HttpRequest;
+
+/// Cabecera con el identificador del usuario que realiza la operación.
+pub const ACTOR_HEADER: &str = "X-Actor-Id";
+
+/// Obtiene el actor de la petición para el registro de auditoría.
+pub fn actor_id(req: &HttpRequest) -> String {
+    req.headers()
+        .get(ACTOR_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+        .unwrap_or("anonymous")
+        .to_string()
+}
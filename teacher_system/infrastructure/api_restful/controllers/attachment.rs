@@ -0,0 +1,43 @@
+use actix_web::http::header;
+
+/// Cabecera `Content-Disposition` para descargar `filename`.
+///
+/// El nombre suele incluir datos de la petición o de la base, así que todo lo
+/// que no sea letra, dígito, `.`, `-` o `_` se reemplaza por `_`: no pueden
+/// colarse comillas, `;` ni saltos de línea en la cabecera.
+pub fn attachment(filename: &str) -> (header::HeaderName, String) {
+    let safe: String = filename
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+
+    (
+        header::CONTENT_DISPOSITION,
+        format!("attachment; filename=\"{}\"", safe),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_plain_names() {
+        let (_, value) = attachment("timetable-audit-2025-I.csv");
+
+        assert_eq!(value, "attachment; filename=\"timetable-audit-2025-I.csv\"");
+    }
+
+    #[test]
+    fn replaces_quotes_separators_and_line_breaks() {
+        let (_, value) = attachment("audit-x\"; name=evil\r\nSet-Cookie: a.csv");
+
+        assert_eq!(
+            value,
+            "attachment; filename=\"audit-x___name_evil__Set-Cookie__a.csv\""
+        );
+    }
+}
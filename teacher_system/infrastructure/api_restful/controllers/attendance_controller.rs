@@ -0,0 +1,144 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::attendance_dto::{AttendanceRecordResponseDTO, AttendanceSummaryResponseDTO, TakeAttendanceDTO};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::attendance_management::AttendanceManagementUseCase;
+use crate::domain::models::attendance::{AttendanceRecord, AttendanceSummary, DEFAULT_RISK_THRESHOLD};
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+fn records_response(records: Vec<AttendanceRecord>) -> HttpResponse {
+    let records: Vec<AttendanceRecordResponseDTO> = records.into_iter().map(AttendanceRecordResponseDTO::from).collect();
+    HttpResponse::Ok().json(records)
+}
+
+fn summaries_response(summaries: Vec<AttendanceSummary>) -> HttpResponse {
+    let summaries: Vec<AttendanceSummaryResponseDTO> = summaries.into_iter().map(AttendanceSummaryResponseDTO::from).collect();
+    HttpResponse::Ok().json(summaries)
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/schedules/{id}/attendance",
+    tag = "schedules",
+    request_body = TakeAttendanceDTO,
+    responses(
+        (status = 200, description = "OK", body = [AttendanceRecordResponseDTO]),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn take_attendance(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, attendance: web::Json<TakeAttendanceDTO>) -> Result<HttpResponse, Error> {
+    let attendance = attendance.into_inner();
+    if let Err(errors) = attendance.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let date = attendance.date;
+    match use_case.attendance_use_case.take_attendance(&id, date, attendance.into_entries(), &actor_id(&req)).await {
+        Ok(records) => Ok(records_response(records)),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error taking attendance: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct OccurrenceParams {
+    pub date: NaiveDate,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/schedules/{id}/attendance",
+    tag = "schedules",
+    params(OccurrenceParams),
+    responses(
+        (status = 200, description = "OK", body = [AttendanceRecordResponseDTO]),
+    )
+)]
+pub async fn get_occurrence_attendance(use_case: web::Data<AppState>, id: web::Path<String>, params: web::Query<OccurrenceParams>) -> Result<HttpResponse, Error> {
+    match use_case.attendance_use_case.get_occurrence(&id, params.date).await {
+        Ok(records) => Ok(records_response(records)),
+        Err(e) => {
+            eprintln!("Error fetching attendance: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/courses/{id}/attendance",
+    tag = "courses",
+    responses(
+        (status = 200, description = "OK", body = [AttendanceSummaryResponseDTO]),
+    )
+)]
+pub async fn get_course_attendance(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.attendance_use_case.get_course_summary(&id).await {
+        Ok(summaries) => Ok(summaries_response(summaries)),
+        Err(e) => {
+            eprintln!("Error fetching course attendance: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/attendance/students/{id}",
+    tag = "attendance",
+    responses(
+        (status = 200, description = "OK", body = [AttendanceSummaryResponseDTO]),
+    )
+)]
+pub async fn get_student_attendance(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.attendance_use_case.get_student_summary(&id).await {
+        Ok(summaries) => Ok(summaries_response(summaries)),
+        Err(e) => {
+            eprintln!("Error fetching student attendance: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AtRiskParams {
+    pub course_id: Option<String>,
+    /// Porcentaje mínimo de asistencia; 70 por defecto
+    pub threshold: Option<f64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/attendance/at-risk",
+    tag = "attendance",
+    params(AtRiskParams),
+    responses(
+        (status = 200, description = "OK", body = [AttendanceSummaryResponseDTO]),
+        (status = 400, description = "Parámetros inválidos"),
+    )
+)]
+pub async fn get_at_risk_students(use_case: web::Data<AppState>, params: web::Query<AtRiskParams>) -> Result<HttpResponse, Error> {
+    let params = params.into_inner();
+    let threshold = params.threshold.unwrap_or(DEFAULT_RISK_THRESHOLD);
+    match use_case.attendance_use_case.get_at_risk(params.course_id, threshold).await {
+        Ok(summaries) => Ok(summaries_response(summaries)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error fetching at-risk students: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::audit_dto::AuditLogResponseDTO;
+use crate::application::use_cases::audit_management::AuditManagementUseCase;
+use crate::domain::models::audit_log::AuditFilter;
+use actix_web::{Error, HttpResponse, web};
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AuditParams {
+    pub entity_id: Option<String>,
+    pub actor_id: Option<String>,
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+}
+
+impl From<AuditParams> for AuditFilter {
+    fn from(params: AuditParams) -> Self {
+        Self {
+            entity_id: params.entity_id,
+            actor_id: params.actor_id,
+            from: params.from,
+            to: params.to,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/audit",
+    tag = "audit",
+    params(AuditParams),
+    responses(
+        (status = 200, description = "OK", body = [AuditLogResponseDTO]),
+        (status = 400, description = "Parámetros inválidos"),
+    )
+)]
+pub async fn get_audit_logs(use_case: web::Data<AppState>, params: web::Query<AuditParams>) -> Result<HttpResponse, Error> {
+    let filter = AuditFilter::from(params.into_inner());
+    match use_case.audit_use_case.get_logs(&filter).await {
+        Ok(logs) => Ok(HttpResponse::Ok().json(logs.into_iter().map(AuditLogResponseDTO::from).collect::<Vec<_>>())),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error fetching audit logs: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::booking_dto::{BookingDTO, BookingResponseDTO};
+use super::super::dto::enums_dto::BookingStatusSchema;
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::booking_management::BookingManagementUseCase;
+use crate::domain::models::booking::{BookingFilter, FacilityBooking};
+use crate::domain::models::enums::BookingStatus;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Respuesta común para las transiciones de estado de una reserva.
+fn review_response(result: Result<FacilityBooking, String>, action: &str) -> Result<HttpResponse, Error> {
+    match result {
+        Ok(booking) => Ok(HttpResponse::Ok().json(BookingResponseDTO::from(booking))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Booking not found")),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("not available") || e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) => {
+            eprintln!("Error trying to {} booking: {}", action, e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct BookingParams {
+    pub facility_id: Option<String>,
+    pub date: Option<NaiveDate>,
+    #[param(value_type = Option<BookingStatusSchema>)]
+    pub status: Option<BookingStatus>,
+}
+
+impl From<BookingParams> for BookingFilter {
+    fn from(params: BookingParams) -> Self {
+        Self {
+            facility_id: params.facility_id,
+            requested_by: None,
+            date: params.date,
+            status: params.status,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/bookings",
+    tag = "bookings",
+    params(BookingParams),
+    responses(
+        (status = 200, description = "OK", body = [BookingResponseDTO]),
+    )
+)]
+pub async fn get_bookings(use_case: web::Data<AppState>, params: web::Query<BookingParams>) -> Result<HttpResponse, Error> {
+    let filter = BookingFilter::from(params.into_inner());
+    let bookings = use_case.booking_use_case.get_all(&filter).await.map_err(|e| {
+        eprintln!("Error fetching bookings: {}", e);
+        actix_web::error::ErrorInternalServerError("Internal server error")
+    })?;
+    let bookings: Vec<BookingResponseDTO> = bookings.into_iter().map(BookingResponseDTO::from).collect();
+    Ok(HttpResponse::Ok().json(bookings))
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/bookings",
+    tag = "bookings",
+    request_body = BookingDTO,
+    responses(
+        (status = 201, description = "Creado", body = BookingResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn request_booking(req: HttpRequest, use_case: web::Data<AppState>, new_booking: web::Json<BookingDTO>) -> Result<HttpResponse, Error> {
+    let new_booking = new_booking.into_inner();
+    if let Err(errors) = new_booking.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let booking = new_booking.into_booking(uuid::Uuid::new_v4().to_string(), actor_id(&req));
+    match use_case.booking_use_case.request(booking).await {
+        Ok(booking) => Ok(HttpResponse::Created().json(BookingResponseDTO::from(booking))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Facility not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not available") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error requesting booking: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/bookings/{id}",
+    tag = "bookings",
+    responses(
+        (status = 200, description = "OK", body = BookingResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_booking_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.booking_use_case.get_by_id(&id).await {
+        Ok(booking) => Ok(HttpResponse::Ok().json(BookingResponseDTO::from(booking))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Booking not found")),
+        Err(e) => {
+            eprintln!("Error fetching booking: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/bookings/{id}/approve",
+    tag = "bookings",
+    responses(
+        (status = 200, description = "OK", body = BookingResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn approve_booking(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    review_response(use_case.booking_use_case.approve(&id, &actor_id(&req)).await, "approve")
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/bookings/{id}/reject",
+    tag = "bookings",
+    responses(
+        (status = 200, description = "OK", body = BookingResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn reject_booking(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    review_response(use_case.booking_use_case.reject(&id, &actor_id(&req)).await, "reject")
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/bookings/{id}/cancel",
+    tag = "bookings",
+    responses(
+        (status = 200, description = "OK", body = BookingResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn cancel_booking(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    review_response(use_case.booking_use_case.cancel(&id, &actor_id(&req)).await, "cancel")
+}
@@ -1,37 +1,93 @@
 use super::super::config::boostrap::AppState;
-use crate::domain::models::course::Course;
-use crate::{application::use_cases::course_management::CourseManagementUseCase, domain::models::schedule::Schedule};
-use actix_web::{Error, HttpResponse, web};
+use super::super::dto::course_dto::{CourseDTO, CourseResponseDTO, ExtracourseDTO};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use super::etag::{etag, if_match_version};
+use crate::application::use_cases::course_management::CourseManagementUseCase;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, http::header, web};
+use serde_json::Value;
 
-pub async fn register_extracourse(use_case: web::Data<AppState>, schedule: web::Json<Schedule>, course: web::Json<Course>) -> Result<HttpResponse, Error> {
-    let course_data = course.into_inner();
-    let schedule = schedule.into_inner();
-    use_case.course_use_case.register_extracourse(course_data, schedule).await.map_err(|e| {
-        eprintln!("Error registering extracourse: {}", e);
-        actix_web::error::ErrorInternalServerError("Internal server error")
-    })?;
-    Ok(HttpResponse::Created().finish())
+pub async fn register_extracourse(req: HttpRequest, use_case: web::Data<AppState>, payload: web::Json<ExtracourseDTO>) -> Result<HttpResponse, Error> {
+    let ExtracourseDTO { course, mut schedule } = payload.into_inner();
+    if let Err(errors) = course.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let course = course.into_course(uuid::Uuid::new_v4().to_string());
+    schedule.course_id = course.id.clone(); // La sesión pertenece al curso nuevo
+    if let Err(errors) = schedule.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+    let schedule = schedule.into_schedule(uuid::Uuid::new_v4().to_string());
+
+    match use_case.course_use_case.register_extracourse(course, schedule, &actor_id(&req)).await {
+        Ok(_) => Ok(HttpResponse::Created().finish()),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error registering extracourse: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
 }
 
+#[utoipa::path(
+    get,
+    path = "/teacher/courses",
+    tag = "courses",
+    responses(
+        (status = 200, description = "OK", body = [CourseResponseDTO]),
+    )
+)]
 pub async fn get_all_courses(use_case: web::Data<AppState>) -> Result<HttpResponse, Error> {
     let courses = use_case.course_use_case.get_all().await.map_err(|e| {
         eprintln!("Error fetching courses: {}", e);
         actix_web::error::ErrorInternalServerError("Internal server error")
     })?;
+    let courses: Vec<CourseResponseDTO> = courses.into_iter().map(CourseResponseDTO::from).collect();
     Ok(HttpResponse::Ok().json(courses))
 }
 
-pub async fn create_course(use_case: web::Data<AppState>, new_course: web::Json<Course>) -> Result<HttpResponse, Error> {
-    let course = use_case.course_use_case.create(new_course.into_inner()).await.map_err(|e| {
-        eprintln!("Error creating course: {}", e);
-        actix_web::error::ErrorInternalServerError("Internal server error")
-    })?;
-    Ok(HttpResponse::Created().json(course))
+#[utoipa::path(
+    post,
+    path = "/teacher/courses",
+    tag = "courses",
+    request_body = CourseDTO,
+    responses(
+        (status = 201, description = "Creado", body = CourseResponseDTO, headers(("ETag" = String, description = "Versión del recurso"))),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn create_course(req: HttpRequest, use_case: web::Data<AppState>, new_course: web::Json<CourseDTO>) -> Result<HttpResponse, Error> {
+    let new_course = new_course.into_inner();
+    if let Err(errors) = new_course.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let course = new_course.into_course(uuid::Uuid::new_v4().to_string());
+    match use_case.course_use_case.create(course, &actor_id(&req)).await {
+        Ok(course) => Ok(HttpResponse::Created().insert_header((header::ETAG, etag(course.version))).json(CourseResponseDTO::from(course))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error creating course: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
 }
 
+#[utoipa::path(
+    get,
+    path = "/teacher/courses/{id}",
+    tag = "courses",
+    responses(
+        (status = 200, description = "OK", body = CourseResponseDTO, headers(("ETag" = String, description = "Versión del recurso"))),
+        (status = 404, description = "No encontrado"),
+    )
+)]
 pub async fn get_course_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
     match use_case.course_use_case.get_by_id(&id).await {
-        Ok(course) => Ok(HttpResponse::Ok().json(course)),
+        Ok(course) => Ok(HttpResponse::Ok().insert_header((header::ETAG, etag(course.version))).json(CourseResponseDTO::from(course))),
         Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
         Err(e) => {
             eprintln!("Error fetching course: {}", e);
@@ -40,22 +96,56 @@ pub async fn get_course_by_id(use_case: web::Data<AppState>, id: web::Path<Strin
     }
 }
 
-pub async fn update_course(use_case: web::Data<AppState>, id: web::Path<String>, updated_course: web::Json<Course>) -> Result<HttpResponse, Error> {
-    let mut course_data = updated_course.into_inner();
-    course_data.id = id.clone(); // Aseguramos que el ID venga del path
+#[utoipa::path(
+    put,
+    path = "/teacher/courses/{id}",
+    tag = "courses",
+    params(("If-Match" = String, Header, description = "Versión esperada, tal como llegó en el `ETag`, o `*`")),
+    request_body = CourseDTO,
+    responses(
+        (status = 200, description = "OK", body = CourseResponseDTO, headers(("ETag" = String, description = "Versión del recurso"))),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 412, description = "La versión no coincide con `If-Match`"),
+        (status = 428, description = "Falta la cabecera `If-Match`"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn update_course(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, updated_course: web::Json<CourseDTO>) -> Result<HttpResponse, Error> {
+    let updated_course = updated_course.into_inner();
+    if let Err(errors) = updated_course.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+    let expected_version = if_match_version(&req)?;
+
+    // El PUT reemplaza el curso entero; el ID lo fija el path
+    let replacement = updated_course.into_course(id.into_inner());
 
-    match use_case.course_use_case.update(&course_data).await {
-        Ok(_) => Ok(HttpResponse::Ok().finish()),
-        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
-        Err(e) => {
+    match use_case.course_use_case.update(&replacement, expected_version, &actor_id(&req)).await {
+        Ok(course) => Ok(HttpResponse::Ok().insert_header((header::ETAG, etag(course.version))).json(CourseResponseDTO::from(course))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("precondition failed") => Ok(HttpResponse::PreconditionFailed().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) => {
             eprintln!("Error updating course: {}", e);
             Err(actix_web::error::ErrorInternalServerError("Internal server error"))
         }
     }
 }
 
-pub async fn delete_course(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
-    match use_case.course_use_case.delete(&id).await {
+#[utoipa::path(
+    delete,
+    path = "/teacher/courses/{id}",
+    tag = "courses",
+    responses(
+        (status = 204, description = "Sin contenido"),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn delete_course(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.course_use_case.delete(&id, &actor_id(&req)).await {
         Ok(_) => Ok(HttpResponse::NoContent().finish()),
         Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
         Err(e) => {
@@ -64,3 +154,58 @@ pub async fn delete_course(use_case: web::Data<AppState>, id: web::Path<String>)
         }
     }
 }
+
+#[utoipa::path(
+    post,
+    path = "/teacher/courses/{id}/restore",
+    tag = "courses",
+    responses(
+        (status = 200, description = "OK"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn restore_course(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.course_use_case.restore(&id, &actor_id(&req)).await {
+        Ok(_) => Ok(HttpResponse::Ok().finish()),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Archived course not found")),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) => {
+            eprintln!("Error restoring course: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/teacher/courses/{id}",
+    tag = "courses",
+    params(("If-Match" = String, Header, description = "Versión esperada, tal como llegó en el `ETag`, o `*`")),
+    request_body(content = Object, description = "Solo los campos a cambiar"),
+    responses(
+        (status = 200, description = "OK", body = CourseResponseDTO, headers(("ETag" = String, description = "Versión del recurso"))),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 412, description = "La versión no coincide con `If-Match`"),
+        (status = 428, description = "Falta la cabecera `If-Match`"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn patch_course(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, patch: web::Json<Value>) -> Result<HttpResponse, Error> {
+    let expected_version = if_match_version(&req)?;
+
+    match use_case.course_use_case.patch(&id, &patch, expected_version, &actor_id(&req)).await {
+        Ok(course) => Ok(HttpResponse::Ok().insert_header((header::ETAG, etag(course.version))).json(CourseResponseDTO::from(course))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid patch") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("precondition failed") => Ok(HttpResponse::PreconditionFailed().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error patching course: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
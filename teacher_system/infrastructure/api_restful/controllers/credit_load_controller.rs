@@ -0,0 +1,191 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::credit_load_dto::{
+    CreditLoadPolicyResponseDTO, OverloadRequestDTO, OverloadRequestResponseDTO, ReviewOverloadDTO, TermLoadResponseDTO,
+};
+use super::super::dto::enums_dto::OverloadStatusSchema;
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::credit_load_management::CreditLoadManagementUseCase;
+use crate::domain::models::credit_load::{OverloadFilter, OverloadRequest};
+use crate::domain::models::enums::OverloadStatus;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Respuesta común para las revisiones de un pedido de sobrecarga.
+fn review_response(result: Result<OverloadRequest, String>, action: &str) -> Result<HttpResponse, Error> {
+    match result {
+        Ok(request) => Ok(HttpResponse::Ok().json(OverloadRequestResponseDTO::from(request))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Overload request not found")),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) => {
+            eprintln!("Error trying to {} overload request: {}", action, e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/credit-limits",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = CreditLoadPolicyResponseDTO),
+    )
+)]
+pub async fn get_credit_limits(use_case: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    Ok(HttpResponse::Ok().json(CreditLoadPolicyResponseDTO::from(use_case.credit_load_use_case.get_policy())))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TermParams {
+    pub academic_year: i32,
+    pub semester: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/{id}/credit-load",
+    tag = "users",
+    params(TermParams),
+    responses(
+        (status = 200, description = "OK", body = TermLoadResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_credit_load(use_case: web::Data<AppState>, id: web::Path<String>, params: web::Query<TermParams>) -> Result<HttpResponse, Error> {
+    match use_case.credit_load_use_case.get_term_load(&id, params.academic_year, &params.semester).await {
+        Ok(load) => Ok(HttpResponse::Ok().json(TermLoadResponseDTO::from(load))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) => {
+            eprintln!("Error fetching credit load: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct OverloadParams {
+    pub student_id: Option<String>,
+    #[param(value_type = Option<OverloadStatusSchema>)]
+    pub status: Option<OverloadStatus>,
+}
+
+impl From<OverloadParams> for OverloadFilter {
+    fn from(params: OverloadParams) -> Self {
+        Self {
+            student_id: params.student_id,
+            status: params.status,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/overload-requests",
+    tag = "overload-requests",
+    params(OverloadParams),
+    responses(
+        (status = 200, description = "OK", body = [OverloadRequestResponseDTO]),
+    )
+)]
+pub async fn get_overload_requests(use_case: web::Data<AppState>, params: web::Query<OverloadParams>) -> Result<HttpResponse, Error> {
+    let filter = OverloadFilter::from(params.into_inner());
+    let requests = use_case.credit_load_use_case.get_all(&filter).await.map_err(|e| {
+        eprintln!("Error fetching overload requests: {}", e);
+        actix_web::error::ErrorInternalServerError("Internal server error")
+    })?;
+    let requests: Vec<OverloadRequestResponseDTO> = requests.into_iter().map(OverloadRequestResponseDTO::from).collect();
+    Ok(HttpResponse::Ok().json(requests))
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/overload-requests/{id}",
+    tag = "overload-requests",
+    responses(
+        (status = 200, description = "OK", body = OverloadRequestResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_overload_request_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.credit_load_use_case.get_by_id(&id).await {
+        Ok(request) => Ok(HttpResponse::Ok().json(OverloadRequestResponseDTO::from(request))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Overload request not found")),
+        Err(e) => {
+            eprintln!("Error fetching overload request: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/overload-requests",
+    tag = "overload-requests",
+    request_body = OverloadRequestDTO,
+    responses(
+        (status = 201, description = "Creado", body = OverloadRequestResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn request_overload(req: HttpRequest, use_case: web::Data<AppState>, new_request: web::Json<OverloadRequestDTO>) -> Result<HttpResponse, Error> {
+    let new_request = new_request.into_inner();
+    if let Err(errors) = new_request.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let request = new_request.into_request(uuid::Uuid::new_v4().to_string(), actor_id(&req));
+    match use_case.credit_load_use_case.request(request).await {
+        Ok(request) => Ok(HttpResponse::Created().json(OverloadRequestResponseDTO::from(request))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error requesting overload: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/overload-requests/{id}/approve",
+    tag = "overload-requests",
+    request_body = Option<ReviewOverloadDTO>,
+    responses(
+        (status = 200, description = "OK", body = OverloadRequestResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn approve_overload(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, review: Option<web::Json<ReviewOverloadDTO>>) -> Result<HttpResponse, Error> {
+    let note = review.map(|r| r.into_inner()).unwrap_or_default().note;
+    review_response(use_case.credit_load_use_case.approve(&id, &actor_id(&req), note).await, "approve")
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/overload-requests/{id}/reject",
+    tag = "overload-requests",
+    request_body = Option<ReviewOverloadDTO>,
+    responses(
+        (status = 200, description = "OK", body = OverloadRequestResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn reject_overload(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, review: Option<web::Json<ReviewOverloadDTO>>) -> Result<HttpResponse, Error> {
+    let note = review.map(|r| r.into_inner()).unwrap_or_default().note;
+    review_response(use_case.credit_load_use_case.reject(&id, &actor_id(&req), note).await, "reject")
+}
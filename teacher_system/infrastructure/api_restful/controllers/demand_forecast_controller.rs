@@ -0,0 +1,36 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::demand_forecast_dto::DemandForecastResponseDTO;
+use crate::application::use_cases::demand_forecast_management::DemandForecastManagementUseCase;
+use actix_web::{Error, HttpResponse, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ForecastParams {
+    pub academic_year: i32,
+    pub semester: String,
+    pub program: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/courses/demand-forecast",
+    tag = "courses",
+    params(ForecastParams),
+    responses(
+        (status = 200, description = "OK", body = DemandForecastResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_demand_forecast(use_case: web::Data<AppState>, params: web::Query<ForecastParams>) -> Result<HttpResponse, Error> {
+    match use_case.demand_forecast_use_case.forecast(params.academic_year, &params.semester, params.program.as_deref()).await {
+        Ok(forecast) => Ok(HttpResponse::Ok().json(DemandForecastResponseDTO::from(forecast))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error building demand forecast: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::enrollment_dto::{EnrollmentResponseDTO, PrerequisiteCheckResponseDTO};
+use crate::application::use_cases::enrollment_management::EnrollmentManagementUseCase;
+use super::actor::actor_id;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[utoipa::path(
+    get,
+    path = "/teacher/enrollments/{id}",
+    tag = "enrollments",
+    responses(
+        (status = 200, description = "OK", body = EnrollmentResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_enrollment_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.enrollment_use_case.get_by_id(&id).await {
+        Ok(enrollment) => Ok(HttpResponse::Ok().json(EnrollmentResponseDTO::from(enrollment))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Enrollment not found")),
+        Err(e) => {
+            eprintln!("Error fetching enrollment: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/enrollments/{id}/confirm",
+    tag = "enrollments",
+    responses(
+        (status = 200, description = "OK", body = EnrollmentResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn confirm_enrollment(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.enrollment_use_case.confirm(&id, &actor_id(&req)).await {
+        Ok(enrollment) => Ok(HttpResponse::Ok().json(EnrollmentResponseDTO::from(enrollment))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Enrollment not found")),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) => {
+            eprintln!("Error confirming enrollment: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PrerequisiteParams {
+    pub student_id: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/courses/{id}/prerequisites/check",
+    tag = "courses",
+    params(PrerequisiteParams),
+    responses(
+        (status = 200, description = "OK", body = PrerequisiteCheckResponseDTO),
+    )
+)]
+pub async fn check_prerequisites(use_case: web::Data<AppState>, id: web::Path<String>, params: web::Query<PrerequisiteParams>) -> Result<HttpResponse, Error> {
+    let course_id = id.into_inner();
+    let student_id = params.into_inner().student_id;
+    match use_case.enrollment_use_case.missing_prerequisites(&student_id, &course_id).await {
+        Ok(missing) => Ok(HttpResponse::Ok().json(PrerequisiteCheckResponseDTO::new(course_id, student_id, missing))),
+        Err(e) => {
+            eprintln!("Error checking prerequisites: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
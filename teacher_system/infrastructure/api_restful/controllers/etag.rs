@@ -0,0 +1,41 @@
+use actix_web::{Error, HttpRequest, error, http::header};
+
+/// ETag fuerte a partir de la versión del recurso.
+pub fn etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Lee la versión esperada de la cabecera `If-Match`, obligatoria en PUT y
+/// PATCH para que nadie pise sin saberlo los cambios de otro.
+///
+/// Devuelve `Ok(None)` con `*`: el cambio se bloquea contra la versión que
+/// lea el servidor. Sin cabecera responde 428 y con un ETag débil 412, porque
+/// `If-Match` exige comparación fuerte (RFC 9110).
+pub fn if_match_version(req: &HttpRequest) -> Result<Option<i32>, Error> {
+    let Some(value) = req.headers().get(header::IF_MATCH) else {
+        return Err(error::ErrorPreconditionRequired(
+            "Precondition required: send the resource ETag in If-Match",
+        ));
+    };
+
+    let value = value
+        .to_str()
+        .map_err(|_| error::ErrorBadRequest("Invalid If-Match header"))?
+        .trim();
+
+    if value == "*" {
+        return Ok(None);
+    }
+    if value.starts_with("W/") {
+        return Err(error::ErrorPreconditionFailed(format!(
+            "Precondition failed: weak ETag {} never matches If-Match",
+            value
+        )));
+    }
+
+    value
+        .trim_matches('"')
+        .parse::<i32>()
+        .map(Some)
+        .map_err(|_| error::ErrorBadRequest(format!("Invalid If-Match header: {}", value)))
+}
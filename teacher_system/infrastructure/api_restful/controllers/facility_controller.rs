@@ -1,29 +1,75 @@
 // src/infrastructure/api_restful/controllers/facility_controller.rs
 
+use super::actor::actor_id;
 use super::super::config::boostrap::AppState;
+use super::super::dto::enums_dto::{FacilityTypeSchema, WeekdaySchema};
+use super::super::dto::facility_dto::{FacilityAvailableResponseDTO, FacilityDTO, FacilityResponseDTO};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
 use crate::application::use_cases::facility_management::FacilityManagementUseCase;
-use crate::domain::models::facilitie::Facility;
-use actix_web::{Error, HttpResponse, web};
+use crate::domain::models::enums::{FacilityType, Weekday};
+use crate::domain::models::facilitie_available::AvailabilityFilter;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use serde_json::Value;
 
+#[utoipa::path(
+    get,
+    path = "/teacher/facilities",
+    tag = "facilities",
+    responses(
+        (status = 200, description = "OK", body = [FacilityResponseDTO]),
+    )
+)]
 pub async fn get_all_facilities(use_case: web::Data<AppState>) -> Result<HttpResponse, Error> {
     let facilities = use_case.facility_use_case.get_all().await.map_err(|e| {
         eprintln!("Error fetching facilities: {}", e);
         actix_web::error::ErrorInternalServerError("Internal server error")
     })?;
+    let facilities: Vec<FacilityResponseDTO> = facilities.into_iter().map(FacilityResponseDTO::from).collect();
     Ok(HttpResponse::Ok().json(facilities))
 }
 
-pub async fn create_facility(use_case: web::Data<AppState>, new_facility: web::Json<Facility>) -> Result<HttpResponse, Error> {
-    let facility = use_case.facility_use_case.create(new_facility.into_inner()).await.map_err(|e| {
-        eprintln!("Error creating facility: {}", e);
-        actix_web::error::ErrorInternalServerError("Internal server error")
-    })?;
-    Ok(HttpResponse::Created().json(facility))
+#[utoipa::path(
+    post,
+    path = "/teacher/facilities",
+    tag = "facilities",
+    request_body = FacilityDTO,
+    responses(
+        (status = 201, description = "Creado", body = FacilityResponseDTO),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn create_facility(req: HttpRequest, use_case: web::Data<AppState>, new_facility: web::Json<FacilityDTO>) -> Result<HttpResponse, Error> {
+    let new_facility = new_facility.into_inner();
+    if let Err(errors) = new_facility.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let facility = new_facility.into_facility(uuid::Uuid::new_v4().to_string());
+    match use_case.facility_use_case.create(facility.clone(), &actor_id(&req)).await {
+        Ok(_) => Ok(HttpResponse::Created().json(FacilityResponseDTO::from(facility))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error creating facility: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
 }
 
+#[utoipa::path(
+    get,
+    path = "/teacher/facilities/{id}",
+    tag = "facilities",
+    responses(
+        (status = 200, description = "OK", body = FacilityResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
 pub async fn get_facility_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
     match use_case.facility_use_case.get_by_id(&id).await {
-        Ok(facility) => Ok(HttpResponse::Ok().json(facility)),
+        Ok(facility) => Ok(HttpResponse::Ok().json(FacilityResponseDTO::from(facility))),
         Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Facility not found")),
         Err(e) => {
             eprintln!("Error fetching facility: {}", e);
@@ -32,27 +78,160 @@ pub async fn get_facility_by_id(use_case: web::Data<AppState>, id: web::Path<Str
     }
 }
 
-pub async fn update_facility(use_case: web::Data<AppState>, id: web::Path<String>, updated_facility: web::Json<Facility>) -> Result<HttpResponse, Error> {
-    let mut facility_data = updated_facility.into_inner();
-    facility_data.id = id.clone(); // asegurar que el ID del path prevalece
+#[utoipa::path(
+    put,
+    path = "/teacher/facilities/{id}",
+    tag = "facilities",
+    request_body = FacilityDTO,
+    responses(
+        (status = 200, description = "OK", body = FacilityResponseDTO),
+        (status = 404, description = "No encontrado"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn update_facility(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, updated_facility: web::Json<FacilityDTO>) -> Result<HttpResponse, Error> {
+    let updated_facility = updated_facility.into_inner();
+    if let Err(errors) = updated_facility.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
 
-    match use_case.facility_use_case.update(&facility_data).await {
-        Ok(_) => Ok(HttpResponse::Ok().finish()),
-        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Facility not found")),
-        Err(e) => {
+    // El ID del path prevalece y `created_at` lo conserva el servidor
+    let replacement = serde_json::to_value(&updated_facility).map_err(actix_web::error::ErrorBadRequest)?;
+
+    match use_case.facility_use_case.patch(&id, &replacement, &actor_id(&req)).await {
+        Ok(facility) => Ok(HttpResponse::Ok().json(FacilityResponseDTO::from(facility))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Facility not found")),
+        Err(DomainError::Other(e)) => {
             eprintln!("Error updating facility: {}", e);
             Err(actix_web::error::ErrorInternalServerError("Internal server error"))
         }
     }
 }
 
-pub async fn delete_facility(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
-    match use_case.facility_use_case.delete(&id).await {
+#[utoipa::path(
+    patch,
+    path = "/teacher/facilities/{id}",
+    tag = "facilities",
+    request_body(content = Object, description = "Solo los campos a cambiar"),
+    responses(
+        (status = 200, description = "OK", body = FacilityResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn patch_facility(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, patch: web::Json<Value>) -> Result<HttpResponse, Error> {
+    match use_case.facility_use_case.patch(&id, &patch, &actor_id(&req)).await {
+        Ok(facility) => Ok(HttpResponse::Ok().json(FacilityResponseDTO::from(facility))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Facility not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid patch") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error patching facility: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ArchiveFacilityParams {
+    pub move_to: Option<String>,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/teacher/facilities/{id}",
+    tag = "facilities",
+    params(ArchiveFacilityParams),
+    responses(
+        (status = 204, description = "Sin contenido"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn delete_facility(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, params: web::Query<ArchiveFacilityParams>) -> Result<HttpResponse, Error> {
+    match use_case.facility_use_case.delete(&id, params.move_to.as_deref(), &actor_id(&req)).await {
         Ok(_) => Ok(HttpResponse::NoContent().finish()),
         Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Facility not found")),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
         Err(e) => {
             eprintln!("Error deleting facility: {}", e);
             Err(actix_web::error::ErrorInternalServerError("Internal server error"))
         }
     }
 }
+
+#[utoipa::path(
+    post,
+    path = "/teacher/facilities/{id}/restore",
+    tag = "facilities",
+    responses(
+        (status = 200, description = "OK"),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn restore_facility(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.facility_use_case.restore(&id, &actor_id(&req)).await {
+        Ok(_) => Ok(HttpResponse::Ok().finish()),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Archived facility not found")),
+        Err(e) => {
+            eprintln!("Error restoring facility: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+/// Filtros de `/facilities/available`; las horas son enteras (0-24). Solo
+/// ocupan el aula las sesiones del semestre indicado.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AvailabilityParams {
+    #[param(value_type = WeekdaySchema)]
+    pub day: Weekday,
+    pub from: Option<u32>,
+    pub to: Option<u32>,
+    pub min_capacity: Option<i32>,
+    #[serde(rename = "type")]
+    #[param(value_type = Option<FacilityTypeSchema>)]
+    pub facility_type: Option<FacilityType>,
+    pub wheelchair_accessible: Option<bool>,
+    pub academic_year: i32,
+    pub semester: String,
+}
+
+impl From<AvailabilityParams> for AvailabilityFilter {
+    fn from(params: AvailabilityParams) -> Self {
+        Self {
+            day: params.day,
+            from: params.from,
+            to: params.to,
+            min_capacity: params.min_capacity,
+            facility_type: params.facility_type,
+            wheelchair_accessible: params.wheelchair_accessible,
+            academic_year: params.academic_year,
+            semester: params.semester,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/facilities/available",
+    tag = "facilities",
+    params(AvailabilityParams),
+    responses(
+        (status = 200, description = "OK", body = [FacilityAvailableResponseDTO]),
+        (status = 400, description = "Parámetros inválidos"),
+    )
+)]
+pub async fn get_available_facilities(use_case: web::Data<AppState>, params: web::Query<AvailabilityParams>) -> Result<HttpResponse, Error> {
+    let filter = AvailabilityFilter::from(params.into_inner());
+    match use_case.facility_use_case.get_available(&filter).await {
+        Ok(available) => Ok(HttpResponse::Ok().json(available.into_iter().map(FacilityAvailableResponseDTO::from).collect::<Vec<_>>())),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error computing facility availability: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
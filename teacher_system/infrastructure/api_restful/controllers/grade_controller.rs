@@ -0,0 +1,139 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::grade_dto::{EnrollmentGradesResponseDTO, GradingSchemeDTO, GradingSchemeResponseDTO, RecordGradesDTO};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::grade_management::GradeManagementUseCase;
+use crate::domain::models::grade::EnrollmentGrades;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+
+fn grades_response(grades: Vec<EnrollmentGrades>) -> HttpResponse {
+    let grades: Vec<EnrollmentGradesResponseDTO> = grades.into_iter().map(EnrollmentGradesResponseDTO::from).collect();
+    HttpResponse::Ok().json(grades)
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/courses/{id}/grading-scheme",
+    tag = "courses",
+    responses(
+        (status = 200, description = "OK", body = GradingSchemeResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_grading_scheme(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.grade_use_case.get_scheme(&id).await {
+        Ok(scheme) => Ok(HttpResponse::Ok().json(GradingSchemeResponseDTO::from(scheme))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) => {
+            eprintln!("Error fetching grading scheme: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/teacher/courses/{id}/grading-scheme",
+    tag = "courses",
+    request_body = GradingSchemeDTO,
+    responses(
+        (status = 200, description = "OK", body = GradingSchemeResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn update_grading_scheme(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, scheme: web::Json<GradingSchemeDTO>) -> Result<HttpResponse, Error> {
+    let scheme = scheme.into_inner().into_scheme(id.into_inner());
+    match use_case.grade_use_case.set_scheme(scheme, &actor_id(&req)).await {
+        Ok(scheme) => Ok(HttpResponse::Ok().json(GradingSchemeResponseDTO::from(scheme))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error updating grading scheme: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/courses/{id}/grades",
+    tag = "courses",
+    responses(
+        (status = 200, description = "OK", body = [EnrollmentGradesResponseDTO]),
+    )
+)]
+pub async fn get_course_grades(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.grade_use_case.get_course_grades(&id).await {
+        Ok(grades) => Ok(grades_response(grades)),
+        Err(e) => {
+            eprintln!("Error fetching course grades: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/courses/{id}/grades",
+    tag = "courses",
+    request_body = RecordGradesDTO,
+    responses(
+        (status = 200, description = "OK", body = [EnrollmentGradesResponseDTO]),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn record_grades(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, grades: web::Json<RecordGradesDTO>) -> Result<HttpResponse, Error> {
+    let grades = grades.into_inner();
+    if let Err(errors) = grades.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let component = grades.component.clone();
+    match use_case.grade_use_case.record_grades(&id, &component, grades.into_entries(), &actor_id(&req)).await {
+        Ok(grades) => Ok(grades_response(grades)),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error recording grades: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/courses/{id}/close",
+    tag = "courses",
+    responses(
+        (status = 200, description = "OK", body = [EnrollmentGradesResponseDTO]),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Faltan notas de alguna evaluación", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn close_course(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.grade_use_case.close_course(&id, &actor_id(&req)).await {
+        Ok(grades) => Ok(grades_response(grades)),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error closing course: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
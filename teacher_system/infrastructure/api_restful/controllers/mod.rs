@@ -1,4 +1,23 @@
+pub mod actor;
+pub mod attachment;
+pub mod attendance_controller;
+pub mod audit_controller;
+pub mod booking_controller;
 pub mod course_controller;
+pub mod credit_load_controller;
+pub mod demand_forecast_controller;
+pub mod enrollment_controller;
+pub mod etag;
 pub mod facility_controller;
+pub mod grade_controller;
+pub mod notification_controller;
 pub mod schedule_controller;
+pub mod section_controller;
+pub mod session_exception_controller;
+pub mod student_status_controller;
+pub mod study_plan_controller;
+pub mod teacher_assignment_controller;
+pub mod teaching_load_controller;
+pub mod transcript_controller;
 pub mod user_controller;
+pub mod webhook_controller;
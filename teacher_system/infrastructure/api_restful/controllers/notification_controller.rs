@@ -0,0 +1,50 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::notification_dto::{NotificationPreferenceDTO, NotificationPreferenceResponseDTO};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use crate::application::use_cases::notification_management::NotificationManagementUseCase;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpResponse, web};
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/{id}/notification-preferences",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = NotificationPreferenceResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_notification_preference(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.notification_use_case.get_preference(&id).await {
+        Ok(preference) => Ok(HttpResponse::Ok().json(NotificationPreferenceResponseDTO::from(preference))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("User not found")),
+        Err(e) => {
+            eprintln!("Error fetching notification preferences: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/teacher/users/{id}/notification-preferences",
+    tag = "users",
+    request_body = NotificationPreferenceDTO,
+    responses(
+        (status = 200, description = "OK", body = NotificationPreferenceResponseDTO),
+        (status = 404, description = "No encontrado"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn update_notification_preference(use_case: web::Data<AppState>, id: web::Path<String>, preference: web::Json<NotificationPreferenceDTO>) -> Result<HttpResponse, Error> {
+    let preference = preference.into_inner().into_preference(id.into_inner());
+    match use_case.notification_use_case.update_preference(&preference).await {
+        Ok(preference) => Ok(HttpResponse::Ok().json(NotificationPreferenceResponseDTO::from(preference))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("User not found")),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error updating notification preferences: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
@@ -1,28 +1,80 @@
 use super::super::config::boostrap::AppState;
+use super::super::dto::enums_dto::WeekdaySchema;
+use super::super::dto::facility_dto::FacilityResponseDTO;
+use super::super::dto::schedule_dto::{
+    ScheduleConflictResponseDTO, ScheduleDTO, ScheduleResponseDTO, TimetableIssueResponseDTO, WeeklyGridResponseDTO, timetable_issues_csv,
+};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::attachment::attachment;
+use super::actor::actor_id;
+use super::etag::{etag, if_match_version};
 use crate::application::use_cases::schedule_management::ScheduleManagementUseCase;
-use crate::domain::{models::enums::Weekday, models::schedule::Schedule};
-use actix_web::{Error, HttpResponse, web};
+use crate::domain::models::enums::Weekday;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, http::header, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use serde_json::Value;
 
 // Operaciones CRUD básicas
+#[utoipa::path(
+    get,
+    path = "/teacher/schedules",
+    tag = "schedules",
+    responses(
+        (status = 200, description = "OK", body = [ScheduleResponseDTO]),
+    )
+)]
 pub async fn get_all_schedules(use_case: web::Data<AppState>) -> Result<HttpResponse, Error> {
     let schedules = use_case.schedule_use_case.get_all().await.map_err(|e| {
         eprintln!("Error fetching schedules: {}", e);
         actix_web::error::ErrorInternalServerError("Internal server error")
     })?;
+    let schedules: Vec<ScheduleResponseDTO> = schedules.into_iter().map(ScheduleResponseDTO::from).collect();
     Ok(HttpResponse::Ok().json(schedules))
 }
 
-pub async fn create_schedule(use_case: web::Data<AppState>, new_schedule: web::Json<Schedule>) -> Result<HttpResponse, Error> {
-    let schedule = use_case.schedule_use_case.create(new_schedule.into_inner()).await.map_err(|e| {
-        eprintln!("Error creating schedule: {}", e);
-        actix_web::error::ErrorInternalServerError("Internal server error")
-    })?;
-    Ok(HttpResponse::Created().json(schedule))
+#[utoipa::path(
+    post,
+    path = "/teacher/schedules",
+    tag = "schedules",
+    request_body = ScheduleDTO,
+    responses(
+        (status = 201, description = "Creado", body = ScheduleResponseDTO, headers(("ETag" = String, description = "Versión del recurso"))),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn create_schedule(req: HttpRequest, use_case: web::Data<AppState>, new_schedule: web::Json<ScheduleDTO>) -> Result<HttpResponse, Error> {
+    let new_schedule = new_schedule.into_inner();
+    if let Err(errors) = new_schedule.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let schedule = new_schedule.into_schedule(uuid::Uuid::new_v4().to_string());
+    match use_case.schedule_use_case.create(schedule, &actor_id(&req)).await {
+        Ok(schedule) => Ok(HttpResponse::Created().insert_header((header::ETAG, etag(schedule.version))).json(ScheduleResponseDTO::from(schedule))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not available") || e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error creating schedule: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
 }
 
+#[utoipa::path(
+    get,
+    path = "/teacher/schedules/{id}",
+    tag = "schedules",
+    responses(
+        (status = 200, description = "OK", body = ScheduleResponseDTO, headers(("ETag" = String, description = "Versión del recurso"))),
+        (status = 404, description = "No encontrado"),
+    )
+)]
 pub async fn get_schedule_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
     match use_case.schedule_use_case.get_by_id(&id).await {
-        Ok(schedule) => Ok(HttpResponse::Ok().json(schedule)),
+        Ok(schedule) => Ok(HttpResponse::Ok().insert_header((header::ETAG, etag(schedule.version))).json(ScheduleResponseDTO::from(schedule))),
         Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Schedule not found")),
         Err(e) => {
             eprintln!("Error fetching schedule: {}", e);
@@ -31,23 +83,89 @@ pub async fn get_schedule_by_id(use_case: web::Data<AppState>, id: web::Path<Str
     }
 }
 
-pub async fn update_schedule(use_case: web::Data<AppState>, id: web::Path<String>, updated_schedule: web::Json<Schedule>) -> Result<HttpResponse, Error> {
-    let mut schedule = updated_schedule.into_inner();
-    schedule.id = id.clone();
+#[utoipa::path(
+    put,
+    path = "/teacher/schedules/{id}",
+    tag = "schedules",
+    params(("If-Match" = String, Header, description = "Versión esperada, tal como llegó en el `ETag`, o `*`")),
+    request_body = ScheduleDTO,
+    responses(
+        (status = 200, description = "OK", body = ScheduleResponseDTO, headers(("ETag" = String, description = "Versión del recurso"))),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 412, description = "La versión no coincide con `If-Match`"),
+        (status = 428, description = "Falta la cabecera `If-Match`"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn update_schedule(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, updated_schedule: web::Json<ScheduleDTO>) -> Result<HttpResponse, Error> {
+    let updated_schedule = updated_schedule.into_inner();
+    if let Err(errors) = updated_schedule.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+    let expected_version = if_match_version(&req)?;
 
-    match use_case.schedule_use_case.update(&schedule).await {
-        Ok(_) => Ok(HttpResponse::Ok().finish()),
-        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Schedule not found")),
-        Err(e) if e.to_lowercase().contains("not available") => Ok(HttpResponse::Conflict().body(e)),
-        Err(e) => {
+    // El PUT reemplaza los campos editables; el ID y la versión los conserva el servidor
+    let replacement = serde_json::to_value(&updated_schedule).map_err(actix_web::error::ErrorBadRequest)?;
+
+    match use_case.schedule_use_case.patch(&id, &replacement, expected_version, &actor_id(&req)).await {
+        Ok(schedule) => Ok(HttpResponse::Ok().insert_header((header::ETAG, etag(schedule.version))).json(ScheduleResponseDTO::from(schedule))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Schedule not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("precondition failed") => Ok(HttpResponse::PreconditionFailed().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not available") || e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) => {
             eprintln!("Error updating schedule: {}", e);
             Err(actix_web::error::ErrorInternalServerError("Internal server error"))
         }
     }
 }
 
-pub async fn delete_schedule(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
-    match use_case.schedule_use_case.delete(&id).await {
+#[utoipa::path(
+    patch,
+    path = "/teacher/schedules/{id}",
+    tag = "schedules",
+    params(("If-Match" = String, Header, description = "Versión esperada, tal como llegó en el `ETag`, o `*`")),
+    request_body(content = Object, description = "Solo los campos a cambiar"),
+    responses(
+        (status = 200, description = "OK", body = ScheduleResponseDTO, headers(("ETag" = String, description = "Versión del recurso"))),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 412, description = "La versión no coincide con `If-Match`"),
+        (status = 428, description = "Falta la cabecera `If-Match`"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn patch_schedule(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, patch: web::Json<Value>) -> Result<HttpResponse, Error> {
+    let expected_version = if_match_version(&req)?;
+
+    match use_case.schedule_use_case.patch(&id, &patch, expected_version, &actor_id(&req)).await {
+        Ok(schedule) => Ok(HttpResponse::Ok().insert_header((header::ETAG, etag(schedule.version))).json(ScheduleResponseDTO::from(schedule))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Schedule not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid patch") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not available") || e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("precondition failed") => Ok(HttpResponse::PreconditionFailed().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error patching schedule: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/teacher/schedules/{id}",
+    tag = "schedules",
+    responses(
+        (status = 204, description = "Sin contenido"),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn delete_schedule(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.schedule_use_case.delete(&id, &actor_id(&req)).await {
         Ok(_) => Ok(HttpResponse::NoContent().finish()),
         Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Schedule not found")),
         Err(e) => {
@@ -57,15 +175,221 @@ pub async fn delete_schedule(use_case: web::Data<AppState>, id: web::Path<String
     }
 }
 
+/// Semestre en el que se buscan horarios libres.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SuggestTimesParams {
+    pub academic_year: i32,
+    pub semester: String,
+}
+
 // Operaciones específicas de gestión
-pub async fn suggest_available_times(use_case: web::Data<AppState>, teacher_id: web::Path<String>, payload: web::Json<(i32, Vec<Weekday>)>) -> Result<HttpResponse, Error> {
+#[utoipa::path(
+    post,
+    path = "/teacher/schedules/suggest/{teacher_id}",
+    tag = "schedules",
+    params(SuggestTimesParams),
+    request_body(content = (i32, Vec<WeekdaySchema>), description = "Arreglo de dos elementos: duración en minutos y días preferidos, p. ej. `[90, [\"Monday\", \"Wednesday\"]]`. Sin días se revisa toda la semana"),
+    responses(
+        (status = 200, description = "Horarios libres del profesor", body = [ScheduleResponseDTO]),
+    )
+)]
+pub async fn suggest_available_times(use_case: web::Data<AppState>, teacher_id: web::Path<String>, params: web::Query<SuggestTimesParams>, payload: web::Json<(i32, Vec<Weekday>)>) -> Result<HttpResponse, Error> {
     let (duration_minutes, preferred_days) = payload.into_inner();
 
-    match use_case.schedule_use_case.suggest_available_times(&teacher_id, duration_minutes, preferred_days).await {
-        Ok(suggestions) => Ok(HttpResponse::Ok().json(suggestions)),
+    match use_case.schedule_use_case.suggest_available_times(&teacher_id, params.academic_year, &params.semester, duration_minutes, preferred_days).await {
+        Ok(suggestions) => {
+            let suggestions: Vec<ScheduleResponseDTO> = suggestions.into_iter().map(ScheduleResponseDTO::from).collect();
+            Ok(HttpResponse::Ok().json(suggestions))
+        }
         Err(e) => {
             eprintln!("Error suggesting times: {}", e);
             Err(actix_web::error::ErrorInternalServerError("Internal server error"))
         }
     }
 }
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CycleConflictParams {
+    pub cycle: i32,
+    pub academic_year: i32,
+    pub semester: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/schedules/conflicts/course/{course_id}",
+    tag = "schedules",
+    responses(
+        (status = 200, description = "OK", body = [ScheduleConflictResponseDTO]),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_course_conflicts(use_case: web::Data<AppState>, course_id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.schedule_use_case.get_course_conflicts(&course_id).await {
+        Ok(conflicts) => {
+            let conflicts: Vec<ScheduleConflictResponseDTO> = conflicts.into_iter().map(ScheduleConflictResponseDTO::from).collect();
+            Ok(HttpResponse::Ok().json(conflicts))
+        }
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
+        Err(e) => {
+            eprintln!("Error detecting course conflicts: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/schedules/conflicts",
+    tag = "schedules",
+    params(CycleConflictParams),
+    responses(
+        (status = 200, description = "OK", body = [ScheduleConflictResponseDTO]),
+        (status = 400, description = "Parámetros inválidos"),
+    )
+)]
+pub async fn get_cycle_conflicts(use_case: web::Data<AppState>, params: web::Query<CycleConflictParams>) -> Result<HttpResponse, Error> {
+    match use_case.schedule_use_case.get_cycle_conflicts(params.cycle, params.academic_year, &params.semester).await {
+        Ok(conflicts) => {
+            let conflicts: Vec<ScheduleConflictResponseDTO> = conflicts.into_iter().map(ScheduleConflictResponseDTO::from).collect();
+            Ok(HttpResponse::Ok().json(conflicts))
+        }
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error detecting cycle conflicts: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SemesterAuditParams {
+    pub academic_year: i32,
+    pub semester: String,
+    /// `json` (por defecto) o `csv`
+    pub format: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/schedules/audit",
+    tag = "schedules",
+    params(SemesterAuditParams),
+    responses(
+        (status = 200, description = "Problemas del semestre en JSON o CSV", content(([TimetableIssueResponseDTO] = "application/json"), (String = "text/csv"))),
+        (status = 400, description = "Parámetros inválidos"),
+    )
+)]
+pub async fn audit_semester(use_case: web::Data<AppState>, params: web::Query<SemesterAuditParams>) -> Result<HttpResponse, Error> {
+    let issues = match use_case.schedule_use_case.audit_semester(params.academic_year, &params.semester).await {
+        Ok(issues) => issues,
+        Err(e) if e.to_lowercase().contains("invalid") => return Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error auditing semester timetable: {}", e);
+            return Err(actix_web::error::ErrorInternalServerError("Internal server error"));
+        }
+    };
+    let issues: Vec<TimetableIssueResponseDTO> = issues.into_iter().map(TimetableIssueResponseDTO::from).collect();
+
+    match params.format.as_deref().map(str::to_lowercase).as_deref() {
+        None | Some("json") => Ok(HttpResponse::Ok().json(issues)),
+        Some("csv") => Ok(HttpResponse::Ok()
+            .content_type("text/csv; charset=utf-8")
+            .insert_header(attachment(&format!("timetable-audit-{}-{}.csv", params.academic_year, params.semester)))
+            .body(timetable_issues_csv(&issues))),
+        Some(other) => Ok(HttpResponse::BadRequest().body(format!("Unsupported format: {}", other))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/schedules/curriculum-check",
+    tag = "schedules",
+    request_body = ScheduleDTO,
+    responses(
+        (status = 200, description = "OK", body = [ScheduleConflictResponseDTO]),
+        (status = 404, description = "No encontrado"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn check_curriculum_clashes(use_case: web::Data<AppState>, proposed: web::Json<ScheduleDTO>) -> Result<HttpResponse, Error> {
+    let proposed = proposed.into_inner();
+    if let Err(errors) = proposed.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    // Simulación: la sesión no se guarda
+    let schedule = proposed.into_schedule(String::new());
+    match use_case.schedule_use_case.check_curriculum_clashes(&schedule).await {
+        Ok(clashes) => {
+            let clashes: Vec<ScheduleConflictResponseDTO> = clashes.into_iter().map(ScheduleConflictResponseDTO::from).collect();
+            Ok(HttpResponse::Ok().json(clashes))
+        }
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
+        Err(e) => {
+            eprintln!("Error checking curriculum clashes: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CycleGridParams {
+    pub program: String,
+    pub cycle: i32,
+    pub academic_year: i32,
+    pub semester: String,
+    pub section: Option<i32>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/schedules/grid",
+    tag = "schedules",
+    params(CycleGridParams),
+    responses(
+        (status = 200, description = "OK", body = WeeklyGridResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+    )
+)]
+pub async fn get_cycle_grid(use_case: web::Data<AppState>, params: web::Query<CycleGridParams>) -> Result<HttpResponse, Error> {
+    match use_case.schedule_use_case.get_cycle_grid(&params.program, params.cycle, params.academic_year, &params.semester, params.section).await {
+        Ok(grid) => Ok(HttpResponse::Ok().json(WeeklyGridResponseDTO::from(grid))),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error building cycle grid: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/schedules/allocate",
+    tag = "schedules",
+    request_body = ScheduleDTO,
+    responses(
+        (status = 200, description = "OK", body = [FacilityResponseDTO]),
+        (status = 404, description = "No encontrado"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn suggest_facilities(use_case: web::Data<AppState>, proposed: web::Json<ScheduleDTO>) -> Result<HttpResponse, Error> {
+    let proposed = proposed.into_inner();
+    if let Err(errors) = proposed.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let schedule = proposed.into_schedule(String::new());
+    match use_case.schedule_use_case.suggest_facilities(&schedule).await {
+        Ok(facilities) => {
+            let facilities: Vec<FacilityResponseDTO> = facilities.into_iter().map(FacilityResponseDTO::from).collect();
+            Ok(HttpResponse::Ok().json(facilities))
+        }
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
+        Err(e) => {
+            eprintln!("Error suggesting facilities: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::section_dto::{CloneTermDTO, MergeSectionsDTO, SectionMergeResponseDTO, SectionSplitResponseDTO, SplitSectionDTO, TermCloneResponseDTO};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::section_management::SectionManagementUseCase;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+
+#[utoipa::path(
+    post,
+    path = "/teacher/courses/clone-term",
+    tag = "courses",
+    request_body = CloneTermDTO,
+    responses(
+        (status = 201, description = "Creado", body = TermCloneResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn clone_term(req: HttpRequest, use_case: web::Data<AppState>, request: web::Json<CloneTermDTO>) -> Result<HttpResponse, Error> {
+    let request = request.into_inner();
+    if let Err(errors) = request.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    match use_case.section_use_case.clone_term(request.into_request(), &actor_id(&req)).await {
+        Ok(result) => Ok(HttpResponse::Created().json(TermCloneResponseDTO::from(result))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error cloning term: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/courses/{id}/split",
+    tag = "courses",
+    request_body = SplitSectionDTO,
+    responses(
+        (status = 201, description = "Creado", body = SectionSplitResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn split_section(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, split: web::Json<SplitSectionDTO>) -> Result<HttpResponse, Error> {
+    let split = split.into_inner();
+    if let Err(errors) = split.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    match use_case.section_use_case.split(&id, split.into_split(), &actor_id(&req)).await {
+        Ok(result) => Ok(HttpResponse::Created().json(SectionSplitResponseDTO::from(result))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error splitting section: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/courses/{id}/merge",
+    tag = "courses",
+    request_body = MergeSectionsDTO,
+    responses(
+        (status = 200, description = "OK", body = SectionMergeResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn merge_sections(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, merge: web::Json<MergeSectionsDTO>) -> Result<HttpResponse, Error> {
+    let merge = merge.into_inner();
+    if let Err(errors) = merge.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    match use_case.section_use_case.merge(&id, merge.into_merge(), &actor_id(&req)).await {
+        Ok(result) => Ok(HttpResponse::Ok().json(SectionMergeResponseDTO::from(result))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Course not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error merging sections: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
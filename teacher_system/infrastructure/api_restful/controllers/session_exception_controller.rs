@@ -0,0 +1,87 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::session_exception_dto::{
+    CancelOccurrenceDTO, RescheduleOccurrenceDTO, SessionExceptionResponseDTO,
+};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::session_exception_management::SessionExceptionManagementUseCase;
+use crate::domain::models::session_exception::SessionException;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+
+/// Respuesta común para cancelaciones y reprogramaciones.
+fn exception_response(result: Result<SessionException, DomainError>, action: &str) -> Result<HttpResponse, Error> {
+    match result {
+        Ok(exception) => Ok(HttpResponse::Created().json(SessionExceptionResponseDTO::from(exception))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not available") || e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error trying to {} session: {}", action, e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/schedules/{id}/exceptions",
+    tag = "schedules",
+    responses(
+        (status = 200, description = "OK", body = [SessionExceptionResponseDTO]),
+    )
+)]
+pub async fn get_session_exceptions(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    let exceptions = use_case.session_exception_use_case.get_by_schedule(&id).await.map_err(|e| {
+        eprintln!("Error fetching session exceptions: {}", e);
+        actix_web::error::ErrorInternalServerError("Internal server error")
+    })?;
+    let exceptions: Vec<SessionExceptionResponseDTO> = exceptions.into_iter().map(SessionExceptionResponseDTO::from).collect();
+    Ok(HttpResponse::Ok().json(exceptions))
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/schedules/{id}/exceptions/cancel",
+    tag = "schedules",
+    request_body = CancelOccurrenceDTO,
+    responses(
+        (status = 201, description = "Creado", body = SessionExceptionResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn cancel_session(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, body: web::Json<CancelOccurrenceDTO>) -> Result<HttpResponse, Error> {
+    let result = use_case
+        .session_exception_use_case
+        .cancel_occurrence(&id, body.date, &body.reason, &actor_id(&req))
+        .await;
+    exception_response(result, "cancel")
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/schedules/{id}/exceptions/reschedule",
+    tag = "schedules",
+    request_body = RescheduleOccurrenceDTO,
+    responses(
+        (status = 201, description = "Creado", body = SessionExceptionResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn reschedule_session(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, body: web::Json<RescheduleOccurrenceDTO>) -> Result<HttpResponse, Error> {
+    let result = use_case
+        .session_exception_use_case
+        .reschedule_occurrence(&id, body.date, &body.reason, body.make_up_slot(), &actor_id(&req))
+        .await;
+    exception_response(result, "reschedule")
+}
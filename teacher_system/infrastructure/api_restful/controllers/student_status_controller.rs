@@ -0,0 +1,100 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::student_status_dto::{EvaluateTermDTO, StatusChangeResponseDTO, StatusRuleSetResponseDTO};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::student_status_management::StudentStatusManagementUseCase;
+use crate::domain::models::student_status::StatusChange;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+
+fn changes_response(changes: Vec<StatusChange>) -> HttpResponse {
+    let changes: Vec<StatusChangeResponseDTO> = changes.into_iter().map(StatusChangeResponseDTO::from).collect();
+    HttpResponse::Ok().json(changes)
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/student-status/rules",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = StatusRuleSetResponseDTO),
+    )
+)]
+pub async fn get_status_rules(use_case: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    Ok(HttpResponse::Ok().json(StatusRuleSetResponseDTO::from(use_case.student_status_use_case.get_rules())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/{id}/status-history",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = [StatusChangeResponseDTO]),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_status_history(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.student_status_use_case.get_history(&id).await {
+        Ok(changes) => Ok(changes_response(changes)),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) => {
+            eprintln!("Error fetching status history: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+/// Devuelve el cambio aplicado, o 204 si el estado se mantiene.
+#[utoipa::path(
+    post,
+    path = "/teacher/users/{id}/status/evaluate",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = StatusChangeResponseDTO),
+        (status = 204, description = "El estado se mantiene"),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn evaluate_student_status(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.student_status_use_case.evaluate_student(&id, &actor_id(&req)).await {
+        Ok(Some(change)) => Ok(HttpResponse::Ok().json(StatusChangeResponseDTO::from(change))),
+        Ok(None) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error evaluating student status: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/users/student-status/evaluate",
+    tag = "users",
+    request_body = EvaluateTermDTO,
+    responses(
+        (status = 200, description = "OK", body = [StatusChangeResponseDTO]),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn evaluate_term_status(req: HttpRequest, use_case: web::Data<AppState>, term: web::Json<EvaluateTermDTO>) -> Result<HttpResponse, Error> {
+    let term = term.into_inner();
+    if let Err(errors) = term.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    match use_case.student_status_use_case.evaluate_term(term.academic_year, &term.semester, &actor_id(&req)).await {
+        Ok(changes) => Ok(changes_response(changes)),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) => {
+            eprintln!("Error evaluating term status: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
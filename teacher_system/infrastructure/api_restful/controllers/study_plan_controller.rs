@@ -0,0 +1,170 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::study_plan_dto::{
+    CycleOfferingResponseDTO, PlanProgressResponseDTO, StudyPlanDTO, StudyPlanResponseDTO,
+};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::study_plan_management::StudyPlanManagementUseCase;
+use crate::domain::models::study_plan::StudyPlan;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Arma la respuesta del plan con los requisitos de cada curso.
+async fn plan_response(use_case: &AppState, plan: StudyPlan) -> Result<StudyPlanResponseDTO, Error> {
+    let prerequisites = use_case.study_plan_use_case.get_prerequisites(&plan).await.map_err(|e| {
+        eprintln!("Error fetching study plan prerequisites: {}", e);
+        actix_web::error::ErrorInternalServerError("Internal server error")
+    })?;
+    Ok(StudyPlanResponseDTO::new(plan, &prerequisites))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct StudyPlanParams {
+    pub program: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/study-plans",
+    tag = "study-plans",
+    params(StudyPlanParams),
+    responses(
+        (status = 200, description = "OK", body = [StudyPlanResponseDTO]),
+    )
+)]
+pub async fn get_study_plans(use_case: web::Data<AppState>, params: web::Query<StudyPlanParams>) -> Result<HttpResponse, Error> {
+    let plans = use_case.study_plan_use_case.get_all(params.program.as_deref()).await.map_err(|e| {
+        eprintln!("Error fetching study plans: {}", e);
+        actix_web::error::ErrorInternalServerError("Internal server error")
+    })?;
+
+    let mut response: Vec<StudyPlanResponseDTO> = Vec::new();
+    for plan in plans {
+        response.push(plan_response(&use_case, plan).await?);
+    }
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/study-plans/{id}",
+    tag = "study-plans",
+    responses(
+        (status = 200, description = "OK", body = StudyPlanResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_study_plan_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.study_plan_use_case.get_by_id(&id).await {
+        Ok(plan) => Ok(HttpResponse::Ok().json(plan_response(&use_case, plan).await?)),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Study plan not found")),
+        Err(e) => {
+            eprintln!("Error fetching study plan: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/study-plans",
+    tag = "study-plans",
+    request_body = StudyPlanDTO,
+    responses(
+        (status = 201, description = "Creado", body = StudyPlanResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn create_study_plan(req: HttpRequest, use_case: web::Data<AppState>, new_plan: web::Json<StudyPlanDTO>) -> Result<HttpResponse, Error> {
+    let new_plan = new_plan.into_inner();
+    if let Err(errors) = new_plan.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let plan = new_plan.into_plan(uuid::Uuid::new_v4().to_string());
+    match use_case.study_plan_use_case.create(plan, &actor_id(&req)).await {
+        Ok(plan) => Ok(HttpResponse::Created().json(plan_response(&use_case, plan).await?)),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error creating study plan: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/study-plans/{id}/activate",
+    tag = "study-plans",
+    responses(
+        (status = 200, description = "OK", body = StudyPlanResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn activate_study_plan(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.study_plan_use_case.activate(&id, &actor_id(&req)).await {
+        Ok(plan) => Ok(HttpResponse::Ok().json(plan_response(&use_case, plan).await?)),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Study plan not found")),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) => {
+            eprintln!("Error activating study plan: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/study-plans/{id}/offering",
+    tag = "study-plans",
+    responses(
+        (status = 200, description = "OK", body = [CycleOfferingResponseDTO]),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_study_plan_offering(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.study_plan_use_case.get_offering(&id).await {
+        Ok(offering) => Ok(HttpResponse::Ok().json(offering.into_iter().map(CycleOfferingResponseDTO::from).collect::<Vec<_>>())),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Study plan not found")),
+        Err(e) => {
+            eprintln!("Error building study plan offering: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PlanProgressParams {
+    /// Por defecto, la versión vigente del programa del alumno
+    pub plan_id: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/{id}/plan-progress",
+    tag = "users",
+    params(PlanProgressParams),
+    responses(
+        (status = 200, description = "OK", body = PlanProgressResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_plan_progress(use_case: web::Data<AppState>, id: web::Path<String>, params: web::Query<PlanProgressParams>) -> Result<HttpResponse, Error> {
+    match use_case.study_plan_use_case.get_student_progress(&id, params.plan_id.as_deref()).await {
+        Ok(progress) => Ok(HttpResponse::Ok().json(PlanProgressResponseDTO::from(progress))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error fetching plan progress: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
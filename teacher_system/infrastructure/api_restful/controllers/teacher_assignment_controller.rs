@@ -0,0 +1,160 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::teacher_assignment_dto::{AcceptAssignmentDTO, AssignmentProposalResponseDTO, OverrideAssignmentDTO, ProposeAssignmentsDTO};
+use super::super::dto::enums_dto::AssignmentStatusSchema;
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::teacher_assignment_management::TeacherAssignmentManagementUseCase;
+use crate::domain::models::enums::AssignmentStatus;
+use crate::domain::models::teacher_assignment::{AssignmentFilter, AssignmentProposal};
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// Respuesta común al aceptar o reemplazar una propuesta.
+fn decision_response(result: Result<AssignmentProposal, String>, action: &str) -> Result<HttpResponse, Error> {
+    match result {
+        Ok(proposal) => Ok(HttpResponse::Ok().json(AssignmentProposalResponseDTO::from(proposal))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) if e.to_lowercase().contains("precondition failed") => Ok(HttpResponse::PreconditionFailed().body(e)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error trying to {} assignment proposal: {}", action, e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/assignments/propose",
+    tag = "assignments",
+    request_body = ProposeAssignmentsDTO,
+    responses(
+        (status = 201, description = "Creado", body = [AssignmentProposalResponseDTO]),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn propose_assignments(req: HttpRequest, use_case: web::Data<AppState>, request: web::Json<ProposeAssignmentsDTO>) -> Result<HttpResponse, Error> {
+    let request = request.into_inner();
+    if let Err(errors) = request.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    match use_case.teacher_assignment_use_case.propose(request.academic_year, &request.semester, &actor_id(&req)).await {
+        Ok(proposals) => {
+            let proposals: Vec<AssignmentProposalResponseDTO> = proposals.into_iter().map(AssignmentProposalResponseDTO::from).collect();
+            Ok(HttpResponse::Created().json(proposals))
+        }
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error proposing teacher assignments: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AssignmentParams {
+    pub academic_year: Option<i32>,
+    pub semester: Option<String>,
+    #[param(value_type = Option<AssignmentStatusSchema>)]
+    pub status: Option<AssignmentStatus>,
+}
+
+impl From<AssignmentParams> for AssignmentFilter {
+    fn from(params: AssignmentParams) -> Self {
+        Self {
+            academic_year: params.academic_year,
+            semester: params.semester,
+            status: params.status,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/assignments",
+    tag = "assignments",
+    params(AssignmentParams),
+    responses(
+        (status = 200, description = "OK", body = [AssignmentProposalResponseDTO]),
+    )
+)]
+pub async fn get_assignment_proposals(use_case: web::Data<AppState>, params: web::Query<AssignmentParams>) -> Result<HttpResponse, Error> {
+    let filter = AssignmentFilter::from(params.into_inner());
+    let proposals = use_case.teacher_assignment_use_case.get_all(&filter).await.map_err(|e| {
+        eprintln!("Error fetching assignment proposals: {}", e);
+        actix_web::error::ErrorInternalServerError("Internal server error")
+    })?;
+    let proposals: Vec<AssignmentProposalResponseDTO> = proposals.into_iter().map(AssignmentProposalResponseDTO::from).collect();
+    Ok(HttpResponse::Ok().json(proposals))
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/assignments/{id}",
+    tag = "assignments",
+    responses(
+        (status = 200, description = "OK", body = AssignmentProposalResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_assignment_proposal_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.teacher_assignment_use_case.get_by_id(&id).await {
+        Ok(proposal) => Ok(HttpResponse::Ok().json(AssignmentProposalResponseDTO::from(proposal))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Assignment proposal not found")),
+        Err(e) => {
+            eprintln!("Error fetching assignment proposal: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/assignments/{id}/accept",
+    tag = "assignments",
+    request_body = Option<AcceptAssignmentDTO>,
+    responses(
+        (status = 200, description = "OK", body = AssignmentProposalResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 412, description = "La versión no coincide con `If-Match`"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn accept_assignment(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, decision: Option<web::Json<AcceptAssignmentDTO>>) -> Result<HttpResponse, Error> {
+    let note = decision.map(|d| d.into_inner()).unwrap_or_default().note;
+    decision_response(use_case.teacher_assignment_use_case.accept(&id, &actor_id(&req), note).await, "accept")
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/assignments/{id}/override",
+    tag = "assignments",
+    request_body = OverrideAssignmentDTO,
+    responses(
+        (status = 200, description = "OK", body = AssignmentProposalResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+        (status = 412, description = "La versión no coincide con `If-Match`"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn override_assignment(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, decision: web::Json<OverrideAssignmentDTO>) -> Result<HttpResponse, Error> {
+    let decision = decision.into_inner();
+    if let Err(errors) = decision.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    decision_response(use_case.teacher_assignment_use_case.override_teacher(&id, &decision.teacher_id, &actor_id(&req), decision.note).await, "override")
+}
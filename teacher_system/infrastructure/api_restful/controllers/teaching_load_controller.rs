@@ -0,0 +1,50 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::enums_dto::LoadStatusSchema;
+use super::super::dto::teaching_load_dto::{TeacherTermLoadResponseDTO, TeachingLoadPolicyResponseDTO};
+use crate::application::use_cases::teaching_load_management::TeachingLoadManagementUseCase;
+use crate::domain::models::teaching_load::LoadStatus;
+use actix_web::{Error, HttpResponse, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/teaching-load/rules",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = TeachingLoadPolicyResponseDTO),
+    )
+)]
+pub async fn get_teaching_load_rules(use_case: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    Ok(HttpResponse::Ok().json(TeachingLoadPolicyResponseDTO::from(use_case.teaching_load_use_case.get_policy())))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TeachingLoadParams {
+    pub academic_year: i32,
+    pub semester: String,
+    /// `under`, `within` u `over`
+    #[param(value_type = Option<LoadStatusSchema>)]
+    pub status: Option<LoadStatus>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/teaching-load",
+    tag = "users",
+    params(TeachingLoadParams),
+    responses(
+        (status = 200, description = "OK", body = [TeacherTermLoadResponseDTO]),
+        (status = 400, description = "Parámetros inválidos"),
+    )
+)]
+pub async fn get_teaching_load_report(use_case: web::Data<AppState>, params: web::Query<TeachingLoadParams>) -> Result<HttpResponse, Error> {
+    match use_case.teaching_load_use_case.get_report(params.academic_year, &params.semester, params.status).await {
+        Ok(report) => Ok(HttpResponse::Ok().json(report.into_iter().map(TeacherTermLoadResponseDTO::from).collect::<Vec<_>>())),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error fetching teaching load report: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
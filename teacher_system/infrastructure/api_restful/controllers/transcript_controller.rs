@@ -0,0 +1,49 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::transcript_dto::TranscriptResponseDTO;
+use super::attachment::attachment;
+use crate::application::use_cases::transcript_management::TranscriptManagementUseCase;
+use crate::infrastructure::documents::transcript_pdf::render_transcript;
+use actix_web::{Error, HttpResponse, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TranscriptParams {
+    /// `json` (por defecto) o `pdf`
+    pub format: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/{id}/transcript",
+    tag = "users",
+    params(TranscriptParams),
+    responses(
+        (status = 200, description = "Historial en JSON o PDF", content((TranscriptResponseDTO = "application/json"), ("application/pdf"))),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_transcript(use_case: web::Data<AppState>, id: web::Path<String>, params: web::Query<TranscriptParams>) -> Result<HttpResponse, Error> {
+    let format = params.into_inner().format.unwrap_or_else(|| "json".to_string()).to_lowercase();
+    if format != "json" && format != "pdf" {
+        return Ok(HttpResponse::BadRequest().body("Invalid format: use json or pdf"));
+    }
+
+    match use_case.transcript_use_case.get_transcript(&id).await {
+        Ok(transcript) if format == "pdf" => {
+            let filename = format!("historial-{}.pdf", transcript.student_code);
+            Ok(HttpResponse::Ok()
+                .content_type("application/pdf")
+                .insert_header(attachment(&filename))
+                .body(render_transcript(&transcript)))
+        }
+        Ok(transcript) => Ok(HttpResponse::Ok().json(TranscriptResponseDTO::from(transcript))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body(e)),
+        Err(e) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(e) => {
+            eprintln!("Error building transcript: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
@@ -1 +1,171 @@
-// Aquí no hay nada :v
+use super::actor::actor_id;
+use super::super::config::boostrap::AppState;
+use super::super::dto::user_dto::{UserDTO, UserResponseDTO};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use crate::application::use_cases::user_management::UserManagementUseCase;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+use serde_json::Value;
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = [UserResponseDTO]),
+    )
+)]
+pub async fn get_all_users(use_case: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let users = use_case.user_use_case.get_all().await.map_err(|e| {
+        eprintln!("Error fetching users: {}", e);
+        actix_web::error::ErrorInternalServerError("Internal server error")
+    })?;
+    let users: Vec<UserResponseDTO> = users.into_iter().map(UserResponseDTO::from).collect();
+    Ok(HttpResponse::Ok().json(users))
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/users",
+    tag = "users",
+    request_body = UserDTO,
+    responses(
+        (status = 201, description = "Creado", body = UserResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn create_user(req: HttpRequest, use_case: web::Data<AppState>, new_user: web::Json<UserDTO>) -> Result<HttpResponse, Error> {
+    let new_user = new_user.into_inner();
+    if let Err(errors) = new_user.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let user = new_user.into_user(uuid::Uuid::new_v4().to_string());
+    match use_case.user_use_case.create(user, &actor_id(&req)).await {
+        Ok(user) => Ok(HttpResponse::Created().json(UserResponseDTO::from(user))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error creating user: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/users/{id}",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK", body = UserResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_user_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.user_use_case.get_by_id(&id).await {
+        Ok(user) => Ok(HttpResponse::Ok().json(UserResponseDTO::from(user))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("User not found")),
+        Err(e) => {
+            eprintln!("Error fetching user: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/teacher/users/{id}",
+    tag = "users",
+    request_body = UserDTO,
+    responses(
+        (status = 200, description = "OK", body = UserResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn update_user(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, updated_user: web::Json<UserDTO>) -> Result<HttpResponse, Error> {
+    let updated_user = updated_user.into_inner();
+    if let Err(errors) = updated_user.validate() {
+        return Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors)));
+    }
+
+    let replacement = serde_json::to_value(&updated_user).map_err(actix_web::error::ErrorBadRequest)?;
+
+    match use_case.user_use_case.patch(&id, &replacement, &actor_id(&req)).await {
+        Ok(user) => Ok(HttpResponse::Ok().json(UserResponseDTO::from(user))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("User not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error updating user: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/teacher/users/{id}",
+    tag = "users",
+    request_body(content = Object, description = "Solo los campos a cambiar"),
+    responses(
+        (status = 200, description = "OK", body = UserResponseDTO),
+        (status = 400, description = "Parámetros inválidos"),
+        (status = 404, description = "No encontrado"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn patch_user(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>, patch: web::Json<Value>) -> Result<HttpResponse, Error> {
+    match use_case.user_use_case.patch(&id, &patch, &actor_id(&req)).await {
+        Ok(user) => Ok(HttpResponse::Ok().json(UserResponseDTO::from(user))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("User not found")),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("invalid") => Ok(HttpResponse::BadRequest().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error patching user: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/teacher/users/{id}",
+    tag = "users",
+    responses(
+        (status = 204, description = "Sin contenido"),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn delete_user(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.user_use_case.delete(&id, &actor_id(&req)).await {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("User not found")),
+        Err(e) => {
+            eprintln!("Error deleting user: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/users/{id}/restore",
+    tag = "users",
+    responses(
+        (status = 200, description = "OK"),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn restore_user(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.user_use_case.restore(&id, &actor_id(&req)).await {
+        Ok(_) => Ok(HttpResponse::Ok().finish()),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Archived user not found")),
+        Err(e) => {
+            eprintln!("Error restoring user: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
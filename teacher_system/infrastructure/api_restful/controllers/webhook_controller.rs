@@ -0,0 +1,115 @@
+use super::super::config::boostrap::AppState;
+use super::super::dto::webhook_dto::{DeliveryAttemptResponseDTO, WebhookDTO, WebhookResponseDTO};
+use super::super::dto::validation_dto::ValidationErrorResponseDTO;
+use super::actor::actor_id;
+use crate::application::use_cases::webhook_management::WebhookManagementUseCase;
+use crate::domain::models::validation::DomainError;
+use actix_web::{Error, HttpRequest, HttpResponse, web};
+
+#[utoipa::path(
+    get,
+    path = "/teacher/webhooks",
+    tag = "webhooks",
+    responses(
+        (status = 200, description = "OK", body = [WebhookResponseDTO]),
+    )
+)]
+pub async fn get_webhooks(use_case: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let webhooks = use_case.webhook_use_case.get_all().await.map_err(|e| {
+        eprintln!("Error fetching webhooks: {}", e);
+        actix_web::error::ErrorInternalServerError("Internal server error")
+    })?;
+    let webhooks: Vec<WebhookResponseDTO> = webhooks.into_iter().map(WebhookResponseDTO::from).collect();
+    Ok(HttpResponse::Ok().json(webhooks))
+}
+
+#[utoipa::path(
+    post,
+    path = "/teacher/webhooks",
+    tag = "webhooks",
+    request_body = WebhookDTO,
+    responses(
+        (status = 201, description = "Creado; el secreto solo se devuelve aquí", body = WebhookResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 422, description = "Errores de validación por campo", body = ValidationErrorResponseDTO),
+    )
+)]
+pub async fn register_webhook(req: HttpRequest, use_case: web::Data<AppState>, new_webhook: web::Json<WebhookDTO>) -> Result<HttpResponse, Error> {
+    let subscription = new_webhook.into_inner().into_subscription(uuid::Uuid::new_v4().to_string(), actor_id(&req));
+    match use_case.webhook_use_case.register(subscription).await {
+        Ok(subscription) => Ok(HttpResponse::Created().json(WebhookResponseDTO::with_secret(subscription))),
+        Err(DomainError::Validation(errors)) => Ok(HttpResponse::UnprocessableEntity().json(ValidationErrorResponseDTO::from(errors))),
+        Err(DomainError::Other(e)) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(DomainError::Other(e)) => {
+            eprintln!("Error registering webhook: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/webhooks/{id}",
+    tag = "webhooks",
+    responses(
+        (status = 200, description = "OK", body = WebhookResponseDTO),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_webhook_by_id(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.webhook_use_case.get_by_id(&id).await {
+        Ok(subscription) => Ok(HttpResponse::Ok().json(WebhookResponseDTO::from(subscription))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Webhook not found")),
+        Err(e) => {
+            eprintln!("Error fetching webhook: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/teacher/webhooks/{id}",
+    tag = "webhooks",
+    responses(
+        (status = 200, description = "OK", body = WebhookResponseDTO),
+        (status = 403, description = "El actor no tiene permiso"),
+        (status = 404, description = "No encontrado"),
+        (status = 409, description = "Choca con el estado actual"),
+    )
+)]
+pub async fn deactivate_webhook(req: HttpRequest, use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.webhook_use_case.deactivate(&id, &actor_id(&req)).await {
+        Ok(subscription) => Ok(HttpResponse::Ok().json(WebhookResponseDTO::from(subscription))),
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Webhook not found")),
+        Err(e) if e.to_lowercase().contains("forbidden") => Ok(HttpResponse::Forbidden().body(e)),
+        Err(e) if e.to_lowercase().contains("conflict") => Ok(HttpResponse::Conflict().body(e)),
+        Err(e) => {
+            eprintln!("Error deactivating webhook: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/teacher/webhooks/{id}/deliveries",
+    tag = "webhooks",
+    responses(
+        (status = 200, description = "OK", body = [DeliveryAttemptResponseDTO]),
+        (status = 404, description = "No encontrado"),
+    )
+)]
+pub async fn get_webhook_deliveries(use_case: web::Data<AppState>, id: web::Path<String>) -> Result<HttpResponse, Error> {
+    match use_case.webhook_use_case.get_deliveries(&id).await {
+        Ok(attempts) => {
+            let attempts: Vec<DeliveryAttemptResponseDTO> = attempts.into_iter().map(DeliveryAttemptResponseDTO::from).collect();
+            Ok(HttpResponse::Ok().json(attempts))
+        }
+        Err(e) if e.to_lowercase().contains("not found") => Ok(HttpResponse::NotFound().body("Webhook not found")),
+        Err(e) => {
+            eprintln!("Error fetching webhook deliveries: {}", e);
+            Err(actix_web::error::ErrorInternalServerError("Internal server error"))
+        }
+    }
+}
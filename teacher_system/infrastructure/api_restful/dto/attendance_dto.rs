@@ -0,0 +1,105 @@
+use super::super::dto::enums_dto::AttendanceStatusSchema;
+use crate::domain::models::{
+    attendance::{AttendanceRecord, AttendanceSummary},
+    enums::AttendanceStatus,
+    validation::ValidationError,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AttendanceEntryDTO {
+    pub student_id: String,
+    #[schema(value_type = AttendanceStatusSchema)]
+    pub status: AttendanceStatus,
+}
+
+/// Cuerpo para pasar lista en una fecha de la sesión.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TakeAttendanceDTO {
+    pub date: NaiveDate,
+    pub entries: Vec<AttendanceEntryDTO>,
+}
+
+impl TakeAttendanceDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.entries.is_empty() {
+            errors.add("entries", "must include at least one student");
+        }
+
+        let mut seen: Vec<&str> = Vec::new();
+        for entry in &self.entries {
+            if seen.contains(&entry.student_id.as_str()) {
+                errors.add("entries", &format!("{} is repeated", entry.student_id));
+            }
+            seen.push(&entry.student_id);
+        }
+
+        errors.into_result()
+    }
+
+    pub fn into_entries(self) -> Vec<(String, AttendanceStatus)> {
+        self.entries
+            .into_iter()
+            .map(|e| (e.student_id, e.status))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AttendanceRecordResponseDTO {
+    pub id: String,
+    pub schedule_id: String,
+    pub course_id: String,
+    pub student_id: String,
+    pub session_date: NaiveDate,
+    #[schema(value_type = AttendanceStatusSchema)]
+    pub status: AttendanceStatus,
+    pub recorded_by: String,
+    pub recorded_at: Option<String>,
+}
+
+impl From<AttendanceRecord> for AttendanceRecordResponseDTO {
+    fn from(record: AttendanceRecord) -> Self {
+        Self {
+            id: record.id,
+            schedule_id: record.schedule_id,
+            course_id: record.course_id,
+            student_id: record.student_id,
+            session_date: record.session_date,
+            status: record.status,
+            recorded_by: record.recorded_by,
+            recorded_at: record.recorded_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AttendanceSummaryResponseDTO {
+    pub student_id: String,
+    pub course_id: String,
+    pub sessions: i32,
+    pub present: i32,
+    pub late: i32,
+    pub absent: i32,
+    pub excused: i32,
+    pub percentage: f64,
+}
+
+impl From<AttendanceSummary> for AttendanceSummaryResponseDTO {
+    fn from(summary: AttendanceSummary) -> Self {
+        Self {
+            student_id: summary.student_id,
+            course_id: summary.course_id,
+            sessions: summary.sessions,
+            present: summary.present,
+            late: summary.late,
+            absent: summary.absent,
+            excused: summary.excused,
+            percentage: summary.percentage,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::super::dto::enums_dto::AuditActionSchema;
+use crate::domain::models::{audit_log::AuditLog, enums::AuditAction};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogResponseDTO {
+    pub id: String,
+    pub actor_id: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    #[schema(value_type = AuditActionSchema)]
+    pub action: AuditAction,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: Option<String>,
+}
+
+impl From<AuditLog> for AuditLogResponseDTO {
+    fn from(log: AuditLog) -> Self {
+        Self {
+            id: log.id,
+            actor_id: log.actor_id,
+            entity_type: log.entity_type,
+            entity_id: log.entity_id,
+            action: log.action,
+            before: log.before,
+            after: log.after,
+            created_at: log.created_at,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::super::dto::enums_dto::BookingStatusSchema;
+use crate::domain::models::{
+    booking::FacilityBooking, enums::BookingStatus, validation::ValidationError,
+};
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Cuerpo para solicitar una reserva puntual de un aula.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BookingDTO {
+    pub facility_id: String,
+    pub purpose: String,
+    pub date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+}
+
+impl BookingDTO {
+    /// Mismas reglas que `FacilityBooking::validate`.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.clone()
+            .into_booking(String::new(), String::new())
+            .validate()
+    }
+
+    pub fn into_booking(self, id: String, requested_by: String) -> FacilityBooking {
+        FacilityBooking {
+            id,
+            facility_id: self.facility_id,
+            requested_by,
+            purpose: self.purpose,
+            date: self.date,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            status: BookingStatus::Pending,
+            reviewed_by: None,
+            created_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BookingResponseDTO {
+    pub id: String,
+    pub facility_id: String,
+    pub requested_by: String,
+    pub purpose: String,
+    pub date: NaiveDate,
+    pub start_time: String,
+    pub end_time: String,
+    #[schema(value_type = BookingStatusSchema)]
+    pub status: BookingStatus,
+    pub reviewed_by: Option<String>,
+    pub created_at: Option<String>,
+}
+
+impl From<FacilityBooking> for BookingResponseDTO {
+    fn from(booking: FacilityBooking) -> Self {
+        Self {
+            id: booking.id,
+            facility_id: booking.facility_id,
+            requested_by: booking.requested_by,
+            purpose: booking.purpose,
+            date: booking.date,
+            start_time: booking.start_time.format("%H:%M").to_string(),
+            end_time: booking.end_time.format("%H:%M").to_string(),
+            status: booking.status,
+            reviewed_by: booking.reviewed_by,
+            created_at: booking.created_at,
+        }
+    }
+}
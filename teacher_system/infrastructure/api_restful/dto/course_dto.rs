@@ -1,30 +1,111 @@
+use super::super::dto::enums_dto::CurriculumSchema;
 use super::super::dto::schedule_dto::ScheduleDTO;
-use crate::domain::models::enums::{Curriculum, SessionType};
+use crate::domain::models::{course::Course, enums::Curriculum, validation::ValidationError};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Cuerpo de creación y actualización de un curso.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CourseDTO {
     pub code: String,
     pub name: String,
     pub section: i32,
+    #[schema(value_type = CurriculumSchema)]
     pub curriculum: Curriculum,
     pub capacity: i32,
     pub credits: i32,
     pub hours_per_week: i32,
     pub cycle: i32,
+    /// Vacío mientras el curso espera la asignación de un profesor
+    #[serde(default)]
+    pub teacher_id: String,
     pub semester: String,
     pub academic_year: i32,
-    pub session_type: SessionType,
+    /// Si se omite al actualizar, el curso conserva su programa
+    #[serde(default)]
+    pub program: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl CourseDTO {
+    /// Mismas reglas que `Course::validate`.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.clone().into_course(String::new()).validate()
+    }
+
+    /// Construye el curso completo; `enrolled`, `facility_id` y `active` los
+    /// gestiona el servidor.
+    pub fn into_course(self, id: String) -> Course {
+        Course {
+            id,
+            code: self.code,
+            name: self.name,
+            section: self.section,
+            curriculum: self.curriculum,
+            capacity: self.capacity,
+            credits: self.credits,
+            hours_per_week: self.hours_per_week,
+            cycle: self.cycle,
+            teacher_id: self.teacher_id,
+            facility_id: String::new(),
+            enrolled: 0,
+            semester: self.semester,
+            academic_year: self.academic_year,
+            program: self.program,
+            active: true,
+            version: 0,
+        }
+    }
+}
+
+/// Cuerpo para registrar un curso extracurricular junto con su sesión.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExtracourseDTO {
+    pub course: CourseDTO,
+    pub schedule: ScheduleDTO,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CourseResponseDTO {
     pub id: String,
     pub code: String,
     pub name: String,
     pub section: i32,
-    pub schedule: ScheduleDTO,
+    #[schema(value_type = CurriculumSchema)]
+    pub curriculum: Curriculum,
+    pub credits: i32,
+    pub hours_per_week: i32,
+    pub cycle: i32,
+    pub teacher_id: String,
+    pub semester: String,
+    pub academic_year: i32,
+    pub program: String,
     pub enrolled: i32,
     pub capacity: i32,
     pub available_spots: i32,
+    pub active: bool,
+    pub version: i32,
+}
+
+impl From<Course> for CourseResponseDTO {
+    fn from(course: Course) -> Self {
+        Self {
+            available_spots: course.available_spots(),
+            id: course.id,
+            code: course.code,
+            name: course.name,
+            section: course.section,
+            curriculum: course.curriculum,
+            credits: course.credits,
+            hours_per_week: course.hours_per_week,
+            cycle: course.cycle,
+            teacher_id: course.teacher_id,
+            semester: course.semester,
+            academic_year: course.academic_year,
+            program: course.program,
+            enrolled: course.enrolled,
+            capacity: course.capacity,
+            active: course.active,
+            version: course.version,
+        }
+    }
 }
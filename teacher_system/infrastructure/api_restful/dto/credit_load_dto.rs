@@ -0,0 +1,158 @@
+use super::super::dto::enums_dto::{OverloadStatusSchema, StudentStatusSchema};
+use crate::domain::models::{
+    credit_load::{CreditLimit, CreditLoadPolicy, OverloadRequest, TermLoad},
+    enums::{OverloadStatus, StudentStatus},
+    validation::ValidationError,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Cuerpo para pedir una sobrecarga de créditos en un semestre.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OverloadRequestDTO {
+    /// Por defecto, quien hace el pedido
+    pub student_id: Option<String>,
+    pub academic_year: i32,
+    pub semester: String,
+    pub requested_credits: i32,
+    pub reason: String,
+}
+
+impl OverloadRequestDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.semester.trim().is_empty() {
+            errors.add("semester", "is required");
+        }
+        if self.reason.trim().is_empty() {
+            errors.add("reason", "is required");
+        }
+        if self.requested_credits <= 0 {
+            errors.add("requested_credits", "must be greater than 0");
+        }
+
+        errors.into_result()
+    }
+
+    pub fn into_request(self, id: String, requested_by: String) -> OverloadRequest {
+        OverloadRequest {
+            id,
+            student_id: self.student_id.unwrap_or_else(|| requested_by.clone()),
+            academic_year: self.academic_year,
+            semester: self.semester,
+            requested_credits: self.requested_credits,
+            reason: self.reason,
+            status: OverloadStatus::Pending,
+            requested_by,
+            reviewed_by: None,
+            review_note: None,
+            created_at: None,
+        }
+    }
+}
+
+/// Comentario opcional del coordinador al aprobar o rechazar.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct ReviewOverloadDTO {
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OverloadRequestResponseDTO {
+    pub id: String,
+    pub student_id: String,
+    pub academic_year: i32,
+    pub semester: String,
+    pub requested_credits: i32,
+    pub reason: String,
+    #[schema(value_type = OverloadStatusSchema)]
+    pub status: OverloadStatus,
+    pub requested_by: String,
+    pub reviewed_by: Option<String>,
+    pub review_note: Option<String>,
+    pub created_at: Option<String>,
+}
+
+impl From<OverloadRequest> for OverloadRequestResponseDTO {
+    fn from(request: OverloadRequest) -> Self {
+        Self {
+            id: request.id,
+            student_id: request.student_id,
+            academic_year: request.academic_year,
+            semester: request.semester,
+            requested_credits: request.requested_credits,
+            reason: request.reason,
+            status: request.status,
+            requested_by: request.requested_by,
+            reviewed_by: request.reviewed_by,
+            review_note: request.review_note,
+            created_at: request.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TermLoadResponseDTO {
+    pub student_id: String,
+    pub academic_year: i32,
+    pub semester: String,
+    pub student_status: Option<String>,
+    pub enrolled_credits: i32,
+    pub pending_credits: i32,
+    pub min_credits: Option<i32>,
+    pub max_credits: Option<i32>,
+    pub approved_credits: Option<i32>,
+    pub effective_max: Option<i32>,
+    pub below_minimum: bool,
+}
+
+impl From<TermLoad> for TermLoadResponseDTO {
+    fn from(load: TermLoad) -> Self {
+        Self {
+            effective_max: load.effective_max(),
+            below_minimum: load.below_minimum(),
+            student_id: load.student_id,
+            academic_year: load.academic_year,
+            semester: load.semester,
+            student_status: load.student_status.map(|s| s.to_string()),
+            enrolled_credits: load.enrolled_credits,
+            pending_credits: load.pending_credits,
+            min_credits: load.min_credits,
+            max_credits: load.max_credits,
+            approved_credits: load.approved_credits,
+        }
+    }
+}
+
+/// Créditos mínimos y máximos por semestre para un estado académico.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreditLimitResponseDTO {
+    #[schema(value_type = StudentStatusSchema)]
+    pub status: StudentStatus,
+    pub min_credits: i32,
+    pub max_credits: i32,
+}
+
+impl From<CreditLimit> for CreditLimitResponseDTO {
+    fn from(limit: CreditLimit) -> Self {
+        Self {
+            status: limit.status,
+            min_credits: limit.min_credits,
+            max_credits: limit.max_credits,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreditLoadPolicyResponseDTO {
+    pub limits: Vec<CreditLimitResponseDTO>,
+}
+
+impl From<CreditLoadPolicy> for CreditLoadPolicyResponseDTO {
+    fn from(policy: CreditLoadPolicy) -> Self {
+        Self {
+            limits: policy.limits.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::super::dto::enums_dto::CurriculumSchema;
+use crate::domain::models::{
+    demand_forecast::{CourseDemand, DemandForecast},
+    enums::Curriculum,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Demanda estimada de un curso del plan para el próximo semestre.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CourseDemandResponseDTO {
+    pub code: String,
+    pub name: String,
+    pub program: String,
+    pub cycle: i32,
+    #[schema(value_type = CurriculumSchema)]
+    pub curriculum: Curriculum,
+    pub elective_group: Option<String>,
+    /// Alumnos que podrán llevarlo según su avance en el plan.
+    pub eligible_students: i32,
+    /// Matriculados por semestre en los últimos semestres dictados.
+    pub historical_average: Option<f64>,
+    pub estimated_demand: i32,
+    /// Aforo del aula apta más grande; `None` si ninguna sirve.
+    pub max_room_capacity: Option<i32>,
+    pub suggested_sections: i32,
+    pub suggested_capacity: i32,
+    pub notes: Vec<String>,
+}
+
+impl From<CourseDemand> for CourseDemandResponseDTO {
+    fn from(demand: CourseDemand) -> Self {
+        Self {
+            code: demand.code,
+            name: demand.name,
+            program: demand.program,
+            cycle: demand.cycle,
+            curriculum: demand.curriculum,
+            elective_group: demand.elective_group,
+            eligible_students: demand.eligible_students,
+            historical_average: demand.historical_average,
+            estimated_demand: demand.estimated_demand,
+            max_room_capacity: demand.max_room_capacity,
+            suggested_sections: demand.suggested_sections,
+            suggested_capacity: demand.suggested_capacity,
+            notes: demand.notes,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DemandForecastResponseDTO {
+    pub academic_year: i32,
+    pub semester: String,
+    pub courses: Vec<CourseDemandResponseDTO>,
+    pub total_sections: i32,
+}
+
+impl From<DemandForecast> for DemandForecastResponseDTO {
+    fn from(forecast: DemandForecast) -> Self {
+        Self {
+            academic_year: forecast.academic_year,
+            semester: forecast.semester,
+            courses: forecast.courses.into_iter().map(Into::into).collect(),
+            total_sections: forecast.total_sections,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::domain::models::{course::Course, enrollment::Enrollment};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EnrollmentResponseDTO {
+    pub id: String,
+    pub student_id: String,
+    pub course_id: String,
+    pub status: String,
+    pub final_grade: Option<f64>,
+}
+
+impl From<Enrollment> for EnrollmentResponseDTO {
+    fn from(enrollment: Enrollment) -> Self {
+        Self {
+            id: enrollment.id,
+            student_id: enrollment.student_id,
+            course_id: enrollment.course_id,
+            status: enrollment.status.to_string(),
+            final_grade: enrollment.final_grade,
+        }
+    }
+}
+
+/// Resultado de revisar si un alumno puede llevar un curso.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PrerequisiteCheckResponseDTO {
+    pub course_id: String,
+    pub student_id: String,
+    pub satisfied: bool,
+    pub missing: Vec<String>,
+}
+
+impl PrerequisiteCheckResponseDTO {
+    pub fn new(course_id: String, student_id: String, missing: Vec<Course>) -> Self {
+        Self {
+            course_id,
+            student_id,
+            satisfied: missing.is_empty(),
+            missing: missing.into_iter().map(|c| c.code).collect(),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Esquemas de los enums del dominio tal como viajan en JSON. Los DTO siguen
+//! usando los enums del dominio y los documentan con
+//! `#[schema(value_type = ...)]`, así el dominio no depende de `utoipa`. Cada
+//! esquema debe repetir el `rename_all` de su enum.
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = Curriculum)]
+pub enum CurriculumSchema {
+    Obligatory,
+    Elective,
+    Prerequisite,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = Weekday)]
+pub enum WeekdaySchema {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = SessionType)]
+pub enum SessionTypeSchema {
+    Theory,
+    Laboratory,
+    Seminar,
+    Practice,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = StudentStatus)]
+pub enum StudentStatusSchema {
+    Regular,
+    Observation,
+    Graduated,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = ContractType)]
+pub enum ContractTypeSchema {
+    Contracted,
+    Principal,
+    Associate,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = AuditAction)]
+pub enum AuditActionSchema {
+    Create,
+    Update,
+    Delete,
+    Restore,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = BookingStatus)]
+#[serde(rename_all = "lowercase")]
+pub enum BookingStatusSchema {
+    Pending,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = FacilityType)]
+#[serde(rename_all = "snake_case")]
+pub enum FacilityTypeSchema {
+    Classroom,
+    Laboratory,
+    ComputerLab,
+    Auditorium,
+    SeminarRoom,
+    Workshop,
+    Other,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = EquipmentKind)]
+#[serde(rename_all = "snake_case")]
+pub enum EquipmentKindSchema {
+    Projector,
+    Computers,
+    LabBenches,
+    Whiteboard,
+    SoundSystem,
+    VideoConference,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = ExceptionKind)]
+#[serde(rename_all = "lowercase")]
+pub enum ExceptionKindSchema {
+    Cancelled,
+    Rescheduled,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = EventKind)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKindSchema {
+    ScheduleChanged,
+    EnrollmentConfirmed,
+    ClassCancelled,
+    ClassRescheduled,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = NotificationChannel)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationChannelSchema {
+    Email,
+    Webhook,
+    Log,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = AttendanceStatus)]
+#[serde(rename_all = "lowercase")]
+pub enum AttendanceStatusSchema {
+    Present,
+    Absent,
+    Late,
+    Excused,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = OverloadStatus)]
+#[serde(rename_all = "lowercase")]
+pub enum OverloadStatusSchema {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = AssignmentStatus)]
+#[serde(rename_all = "lowercase")]
+pub enum AssignmentStatusSchema {
+    Proposed,
+    Accepted,
+    Overridden,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = LoadStatus)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadStatusSchema {
+    Under,
+    Within,
+    Over,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = ConflictKind)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictKindSchema {
+    /// Dos sesiones del mismo curso se solapan.
+    SameCourse,
+    /// Se solapan cursos distintos que la misma sección del ciclo lleva en conjunto.
+    CrossSection,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(as = TimetableIssueKind)]
+#[serde(rename_all = "snake_case")]
+pub enum TimetableIssueKindSchema {
+    TeacherDoubleBooking,
+    FacilityDoubleBooking,
+    OverCapacity,
+    SessionTypeMismatch,
+}
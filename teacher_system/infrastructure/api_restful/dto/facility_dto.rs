@@ -0,0 +1,156 @@
+use super::super::dto::enums_dto::{EquipmentKindSchema, FacilityTypeSchema, WeekdaySchema};
+use crate::domain::models::{
+    enums::{EquipmentKind, FacilityType, Weekday},
+    facilitie::{Accessibility, EquipmentItem, Facility},
+    facilitie_available::FacilityAvailable,
+    validation::ValidationError,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EquipmentItemDTO {
+    #[schema(value_type = EquipmentKindSchema)]
+    pub kind: EquipmentKind,
+    pub quantity: i32,
+}
+
+impl From<EquipmentItem> for EquipmentItemDTO {
+    fn from(item: EquipmentItem) -> Self {
+        Self {
+            kind: item.kind,
+            quantity: item.quantity,
+        }
+    }
+}
+
+impl From<EquipmentItemDTO> for EquipmentItem {
+    fn from(item: EquipmentItemDTO) -> Self {
+        Self {
+            kind: item.kind,
+            quantity: item.quantity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct AccessibilityDTO {
+    pub wheelchair_accessible: bool,
+    pub step_free_access: bool,
+    pub hearing_loop: bool,
+}
+
+impl From<Accessibility> for AccessibilityDTO {
+    fn from(accessibility: Accessibility) -> Self {
+        Self {
+            wheelchair_accessible: accessibility.wheelchair_accessible,
+            step_free_access: accessibility.step_free_access,
+            hearing_loop: accessibility.hearing_loop,
+        }
+    }
+}
+
+impl From<AccessibilityDTO> for Accessibility {
+    fn from(accessibility: AccessibilityDTO) -> Self {
+        Self {
+            wheelchair_accessible: accessibility.wheelchair_accessible,
+            step_free_access: accessibility.step_free_access,
+            hearing_loop: accessibility.hearing_loop,
+        }
+    }
+}
+
+/// Cuerpo de creación y actualización de un aula o instalación.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FacilityDTO {
+    pub name: String,
+    pub capacity: i32,
+    #[schema(value_type = FacilityTypeSchema)]
+    pub facility_type: FacilityType,
+    pub building: Option<String>,
+    pub floor: Option<i32>,
+    pub location: Option<String>,
+    #[serde(default)]
+    pub equipment: Vec<EquipmentItemDTO>,
+    #[serde(default)]
+    pub accessibility: AccessibilityDTO,
+}
+
+impl FacilityDTO {
+    /// Mismas reglas que `Facility::validate`.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.clone().into_facility(String::new()).validate()
+    }
+
+    pub fn into_facility(self, id: String) -> Facility {
+        Facility {
+            id,
+            name: self.name,
+            capacity: self.capacity,
+            facility_type: self.facility_type,
+            building: self.building,
+            floor: self.floor,
+            location: self.location,
+            equipment: self.equipment.into_iter().map(Into::into).collect(),
+            accessibility: self.accessibility.into(),
+            created_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FacilityResponseDTO {
+    pub id: String,
+    pub name: String,
+    pub capacity: i32,
+    #[schema(value_type = FacilityTypeSchema)]
+    pub facility_type: FacilityType,
+    pub building: Option<String>,
+    pub floor: Option<i32>,
+    pub location: Option<String>,
+    pub equipment: Vec<EquipmentItemDTO>,
+    pub accessibility: AccessibilityDTO,
+    pub created_at: Option<String>,
+}
+
+impl From<Facility> for FacilityResponseDTO {
+    fn from(facility: Facility) -> Self {
+        Self {
+            id: facility.id,
+            name: facility.name,
+            capacity: facility.capacity,
+            facility_type: facility.facility_type,
+            building: facility.building,
+            floor: facility.floor,
+            location: facility.location,
+            equipment: facility.equipment.into_iter().map(Into::into).collect(),
+            accessibility: facility.accessibility.into(),
+            created_at: facility.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FacilityAvailableResponseDTO {
+    pub id: String,
+    pub name: String,
+    pub capacity: i32,
+    #[schema(value_type = FacilityTypeSchema)]
+    pub facility_type: FacilityType,
+    #[schema(value_type = WeekdaySchema)]
+    pub day: Weekday,
+    pub hours_range: Vec<(u32, u32)>,
+}
+
+impl From<FacilityAvailable> for FacilityAvailableResponseDTO {
+    fn from(available: FacilityAvailable) -> Self {
+        Self {
+            id: available.id,
+            name: available.name,
+            capacity: available.capacity,
+            facility_type: available.facility_type,
+            day: available.day,
+            hours_range: available.hours_range,
+        }
+    }
+}
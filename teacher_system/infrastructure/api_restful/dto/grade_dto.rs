@@ -0,0 +1,167 @@
+use crate::domain::models::{
+    grade::{
+        ComponentGrade, DEFAULT_PASSING_GRADE, EnrollmentGrades, GradeComponent, GradingScheme,
+        MAX_GRADE,
+    },
+    validation::ValidationError,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Evaluación del curso y su peso porcentual en la nota final.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GradeComponentDTO {
+    pub name: String,
+    pub weight: f64,
+}
+
+impl From<GradeComponent> for GradeComponentDTO {
+    fn from(component: GradeComponent) -> Self {
+        Self {
+            name: component.name,
+            weight: component.weight,
+        }
+    }
+}
+
+impl From<GradeComponentDTO> for GradeComponent {
+    fn from(component: GradeComponentDTO) -> Self {
+        Self {
+            name: component.name,
+            weight: component.weight,
+        }
+    }
+}
+
+/// Cuerpo para reemplazar el esquema de calificación de un curso.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GradingSchemeDTO {
+    pub components: Vec<GradeComponentDTO>,
+    /// 10.5 por defecto
+    pub passing_grade: Option<f64>,
+}
+
+impl GradingSchemeDTO {
+    pub fn into_scheme(self, course_id: String) -> GradingScheme {
+        GradingScheme {
+            course_id,
+            components: self.components.into_iter().map(Into::into).collect(),
+            passing_grade: self.passing_grade.unwrap_or(DEFAULT_PASSING_GRADE),
+            updated_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GradingSchemeResponseDTO {
+    pub course_id: String,
+    pub components: Vec<GradeComponentDTO>,
+    pub passing_grade: f64,
+    pub updated_at: Option<String>,
+}
+
+impl From<GradingScheme> for GradingSchemeResponseDTO {
+    fn from(scheme: GradingScheme) -> Self {
+        Self {
+            course_id: scheme.course_id,
+            components: scheme.components.into_iter().map(Into::into).collect(),
+            passing_grade: scheme.passing_grade,
+            updated_at: scheme.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GradeEntryDTO {
+    pub enrollment_id: String,
+    pub score: f64,
+}
+
+/// Cuerpo para registrar las notas de una evaluación.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RecordGradesDTO {
+    pub component: String,
+    pub entries: Vec<GradeEntryDTO>,
+}
+
+impl RecordGradesDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.component.trim().is_empty() {
+            errors.add("component", "is required");
+        }
+        if self.entries.is_empty() {
+            errors.add("entries", "must include at least one enrollment");
+        }
+
+        let mut seen: Vec<&str> = Vec::new();
+        for entry in &self.entries {
+            if seen.contains(&entry.enrollment_id.as_str()) {
+                errors.add("entries", &format!("{} is repeated", entry.enrollment_id));
+            }
+            if !(0.0..=MAX_GRADE).contains(&entry.score) {
+                errors.add(
+                    "entries",
+                    &format!("score for {} must be between 0 and {}", entry.enrollment_id, MAX_GRADE),
+                );
+            }
+            seen.push(&entry.enrollment_id);
+        }
+
+        errors.into_result()
+    }
+
+    pub fn into_entries(self) -> Vec<(String, f64)> {
+        self.entries
+            .into_iter()
+            .map(|e| (e.enrollment_id, e.score))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ComponentGradeResponseDTO {
+    pub component: String,
+    pub score: f64,
+    pub recorded_by: String,
+    pub recorded_at: Option<String>,
+}
+
+impl From<ComponentGrade> for ComponentGradeResponseDTO {
+    fn from(grade: ComponentGrade) -> Self {
+        Self {
+            component: grade.component,
+            score: grade.score,
+            recorded_by: grade.recorded_by,
+            recorded_at: grade.recorded_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EnrollmentGradesResponseDTO {
+    pub enrollment_id: String,
+    pub student_id: String,
+    pub status: String,
+    pub components: Vec<ComponentGradeResponseDTO>,
+    pub running_grade: f64,
+    pub final_grade: Option<f64>,
+}
+
+impl From<EnrollmentGrades> for EnrollmentGradesResponseDTO {
+    fn from(grades: EnrollmentGrades) -> Self {
+        Self {
+            enrollment_id: grades.enrollment_id,
+            student_id: grades.student_id,
+            status: grades.status.to_string(),
+            components: grades
+                .components
+                .into_iter()
+                .map(ComponentGradeResponseDTO::from)
+                .collect(),
+            running_grade: grades.running_grade,
+            final_grade: grades.final_grade,
+        }
+    }
+}
@@ -1,3 +1,23 @@
+pub mod attendance_dto;
+pub mod audit_dto;
+pub mod booking_dto;
 pub mod course_dto;
+pub mod credit_load_dto;
+pub mod demand_forecast_dto;
+pub mod enrollment_dto;
+pub mod enums_dto;
+pub mod facility_dto;
+pub mod grade_dto;
+pub mod notification_dto;
 pub mod schedule_dto;
+pub mod section_dto;
+pub mod session_exception_dto;
+pub mod student_status_dto;
+pub mod study_plan_dto;
+pub mod teacher_assignment_dto;
 pub mod teacher_dto;
+pub mod teaching_load_dto;
+pub mod transcript_dto;
+pub mod user_dto;
+pub mod validation_dto;
+pub mod webhook_dto;
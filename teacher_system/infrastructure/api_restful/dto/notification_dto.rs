@@ -0,0 +1,49 @@
+use super::super::dto::enums_dto::{EventKindSchema, NotificationChannelSchema};
+use crate::domain::models::{
+    enums::{EventKind, NotificationChannel},
+    notification::NotificationPreference,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Cuerpo para reemplazar las preferencias de aviso de un usuario.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferenceDTO {
+    #[schema(value_type = Vec<NotificationChannelSchema>)]
+    pub channels: Vec<NotificationChannel>,
+    #[serde(default)]
+    #[schema(value_type = Vec<EventKindSchema>)]
+    pub muted_events: Vec<EventKind>,
+}
+
+impl NotificationPreferenceDTO {
+    pub fn into_preference(self, user_id: String) -> NotificationPreference {
+        NotificationPreference {
+            user_id,
+            channels: self.channels,
+            muted_events: self.muted_events,
+            updated_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferenceResponseDTO {
+    pub user_id: String,
+    #[schema(value_type = Vec<NotificationChannelSchema>)]
+    pub channels: Vec<NotificationChannel>,
+    #[schema(value_type = Vec<EventKindSchema>)]
+    pub muted_events: Vec<EventKind>,
+    pub updated_at: Option<String>,
+}
+
+impl From<NotificationPreference> for NotificationPreferenceResponseDTO {
+    fn from(preference: NotificationPreference) -> Self {
+        Self {
+            user_id: preference.user_id,
+            channels: preference.channels,
+            muted_events: preference.muted_events,
+            updated_at: preference.updated_at,
+        }
+    }
+}
@@ -1,25 +1,226 @@
-use crate::domain::models::enums::{SessionType, Weekday};
+use super::super::dto::facility_dto::EquipmentItemDTO;
+use super::super::dto::enums_dto::{ConflictKindSchema, SessionTypeSchema, TimetableIssueKindSchema, WeekdaySchema};
+use crate::domain::models::{
+    conflict::{ConflictKind, ScheduleConflict, TimetableIssue, TimetableIssueKind},
+    enums::{SessionType, Weekday},
+    schedule::Schedule,
+    validation::ValidationError,
+    weekly_grid::{GridDay, GridSession, WeeklyGrid},
+};
 use chrono::NaiveTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Cuerpo de creación y actualización de una sesión.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ScheduleDTO {
+    #[schema(value_type = WeekdaySchema)]
     pub day: Weekday,
     pub course_id: String,
     pub start_time: NaiveTime,
     pub end_time: NaiveTime,
+    #[schema(value_type = SessionTypeSchema)]
     pub session_type: SessionType,
+    /// Vacío para que el servidor asigne el aula más ajustada
+    #[serde(default)]
     pub facility_id: String,
-    pub location_detail: String,
+    pub location_detail: Option<String>,
+    #[serde(default)]
+    pub required_equipment: Vec<EquipmentItemDTO>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ScheduleDTO {
+    /// Mismas reglas que `Schedule::validate_request`: el aula puede faltar.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.clone().into_schedule(String::new()).validate_request()
+    }
+
+    pub fn into_schedule(self, id: String) -> Schedule {
+        Schedule {
+            id,
+            course_id: self.course_id,
+            day: self.day,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            session_type: self.session_type,
+            location_detail: self.location_detail,
+            created_at: None,
+            facility_id: self.facility_id,
+            required_equipment: self.required_equipment.into_iter().map(Into::into).collect(),
+            version: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ScheduleResponseDTO {
     pub id: String,
-    pub facility_name: String,
+    pub course_id: String,
+    pub facility_id: String,
+    #[schema(value_type = WeekdaySchema)]
     pub day: Weekday,
     pub start_time: String,
     pub end_time: String,
+    #[schema(value_type = SessionTypeSchema)]
     pub session_type: SessionType,
-    pub location_detail: String,
+    pub location_detail: Option<String>,
+    pub required_equipment: Vec<EquipmentItemDTO>,
+    pub version: i32,
+}
+
+impl From<Schedule> for ScheduleResponseDTO {
+    fn from(schedule: Schedule) -> Self {
+        Self {
+            id: schedule.id,
+            course_id: schedule.course_id,
+            facility_id: schedule.facility_id,
+            day: schedule.day,
+            start_time: schedule.start_time.format("%H:%M").to_string(),
+            end_time: schedule.end_time.format("%H:%M").to_string(),
+            session_type: schedule.session_type,
+            location_detail: schedule.location_detail,
+            required_equipment: schedule.required_equipment.into_iter().map(Into::into).collect(),
+            version: schedule.version,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScheduleConflictResponseDTO {
+    #[schema(value_type = ConflictKindSchema)]
+    pub kind: ConflictKind,
+    pub first: ScheduleResponseDTO,
+    pub second: ScheduleResponseDTO,
+}
+
+impl From<ScheduleConflict> for ScheduleConflictResponseDTO {
+    fn from(conflict: ScheduleConflict) -> Self {
+        Self {
+            kind: conflict.kind,
+            first: ScheduleResponseDTO::from(conflict.first),
+            second: ScheduleResponseDTO::from(conflict.second),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimetableIssueResponseDTO {
+    #[schema(value_type = TimetableIssueKindSchema)]
+    pub kind: TimetableIssueKind,
+    pub schedule_ids: Vec<String>,
+    pub course_ids: Vec<String>,
+    pub teacher_id: Option<String>,
+    pub facility_id: Option<String>,
+    pub detail: String,
+}
+
+impl From<TimetableIssue> for TimetableIssueResponseDTO {
+    fn from(issue: TimetableIssue) -> Self {
+        Self {
+            kind: issue.kind,
+            schedule_ids: issue.schedule_ids,
+            course_ids: issue.course_ids,
+            teacher_id: issue.teacher_id,
+            facility_id: issue.facility_id,
+            detail: issue.detail,
+        }
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Reporte de auditoría en CSV; los IDs múltiples van separados por `;`.
+pub fn timetable_issues_csv(issues: &[TimetableIssueResponseDTO]) -> String {
+    let mut csv = String::from("kind,schedule_ids,course_ids,teacher_id,facility_id,detail\n");
+
+    for issue in issues {
+        let row = [
+            issue.kind.to_string(),
+            issue.schedule_ids.join(";"),
+            issue.course_ids.join(";"),
+            issue.teacher_id.clone().unwrap_or_default(),
+            issue.facility_id.clone().unwrap_or_default(),
+            issue.detail.clone(),
+        ];
+        let row: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GridSessionResponseDTO {
+    pub schedule_id: String,
+    pub course_id: String,
+    pub course_code: String,
+    pub course_name: String,
+    pub section: i32,
+    pub start_time: String,
+    pub end_time: String,
+    #[schema(value_type = SessionTypeSchema)]
+    pub session_type: SessionType,
+    pub facility_id: String,
+    pub clashes: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GridDayResponseDTO {
+    #[schema(value_type = WeekdaySchema)]
+    pub day: Weekday,
+    pub sessions: Vec<GridSessionResponseDTO>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WeeklyGridResponseDTO {
+    pub program: String,
+    pub cycle: i32,
+    pub academic_year: i32,
+    pub semester: String,
+    pub days: Vec<GridDayResponseDTO>,
+}
+
+impl From<GridSession> for GridSessionResponseDTO {
+    fn from(session: GridSession) -> Self {
+        Self {
+            schedule_id: session.schedule_id,
+            course_id: session.course_id,
+            course_code: session.course_code,
+            course_name: session.course_name,
+            section: session.section,
+            start_time: session.start_time.format("%H:%M").to_string(),
+            end_time: session.end_time.format("%H:%M").to_string(),
+            session_type: session.session_type,
+            facility_id: session.facility_id,
+            clashes: session.clashes,
+        }
+    }
+}
+
+impl From<GridDay> for GridDayResponseDTO {
+    fn from(day: GridDay) -> Self {
+        Self {
+            day: day.day,
+            sessions: day.sessions.into_iter().map(GridSessionResponseDTO::from).collect(),
+        }
+    }
+}
+
+impl From<WeeklyGrid> for WeeklyGridResponseDTO {
+    fn from(grid: WeeklyGrid) -> Self {
+        Self {
+            program: grid.program,
+            cycle: grid.cycle,
+            academic_year: grid.academic_year,
+            semester: grid.semester,
+            days: grid.days.into_iter().map(GridDayResponseDTO::from).collect(),
+        }
+    }
 }
@@ -0,0 +1,207 @@
+use super::super::dto::{course_dto::CourseResponseDTO, schedule_dto::ScheduleResponseDTO};
+use super::super::dto::facility_dto::EquipmentItemDTO;
+use super::super::dto::enums_dto::{SessionTypeSchema, WeekdaySchema};
+use crate::domain::models::{
+    enums::{SessionType, Weekday},
+    schedule::Schedule,
+    section::{
+        SectionMerge, SectionMergeResult, SectionSplit, SectionSplitResult, TermClone,
+        TermCloneResult,
+    },
+    validation::ValidationError,
+};
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Cuerpo para copiar las secciones de un semestre a otro.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CloneTermDTO {
+    pub from_academic_year: i32,
+    pub from_semester: String,
+    pub to_academic_year: i32,
+    pub to_semester: String,
+    pub program: Option<String>,
+}
+
+impl CloneTermDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.from_semester.trim().is_empty() {
+            errors.add("from_semester", "is required");
+        }
+        if self.to_semester.trim().is_empty() {
+            errors.add("to_semester", "is required");
+        }
+
+        errors.into_result()
+    }
+
+    pub fn into_request(self) -> TermClone {
+        TermClone {
+            from_academic_year: self.from_academic_year,
+            from_semester: self.from_semester,
+            to_academic_year: self.to_academic_year,
+            to_semester: self.to_semester,
+            program: self.program,
+        }
+    }
+}
+
+/// Sesión de la sección nueva al dividir.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SectionSessionDTO {
+    #[schema(value_type = WeekdaySchema)]
+    pub day: Weekday,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    #[schema(value_type = SessionTypeSchema)]
+    pub session_type: SessionType,
+    /// Vacío para que el servidor asigne un aula libre
+    #[serde(default)]
+    pub facility_id: String,
+    pub location_detail: Option<String>,
+    #[serde(default)]
+    pub required_equipment: Vec<EquipmentItemDTO>,
+}
+
+/// Cuerpo para abrir una sección con parte de las matrículas de otra.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SplitSectionDTO {
+    pub enrollment_ids: Vec<String>,
+    pub teacher_id: Option<String>,
+    pub capacity: Option<i32>,
+    /// Sin sesiones se copian las de la sección de origen
+    #[serde(default)]
+    pub sessions: Vec<SectionSessionDTO>,
+}
+
+impl SplitSectionDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.enrollment_ids.is_empty() {
+            errors.add("enrollment_ids", "must not be empty");
+        }
+        if self.capacity.is_some_and(|c| c <= 0) {
+            errors.add("capacity", "must be positive");
+        }
+        if self.sessions.iter().any(|s| s.start_time >= s.end_time) {
+            errors.add("sessions", "start_time must be before end_time");
+        }
+
+        errors.into_result()
+    }
+
+    pub fn into_split(self) -> SectionSplit {
+        let mut enrollment_ids = self.enrollment_ids;
+        enrollment_ids.sort();
+        enrollment_ids.dedup();
+
+        SectionSplit {
+            enrollment_ids,
+            teacher_id: self.teacher_id,
+            capacity: self.capacity,
+            sessions: self
+                .sessions
+                .into_iter()
+                .map(|s| Schedule {
+                    id: String::new(),
+                    course_id: String::new(),
+                    day: s.day,
+                    start_time: s.start_time,
+                    end_time: s.end_time,
+                    session_type: s.session_type,
+                    location_detail: s.location_detail,
+                    created_at: None,
+                    facility_id: s.facility_id,
+                    required_equipment: s.required_equipment.into_iter().map(Into::into).collect(),
+                    version: 0,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Cuerpo para juntar otras secciones en la indicada en la ruta.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MergeSectionsDTO {
+    pub section_ids: Vec<String>,
+    pub capacity: Option<i32>,
+}
+
+impl MergeSectionsDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.section_ids.is_empty() {
+            errors.add("section_ids", "must not be empty");
+        }
+        if self.capacity.is_some_and(|c| c <= 0) {
+            errors.add("capacity", "must be positive");
+        }
+
+        errors.into_result()
+    }
+
+    pub fn into_merge(self) -> SectionMerge {
+        SectionMerge {
+            section_ids: self.section_ids,
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TermCloneResponseDTO {
+    pub created: Vec<CourseResponseDTO>,
+    pub sessions_created: i32,
+    pub skipped: Vec<String>,
+}
+
+impl From<TermCloneResult> for TermCloneResponseDTO {
+    fn from(result: TermCloneResult) -> Self {
+        Self {
+            created: result.created.into_iter().map(CourseResponseDTO::from).collect(),
+            sessions_created: result.sessions_created,
+            skipped: result.skipped,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SectionSplitResponseDTO {
+    pub source: CourseResponseDTO,
+    pub section: CourseResponseDTO,
+    pub sessions: Vec<ScheduleResponseDTO>,
+    pub moved: Vec<String>,
+}
+
+impl From<SectionSplitResult> for SectionSplitResponseDTO {
+    fn from(result: SectionSplitResult) -> Self {
+        Self {
+            source: result.source.into(),
+            section: result.section.into(),
+            sessions: result.sessions.into_iter().map(ScheduleResponseDTO::from).collect(),
+            moved: result.moved,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SectionMergeResponseDTO {
+    pub section: CourseResponseDTO,
+    pub moved: i32,
+    pub archived: Vec<String>,
+}
+
+impl From<SectionMergeResult> for SectionMergeResponseDTO {
+    fn from(result: SectionMergeResult) -> Self {
+        Self {
+            section: result.section.into(),
+            moved: result.moved,
+            archived: result.archived,
+        }
+    }
+}
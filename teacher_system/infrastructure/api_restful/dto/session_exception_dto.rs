@@ -0,0 +1,81 @@
+use super::super::dto::enums_dto::ExceptionKindSchema;
+use crate::domain::models::{
+    enums::ExceptionKind,
+    session_exception::{MakeUpSlot, SessionException},
+};
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Cuerpo para cancelar una fecha concreta de una sesión.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CancelOccurrenceDTO {
+    pub date: NaiveDate,
+    pub reason: String,
+}
+
+/// Cuerpo para mover una fecha concreta de una sesión; sin aula se usa la habitual.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RescheduleOccurrenceDTO {
+    pub date: NaiveDate,
+    pub reason: String,
+    pub new_date: NaiveDate,
+    pub new_start_time: NaiveTime,
+    pub new_end_time: NaiveTime,
+    pub new_facility_id: Option<String>,
+}
+
+impl RescheduleOccurrenceDTO {
+    pub fn make_up_slot(&self) -> MakeUpSlot {
+        MakeUpSlot {
+            date: self.new_date,
+            start_time: self.new_start_time,
+            end_time: self.new_end_time,
+            facility_id: self.new_facility_id.clone().unwrap_or_default(),
+            booking_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MakeUpSlotResponseDTO {
+    pub date: NaiveDate,
+    pub start_time: String,
+    pub end_time: String,
+    pub facility_id: String,
+    pub booking_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SessionExceptionResponseDTO {
+    pub id: String,
+    pub schedule_id: String,
+    pub original_date: NaiveDate,
+    #[schema(value_type = ExceptionKindSchema)]
+    pub kind: ExceptionKind,
+    pub reason: String,
+    pub make_up: Option<MakeUpSlotResponseDTO>,
+    pub created_by: String,
+    pub created_at: Option<String>,
+}
+
+impl From<SessionException> for SessionExceptionResponseDTO {
+    fn from(exception: SessionException) -> Self {
+        Self {
+            id: exception.id,
+            schedule_id: exception.schedule_id,
+            original_date: exception.original_date,
+            kind: exception.kind,
+            reason: exception.reason,
+            make_up: exception.make_up.map(|slot| MakeUpSlotResponseDTO {
+                date: slot.date,
+                start_time: slot.start_time.format("%H:%M").to_string(),
+                end_time: slot.end_time.format("%H:%M").to_string(),
+                facility_id: slot.facility_id,
+                booking_id: slot.booking_id,
+            }),
+            created_by: exception.created_by,
+            created_at: exception.created_at,
+        }
+    }
+}
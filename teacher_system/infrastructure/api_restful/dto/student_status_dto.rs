@@ -0,0 +1,88 @@
+use crate::domain::models::{
+    student_status::{StatusChange, StatusRule, StatusRuleSet},
+    validation::ValidationError,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Cuerpo para evaluar a los alumnos al cierre de un semestre.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EvaluateTermDTO {
+    pub academic_year: i32,
+    pub semester: String,
+}
+
+impl EvaluateTermDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.semester.trim().is_empty() {
+            errors.add("semester", "is required");
+        }
+        if self.academic_year < 1 {
+            errors.add("academic_year", "must be a valid year");
+        }
+
+        errors.into_result()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StatusChangeResponseDTO {
+    pub id: String,
+    pub student_id: String,
+    pub previous_status: Option<String>,
+    pub new_status: String,
+    pub reasons: Vec<String>,
+    pub changed_by: String,
+    pub changed_at: Option<String>,
+}
+
+impl From<StatusChange> for StatusChangeResponseDTO {
+    fn from(change: StatusChange) -> Self {
+        Self {
+            id: change.id,
+            student_id: change.student_id,
+            previous_status: change.previous_status.map(|s| s.to_string()),
+            new_status: change.new_status.to_string(),
+            reasons: change.reasons,
+            changed_by: change.changed_by,
+            changed_at: change.changed_at,
+        }
+    }
+}
+
+/// Regla que se evalúa sobre el historial del alumno al cerrar el semestre.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum StatusRuleResponseDTO {
+    /// Desaprobó el mismo curso `times` veces y aún no lo aprueba.
+    RepeatedFailure { times: i32 },
+    /// Promedio ponderado acumulado por debajo de `below`.
+    LowAverage { below: f64 },
+    /// Aprobó todos los créditos obligatorios del programa.
+    ProgramCompleted,
+}
+
+impl From<StatusRule> for StatusRuleResponseDTO {
+    fn from(rule: StatusRule) -> Self {
+        match rule {
+            StatusRule::RepeatedFailure { times } => Self::RepeatedFailure { times },
+            StatusRule::LowAverage { below } => Self::LowAverage { below },
+            StatusRule::ProgramCompleted => Self::ProgramCompleted,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StatusRuleSetResponseDTO {
+    pub rules: Vec<StatusRuleResponseDTO>,
+}
+
+impl From<StatusRuleSet> for StatusRuleSetResponseDTO {
+    fn from(rule_set: StatusRuleSet) -> Self {
+        Self {
+            rules: rule_set.rules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use super::super::dto::enums_dto::CurriculumSchema;
+use crate::domain::models::{
+    enums::Curriculum,
+    study_plan::{
+        CycleOffering, CycleProgress, ElectiveGroup, ElectiveGroupProgress, OfferedCourse,
+        PlanCourse, PlanProgress, StudyPlan,
+    },
+    validation::ValidationError,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use std::collections::HashMap;
+
+/// Cuerpo para publicar una nueva versión del plan de un programa.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StudyPlanDTO {
+    pub program: String,
+    pub faculty: String,
+    pub name: String,
+    pub courses: Vec<PlanCourseDTO>,
+    #[serde(default)]
+    pub elective_groups: Vec<ElectiveGroupDTO>,
+}
+
+/// Curso del plan, identificado por código para abarcar todas sus secciones.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PlanCourseDTO {
+    pub code: String,
+    pub name: String,
+    pub cycle: i32,
+    pub credits: i32,
+    #[schema(value_type = CurriculumSchema)]
+    pub curriculum: Curriculum,
+    /// Solo los electivos pertenecen a un grupo.
+    pub elective_group: Option<String>,
+}
+
+impl From<PlanCourseDTO> for PlanCourse {
+    fn from(course: PlanCourseDTO) -> Self {
+        Self {
+            code: course.code,
+            name: course.name,
+            cycle: course.cycle,
+            credits: course.credits,
+            curriculum: course.curriculum,
+            elective_group: course.elective_group,
+        }
+    }
+}
+
+/// Bolsa de electivos de la que el alumno debe aprobar `min_credits`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ElectiveGroupDTO {
+    pub name: String,
+    pub min_credits: i32,
+}
+
+impl From<ElectiveGroupDTO> for ElectiveGroup {
+    fn from(group: ElectiveGroupDTO) -> Self {
+        Self {
+            name: group.name,
+            min_credits: group.min_credits,
+        }
+    }
+}
+
+impl From<ElectiveGroup> for ElectiveGroupDTO {
+    fn from(group: ElectiveGroup) -> Self {
+        Self {
+            name: group.name,
+            min_credits: group.min_credits,
+        }
+    }
+}
+
+impl StudyPlanDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.program.trim().is_empty() {
+            errors.add("program", "is required");
+        }
+        if self.faculty.trim().is_empty() {
+            errors.add("faculty", "is required");
+        }
+        if self.name.trim().is_empty() {
+            errors.add("name", "is required");
+        }
+        if self.courses.is_empty() {
+            errors.add("courses", "must include at least one course");
+        }
+
+        errors.into_result()
+    }
+
+    /// La versión la asigna el caso de uso al crear el plan.
+    pub fn into_plan(self, id: String) -> StudyPlan {
+        StudyPlan {
+            id,
+            program: self.program,
+            faculty: self.faculty,
+            version: 0,
+            name: self.name,
+            active: false,
+            courses: self.courses.into_iter().map(Into::into).collect(),
+            elective_groups: self.elective_groups.into_iter().map(Into::into).collect(),
+            created_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PlanCourseResponseDTO {
+    pub code: String,
+    pub name: String,
+    pub cycle: i32,
+    pub credits: i32,
+    #[schema(value_type = CurriculumSchema)]
+    pub curriculum: Curriculum,
+    pub elective_group: Option<String>,
+    /// Códigos tomados de `course_prerequisites`.
+    pub prerequisites: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StudyPlanResponseDTO {
+    pub id: String,
+    pub program: String,
+    pub faculty: String,
+    pub version: i32,
+    pub name: String,
+    pub active: bool,
+    pub required_credits: i32,
+    pub courses: Vec<PlanCourseResponseDTO>,
+    pub elective_groups: Vec<ElectiveGroupDTO>,
+    pub created_at: Option<String>,
+}
+
+impl StudyPlanResponseDTO {
+    pub fn new(plan: StudyPlan, prerequisites: &HashMap<String, Vec<String>>) -> Self {
+        let mut courses: Vec<PlanCourseResponseDTO> = plan
+            .courses
+            .iter()
+            .map(|c| PlanCourseResponseDTO {
+                code: c.code.clone(),
+                name: c.name.clone(),
+                cycle: c.cycle,
+                credits: c.credits,
+                curriculum: c.curriculum.clone(),
+                elective_group: c.elective_group.clone(),
+                prerequisites: prerequisites.get(&c.code).cloned().unwrap_or_default(),
+            })
+            .collect();
+        courses.sort_by(|a, b| (a.cycle, &a.code).cmp(&(b.cycle, &b.code)));
+
+        Self {
+            required_credits: plan.required_credits(),
+            id: plan.id,
+            program: plan.program,
+            faculty: plan.faculty,
+            version: plan.version,
+            name: plan.name,
+            active: plan.active,
+            courses,
+            elective_groups: plan.elective_groups.into_iter().map(Into::into).collect(),
+            created_at: plan.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CycleProgressResponseDTO {
+    pub cycle: i32,
+    pub required_credits: i32,
+    pub earned_credits: i32,
+    pub pending_courses: Vec<String>,
+}
+
+impl From<CycleProgress> for CycleProgressResponseDTO {
+    fn from(progress: CycleProgress) -> Self {
+        Self {
+            cycle: progress.cycle,
+            required_credits: progress.required_credits,
+            earned_credits: progress.earned_credits,
+            pending_courses: progress.pending_courses,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ElectiveGroupProgressResponseDTO {
+    pub name: String,
+    pub min_credits: i32,
+    pub earned_credits: i32,
+    pub satisfied: bool,
+}
+
+impl From<ElectiveGroupProgress> for ElectiveGroupProgressResponseDTO {
+    fn from(progress: ElectiveGroupProgress) -> Self {
+        Self {
+            name: progress.name,
+            min_credits: progress.min_credits,
+            earned_credits: progress.earned_credits,
+            satisfied: progress.satisfied,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PlanProgressResponseDTO {
+    pub student_id: String,
+    pub plan_id: String,
+    pub program: String,
+    pub version: i32,
+    pub cycles: Vec<CycleProgressResponseDTO>,
+    pub elective_groups: Vec<ElectiveGroupProgressResponseDTO>,
+    pub required_credits: i32,
+    pub earned_credits: i32,
+    pub percentage: f64,
+    pub eligible_courses: Vec<String>,
+}
+
+impl From<PlanProgress> for PlanProgressResponseDTO {
+    fn from(progress: PlanProgress) -> Self {
+        Self {
+            student_id: progress.student_id,
+            plan_id: progress.plan_id,
+            program: progress.program,
+            version: progress.version,
+            cycles: progress.cycles.into_iter().map(Into::into).collect(),
+            elective_groups: progress.elective_groups.into_iter().map(Into::into).collect(),
+            required_credits: progress.required_credits,
+            earned_credits: progress.earned_credits,
+            percentage: progress.percentage,
+            eligible_courses: progress.eligible_courses,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OfferedCourseResponseDTO {
+    pub code: String,
+    pub name: String,
+    #[schema(value_type = CurriculumSchema)]
+    pub curriculum: Curriculum,
+    pub elective_group: Option<String>,
+    pub eligible_students: i32,
+}
+
+impl From<OfferedCourse> for OfferedCourseResponseDTO {
+    fn from(course: OfferedCourse) -> Self {
+        Self {
+            code: course.code,
+            name: course.name,
+            curriculum: course.curriculum,
+            elective_group: course.elective_group,
+            eligible_students: course.eligible_students,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CycleOfferingResponseDTO {
+    pub cycle: i32,
+    pub courses: Vec<OfferedCourseResponseDTO>,
+}
+
+impl From<CycleOffering> for CycleOfferingResponseDTO {
+    fn from(offering: CycleOffering) -> Self {
+        Self {
+            cycle: offering.cycle,
+            courses: offering.courses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::super::dto::enums_dto::{AssignmentStatusSchema, ContractTypeSchema};
+use crate::domain::models::{
+    enums::{AssignmentStatus, ContractType},
+    teacher_assignment::{AssignmentProposal, TeacherCandidate},
+    validation::ValidationError,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Semestre para el que se generan las propuestas.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProposeAssignmentsDTO {
+    pub academic_year: i32,
+    pub semester: String,
+}
+
+impl ProposeAssignmentsDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.semester.trim().is_empty() {
+            errors.add("semester", "is required");
+        }
+
+        errors.into_result()
+    }
+}
+
+/// Comentario opcional del coordinador al aceptar la propuesta.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct AcceptAssignmentDTO {
+    pub note: Option<String>,
+}
+
+/// Profesor elegido por el coordinador en lugar del propuesto.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OverrideAssignmentDTO {
+    pub teacher_id: String,
+    pub note: Option<String>,
+}
+
+impl OverrideAssignmentDTO {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::default();
+
+        if self.teacher_id.trim().is_empty() {
+            errors.add("teacher_id", "is required");
+        }
+
+        errors.into_result()
+    }
+}
+
+/// Profesor que puede dictar un curso sin chocar ni pasarse de horas.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TeacherCandidateResponseDTO {
+    pub teacher_id: String,
+    pub full_name: String,
+    #[schema(value_type = ContractTypeSchema)]
+    pub contract_type: ContractType,
+    pub specialty_match: bool,
+    pub same_faculty: bool,
+    pub taught_before: bool,
+    /// Horas semanales en el semestre antes de sumar este curso.
+    pub current_hours: i32,
+    pub max_hours_per_week: Option<i32>,
+}
+
+impl From<TeacherCandidate> for TeacherCandidateResponseDTO {
+    fn from(candidate: TeacherCandidate) -> Self {
+        Self {
+            teacher_id: candidate.teacher_id,
+            full_name: candidate.full_name,
+            contract_type: candidate.contract_type,
+            specialty_match: candidate.specialty_match,
+            same_faculty: candidate.same_faculty,
+            taught_before: candidate.taught_before,
+            current_hours: candidate.current_hours,
+            max_hours_per_week: candidate.max_hours_per_week,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssignmentProposalResponseDTO {
+    pub id: String,
+    pub course_id: String,
+    pub academic_year: i32,
+    pub semester: String,
+    pub proposed_teacher_id: Option<String>,
+    pub candidates: Vec<TeacherCandidateResponseDTO>,
+    pub reasons: Vec<String>,
+    #[schema(value_type = AssignmentStatusSchema)]
+    pub status: AssignmentStatus,
+    pub assigned_teacher_id: Option<String>,
+    pub decided_by: Option<String>,
+    pub note: Option<String>,
+    pub created_at: Option<String>,
+}
+
+impl From<AssignmentProposal> for AssignmentProposalResponseDTO {
+    fn from(proposal: AssignmentProposal) -> Self {
+        Self {
+            id: proposal.id,
+            course_id: proposal.course_id,
+            academic_year: proposal.academic_year,
+            semester: proposal.semester,
+            proposed_teacher_id: proposal.proposed_teacher_id,
+            candidates: proposal.candidates.into_iter().map(Into::into).collect(),
+            reasons: proposal.reasons,
+            status: proposal.status,
+            assigned_teacher_id: proposal.assigned_teacher_id,
+            decided_by: proposal.decided_by,
+            note: proposal.note,
+            created_at: proposal.created_at,
+        }
+    }
+}
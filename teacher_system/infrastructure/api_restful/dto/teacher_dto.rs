@@ -1,7 +1,8 @@
 use super::super::dto::{course_dto::CourseResponseDTO, schedule_dto::ScheduleResponseDTO};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TeacherDTO {
     pub id: String,
     pub code: String,
@@ -12,7 +13,7 @@ pub struct TeacherDTO {
     pub current_hours: i32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TeacherScheduleDTO {
     pub courses: Vec<CourseResponseDTO>,
     pub extracurricular: Vec<ScheduleResponseDTO>,
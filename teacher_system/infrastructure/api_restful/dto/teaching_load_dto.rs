@@ -0,0 +1,82 @@
+use super::super::dto::enums_dto::{ContractTypeSchema, LoadStatusSchema, WeekdaySchema};
+use crate::domain::models::{
+    enums::{ContractType, Weekday},
+    teaching_load::{ContractLoadRule, LoadStatus, TeacherTermLoad, TeachingLoadPolicy},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TeacherTermLoadResponseDTO {
+    pub teacher_id: String,
+    pub full_name: String,
+    pub contract_type: Option<String>,
+    pub academic_year: i32,
+    pub semester: String,
+    pub weekly_hours: f64,
+    pub evening_sessions: i32,
+    pub min_hours: Option<i32>,
+    pub max_hours: Option<i32>,
+    pub max_evening_sessions: Option<i32>,
+    #[schema(value_type = LoadStatusSchema)]
+    pub status: LoadStatus,
+    pub flags: Vec<String>,
+}
+
+impl From<TeacherTermLoad> for TeacherTermLoadResponseDTO {
+    fn from(load: TeacherTermLoad) -> Self {
+        Self {
+            teacher_id: load.teacher_id,
+            full_name: load.full_name,
+            contract_type: load.contract_type.map(|c| c.to_string()),
+            academic_year: load.academic_year,
+            semester: load.semester,
+            weekly_hours: load.weekly_hours,
+            evening_sessions: load.evening_sessions,
+            min_hours: load.min_hours,
+            max_hours: load.max_hours,
+            max_evening_sessions: load.max_evening_sessions,
+            status: load.status,
+            flags: load.flags,
+        }
+    }
+}
+
+/// Carga permitida para un tipo de contrato.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ContractLoadRuleResponseDTO {
+    #[schema(value_type = ContractTypeSchema)]
+    pub contract_type: ContractType,
+    pub min_hours: i32,
+    pub max_hours: i32,
+    /// Vacío si puede dictar cualquier día.
+    #[schema(value_type = Vec<WeekdaySchema>)]
+    pub allowed_days: Vec<Weekday>,
+    /// `None` si no hay tope de sesiones nocturnas.
+    pub max_evening_sessions: Option<i32>,
+}
+
+impl From<ContractLoadRule> for ContractLoadRuleResponseDTO {
+    fn from(rule: ContractLoadRule) -> Self {
+        Self {
+            contract_type: rule.contract_type,
+            min_hours: rule.min_hours,
+            max_hours: rule.max_hours,
+            allowed_days: rule.allowed_days,
+            max_evening_sessions: rule.max_evening_sessions,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TeachingLoadPolicyResponseDTO {
+    pub rules: Vec<ContractLoadRuleResponseDTO>,
+}
+
+impl From<TeachingLoadPolicy> for TeachingLoadPolicyResponseDTO {
+    fn from(policy: TeachingLoadPolicy) -> Self {
+        Self {
+            rules: policy.rules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
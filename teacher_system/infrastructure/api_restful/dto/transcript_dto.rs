@@ -0,0 +1,110 @@
+use crate::domain::models::transcript::{
+    ProgramProgress, Transcript, TranscriptEntry, TranscriptTerm,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TranscriptResponseDTO {
+    pub student_id: String,
+    pub student_code: String,
+    pub full_name: String,
+    pub student_status: Option<String>,
+    pub admission_date: Option<String>,
+    pub terms: Vec<TranscriptTermResponseDTO>,
+    pub credits_attempted: i32,
+    pub credits_earned: i32,
+    pub weighted_average: f64,
+    pub progress: ProgramProgressResponseDTO,
+}
+
+impl From<Transcript> for TranscriptResponseDTO {
+    fn from(transcript: Transcript) -> Self {
+        Self {
+            student_id: transcript.student_id,
+            student_code: transcript.student_code,
+            full_name: transcript.full_name,
+            student_status: transcript.student_status.map(|s| s.to_string()),
+            admission_date: transcript.admission_date,
+            terms: transcript.terms.into_iter().map(Into::into).collect(),
+            credits_attempted: transcript.credits_attempted,
+            credits_earned: transcript.credits_earned,
+            weighted_average: transcript.weighted_average,
+            progress: transcript.progress.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TranscriptEntryResponseDTO {
+    pub course_id: String,
+    pub code: String,
+    pub name: String,
+    pub credits: i32,
+    pub final_grade: f64,
+    pub passed: bool,
+}
+
+impl From<TranscriptEntry> for TranscriptEntryResponseDTO {
+    fn from(entry: TranscriptEntry) -> Self {
+        Self {
+            course_id: entry.course_id,
+            code: entry.code,
+            name: entry.name,
+            credits: entry.credits,
+            final_grade: entry.final_grade,
+            passed: entry.passed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TranscriptTermResponseDTO {
+    pub academic_year: i32,
+    pub semester: String,
+    pub entries: Vec<TranscriptEntryResponseDTO>,
+    pub credits_attempted: i32,
+    pub credits_earned: i32,
+    pub weighted_average: f64,
+}
+
+impl From<TranscriptTerm> for TranscriptTermResponseDTO {
+    fn from(term: TranscriptTerm) -> Self {
+        Self {
+            academic_year: term.academic_year,
+            semester: term.semester,
+            entries: term.entries.into_iter().map(Into::into).collect(),
+            credits_attempted: term.credits_attempted,
+            credits_earned: term.credits_earned,
+            weighted_average: term.weighted_average,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProgramProgressResponseDTO {
+    pub program: String,
+    /// Versión del plan medida; `None` si el programa aún no tiene plan.
+    pub plan_version: Option<i32>,
+    pub required_credits: i32,
+    pub earned_required_credits: i32,
+    pub earned_elective_credits: i32,
+    pub percentage: f64,
+    pub pending_courses: Vec<String>,
+    pub pending_elective_groups: Vec<String>,
+}
+
+impl From<ProgramProgress> for ProgramProgressResponseDTO {
+    fn from(progress: ProgramProgress) -> Self {
+        Self {
+            program: progress.program,
+            plan_version: progress.plan_version,
+            required_credits: progress.required_credits,
+            earned_required_credits: progress.earned_required_credits,
+            earned_elective_credits: progress.earned_elective_credits,
+            percentage: progress.percentage,
+            pending_courses: progress.pending_courses,
+            pending_elective_groups: progress.pending_elective_groups,
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::super::dto::enums_dto::{ContractTypeSchema, StudentStatusSchema};
+use crate::domain::models::{
+    enums::{ContractType, StudentStatus},
+    user::User,
+    validation::ValidationError,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Cuerpo de creación y actualización de un usuario.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserDTO {
+    pub code: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub faculty: String,
+    pub program: String,
+    pub specialty: String,
+    pub role: String,
+    #[schema(value_type = Option<StudentStatusSchema>)]
+    pub student_status: Option<StudentStatus>,
+    pub admission_date: Option<String>,
+    #[schema(value_type = Option<ContractTypeSchema>)]
+    pub contract_type: Option<ContractType>,
+    pub max_hours_per_week: Option<i32>,
+    pub hire_date: Option<String>,
+    pub full_name: String,
+}
+
+impl UserDTO {
+    /// Mismas reglas que `User::validate`.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.clone().into_user(String::new()).validate()
+    }
+
+    pub fn into_user(self, id: String) -> User {
+        User {
+            id,
+            code: self.code,
+            email: self.email,
+            phone: self.phone,
+            faculty: self.faculty,
+            program: self.program,
+            specialty: self.specialty,
+            role: self.role,
+            student_status: self.student_status,
+            admission_date: self.admission_date,
+            contract_type: self.contract_type,
+            max_hours_per_week: self.max_hours_per_week,
+            hire_date: self.hire_date,
+            full_name: self.full_name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserResponseDTO {
+    pub id: String,
+    pub code: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub faculty: String,
+    pub program: String,
+    pub specialty: String,
+    pub role: String,
+    #[schema(value_type = Option<StudentStatusSchema>)]
+    pub student_status: Option<StudentStatus>,
+    pub admission_date: Option<String>,
+    #[schema(value_type = Option<ContractTypeSchema>)]
+    pub contract_type: Option<ContractType>,
+    pub max_hours_per_week: Option<i32>,
+    pub hire_date: Option<String>,
+    pub full_name: String,
+}
+
+impl From<User> for UserResponseDTO {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            code: user.code,
+            email: user.email,
+            phone: user.phone,
+            faculty: user.faculty,
+            program: user.program,
+            specialty: user.specialty,
+            role: user.role,
+            student_status: user.student_status,
+            admission_date: user.admission_date,
+            contract_type: user.contract_type,
+            max_hours_per_week: user.max_hours_per_week,
+            hire_date: user.hire_date,
+            full_name: user.full_name,
+        }
+    }
+}
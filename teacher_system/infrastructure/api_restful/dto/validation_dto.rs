@@ -0,0 +1,32 @@
+use crate::domain::models::validation::{FieldError, ValidationError};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FieldErrorResponseDTO {
+    pub field: String,
+    pub message: String,
+}
+
+/// Cuerpo de las respuestas 422: un error por campo.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ValidationErrorResponseDTO {
+    pub errors: Vec<FieldErrorResponseDTO>,
+}
+
+impl From<FieldError> for FieldErrorResponseDTO {
+    fn from(error: FieldError) -> Self {
+        Self {
+            field: error.field,
+            message: error.message,
+        }
+    }
+}
+
+impl From<ValidationError> for ValidationErrorResponseDTO {
+    fn from(error: ValidationError) -> Self {
+        Self {
+            errors: error.errors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::super::dto::enums_dto::EventKindSchema;
+use crate::domain::models::{
+    enums::EventKind,
+    webhook::{DeliveryAttempt, WebhookSubscription},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Cuerpo para registrar un webhook; sin `secret` se genera uno.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDTO {
+    pub target_url: String,
+    #[schema(value_type = Vec<EventKindSchema>)]
+    pub events: Vec<EventKind>,
+    pub secret: Option<String>,
+}
+
+impl WebhookDTO {
+    pub fn into_subscription(self, id: String, created_by: String) -> WebhookSubscription {
+        let secret = self.secret.unwrap_or_else(|| {
+            format!(
+                "{}{}",
+                uuid::Uuid::new_v4().simple(),
+                uuid::Uuid::new_v4().simple()
+            )
+        });
+
+        WebhookSubscription {
+            id,
+            target_url: self.target_url,
+            secret,
+            events: self.events,
+            active: true,
+            created_by,
+            created_at: None,
+        }
+    }
+}
+
+/// El secreto solo se devuelve al registrar el webhook.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookResponseDTO {
+    pub id: String,
+    pub target_url: String,
+    #[schema(value_type = Vec<EventKindSchema>)]
+    pub events: Vec<EventKind>,
+    pub active: bool,
+    pub created_by: String,
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+impl WebhookResponseDTO {
+    pub fn with_secret(subscription: WebhookSubscription) -> Self {
+        let secret = subscription.secret.clone();
+        Self {
+            secret: Some(secret),
+            ..Self::from(subscription)
+        }
+    }
+}
+
+impl From<WebhookSubscription> for WebhookResponseDTO {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id,
+            target_url: subscription.target_url,
+            events: subscription.events,
+            active: subscription.active,
+            created_by: subscription.created_by,
+            created_at: subscription.created_at,
+            secret: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DeliveryAttemptResponseDTO {
+    pub id: String,
+    pub outbox_id: String,
+    #[schema(value_type = EventKindSchema)]
+    pub event_kind: EventKind,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub duration_ms: i64,
+    pub attempted_at: Option<String>,
+}
+
+impl From<DeliveryAttempt> for DeliveryAttemptResponseDTO {
+    fn from(attempt: DeliveryAttempt) -> Self {
+        Self {
+            id: attempt.id,
+            outbox_id: attempt.outbox_id,
+            event_kind: attempt.event_kind,
+            attempt: attempt.attempt,
+            status_code: attempt.status_code,
+            error: attempt.error,
+            duration_ms: attempt.duration_ms,
+            attempted_at: attempt.attempted_at,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod config;
 pub mod controllers;
 pub mod dto;
+pub mod openapi;
 pub mod routes;
@@ -0,0 +1,157 @@
+use super::controllers::actor::ACTOR_HEADER;
+use super::controllers::{
+    attendance_controller, audit_controller, booking_controller, course_controller,
+    credit_load_controller, demand_forecast_controller, enrollment_controller, facility_controller,
+    grade_controller, notification_controller, schedule_controller, section_controller,
+    session_exception_controller, student_status_controller, study_plan_controller,
+    teacher_assignment_controller, teaching_load_controller, transcript_controller,
+    user_controller, webhook_controller,
+};
+use super::dto::enums_dto::{CurriculumSchema, SessionTypeSchema, WeekdaySchema};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+use utoipa_swagger_ui::SwaggerUi;
+
+/// Contrato de la API generado a partir de las anotaciones de los
+/// controladores. Los esquemas de los cuerpos y respuestas se registran solos;
+/// los enums se listan para que aparezcan aunque solo se usen en línea.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Teacher System API", description = "API de cursos, horarios, aulas y usuarios."),
+    paths(
+        course_controller::get_all_courses,
+        course_controller::create_course,
+        demand_forecast_controller::get_demand_forecast,
+        section_controller::clone_term,
+        course_controller::get_course_by_id,
+        course_controller::update_course,
+        course_controller::patch_course,
+        course_controller::delete_course,
+        course_controller::restore_course,
+        section_controller::split_section,
+        section_controller::merge_sections,
+        attendance_controller::get_course_attendance,
+        grade_controller::get_grading_scheme,
+        grade_controller::update_grading_scheme,
+        grade_controller::get_course_grades,
+        grade_controller::record_grades,
+        grade_controller::close_course,
+        enrollment_controller::check_prerequisites,
+        schedule_controller::get_all_schedules,
+        schedule_controller::create_schedule,
+        schedule_controller::get_cycle_conflicts,
+        schedule_controller::get_course_conflicts,
+        schedule_controller::audit_semester,
+        schedule_controller::get_cycle_grid,
+        schedule_controller::check_curriculum_clashes,
+        schedule_controller::suggest_facilities,
+        schedule_controller::get_schedule_by_id,
+        schedule_controller::update_schedule,
+        schedule_controller::patch_schedule,
+        schedule_controller::delete_schedule,
+        attendance_controller::get_occurrence_attendance,
+        attendance_controller::take_attendance,
+        session_exception_controller::get_session_exceptions,
+        session_exception_controller::cancel_session,
+        session_exception_controller::reschedule_session,
+        schedule_controller::suggest_available_times,
+        facility_controller::get_all_facilities,
+        facility_controller::create_facility,
+        facility_controller::get_available_facilities,
+        facility_controller::get_facility_by_id,
+        facility_controller::update_facility,
+        facility_controller::patch_facility,
+        facility_controller::delete_facility,
+        facility_controller::restore_facility,
+        booking_controller::get_bookings,
+        booking_controller::request_booking,
+        booking_controller::get_booking_by_id,
+        booking_controller::approve_booking,
+        booking_controller::reject_booking,
+        booking_controller::cancel_booking,
+        user_controller::get_all_users,
+        user_controller::create_user,
+        student_status_controller::get_status_rules,
+        student_status_controller::evaluate_term_status,
+        credit_load_controller::get_credit_limits,
+        teaching_load_controller::get_teaching_load_report,
+        teaching_load_controller::get_teaching_load_rules,
+        user_controller::get_user_by_id,
+        user_controller::update_user,
+        user_controller::patch_user,
+        user_controller::delete_user,
+        user_controller::restore_user,
+        notification_controller::get_notification_preference,
+        notification_controller::update_notification_preference,
+        transcript_controller::get_transcript,
+        credit_load_controller::get_credit_load,
+        study_plan_controller::get_plan_progress,
+        student_status_controller::get_status_history,
+        student_status_controller::evaluate_student_status,
+        enrollment_controller::get_enrollment_by_id,
+        enrollment_controller::confirm_enrollment,
+        credit_load_controller::get_overload_requests,
+        credit_load_controller::request_overload,
+        credit_load_controller::get_overload_request_by_id,
+        credit_load_controller::approve_overload,
+        credit_load_controller::reject_overload,
+        study_plan_controller::get_study_plans,
+        study_plan_controller::create_study_plan,
+        study_plan_controller::get_study_plan_by_id,
+        study_plan_controller::activate_study_plan,
+        study_plan_controller::get_study_plan_offering,
+        teacher_assignment_controller::get_assignment_proposals,
+        teacher_assignment_controller::propose_assignments,
+        teacher_assignment_controller::get_assignment_proposal_by_id,
+        teacher_assignment_controller::accept_assignment,
+        teacher_assignment_controller::override_assignment,
+        webhook_controller::get_webhooks,
+        webhook_controller::register_webhook,
+        webhook_controller::get_webhook_by_id,
+        webhook_controller::deactivate_webhook,
+        webhook_controller::get_webhook_deliveries,
+        attendance_controller::get_at_risk_students,
+        attendance_controller::get_student_attendance,
+        audit_controller::get_audit_logs,
+    ),
+    components(schemas(WeekdaySchema, SessionTypeSchema, CurriculumSchema)),
+    modifiers(&ActorHeader),
+    security((), ("actor_id" = [])),
+    tags(
+        (name = "courses", description = "Cursos, secciones y calificaciones"),
+        (name = "schedules", description = "Sesiones semanales, choques y excepciones de fecha"),
+        (name = "facilities", description = "Aulas y laboratorios"),
+        (name = "bookings", description = "Reservas puntuales de aulas"),
+        (name = "users", description = "Usuarios, estado académico, créditos y carga docente"),
+        (name = "enrollments", description = "Matrículas"),
+        (name = "overload-requests", description = "Pedidos de sobrecarga de créditos y su revisión"),
+        (name = "study-plans", description = "Versiones de los planes de estudio por programa"),
+        (name = "assignments", description = "Propuestas de profesores para los cursos sin asignar"),
+        (name = "webhooks", description = "Suscripciones de webhooks y su registro de entregas"),
+        (name = "attendance", description = "Resúmenes de asistencia y alumnos en riesgo"),
+        (name = "audit", description = "Registro de auditoría"),
+    )
+)]
+pub struct ApiDoc;
+
+/// La cabecera `X-Actor-Id` identifica a quien opera; sin ella se registra
+/// como `anonymous`.
+struct ActorHeader;
+
+impl Modify for ActorHeader {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "actor_id",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::with_description(
+                ACTOR_HEADER,
+                "ID del usuario que realiza la operación",
+            ))),
+        );
+    }
+}
+
+/// Swagger UI en `/docs/` y la especificación en `/docs/openapi.json`.
+pub fn docs_routes() -> SwaggerUi {
+    SwaggerUi::new("/docs/{_:.*}").url("/docs/openapi.json", ApiDoc::openapi())
+}
@@ -1,5 +1,10 @@
 use crate::infrastructure::api_restful::controllers::{
-    course_controller, facility_controller, schedule_controller,
+    attendance_controller, audit_controller, booking_controller, course_controller,
+    credit_load_controller, demand_forecast_controller, enrollment_controller,
+    facility_controller, grade_controller, notification_controller, schedule_controller,
+    section_controller, session_exception_controller, student_status_controller,
+    study_plan_controller, teacher_assignment_controller, teaching_load_controller,
+    transcript_controller, user_controller, webhook_controller,
 };
 use actix_web::web::ServiceConfig;
 use actix_web::{Scope, web};
@@ -13,6 +18,15 @@ pub fn app_routes() -> Scope {
         .service(course_routes())
         .service(schedule_routes())
         .service(facility_routes())
+        .service(booking_routes())
+        .service(user_routes())
+        .service(enrollment_routes())
+        .service(overload_routes())
+        .service(study_plan_routes())
+        .service(assignment_routes())
+        .service(webhook_routes())
+        .service(attendance_routes())
+        .service(audit_routes())
 }
 
 /// Rutas relacionadas a `Course`
@@ -20,9 +34,43 @@ fn course_routes() -> Scope {
     web::scope("/courses")
         .route("", web::get().to(course_controller::get_all_courses))
         .route("", web::post().to(course_controller::create_course))
+        // Antes de `/{id}` para que no se interprete como un ID
+        .route(
+            "/demand-forecast",
+            web::get().to(demand_forecast_controller::get_demand_forecast),
+        )
+        .route("/clone-term", web::post().to(section_controller::clone_term))
         .route("/{id}", web::get().to(course_controller::get_course_by_id))
         .route("/{id}", web::put().to(course_controller::update_course))
+        .route("/{id}", web::patch().to(course_controller::patch_course))
         .route("/{id}", web::delete().to(course_controller::delete_course))
+        .route(
+            "/{id}/restore",
+            web::post().to(course_controller::restore_course),
+        )
+        // Secciones del mismo curso en el semestre
+        .route("/{id}/split", web::post().to(section_controller::split_section))
+        .route("/{id}/merge", web::post().to(section_controller::merge_sections))
+        .route(
+            "/{id}/attendance",
+            web::get().to(attendance_controller::get_course_attendance),
+        )
+        // Calificaciones y cierre del curso
+        .route(
+            "/{id}/grading-scheme",
+            web::get().to(grade_controller::get_grading_scheme),
+        )
+        .route(
+            "/{id}/grading-scheme",
+            web::put().to(grade_controller::update_grading_scheme),
+        )
+        .route("/{id}/grades", web::get().to(grade_controller::get_course_grades))
+        .route("/{id}/grades", web::post().to(grade_controller::record_grades))
+        .route("/{id}/close", web::post().to(grade_controller::close_course))
+        .route(
+            "/{id}/prerequisites/check",
+            web::get().to(enrollment_controller::check_prerequisites),
+        )
 }
 
 /// Rutas relacionadas a `Schedule`
@@ -30,15 +78,59 @@ fn schedule_routes() -> Scope {
     web::scope("/schedules")
         .route("", web::get().to(schedule_controller::get_all_schedules))
         .route("", web::post().to(schedule_controller::create_schedule))
+        // Antes de `/{id}` para que no se interprete como un ID
+        .route(
+            "/conflicts",
+            web::get().to(schedule_controller::get_cycle_conflicts),
+        )
+        .route(
+            "/conflicts/course/{course_id}",
+            web::get().to(schedule_controller::get_course_conflicts),
+        )
+        .route("/audit", web::get().to(schedule_controller::audit_semester))
+        .route("/grid", web::get().to(schedule_controller::get_cycle_grid))
+        .route(
+            "/curriculum-check",
+            web::post().to(schedule_controller::check_curriculum_clashes),
+        )
+        .route(
+            "/allocate",
+            web::post().to(schedule_controller::suggest_facilities),
+        )
         .route(
             "/{id}",
             web::get().to(schedule_controller::get_schedule_by_id),
         )
         .route("/{id}", web::put().to(schedule_controller::update_schedule))
+        .route(
+            "/{id}",
+            web::patch().to(schedule_controller::patch_schedule),
+        )
         .route(
             "/{id}",
             web::delete().to(schedule_controller::delete_schedule),
         )
+        .route(
+            "/{id}/attendance",
+            web::get().to(attendance_controller::get_occurrence_attendance),
+        )
+        .route(
+            "/{id}/attendance",
+            web::post().to(attendance_controller::take_attendance),
+        )
+        // Cancelaciones y recuperaciones de fechas concretas
+        .route(
+            "/{id}/exceptions",
+            web::get().to(session_exception_controller::get_session_exceptions),
+        )
+        .route(
+            "/{id}/exceptions/cancel",
+            web::post().to(session_exception_controller::cancel_session),
+        )
+        .route(
+            "/{id}/exceptions/reschedule",
+            web::post().to(session_exception_controller::reschedule_session),
+        )
         // Operaciones específicas
         .route(
             "/suggest/{teacher_id}",
@@ -51,13 +143,223 @@ fn facility_routes() -> Scope {
     web::scope("/facilities")
         .route("", web::get().to(facility_controller::get_all_facilities))
         .route("", web::post().to(facility_controller::create_facility))
+        .route(
+            "/available",
+            web::get().to(facility_controller::get_available_facilities),
+        )
         .route(
             "/{id}",
             web::get().to(facility_controller::get_facility_by_id),
         )
         .route("/{id}", web::put().to(facility_controller::update_facility))
+        .route(
+            "/{id}",
+            web::patch().to(facility_controller::patch_facility),
+        )
         .route(
             "/{id}",
             web::delete().to(facility_controller::delete_facility),
         )
+        .route(
+            "/{id}/restore",
+            web::post().to(facility_controller::restore_facility),
+        )
+}
+
+/// Rutas de reservas puntuales de aulas
+fn booking_routes() -> Scope {
+    web::scope("/bookings")
+        .route("", web::get().to(booking_controller::get_bookings))
+        .route("", web::post().to(booking_controller::request_booking))
+        .route("/{id}", web::get().to(booking_controller::get_booking_by_id))
+        .route(
+            "/{id}/approve",
+            web::post().to(booking_controller::approve_booking),
+        )
+        .route(
+            "/{id}/reject",
+            web::post().to(booking_controller::reject_booking),
+        )
+        .route(
+            "/{id}/cancel",
+            web::post().to(booking_controller::cancel_booking),
+        )
+}
+
+/// Rutas relacionadas a `User`
+fn user_routes() -> Scope {
+    web::scope("/users")
+        .route("", web::get().to(user_controller::get_all_users))
+        .route("", web::post().to(user_controller::create_user))
+        // Estado académico: reglas vigentes y evaluación al cierre del semestre
+        .route(
+            "/student-status/rules",
+            web::get().to(student_status_controller::get_status_rules),
+        )
+        .route(
+            "/student-status/evaluate",
+            web::post().to(student_status_controller::evaluate_term_status),
+        )
+        .route(
+            "/credit-limits",
+            web::get().to(credit_load_controller::get_credit_limits),
+        )
+        // Carga docente del semestre frente a las reglas de cada contrato
+        .route(
+            "/teaching-load",
+            web::get().to(teaching_load_controller::get_teaching_load_report),
+        )
+        .route(
+            "/teaching-load/rules",
+            web::get().to(teaching_load_controller::get_teaching_load_rules),
+        )
+        .route("/{id}", web::get().to(user_controller::get_user_by_id))
+        .route("/{id}", web::put().to(user_controller::update_user))
+        .route("/{id}", web::patch().to(user_controller::patch_user))
+        .route("/{id}", web::delete().to(user_controller::delete_user))
+        .route(
+            "/{id}/restore",
+            web::post().to(user_controller::restore_user),
+        )
+        .route(
+            "/{id}/notification-preferences",
+            web::get().to(notification_controller::get_notification_preference),
+        )
+        .route(
+            "/{id}/notification-preferences",
+            web::put().to(notification_controller::update_notification_preference),
+        )
+        .route(
+            "/{id}/transcript",
+            web::get().to(transcript_controller::get_transcript),
+        )
+        .route(
+            "/{id}/credit-load",
+            web::get().to(credit_load_controller::get_credit_load),
+        )
+        .route(
+            "/{id}/plan-progress",
+            web::get().to(study_plan_controller::get_plan_progress),
+        )
+        .route(
+            "/{id}/status-history",
+            web::get().to(student_status_controller::get_status_history),
+        )
+        .route(
+            "/{id}/status/evaluate",
+            web::post().to(student_status_controller::evaluate_student_status),
+        )
+}
+
+/// Rutas relacionadas a `Enrollment`
+fn enrollment_routes() -> Scope {
+    web::scope("/enrollments")
+        .route(
+            "/{id}",
+            web::get().to(enrollment_controller::get_enrollment_by_id),
+        )
+        .route(
+            "/{id}/confirm",
+            web::post().to(enrollment_controller::confirm_enrollment),
+        )
+}
+
+/// Pedidos de sobrecarga de créditos y su revisión
+fn overload_routes() -> Scope {
+    web::scope("/overload-requests")
+        .route(
+            "",
+            web::get().to(credit_load_controller::get_overload_requests),
+        )
+        .route("", web::post().to(credit_load_controller::request_overload))
+        .route(
+            "/{id}",
+            web::get().to(credit_load_controller::get_overload_request_by_id),
+        )
+        .route(
+            "/{id}/approve",
+            web::post().to(credit_load_controller::approve_overload),
+        )
+        .route(
+            "/{id}/reject",
+            web::post().to(credit_load_controller::reject_overload),
+        )
+}
+
+/// Propuestas de profesores para los cursos sin asignar
+fn assignment_routes() -> Scope {
+    web::scope("/assignments")
+        .route(
+            "",
+            web::get().to(teacher_assignment_controller::get_assignment_proposals),
+        )
+        .route(
+            "/propose",
+            web::post().to(teacher_assignment_controller::propose_assignments),
+        )
+        .route(
+            "/{id}",
+            web::get().to(teacher_assignment_controller::get_assignment_proposal_by_id),
+        )
+        .route(
+            "/{id}/accept",
+            web::post().to(teacher_assignment_controller::accept_assignment),
+        )
+        .route(
+            "/{id}/override",
+            web::post().to(teacher_assignment_controller::override_assignment),
+        )
+}
+
+/// Versiones de los planes de estudio por programa
+fn study_plan_routes() -> Scope {
+    web::scope("/study-plans")
+        .route("", web::get().to(study_plan_controller::get_study_plans))
+        .route("", web::post().to(study_plan_controller::create_study_plan))
+        .route(
+            "/{id}",
+            web::get().to(study_plan_controller::get_study_plan_by_id),
+        )
+        .route(
+            "/{id}/activate",
+            web::post().to(study_plan_controller::activate_study_plan),
+        )
+        .route(
+            "/{id}/offering",
+            web::get().to(study_plan_controller::get_study_plan_offering),
+        )
+}
+
+/// Suscripciones de webhooks y su registro de entregas
+fn webhook_routes() -> Scope {
+    web::scope("/webhooks")
+        .route("", web::get().to(webhook_controller::get_webhooks))
+        .route("", web::post().to(webhook_controller::register_webhook))
+        .route("/{id}", web::get().to(webhook_controller::get_webhook_by_id))
+        .route(
+            "/{id}",
+            web::delete().to(webhook_controller::deactivate_webhook),
+        )
+        .route(
+            "/{id}/deliveries",
+            web::get().to(webhook_controller::get_webhook_deliveries),
+        )
+}
+
+/// Resúmenes de asistencia por alumno y reporte de alumnos en riesgo
+fn attendance_routes() -> Scope {
+    web::scope("/attendance")
+        .route(
+            "/at-risk",
+            web::get().to(attendance_controller::get_at_risk_students),
+        )
+        .route(
+            "/students/{id}",
+            web::get().to(attendance_controller::get_student_attendance),
+        )
+}
+
+/// Rutas de consulta del registro de auditoría
+fn audit_routes() -> Scope {
+    web::scope("/audit").route("", web::get().to(audit_controller::get_audit_logs))
 }
@@ -17,10 +17,14 @@ pub struct Model {
     pub location_detail: Option<String>,
     pub created_at: Option<DateTime>,
     pub facility_id: String,
+    pub required_equipment: Option<Json>,
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::attendance_records::Entity")]
+    AttendanceRecords,
     #[sea_orm(
         belongs_to = "super::courses::Entity",
         from = "Column::CourseId",
@@ -37,6 +41,14 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Facilities,
+    #[sea_orm(has_many = "super::session_exceptions::Entity")]
+    SessionExceptions,
+}
+
+impl Related<super::attendance_records::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AttendanceRecords.def()
+    }
 }
 
 impl Related<super::courses::Entity> for Entity {
@@ -51,4 +63,10 @@ impl Related<super::facilities::Entity> for Entity {
     }
 }
 
+impl Related<super::session_exceptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SessionExceptions.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
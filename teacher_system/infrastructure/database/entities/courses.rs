@@ -16,16 +16,19 @@ pub struct Model {
     pub credits: i32,
     pub hours_per_week: i32,
     pub cycle: i32,
-    pub teacher_id: String,
+    pub teacher_id: Option<String>,
     pub facility_id: String,
     pub enrolled: i32,
     pub max_capacity: Option<i32>,
     pub available_spots: Option<i32>,
     pub semester: String,
     pub academic_year: i32,
+    pub program: Option<String>,
     pub active: Option<bool>,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
+    pub deleted_at: Option<DateTime>,
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -34,6 +37,8 @@ pub enum Relation {
     CourseSchedules,
     #[sea_orm(has_many = "super::enrollments::Entity")]
     Enrollments,
+    #[sea_orm(has_one = "super::grading_schemes::Entity")]
+    GradingSchemes,
     #[sea_orm(
         belongs_to = "super::users::Entity",
         from = "Column::TeacherId",
@@ -56,6 +61,12 @@ impl Related<super::enrollments::Entity> for Entity {
     }
 }
 
+impl Related<super::grading_schemes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GradingSchemes.def()
+    }
+}
+
 impl Related<super::users::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Users.def()
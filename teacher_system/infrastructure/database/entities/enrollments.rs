@@ -3,7 +3,7 @@
 use super::sea_orm_active_enums::EnrollmentStatus;
 use sea_orm::entity::prelude::*;
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "enrollments")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -11,10 +11,14 @@ pub struct Model {
     pub student_id: String,
     pub course_id: String,
     pub status: EnrollmentStatus,
+    #[sea_orm(column_type = "Double", nullable)]
+    pub final_grade: Option<f64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::component_grades::Entity")]
+    ComponentGrades,
     #[sea_orm(
         belongs_to = "super::courses::Entity",
         from = "Column::CourseId",
@@ -33,6 +37,12 @@ pub enum Relation {
     Users,
 }
 
+impl Related<super::component_grades::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ComponentGrades.def()
+    }
+}
+
 impl Related<super::courses::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Courses.def()
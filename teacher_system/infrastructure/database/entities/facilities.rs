@@ -10,13 +10,23 @@ pub struct Model {
     pub name: String,
     pub capacity: Option<i32>,
     pub facility_type: Option<String>,
+    pub building: Option<String>,
+    pub floor: Option<i32>,
+    pub location: Option<String>,
+    pub equipment: Option<Json>,
+    pub wheelchair_accessible: Option<bool>,
+    pub step_free_access: Option<bool>,
+    pub hearing_loop: Option<bool>,
     pub created_at: Option<DateTime>,
+    pub deleted_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::course_schedules::Entity")]
     CourseSchedules,
+    #[sea_orm(has_many = "super::facility_bookings::Entity")]
+    FacilityBookings,
 }
 
 impl Related<super::course_schedules::Entity> for Entity {
@@ -25,4 +35,10 @@ impl Related<super::course_schedules::Entity> for Entity {
     }
 }
 
+impl Related<super::facility_bookings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FacilityBookings.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
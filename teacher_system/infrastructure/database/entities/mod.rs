@@ -2,10 +2,25 @@
 
 pub mod prelude;
 
+pub mod attendance_records;
+pub mod audit_logs;
+pub mod component_grades;
 pub mod course_prerequisites;
 pub mod course_schedules;
 pub mod courses;
 pub mod enrollments;
+pub mod event_outbox;
+pub mod facility_bookings;
 pub mod facilities;
+pub mod grading_schemes;
+pub mod notification_preferences;
+pub mod overload_requests;
 pub mod sea_orm_active_enums;
+pub mod session_exceptions;
+pub mod student_status_history;
+pub mod study_plans;
+pub mod teacher_assignments;
 pub mod users;
+pub mod webhook_deliveries;
+pub mod webhook_outbox;
+pub mod webhook_subscriptions;
@@ -1,8 +1,23 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.12
 
+pub use super::attendance_records::Entity as AttendanceRecords;
+pub use super::audit_logs::Entity as AuditLogs;
+pub use super::component_grades::Entity as ComponentGrades;
 pub use super::course_prerequisites::Entity as CoursePrerequisites;
 pub use super::course_schedules::Entity as CourseSchedules;
 pub use super::courses::Entity as Courses;
 pub use super::enrollments::Entity as Enrollments;
+pub use super::event_outbox::Entity as EventOutbox;
 pub use super::facilities::Entity as Facilities;
+pub use super::facility_bookings::Entity as FacilityBookings;
+pub use super::grading_schemes::Entity as GradingSchemes;
+pub use super::notification_preferences::Entity as NotificationPreferences;
+pub use super::overload_requests::Entity as OverloadRequests;
+pub use super::session_exceptions::Entity as SessionExceptions;
+pub use super::student_status_history::Entity as StudentStatusHistory;
+pub use super::study_plans::Entity as StudyPlans;
+pub use super::teacher_assignments::Entity as TeacherAssignments;
 pub use super::users::Entity as Users;
+pub use super::webhook_deliveries::Entity as WebhookDeliveries;
+pub use super::webhook_outbox::Entity as WebhookOutbox;
+pub use super::webhook_subscriptions::Entity as WebhookSubscriptions;
@@ -3,9 +3,10 @@
 use sea_orm::entity::prelude::*;
 
 use crate::domain::models::enums::{
-    ContractType as Contract, Curriculum, EnrollmentStatus as Enrollment, SessionType as Session,
-    StudentStatus as Student, Weekday,
+    ContractType as Contract, Curriculum, EnrollmentStatus as Enrollment, FacilityType,
+    SessionType as Session, StudentStatus as Student, Weekday,
 };
+use crate::domain::models::facilitie::EquipmentItem;
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "curriculum_type")]
@@ -228,3 +229,26 @@ pub fn to_db_contract(contract: &Contract) -> TeacherContract {
         Contract::Associate => TeacherContract::Asociado,
     }
 }
+
+// Los tipos de aula antiguos eran texto libre; lo que no se reconoce queda como
+// `Other`, pero se avisa para poder corregir el dato
+pub fn to_domain_facility_type(facility_type: Option<String>) -> FacilityType {
+    let Some(facility_type) = facility_type else {
+        return FacilityType::Other;
+    };
+    facility_type.parse().unwrap_or_else(|_| {
+        log::warn!("Unknown facility type '{}', using 'other'", facility_type);
+        FacilityType::Other
+    })
+}
+
+pub fn to_domain_equipment(equipment: Option<Json>) -> Result<Vec<EquipmentItem>, String> {
+    match equipment {
+        Some(json) => serde_json::from_value(json).map_err(|e| format!("Invalid equipment: {}", e)),
+        None => Ok(Vec::new()),
+    }
+}
+
+pub fn to_db_equipment(equipment: &[EquipmentItem]) -> Option<Json> {
+    serde_json::to_value(equipment).ok()
+}
@@ -26,14 +26,29 @@ pub struct Model {
     pub hire_date: Option<Date>,
     pub full_name: Option<String>,
     pub password: String,
+    pub deleted_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::attendance_records::Entity")]
+    AttendanceRecords,
     #[sea_orm(has_many = "super::courses::Entity")]
     Courses,
     #[sea_orm(has_many = "super::enrollments::Entity")]
     Enrollments,
+    #[sea_orm(has_one = "super::notification_preferences::Entity")]
+    NotificationPreferences,
+    #[sea_orm(has_many = "super::overload_requests::Entity")]
+    OverloadRequests,
+    #[sea_orm(has_many = "super::student_status_history::Entity")]
+    StudentStatusHistory,
+}
+
+impl Related<super::attendance_records::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AttendanceRecords.def()
+    }
 }
 
 impl Related<super::courses::Entity> for Entity {
@@ -48,4 +63,22 @@ impl Related<super::enrollments::Entity> for Entity {
     }
 }
 
+impl Related<super::notification_preferences::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NotificationPreferences.def()
+    }
+}
+
+impl Related<super::overload_requests::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OverloadRequests.def()
+    }
+}
+
+impl Related<super::student_status_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StudentStatusHistory.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,108 @@
+use crate::domain::{
+    models::attendance::{AttendanceFilter, AttendanceRecord},
+    models::enums::AttendanceStatus,
+    repositories::attendance_repository::AttendanceRepository,
+};
+use crate::infrastructure::database::entities::attendance_records;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    sea_query::OnConflict,
+};
+use shared::config::connect_to_supabase;
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub struct SupabaseAttendanceRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseAttendanceRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_record(r: attendance_records::Model) -> Result<AttendanceRecord, String> {
+    Ok(AttendanceRecord {
+        id: r.id,
+        schedule_id: r.schedule_id,
+        course_id: r.course_id,
+        student_id: r.student_id,
+        session_date: r.session_date,
+        status: AttendanceStatus::from_str(&r.status)?,
+        recorded_by: r.recorded_by,
+        recorded_at: Some(r.recorded_at.to_string()),
+    })
+}
+
+#[async_trait]
+impl AttendanceRepository for SupabaseAttendanceRepository {
+    async fn save_records(&self, records: &[AttendanceRecord]) -> Result<(), String> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let models = records.iter().map(|r| attendance_records::ActiveModel {
+            id: Set(r.id.clone()),
+            schedule_id: Set(r.schedule_id.clone()),
+            course_id: Set(r.course_id.clone()),
+            student_id: Set(r.student_id.clone()),
+            session_date: Set(r.session_date),
+            status: Set(r.status.to_string()),
+            recorded_by: Set(r.recorded_by.clone()),
+            recorded_at: Set(Utc::now().naive_utc()),
+        });
+
+        // Una fila por alumno y fecha de la sesión: volver a pasar lista la corrige
+        attendance_records::Entity::insert_many(models)
+            .on_conflict(
+                OnConflict::columns([
+                    attendance_records::Column::ScheduleId,
+                    attendance_records::Column::SessionDate,
+                    attendance_records::Column::StudentId,
+                ])
+                .update_columns([
+                    attendance_records::Column::Status,
+                    attendance_records::Column::RecordedBy,
+                    attendance_records::Column::RecordedAt,
+                ])
+                .to_owned(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_records(
+        &self,
+        filter: &AttendanceFilter,
+    ) -> Result<Vec<AttendanceRecord>, String> {
+        let mut query = attendance_records::Entity::find();
+
+        if let Some(course_id) = &filter.course_id {
+            query = query.filter(attendance_records::Column::CourseId.eq(course_id));
+        }
+        if let Some(student_id) = &filter.student_id {
+            query = query.filter(attendance_records::Column::StudentId.eq(student_id));
+        }
+        if let Some(schedule_id) = &filter.schedule_id {
+            query = query.filter(attendance_records::Column::ScheduleId.eq(schedule_id));
+        }
+        if let Some(date) = filter.session_date {
+            query = query.filter(attendance_records::Column::SessionDate.eq(date));
+        }
+
+        query
+            .order_by_asc(attendance_records::Column::SessionDate)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_record)
+            .collect()
+    }
+}
@@ -0,0 +1,86 @@
+use crate::domain::{
+    models::audit_log::{AuditFilter, AuditLog},
+    models::enums::AuditAction,
+    repositories::audit_repository::AuditRepository,
+};
+use crate::infrastructure::database::entities::audit_logs;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use shared::config::connect_to_supabase;
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub struct SupabaseAuditRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseAuditRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+pub(crate) async fn create_log<C: ConnectionTrait>(db: &C, log: &AuditLog) -> Result<(), String> {
+    let log_model = audit_logs::ActiveModel {
+        id: Set(log.id.clone()),
+        actor_id: Set(log.actor_id.clone()),
+        entity_type: Set(log.entity_type.clone()),
+        entity_id: Set(log.entity_id.clone()),
+        action: Set(log.action.to_string()),
+        before: Set(log.before.clone()),
+        after: Set(log.after.clone()),
+        created_at: Set(Utc::now().naive_utc()),
+    };
+
+    log_model.insert(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[async_trait]
+impl AuditRepository for SupabaseAuditRepository {
+    async fn create_log(&self, log: &AuditLog) -> Result<(), String> {
+        create_log(&self.db, log).await
+    }
+
+    async fn get_logs(&self, filter: &AuditFilter) -> Result<Vec<AuditLog>, String> {
+        let mut query = audit_logs::Entity::find();
+
+        if let Some(entity_id) = &filter.entity_id {
+            query = query.filter(audit_logs::Column::EntityId.eq(entity_id));
+        }
+        if let Some(actor_id) = &filter.actor_id {
+            query = query.filter(audit_logs::Column::ActorId.eq(actor_id));
+        }
+        if let Some(from) = filter.from {
+            query = query.filter(audit_logs::Column::CreatedAt.gte(from));
+        }
+        if let Some(to) = filter.to {
+            query = query.filter(audit_logs::Column::CreatedAt.lte(to));
+        }
+
+        query
+            .order_by_asc(audit_logs::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|l| {
+                Ok(AuditLog {
+                    id: l.id,
+                    actor_id: l.actor_id,
+                    entity_type: l.entity_type,
+                    entity_id: l.entity_id,
+                    action: AuditAction::from_str(&l.action)?,
+                    before: l.before,
+                    after: l.after,
+                    created_at: Some(l.created_at.to_string()),
+                })
+            })
+            .collect()
+    }
+}
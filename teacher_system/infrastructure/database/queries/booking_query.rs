@@ -0,0 +1,141 @@
+use crate::domain::{
+    models::booking::{BookingFilter, FacilityBooking},
+    models::enums::BookingStatus,
+    repositories::booking_repository::BookingRepository,
+};
+use crate::infrastructure::database::entities::facility_bookings;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use shared::config::connect_to_supabase;
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub struct SupabaseBookingRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseBookingRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_booking(b: facility_bookings::Model) -> Result<FacilityBooking, String> {
+    Ok(FacilityBooking {
+        id: b.id,
+        facility_id: b.facility_id,
+        requested_by: b.requested_by,
+        purpose: b.purpose,
+        date: b.booking_date,
+        start_time: b.start_time,
+        end_time: b.end_time,
+        status: BookingStatus::from_str(&b.status)?,
+        reviewed_by: b.reviewed_by,
+        created_at: Some(b.created_at.to_string()),
+    })
+}
+
+pub(crate) async fn create_booking<C: ConnectionTrait>(
+    db: &C,
+    booking: &FacilityBooking,
+) -> Result<(), String> {
+    let booking_model = facility_bookings::ActiveModel {
+        id: Set(booking.id.clone()),
+        facility_id: Set(booking.facility_id.clone()),
+        requested_by: Set(booking.requested_by.clone()),
+        purpose: Set(booking.purpose.clone()),
+        booking_date: Set(booking.date),
+        start_time: Set(booking.start_time),
+        end_time: Set(booking.end_time),
+        status: Set(booking.status.to_string()),
+        // Las reservas ya aprobadas (recuperaciones) guardan quién las dio por buenas
+        reviewed_by: Set(booking.reviewed_by.clone()),
+        reviewed_at: Set(booking.reviewed_by.as_ref().map(|_| Utc::now().naive_utc())),
+        created_at: Set(Utc::now().naive_utc()),
+    };
+
+    booking_model.insert(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[async_trait]
+impl BookingRepository for SupabaseBookingRepository {
+    async fn create_booking(&self, booking: &FacilityBooking) -> Result<(), String> {
+        create_booking(&self.db, booking).await
+    }
+
+    async fn update_booking_status(
+        &self,
+        booking_id: &str,
+        from: &[BookingStatus],
+        status: BookingStatus,
+        reviewed_by: Option<&str>,
+    ) -> Result<(), String> {
+        let mut booking = facility_bookings::ActiveModel {
+            status: Set(status.to_string()),
+            ..Default::default()
+        };
+        if let Some(reviewer) = reviewed_by {
+            booking.reviewed_by = Set(Some(reviewer.to_string()));
+            booking.reviewed_at = Set(Some(Utc::now().naive_utc()));
+        }
+
+        // Solo cambia si nadie la revisó o canceló desde que se leyó
+        let result = facility_bookings::Entity::update_many()
+            .set(booking)
+            .filter(facility_bookings::Column::Id.eq(booking_id))
+            .filter(facility_bookings::Column::Status.is_in(from.iter().map(ToString::to_string)))
+            .exec(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if result.rows_affected == 0 {
+            return match self.get_booking_by_id(booking_id).await? {
+                Some(current) => Err(format!("Conflict: booking is already {}", current.status)),
+                None => Err("Booking not found".to_string()),
+            };
+        }
+        Ok(())
+    }
+
+    async fn get_booking_by_id(&self, booking_id: &str) -> Result<Option<FacilityBooking>, String> {
+        facility_bookings::Entity::find_by_id(booking_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_booking)
+            .transpose()
+    }
+
+    async fn get_bookings(&self, filter: &BookingFilter) -> Result<Vec<FacilityBooking>, String> {
+        let mut query = facility_bookings::Entity::find();
+
+        if let Some(facility_id) = &filter.facility_id {
+            query = query.filter(facility_bookings::Column::FacilityId.eq(facility_id));
+        }
+        if let Some(requested_by) = &filter.requested_by {
+            query = query.filter(facility_bookings::Column::RequestedBy.eq(requested_by));
+        }
+        if let Some(date) = filter.date {
+            query = query.filter(facility_bookings::Column::BookingDate.eq(date));
+        }
+        if let Some(status) = filter.status {
+            query = query.filter(facility_bookings::Column::Status.eq(status.to_string()));
+        }
+
+        query
+            .order_by_asc(facility_bookings::Column::BookingDate)
+            .order_by_asc(facility_bookings::Column::StartTime)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_booking)
+            .collect()
+    }
+}
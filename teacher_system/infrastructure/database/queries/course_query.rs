@@ -1,14 +1,21 @@
-use crate::domain::{models::course::Course, repositories::course_repository::CourseRepository};
+use crate::domain::{
+    models::{
+        course::{Course, PrerequisiteLink},
+        enums::FacilityType,
+    },
+    repositories::course_repository::CourseRepository,
+};
 use crate::infrastructure::database::entities::{
-    course_schedules, courses, facilities, sea_orm_active_enums,
+    course_prerequisites, course_schedules, courses, facilities, sea_orm_active_enums,
 };
 use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, JoinType, ModelTrait,
-    QueryFilter, QuerySelect, RelationTrait, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, JoinType,
+    ModelTrait, QueryFilter, QuerySelect, RelationTrait, Set,
 };
 use shared::config::connect_to_supabase;
+use std::collections::HashMap;
 
 #[derive(Clone)]
 pub struct SupabaseCourseRepository {
@@ -20,145 +27,240 @@ impl SupabaseCourseRepository {
         let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
         Ok(Self { db })
     }
+}
 
-    // Función auxiliar para asignar aula automáticamente
-    async fn assign_facility(&self, course: &Course) -> Result<String, String> {
-        // Lógica simple para encontrar un aula disponible
-        // En una implementación real, considerarías capacidad, horarios, etc.
+// Función auxiliar para asignar aula automáticamente
+async fn assign_facility<C: ConnectionTrait>(db: &C, course: &Course) -> Result<String, String> {
+    // Lógica simple para encontrar un aula disponible
+    // En una implementación real, considerarías capacidad, horarios, etc.
 
-        // Buscar aulas con capacidad suficiente
-        let facility = facilities::Entity::find()
-            .filter(facilities::Column::Capacity.gte(course.capacity))
-            .filter(facilities::Column::FacilityType.eq("classroom"))
-            .one(&self.db)
-            .await
-            .map_err(|e| e.to_string())?;
+    // Buscar aulas con capacidad suficiente
+    let facility = facilities::Entity::find()
+        .filter(facilities::Column::Capacity.gte(course.capacity))
+        .filter(facilities::Column::FacilityType.eq(FacilityType::Classroom.to_string()))
+        .filter(facilities::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?;
 
-        match facility {
-            Some(f) => Ok(f.id),
-            None => Err("No hay aulas disponibles que cumplan los requisitos".to_string()),
-        }
+    match facility {
+        Some(f) => Ok(f.id),
+        None => Err("No hay aulas disponibles que cumplan los requisitos".to_string()),
     }
 }
 
+fn to_domain_course(c: courses::Model) -> Course {
+    Course {
+        id: c.id,
+        code: c.code,
+        name: c.name,
+        section: c.section,
+        curriculum: sea_orm_active_enums::to_domain_curriculum(&c.curriculum),
+        capacity: c.capacity,
+        credits: c.credits,
+        hours_per_week: c.hours_per_week,
+        cycle: c.cycle,
+        teacher_id: c.teacher_id.unwrap_or_default(),
+        facility_id: c.facility_id,
+        enrolled: c.enrolled,
+        semester: c.semester,
+        academic_year: c.academic_year,
+        program: c.program.unwrap_or_default(),
+        active: c.active.unwrap_or(true),
+        version: c.version,
+    }
+}
+
+pub(crate) async fn create_course<C: ConnectionTrait>(
+    db: &C,
+    course: &Course,
+) -> Result<(), String> {
+    let new_course = courses::ActiveModel {
+        id: Set(course.id.clone()),
+        code: Set(course.code.clone()),
+        name: Set(course.name.clone()),
+        section: Set(course.section),
+        curriculum: Set(sea_orm_active_enums::to_db_curriculum(&course.curriculum)),
+        capacity: Set(course.capacity),
+        credits: Set(course.credits),
+        hours_per_week: Set(course.hours_per_week),
+        cycle: Set(course.cycle),
+        teacher_id: Set(Some(course.teacher_id.clone()).filter(|id| !id.is_empty())),
+        facility_id: Set(match course.facility_id.as_str() {
+            "" => assign_facility(db, course).await?,
+            facility_id => facility_id.to_string(),
+        }),
+        enrolled: Set(course.enrolled),
+        max_capacity: Set(Some(course.capacity)),
+        available_spots: Set(Some(course.available_spots())),
+        semester: Set(course.semester.clone()),
+        academic_year: Set(course.academic_year),
+        program: Set(course.program().map(str::to_string)),
+        active: Set(Some(course.active)),
+        created_at: Set(Some(Utc::now().naive_utc())),
+        updated_at: Set(Some(Utc::now().naive_utc())),
+        deleted_at: Set(None),
+        version: Set(1),
+    };
+
+    new_course.insert(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub(crate) async fn update_course<C: ConnectionTrait>(
+    db: &C,
+    course: &Course,
+    expected_version: Option<i32>,
+) -> Result<(), String> {
+    let existing = courses::Entity::find_by_id(&course.id)
+        .filter(courses::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Course not found".to_string())?;
+
+    let current_version = expected_version.unwrap_or(existing.version);
+    let mut course_to_update: courses::ActiveModel = existing.into();
+
+    course_to_update.code = Set(course.code.clone());
+    course_to_update.name = Set(course.name.clone());
+    course_to_update.section = Set(course.section);
+    course_to_update.curriculum = Set(sea_orm_active_enums::to_db_curriculum(&course.curriculum));
+    course_to_update.capacity = Set(course.capacity);
+    course_to_update.credits = Set(course.credits);
+    course_to_update.hours_per_week = Set(course.hours_per_week);
+    course_to_update.cycle = Set(course.cycle);
+    course_to_update.teacher_id = Set(Some(course.teacher_id.clone()).filter(|id| !id.is_empty()));
+    // Sin aula indicada se conserva la que ya tiene el curso
+    if !course.facility_id.is_empty() {
+        course_to_update.facility_id = Set(course.facility_id.clone());
+    }
+    course_to_update.enrolled = Set(course.enrolled);
+    course_to_update.max_capacity = Set(Some(course.capacity));
+    course_to_update.available_spots = Set(Some(course.available_spots()));
+    course_to_update.semester = Set(course.semester.clone());
+    course_to_update.academic_year = Set(course.academic_year);
+    course_to_update.program = Set(course.program().map(str::to_string));
+    course_to_update.active = Set(Some(course.active));
+    course_to_update.updated_at = Set(Some(Utc::now().naive_utc()));
+    course_to_update.version = Set(current_version + 1);
+
+    // Solo se actualiza si nadie modificó el curso desde que se leyó
+    let result = courses::Entity::update_many()
+        .set(course_to_update)
+        .filter(courses::Column::Id.eq(&course.id))
+        .filter(courses::Column::Version.eq(current_version))
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if result.rows_affected == 0 {
+        return Err("Precondition failed: course was modified by another request".to_string());
+    }
+    Ok(())
+}
+
+pub(crate) async fn delete_course<C: ConnectionTrait>(db: &C, id: &str) -> Result<(), String> {
+    let course = courses::Entity::find_by_id(id)
+        .filter(courses::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Course not found".to_string())?;
+
+    let mut course: courses::ActiveModel = course.into();
+    course.active = Set(Some(false)); // Borrado lógico
+    course.deleted_at = Set(Some(Utc::now().naive_utc()));
+    course.updated_at = Set(Some(Utc::now().naive_utc()));
+
+    course.update(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub(crate) async fn restore_course<C: ConnectionTrait>(db: &C, id: &str) -> Result<(), String> {
+    let course = courses::Entity::find_by_id(id)
+        .filter(courses::Column::DeletedAt.is_not_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Archived course not found".to_string())?;
+
+    let mut course: courses::ActiveModel = course.into();
+    course.active = Set(Some(true));
+    course.deleted_at = Set(None);
+    course.updated_at = Set(Some(Utc::now().naive_utc()));
+
+    course.update(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 #[async_trait]
 impl CourseRepository for SupabaseCourseRepository {
     async fn create_course(&self, course: &Course) -> Result<(), String> {
-        let new_course = courses::ActiveModel {
-            id: Set(course.id.clone()),
-            code: Set(course.code.clone()),
-            name: Set(course.name.clone()),
-            section: Set(course.section),
-            curriculum: Set(sea_orm_active_enums::to_db_curriculum(&course.curriculum)),
-            capacity: Set(course.capacity),
-            credits: Set(course.credits),
-            hours_per_week: Set(course.hours_per_week),
-            cycle: Set(course.cycle),
-            teacher_id: Set(course.teacher_id.clone()),
-            facility_id: Set(self.assign_facility(course).await?), // Campo temporal
-            enrolled: Set(course.enrolled),
-            max_capacity: Set(Some(course.capacity)),
-            available_spots: Set(Some(course.available_spots())),
-            semester: Set(course.semester.clone()),
-            academic_year: Set(course.academic_year),
-            active: Set(Some(course.active)),
-            created_at: Set(Some(Utc::now().naive_utc())),
-            updated_at: Set(Some(Utc::now().naive_utc())),
-        };
-
-        new_course
-            .insert(&self.db)
+        create_course(&self.db, course).await
+    }
+
+    async fn update_course(
+        &self,
+        course: &Course,
+        expected_version: Option<i32>,
+    ) -> Result<(), String> {
+        update_course(&self.db, course, expected_version).await
+    }
+
+    async fn get_course_by_id(&self, id: &str) -> Result<Course, String> {
+        let course_model = courses::Entity::find_by_id(id)
+            .filter(courses::Column::DeletedAt.is_null())
+            .one(&self.db)
             .await
-            .map_err(|e| e.to_string())?;
-        Ok(())
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Course not found".to_string())?;
+
+        Ok(to_domain_course(course_model))
     }
 
-    async fn update_course(&self, course: &Course) -> Result<(), String> {
-        let mut course_to_update: courses::ActiveModel = courses::Entity::find_by_id(&course.id)
+    async fn get_course_including_archived(&self, id: &str) -> Result<Course, String> {
+        courses::Entity::find_by_id(id)
             .one(&self.db)
             .await
             .map_err(|e| e.to_string())?
-            .ok_or_else(|| "Curso no encontrado".to_string())?
-            .into();
-
-        course_to_update.code = Set(course.code.clone());
-        course_to_update.name = Set(course.name.clone());
-        course_to_update.section = Set(course.section);
-        course_to_update.curriculum =
-            Set(sea_orm_active_enums::to_db_curriculum(&course.curriculum));
-        course_to_update.capacity = Set(course.capacity);
-        course_to_update.credits = Set(course.credits);
-        course_to_update.hours_per_week = Set(course.hours_per_week);
-        course_to_update.cycle = Set(course.cycle);
-        course_to_update.teacher_id = Set(course.teacher_id.clone());
-        course_to_update.facility_id = Set(self.assign_facility(course).await?); // Campo temporal
-        course_to_update.enrolled = Set(course.enrolled);
-        course_to_update.max_capacity = Set(Some(course.capacity));
-        course_to_update.available_spots = Set(Some(course.available_spots()));
-        course_to_update.semester = Set(course.semester.clone());
-        course_to_update.academic_year = Set(course.academic_year);
-        course_to_update.active = Set(Some(course.active));
-        course_to_update.updated_at = Set(Some(Utc::now().naive_utc()));
-
-        course_to_update
-            .update(&self.db)
+            .map(to_domain_course)
+            .ok_or_else(|| "Course not found".to_string())
+    }
+
+    async fn get_courses_including_archived(&self, ids: &[String]) -> Result<Vec<Course>, String> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(courses::Entity::find()
+            .filter(courses::Column::Id.is_in(ids.iter().cloned()))
+            .all(&self.db)
             .await
-            .map_err(|e| e.to_string())?;
-        Ok(())
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_course)
+            .collect())
     }
 
-    async fn get_course_by_id(&self, id: &str) -> Result<Course, String> {
-        let course_model = courses::Entity::find_by_id(id)
-            .one(&self.db)
+    async fn get_all_courses_including_archived(&self) -> Result<Vec<Course>, String> {
+        Ok(courses::Entity::find()
+            .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
-            .ok_or_else(|| "Curso no encontrado".to_string())?;
-
-        Ok(Course {
-            id: course_model.id,
-            code: course_model.code,
-            name: course_model.name,
-            section: course_model.section,
-            curriculum: sea_orm_active_enums::to_domain_curriculum(&course_model.curriculum),
-            capacity: course_model.capacity,
-            credits: course_model.credits,
-            hours_per_week: course_model.hours_per_week,
-            cycle: course_model.cycle,
-            teacher_id: course_model.teacher_id,
-            facility_id: "".to_string(), // Campo temporal
-            enrolled: course_model.enrolled,
-            semester: course_model.semester,
-            academic_year: course_model.academic_year,
-            active: course_model.active.unwrap_or(true),
-        })
+            .into_iter()
+            .map(to_domain_course)
+            .collect())
     }
 
     async fn get_all_courses(&self) -> Result<Vec<Course>, String> {
         let courses = courses::Entity::find()
+            .filter(courses::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(courses
-            .into_iter()
-            .map(|c| Course {
-                id: c.id,
-                code: c.code,
-                name: c.name,
-                section: c.section,
-                curriculum: sea_orm_active_enums::to_domain_curriculum(&c.curriculum),
-                capacity: c.capacity,
-                credits: c.credits,
-                hours_per_week: c.hours_per_week,
-                cycle: c.cycle,
-                teacher_id: c.teacher_id,
-                facility_id: "".to_string(), // Campo temporal
-                enrolled: c.enrolled,
-                semester: c.semester,
-                academic_year: c.academic_year,
-                active: c.active.unwrap_or(true),
-            })
-            .collect())
+        Ok(courses.into_iter().map(to_domain_course).collect())
     }
 
     async fn get_courses_by_user(&self, user_id: &str) -> Result<Vec<Course>, String> {
@@ -166,59 +268,23 @@ impl CourseRepository for SupabaseCourseRepository {
         // Para profesores: cursos que enseñan
         let courses = courses::Entity::find()
             .filter(courses::Column::TeacherId.eq(user_id))
+            .filter(courses::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(courses
-            .into_iter()
-            .map(|c| Course {
-                id: c.id,
-                code: c.code,
-                name: c.name,
-                section: c.section,
-                curriculum: sea_orm_active_enums::to_domain_curriculum(&c.curriculum),
-                capacity: c.capacity,
-                credits: c.credits,
-                hours_per_week: c.hours_per_week,
-                cycle: c.cycle,
-                teacher_id: c.teacher_id,
-                facility_id: "".to_string(), // Campo temporal
-                enrolled: c.enrolled,
-                semester: c.semester,
-                academic_year: c.academic_year,
-                active: c.active.unwrap_or(true),
-            })
-            .collect())
+        Ok(courses.into_iter().map(to_domain_course).collect())
     }
 
     async fn get_courses_by_facility(&self, facility_id: &str) -> Result<Vec<Course>, String> {
         let courses = courses::Entity::find()
             .filter(courses::Column::FacilityId.eq(facility_id))
+            .filter(courses::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(courses
-            .into_iter()
-            .map(|c| Course {
-                id: c.id,
-                code: c.code,
-                name: c.name,
-                section: c.section,
-                curriculum: sea_orm_active_enums::to_domain_curriculum(&c.curriculum),
-                capacity: c.capacity,
-                credits: c.credits,
-                hours_per_week: c.hours_per_week,
-                cycle: c.cycle,
-                teacher_id: c.teacher_id,
-                facility_id: "".to_string(), // Campo temporal
-                enrolled: c.enrolled,
-                semester: c.semester,
-                academic_year: c.academic_year,
-                active: c.active.unwrap_or(true),
-            })
-            .collect())
+        Ok(courses.into_iter().map(to_domain_course).collect())
     }
 
     async fn get_courses_by_facility_name(
@@ -235,30 +301,12 @@ impl CourseRepository for SupabaseCourseRepository {
                 course_schedules::Relation::Facilities.def(),
             )
             .filter(facilities::Column::Name.eq(name_facility))
+            .filter(courses::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(courses
-            .into_iter()
-            .map(|c| Course {
-                id: c.id,
-                code: c.code,
-                name: c.name,
-                section: c.section,
-                curriculum: sea_orm_active_enums::to_domain_curriculum(&c.curriculum),
-                capacity: c.capacity,
-                credits: c.credits,
-                hours_per_week: c.hours_per_week,
-                cycle: c.cycle,
-                teacher_id: c.teacher_id,
-                facility_id: "".to_string(),
-                enrolled: c.enrolled,
-                semester: c.semester,
-                academic_year: c.academic_year,
-                active: c.active.unwrap_or(true),
-            })
-            .collect())
+        Ok(courses.into_iter().map(to_domain_course).collect())
     }
 
     async fn get_courses_by_schedule(&self, schedule_id: &str) -> Result<Course, String> {
@@ -267,46 +315,84 @@ impl CourseRepository for SupabaseCourseRepository {
             .one(&self.db)
             .await
             .map_err(|e| e.to_string())?
-            .ok_or_else(|| "Horario no encontrado".to_string())?;
+            .ok_or_else(|| "Schedule not found".to_string())?;
 
         let course_model = course_schedule
             .find_related(courses::Entity)
+            .filter(courses::Column::DeletedAt.is_null())
             .one(&self.db)
             .await
             .map_err(|e| e.to_string())?
-            .ok_or_else(|| "Curso no encontrado para este horario".to_string())?;
-
-        Ok(Course {
-            id: course_model.id,
-            code: course_model.code,
-            name: course_model.name,
-            section: course_model.section,
-            curriculum: sea_orm_active_enums::to_domain_curriculum(&course_model.curriculum),
-            capacity: course_model.capacity,
-            credits: course_model.credits,
-            hours_per_week: course_model.hours_per_week,
-            cycle: course_model.cycle,
-            teacher_id: course_model.teacher_id,
-            facility_id: "".to_string(), // Campo temporal
-            enrolled: course_model.enrolled,
-            semester: course_model.semester,
-            academic_year: course_model.academic_year,
-            active: course_model.active.unwrap_or(true),
-        })
+            .ok_or_else(|| "Course not found for this schedule".to_string())?;
+
+        Ok(to_domain_course(course_model))
     }
 
-    async fn delete_course(&self, id: &str) -> Result<(), String> {
-        let course = courses::Entity::find_by_id(id)
-            .one(&self.db)
+    async fn get_prerequisites(&self, course_id: &str) -> Result<Vec<Course>, String> {
+        let prerequisite_ids: Vec<String> = course_prerequisites::Entity::find()
+            .filter(course_prerequisites::Column::CourseId.eq(course_id))
+            .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
-            .ok_or_else(|| "Curso no encontrado".to_string())?;
+            .into_iter()
+            .map(|p| p.prerequisite_course_id)
+            .collect();
 
-        let mut course: courses::ActiveModel = course.into();
-        course.active = Set(Some(false)); // Borrado lógico
-        course.updated_at = Set(Some(Utc::now().naive_utc()));
+        if prerequisite_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Sin filtrar borrados: un curso archivado sigue siendo requisito
+        let courses = courses::Entity::find()
+            .filter(courses::Column::Id.is_in(prerequisite_ids))
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(courses.into_iter().map(to_domain_course).collect())
+    }
+
+    async fn get_prerequisite_links(&self) -> Result<Vec<PrerequisiteLink>, String> {
+        let rows = course_prerequisites::Entity::find()
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Códigos de todos los cursos, incluidos los archivados
+        let codes: HashMap<String, String> = courses::Entity::find()
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|c| (c.id, c.code))
+            .collect();
+
+        let mut links: Vec<PrerequisiteLink> = Vec::new();
+        for row in rows {
+            let (Some(course_code), Some(prerequisite_code)) = (
+                codes.get(&row.course_id),
+                codes.get(&row.prerequisite_course_id),
+            ) else {
+                continue;
+            };
+            if !links
+                .iter()
+                .any(|l| &l.course_code == course_code && &l.prerequisite_code == prerequisite_code)
+            {
+                links.push(PrerequisiteLink {
+                    course_code: course_code.clone(),
+                    prerequisite_code: prerequisite_code.clone(),
+                });
+            }
+        }
+        Ok(links)
+    }
+
+    async fn delete_course(&self, id: &str) -> Result<(), String> {
+        delete_course(&self.db, id).await
+    }
 
-        course.update(&self.db).await.map_err(|e| e.to_string())?;
-        Ok(())
+    async fn restore_course(&self, id: &str) -> Result<(), String> {
+        restore_course(&self.db, id).await
     }
 }
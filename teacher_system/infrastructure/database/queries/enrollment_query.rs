@@ -0,0 +1,276 @@
+use crate::domain::{
+    models::{enrollment::Enrollment, enums::EnrollmentStatus},
+    repositories::enrollment_repository::EnrollmentRepository,
+};
+use crate::infrastructure::database::entities::{
+    courses, enrollments, sea_orm_active_enums, users,
+};
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QuerySelect, Set,
+    sea_query::{Expr, LockType},
+};
+use shared::config::connect_to_supabase;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct SupabaseEnrollmentRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseEnrollmentRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_enrollment(e: enrollments::Model) -> Enrollment {
+    Enrollment {
+        id: e.id,
+        student_id: e.student_id,
+        course_id: e.course_id,
+        status: sea_orm_active_enums::to_domain_enrollment(&e.status),
+        final_grade: e.final_grade,
+    }
+}
+
+pub(crate) async fn update_enrollment_status<C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+    status: &EnrollmentStatus,
+) -> Result<(), String> {
+    let existing = enrollments::Entity::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Enrollment not found")?;
+
+    let mut enrollment_model: enrollments::ActiveModel = existing.into();
+    enrollment_model.status = Set(sea_orm_active_enums::to_db_enrollment(status));
+
+    enrollment_model
+        .update(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Confirma la matrícula solo si sigue pendiente. Bloquea la fila del alumno
+/// para que dos confirmaciones a la vez no pasen juntas el tope de créditos, y
+/// la del curso para ocupar su vacante sin pasar la capacidad.
+pub(crate) async fn confirm_enrollment<C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+    max_credits: Option<i32>,
+) -> Result<(), String> {
+    let enrollment = enrollments::Entity::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Enrollment not found")?;
+
+    users::Entity::find_by_id(enrollment.student_id.clone())
+        .lock(LockType::Update)
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    let course = courses::Entity::find_by_id(enrollment.course_id.clone())
+        .lock(LockType::Update)
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Course not found")?;
+
+    let result = enrollments::Entity::update_many()
+        .set(enrollments::ActiveModel {
+            status: Set(sea_orm_active_enums::EnrollmentStatus::Enrolled),
+            ..Default::default()
+        })
+        .filter(enrollments::Column::Id.eq(id))
+        .filter(enrollments::Column::Status.eq(sea_orm_active_enums::EnrollmentStatus::Pending))
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    if result.rows_affected == 0 {
+        return Err("Conflict: enrollment is no longer pending".to_string());
+    }
+
+    if course.enrolled >= course.capacity {
+        return Err(format!(
+            "Conflict: {} section {} is full ({} of {})",
+            course.code, course.section, course.enrolled, course.capacity
+        ));
+    }
+    courses::Entity::update_many()
+        .col_expr(
+            courses::Column::Enrolled,
+            Expr::col(courses::Column::Enrolled).add(1),
+        )
+        .filter(courses::Column::Id.eq(&course.id))
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let Some(max) = max_credits else {
+        return Ok(());
+    };
+    let credits: i32 = enrollments::Entity::find()
+        .find_also_related(courses::Entity)
+        .filter(enrollments::Column::StudentId.eq(&enrollment.student_id))
+        .filter(enrollments::Column::Status.eq(sea_orm_active_enums::EnrollmentStatus::Enrolled))
+        .filter(courses::Column::AcademicYear.eq(course.academic_year))
+        .filter(courses::Column::Semester.eq(&course.semester))
+        .all(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter_map(|(_, course)| course)
+        .map(|course| course.credits)
+        .sum();
+    if credits > max {
+        return Err(format!(
+            "Conflict: {} credits would exceed the maximum of {} for {} {}; an overload request must be approved first",
+            credits, max, course.academic_year, course.semester
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) async fn move_enrollments<C: ConnectionTrait>(
+    db: &C,
+    enrollment_ids: &[String],
+    course_id: &str,
+) -> Result<(), String> {
+    if enrollment_ids.is_empty() {
+        return Ok(());
+    }
+
+    enrollments::Entity::update_many()
+        .col_expr(enrollments::Column::CourseId, Expr::value(course_id))
+        .filter(enrollments::Column::Id.is_in(enrollment_ids.iter().cloned()))
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub(crate) async fn record_result<C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+    status: &EnrollmentStatus,
+    final_grade: f64,
+) -> Result<(), String> {
+    let existing = enrollments::Entity::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Enrollment not found")?;
+
+    let mut enrollment_model: enrollments::ActiveModel = existing.into();
+    enrollment_model.status = Set(sea_orm_active_enums::to_db_enrollment(status));
+    enrollment_model.final_grade = Set(Some(final_grade));
+
+    enrollment_model
+        .update(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[async_trait]
+impl EnrollmentRepository for SupabaseEnrollmentRepository {
+    async fn get_enrollment_by_id(&self, id: &str) -> Result<Option<Enrollment>, String> {
+        let enrollment = enrollments::Entity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_enrollment);
+
+        Ok(enrollment)
+    }
+
+    async fn get_enrollments_by_course(&self, course_id: &str) -> Result<Vec<Enrollment>, String> {
+        let enrollments = enrollments::Entity::find()
+            .filter(enrollments::Column::CourseId.eq(course_id))
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_enrollment)
+            .collect();
+
+        Ok(enrollments)
+    }
+
+    async fn get_enrollments_by_student(
+        &self,
+        student_id: &str,
+    ) -> Result<Vec<Enrollment>, String> {
+        let enrollments = enrollments::Entity::find()
+            .filter(enrollments::Column::StudentId.eq(student_id))
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_enrollment)
+            .collect();
+
+        Ok(enrollments)
+    }
+
+    async fn get_passed_codes(
+        &self,
+        student_ids: &[String],
+        include_in_progress: bool,
+    ) -> Result<HashMap<String, Vec<String>>, String> {
+        let mut statuses = vec![sea_orm_active_enums::EnrollmentStatus::Completed];
+        if include_in_progress {
+            statuses.push(sea_orm_active_enums::EnrollmentStatus::Enrolled);
+        }
+
+        let mut passed: HashMap<String, Vec<String>> = HashMap::new();
+        if student_ids.is_empty() {
+            return Ok(passed);
+        }
+        for (enrollment, course) in enrollments::Entity::find()
+            .find_also_related(courses::Entity)
+            .filter(enrollments::Column::StudentId.is_in(student_ids.iter().cloned()))
+            .filter(enrollments::Column::Status.is_in(statuses))
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            let Some(course) = course else {
+                continue;
+            };
+            let codes = passed.entry(enrollment.student_id).or_default();
+            if !codes.contains(&course.code) {
+                codes.push(course.code);
+            }
+        }
+        Ok(passed)
+    }
+
+    async fn update_enrollment_status(
+        &self,
+        id: &str,
+        status: &EnrollmentStatus,
+    ) -> Result<(), String> {
+        update_enrollment_status(&self.db, id, status).await
+    }
+
+    async fn record_result(
+        &self,
+        id: &str,
+        status: &EnrollmentStatus,
+        final_grade: f64,
+    ) -> Result<(), String> {
+        record_result(&self.db, id, status, final_grade).await
+    }
+
+    async fn move_enrollments(&self, enrollment_ids: &[String], course_id: &str) -> Result<(), String> {
+        move_enrollments(&self.db, enrollment_ids, course_id).await
+    }
+}
@@ -0,0 +1,127 @@
+use crate::domain::{
+    models::enums::DeliveryStatus,
+    models::event::{EventEnvelope, OutboxEvent},
+    repositories::event_outbox_repository::EventOutboxRepository,
+};
+use crate::infrastructure::database::entities::event_outbox;
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime, Utc};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+    sea_query::{Expr, LockBehavior, LockType},
+};
+use shared::config::connect_to_supabase;
+use std::str::FromStr;
+
+/// Tiempo que un evento tomado queda apartado; si el proceso cae antes de
+/// actualizarlo, vuelve a estar disponible.
+const CLAIM_TIMEOUT_MINUTES: i64 = 30;
+
+#[derive(Clone)]
+pub struct SupabaseEventOutboxRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseEventOutboxRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_event(e: event_outbox::Model) -> Result<OutboxEvent, String> {
+    let envelope: EventEnvelope = serde_json::from_value(e.payload)
+        .map_err(|err| format!("Invalid event {}: {}", e.id, err))?;
+
+    Ok(OutboxEvent {
+        envelope,
+        status: DeliveryStatus::from_str(&e.status)?,
+        attempts: e.attempts,
+        next_attempt_at: e.next_attempt_at,
+        last_error: e.last_error,
+        handled_by: serde_json::from_value(e.handled_by).map_err(|err| err.to_string())?,
+    })
+}
+
+pub(crate) async fn create_event<C: ConnectionTrait>(
+    db: &C,
+    event: &OutboxEvent,
+) -> Result<(), String> {
+    let event_model = event_outbox::ActiveModel {
+        id: Set(event.envelope.id.clone()),
+        event_kind: Set(event.envelope.event.kind().to_string()),
+        payload: Set(serde_json::to_value(&event.envelope).map_err(|e| e.to_string())?),
+        status: Set(event.status.to_string()),
+        attempts: Set(event.attempts),
+        next_attempt_at: Set(event.next_attempt_at),
+        last_error: Set(event.last_error.clone()),
+        handled_by: Set(serde_json::to_value(&event.handled_by).map_err(|e| e.to_string())?),
+        created_at: Set(Utc::now().naive_utc()),
+        processed_at: Set(None),
+    };
+
+    event_outbox::Entity::insert(event_model)
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[async_trait]
+impl EventOutboxRepository for SupabaseEventOutboxRepository {
+    async fn claim_due_events(
+        &self,
+        now: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<OutboxEvent>, String> {
+        let txn = self.db.begin().await.map_err(|e| e.to_string())?;
+
+        // Las filas que otro proceso está tomando se saltan en vez de esperar
+        let due = event_outbox::Entity::find()
+            .filter(event_outbox::Column::Status.eq(DeliveryStatus::Pending.to_string()))
+            .filter(event_outbox::Column::NextAttemptAt.lte(now))
+            .order_by_asc(event_outbox::Column::NextAttemptAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !due.is_empty() {
+            event_outbox::Entity::update_many()
+                .col_expr(
+                    event_outbox::Column::NextAttemptAt,
+                    Expr::value(now + Duration::minutes(CLAIM_TIMEOUT_MINUTES)),
+                )
+                .filter(event_outbox::Column::Id.is_in(due.iter().map(|e| e.id.clone())))
+                .exec(&txn)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        txn.commit().await.map_err(|e| e.to_string())?;
+
+        due.into_iter().map(to_domain_event).collect()
+    }
+
+    async fn update_event(&self, event: &OutboxEvent) -> Result<(), String> {
+        let processed = event.status != DeliveryStatus::Pending;
+        let event_model = event_outbox::ActiveModel {
+            status: Set(event.status.to_string()),
+            attempts: Set(event.attempts),
+            next_attempt_at: Set(event.next_attempt_at),
+            last_error: Set(event.last_error.clone()),
+            handled_by: Set(serde_json::to_value(&event.handled_by).map_err(|e| e.to_string())?),
+            processed_at: Set(processed.then(|| Utc::now().naive_utc())),
+            ..Default::default()
+        };
+
+        event_outbox::Entity::update_many()
+            .set(event_model)
+            .filter(event_outbox::Column::Id.eq(&event.envelope.id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
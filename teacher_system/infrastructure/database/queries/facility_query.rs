@@ -1,12 +1,15 @@
 use crate::domain::{
-    models::facilitie::Facility, repositories::facility_repository::FacilityRepository,
+    models::facilitie::{Accessibility, Facility},
+    repositories::facility_repository::FacilityRepository,
+};
+use crate::infrastructure::database::entities::{
+    course_schedules, courses, facilities, sea_orm_active_enums, users,
 };
-use crate::infrastructure::database::entities::{course_schedules, courses, facilities, users};
 use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, JoinType, QueryFilter,
-    QuerySelect, RelationTrait, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, JoinType,
+    QueryFilter, QuerySelect, RelationTrait, Set,
 };
 use shared::config::connect_to_supabase;
 
@@ -22,70 +25,144 @@ impl SupabaseFacilityRepository {
     }
 }
 
+fn to_domain_facility(model: facilities::Model) -> Result<Facility, String> {
+    Ok(Facility {
+        id: model.id,
+        name: model.name,
+        capacity: model.capacity.unwrap_or_default(),
+        facility_type: sea_orm_active_enums::to_domain_facility_type(model.facility_type),
+        building: model.building,
+        floor: model.floor,
+        location: model.location,
+        equipment: sea_orm_active_enums::to_domain_equipment(model.equipment)?,
+        accessibility: Accessibility {
+            wheelchair_accessible: model.wheelchair_accessible.unwrap_or_default(),
+            step_free_access: model.step_free_access.unwrap_or_default(),
+            hearing_loop: model.hearing_loop.unwrap_or_default(),
+        },
+        created_at: model.created_at.map(|dt| dt.to_string()),
+    })
+}
+
+pub(crate) async fn archive_facility<C: ConnectionTrait>(db: &C, id: &str) -> Result<(), String> {
+    let facility = facilities::Entity::find_by_id(id)
+        .filter(facilities::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Facility not found".to_string())?;
+
+    let mut model: facilities::ActiveModel = facility.into();
+    model.deleted_at = Set(Some(Utc::now().naive_utc()));
+
+    model.update(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub(crate) async fn create_facility<C: ConnectionTrait>(
+    db: &C,
+    facility: &Facility,
+) -> Result<(), String> {
+    let new_facility = facilities::ActiveModel {
+        id: Set(facility.id.clone()),
+        name: Set(facility.name.clone()),
+        capacity: Set(Some(facility.capacity.clone())),
+        facility_type: Set(Some(facility.facility_type.to_string())),
+        building: Set(facility.building.clone()),
+        floor: Set(facility.floor),
+        location: Set(facility.location.clone()),
+        equipment: Set(sea_orm_active_enums::to_db_equipment(&facility.equipment)),
+        wheelchair_accessible: Set(Some(facility.accessibility.wheelchair_accessible)),
+        step_free_access: Set(Some(facility.accessibility.step_free_access)),
+        hearing_loop: Set(Some(facility.accessibility.hearing_loop)),
+        created_at: Set(Some(Utc::now().naive_utc())),
+        deleted_at: Set(None),
+    };
+
+    new_facility.insert(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub(crate) async fn update_facility<C: ConnectionTrait>(
+    db: &C,
+    facility: &Facility,
+) -> Result<(), String> {
+    let existing = facilities::Entity::find_by_id(&facility.id)
+        .filter(facilities::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Facility not found".to_string())?;
+
+    let mut model: facilities::ActiveModel = existing.into();
+    model.name = Set(facility.name.clone());
+    model.capacity = Set(Some(facility.capacity.clone()));
+    model.facility_type = Set(Some(facility.facility_type.to_string()));
+    model.building = Set(facility.building.clone());
+    model.floor = Set(facility.floor);
+    model.location = Set(facility.location.clone());
+    model.equipment = Set(sea_orm_active_enums::to_db_equipment(&facility.equipment));
+    model.wheelchair_accessible = Set(Some(facility.accessibility.wheelchair_accessible));
+    model.step_free_access = Set(Some(facility.accessibility.step_free_access));
+    model.hearing_loop = Set(Some(facility.accessibility.hearing_loop));
+
+    model.update(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub(crate) async fn restore_facility<C: ConnectionTrait>(db: &C, id: &str) -> Result<(), String> {
+    let facility = facilities::Entity::find_by_id(id)
+        .filter(facilities::Column::DeletedAt.is_not_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Archived facility not found".to_string())?;
+
+    let mut model: facilities::ActiveModel = facility.into();
+    model.deleted_at = Set(None);
+
+    model.update(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 #[async_trait]
 impl FacilityRepository for SupabaseFacilityRepository {
     async fn create_facility(&self, facility: &Facility) -> Result<(), String> {
-        let new_facility = facilities::ActiveModel {
-            id: Set(facility.id.clone()),
-            name: Set(facility.name.clone()),
-            capacity: Set(Some(facility.capacity.clone())),
-            facility_type: Set(Some(facility.facility_type.clone())),
-            created_at: Set(Some(Utc::now().naive_utc())),
-        };
-
-        new_facility
-            .insert(&self.db)
-            .await
-            .map_err(|e| e.to_string())?;
-        Ok(())
+        create_facility(&self.db, facility).await
     }
 
     async fn update_facility(&self, facility: &Facility) -> Result<(), String> {
-        let existing = facilities::Entity::find_by_id(&facility.id)
+        update_facility(&self.db, facility).await
+    }
+
+    async fn get_facility_by_id(&self, id: &str) -> Result<Facility, String> {
+        facilities::Entity::find_by_id(id)
+            .filter(facilities::Column::DeletedAt.is_null())
             .one(&self.db)
             .await
             .map_err(|e| e.to_string())?
-            .ok_or_else(|| "Facility not found".to_string())?;
-
-        let mut model: facilities::ActiveModel = existing.into();
-        model.name = Set(facility.name.clone());
-        model.capacity = Set(Some(facility.capacity.clone()));
-        model.facility_type = Set(Some(facility.facility_type.clone()));
-
-        model.update(&self.db).await.map_err(|e| e.to_string())?;
-        Ok(())
+            .ok_or_else(|| "Facility not found".to_string())
+            .and_then(to_domain_facility)
     }
 
-    async fn get_facility_by_id(&self, id: &str) -> Result<Facility, String> {
+    async fn get_archived_facility(&self, id: &str) -> Result<Facility, String> {
         facilities::Entity::find_by_id(id)
+            .filter(facilities::Column::DeletedAt.is_not_null())
             .one(&self.db)
             .await
             .map_err(|e| e.to_string())?
-            .map(|model| Facility {
-                id: model.id,
-                name: model.name,
-                capacity: model.capacity.unwrap_or_default(),
-                facility_type: model.facility_type.unwrap_or_default(),
-                created_at: model.created_at.map(|dt| dt.to_string()),
-            })
-            .ok_or_else(|| "Facility not found".to_string())
+            .ok_or_else(|| "Archived facility not found".to_string())
+            .and_then(to_domain_facility)
     }
 
     async fn get_all_facilities(&self) -> Result<Vec<Facility>, String> {
         facilities::Entity::find()
+            .filter(facilities::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|model| {
-                Ok(Facility {
-                    id: model.id,
-                    name: model.name,
-                    capacity: model.capacity.unwrap_or_default(),
-                    facility_type: model.facility_type.unwrap_or_default(),
-                    created_at: model.created_at.map(|dt| dt.to_string()),
-                })
-            })
+            .map(to_domain_facility)
             .collect()
     }
 
@@ -97,24 +174,16 @@ impl FacilityRepository for SupabaseFacilityRepository {
             .await
             .map_err(|e| e.to_string())?;
 
-        let facilities = schedules
+        schedules
             .into_iter()
             .filter_map(|(_, facility_opt)| facility_opt)
             .next()
-            .map(|model| Facility {
-                id: model.id,
-                name: model.name,
-                capacity: model.capacity.unwrap_or_default(),
-                facility_type: model.facility_type.unwrap_or_default(),
-                created_at: model.created_at.map(|dt| dt.to_string()),
-            })
-            .ok_or_else(|| "No facility found for this course".to_string())?;
-
-        Ok(facilities)
+            .ok_or_else(|| "No facility found for this course".to_string())
+            .and_then(to_domain_facility)
     }
 
     async fn get_facilities_name_course(&self, name_course: &str) -> Result<Vec<Facility>, String> {
-        let facilities = facilities::Entity::find()
+        facilities::Entity::find()
             .join(
                 JoinType::InnerJoin,
                 facilities::Relation::CourseSchedules.def(),
@@ -124,20 +193,13 @@ impl FacilityRepository for SupabaseFacilityRepository {
                 course_schedules::Relation::Courses.def(),
             )
             .filter(courses::Column::Name.eq(name_course))
+            .filter(facilities::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|model| Facility {
-                id: model.id,
-                name: model.name,
-                capacity: model.capacity.unwrap_or_default(),
-                facility_type: model.facility_type.unwrap_or_default(),
-                created_at: model.created_at.map(|dt| dt.to_string()),
-            })
-            .collect();
-
-        Ok(facilities)
+            .map(to_domain_facility)
+            .collect()
     }
 
     async fn get_facilities_by_schedule(&self, schedule_id: &str) -> Result<Facility, String> {
@@ -147,45 +209,32 @@ impl FacilityRepository for SupabaseFacilityRepository {
             .await
             .map_err(|e| e.to_string())?
             .and_then(|(_, facility)| facility)
-            .map(|model| Facility {
-                id: model.id,
-                name: model.name,
-                capacity: model.capacity.unwrap_or_default(),
-                facility_type: model.facility_type.unwrap_or_default(),
-                created_at: model.created_at.map(|dt| dt.to_string()),
-            })
             .ok_or_else(|| "Facility not found for this schedule".to_string())
+            .and_then(to_domain_facility)
     }
 
     async fn get_facilities_by_user(&self, user_id: &str) -> Result<Vec<Facility>, String> {
-        let facilitie = facilities::Entity::find()
+        facilities::Entity::find()
             .join(
                 JoinType::InnerJoin,
                 facilities::Relation::CourseSchedules.def(),
             )
             .join(JoinType::InnerJoin, courses::Relation::Users.def())
             .filter(users::Column::Id.eq(user_id))
+            .filter(facilities::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|model| Facility {
-                id: model.id,
-                name: model.name,
-                capacity: model.capacity.unwrap_or_default(),
-                facility_type: model.facility_type.unwrap_or_default(),
-                created_at: model.created_at.map(|dt| dt.to_string()),
-            })
-            .collect();
-
-        Ok(facilitie)
+            .map(to_domain_facility)
+            .collect()
     }
 
     async fn delete_facility(&self, id: &str) -> Result<(), String> {
-        facilities::Entity::delete_by_id(id)
-            .exec(&self.db)
-            .await
-            .map_err(|e| e.to_string())?;
-        Ok(())
+        archive_facility(&self.db, id).await
+    }
+
+    async fn restore_facility(&self, id: &str) -> Result<(), String> {
+        restore_facility(&self.db, id).await
     }
 }
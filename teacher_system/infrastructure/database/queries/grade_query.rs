@@ -0,0 +1,141 @@
+use crate::domain::{
+    models::grade::{ComponentGrade, GradingScheme},
+    repositories::grade_repository::GradeRepository,
+};
+use crate::infrastructure::database::entities::{component_grades, enrollments, grading_schemes};
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, JoinType, QueryFilter,
+    QuerySelect, RelationTrait, Set, sea_query::OnConflict,
+};
+use shared::config::connect_to_supabase;
+
+#[derive(Clone)]
+pub struct SupabaseGradeRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseGradeRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_scheme(s: grading_schemes::Model) -> Result<GradingScheme, String> {
+    Ok(GradingScheme {
+        course_id: s.course_id,
+        components: serde_json::from_value(s.components).map_err(|e| e.to_string())?,
+        passing_grade: s.passing_grade,
+        updated_at: Some(s.updated_at.to_string()),
+    })
+}
+
+fn to_domain_grade(g: component_grades::Model) -> ComponentGrade {
+    ComponentGrade {
+        enrollment_id: g.enrollment_id,
+        component: g.component,
+        score: g.score,
+        recorded_by: g.recorded_by,
+        recorded_at: Some(g.recorded_at.to_string()),
+    }
+}
+
+pub(crate) async fn save_scheme<C: ConnectionTrait>(
+    db: &C,
+    scheme: &GradingScheme,
+) -> Result<(), String> {
+    let scheme_model = grading_schemes::ActiveModel {
+        course_id: Set(scheme.course_id.clone()),
+        components: Set(serde_json::to_value(&scheme.components).map_err(|e| e.to_string())?),
+        passing_grade: Set(scheme.passing_grade),
+        updated_at: Set(Utc::now().naive_utc()),
+    };
+
+    // Un esquema por curso: se crea la primera vez y luego se sobrescribe
+    grading_schemes::Entity::insert(scheme_model)
+        .on_conflict(
+            OnConflict::column(grading_schemes::Column::CourseId)
+                .update_columns([
+                    grading_schemes::Column::Components,
+                    grading_schemes::Column::PassingGrade,
+                    grading_schemes::Column::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub(crate) async fn save_grades<C: ConnectionTrait>(
+    db: &C,
+    grades: &[ComponentGrade],
+) -> Result<(), String> {
+    if grades.is_empty() {
+        return Ok(());
+    }
+
+    let models = grades.iter().map(|g| component_grades::ActiveModel {
+        enrollment_id: Set(g.enrollment_id.clone()),
+        component: Set(g.component.clone()),
+        score: Set(g.score),
+        recorded_by: Set(g.recorded_by.clone()),
+        recorded_at: Set(Utc::now().naive_utc()),
+    });
+
+    // Una nota por matrícula y evaluación: volver a registrarla la corrige
+    component_grades::Entity::insert_many(models)
+        .on_conflict(
+            OnConflict::columns([
+                component_grades::Column::EnrollmentId,
+                component_grades::Column::Component,
+            ])
+            .update_columns([
+                component_grades::Column::Score,
+                component_grades::Column::RecordedBy,
+                component_grades::Column::RecordedAt,
+            ])
+            .to_owned(),
+        )
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[async_trait]
+impl GradeRepository for SupabaseGradeRepository {
+    async fn get_scheme(&self, course_id: &str) -> Result<Option<GradingScheme>, String> {
+        grading_schemes::Entity::find_by_id(course_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_scheme)
+            .transpose()
+    }
+
+    async fn save_scheme(&self, scheme: &GradingScheme) -> Result<(), String> {
+        save_scheme(&self.db, scheme).await
+    }
+
+    async fn save_grades(&self, grades: &[ComponentGrade]) -> Result<(), String> {
+        save_grades(&self.db, grades).await
+    }
+
+    async fn get_grades_by_course(&self, course_id: &str) -> Result<Vec<ComponentGrade>, String> {
+        let grades = component_grades::Entity::find()
+            .join(JoinType::InnerJoin, component_grades::Relation::Enrollments.def())
+            .filter(enrollments::Column::CourseId.eq(course_id))
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_grade)
+            .collect();
+
+        Ok(grades)
+    }
+}
@@ -1,4 +1,18 @@
+pub mod attendance_query;
+pub mod audit_query;
+pub mod booking_query;
 pub mod course_query;
+pub mod enrollment_query;
+pub mod event_outbox_query;
 pub mod facility_query;
+pub mod grade_query;
+pub mod notification_preference_query;
+pub mod overload_query;
 pub mod schedule_query;
+pub mod session_exception_query;
+pub mod student_status_query;
+pub mod study_plan_query;
+pub mod teacher_assignment_query;
+pub mod unit_of_work_query;
 pub mod user_query;
+pub mod webhook_query;
@@ -0,0 +1,74 @@
+use crate::domain::{
+    models::notification::NotificationPreference,
+    repositories::notification_preference_repository::NotificationPreferenceRepository,
+};
+use crate::infrastructure::database::entities::notification_preferences;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{DatabaseConnection, EntityTrait, Set, sea_query::OnConflict};
+use shared::config::connect_to_supabase;
+
+#[derive(Clone)]
+pub struct SupabaseNotificationPreferenceRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseNotificationPreferenceRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_preference(
+    p: notification_preferences::Model,
+) -> Result<NotificationPreference, String> {
+    Ok(NotificationPreference {
+        user_id: p.user_id,
+        channels: serde_json::from_value(p.channels).map_err(|e| e.to_string())?,
+        muted_events: serde_json::from_value(p.muted_events).map_err(|e| e.to_string())?,
+        updated_at: Some(p.updated_at.to_string()),
+    })
+}
+
+#[async_trait]
+impl NotificationPreferenceRepository for SupabaseNotificationPreferenceRepository {
+    async fn get_preference(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<NotificationPreference>, String> {
+        notification_preferences::Entity::find_by_id(user_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_preference)
+            .transpose()
+    }
+
+    async fn save_preference(&self, preference: &NotificationPreference) -> Result<(), String> {
+        let preference_model = notification_preferences::ActiveModel {
+            user_id: Set(preference.user_id.clone()),
+            channels: Set(serde_json::to_value(&preference.channels).map_err(|e| e.to_string())?),
+            muted_events: Set(
+                serde_json::to_value(&preference.muted_events).map_err(|e| e.to_string())?
+            ),
+            updated_at: Set(Utc::now().naive_utc()),
+        };
+
+        // Una fila por usuario: se crea la primera vez y luego se sobrescribe
+        notification_preferences::Entity::insert(preference_model)
+            .on_conflict(
+                OnConflict::column(notification_preferences::Column::UserId)
+                    .update_columns([
+                        notification_preferences::Column::Channels,
+                        notification_preferences::Column::MutedEvents,
+                        notification_preferences::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use crate::domain::{
+    models::credit_load::{OverloadFilter, OverloadRequest},
+    models::enums::OverloadStatus,
+    repositories::overload_repository::OverloadRepository,
+};
+use crate::infrastructure::database::entities::overload_requests;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use shared::config::connect_to_supabase;
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub struct SupabaseOverloadRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseOverloadRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_request(r: overload_requests::Model) -> Result<OverloadRequest, String> {
+    Ok(OverloadRequest {
+        id: r.id,
+        student_id: r.student_id,
+        academic_year: r.academic_year,
+        semester: r.semester,
+        requested_credits: r.requested_credits,
+        reason: r.reason,
+        status: OverloadStatus::from_str(&r.status)?,
+        requested_by: r.requested_by,
+        reviewed_by: r.reviewed_by,
+        review_note: r.review_note,
+        created_at: Some(r.created_at.to_string()),
+    })
+}
+
+#[async_trait]
+impl OverloadRepository for SupabaseOverloadRepository {
+    async fn create_request(&self, request: &OverloadRequest) -> Result<(), String> {
+        let request_model = overload_requests::ActiveModel {
+            id: Set(request.id.clone()),
+            student_id: Set(request.student_id.clone()),
+            academic_year: Set(request.academic_year),
+            semester: Set(request.semester.clone()),
+            requested_credits: Set(request.requested_credits),
+            reason: Set(request.reason.clone()),
+            status: Set(request.status.to_string()),
+            requested_by: Set(request.requested_by.clone()),
+            reviewed_by: Set(None),
+            reviewed_at: Set(None),
+            review_note: Set(None),
+            created_at: Set(Utc::now().naive_utc()),
+        };
+
+        request_model
+            .insert(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn update_request_status(
+        &self,
+        request_id: &str,
+        status: OverloadStatus,
+        reviewed_by: &str,
+        review_note: Option<&str>,
+    ) -> Result<(), String> {
+        let request = overload_requests::ActiveModel {
+            status: Set(status.to_string()),
+            reviewed_by: Set(Some(reviewed_by.to_string())),
+            reviewed_at: Set(Some(Utc::now().naive_utc())),
+            review_note: Set(review_note.map(|n| n.to_string())),
+            ..Default::default()
+        };
+
+        // Solo se revisa una vez: si otro revisor se adelantó no se pisa su decisión
+        let result = overload_requests::Entity::update_many()
+            .set(request)
+            .filter(overload_requests::Column::Id.eq(request_id))
+            .filter(overload_requests::Column::Status.eq(OverloadStatus::Pending.to_string()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if result.rows_affected == 0 {
+            return match self.get_request_by_id(request_id).await? {
+                Some(current) => Err(format!(
+                    "Conflict: overload request is already {}",
+                    current.status
+                )),
+                None => Err("Overload request not found".to_string()),
+            };
+        }
+        Ok(())
+    }
+
+    async fn get_request_by_id(&self, request_id: &str) -> Result<Option<OverloadRequest>, String> {
+        overload_requests::Entity::find_by_id(request_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_request)
+            .transpose()
+    }
+
+    async fn get_requests(&self, filter: &OverloadFilter) -> Result<Vec<OverloadRequest>, String> {
+        let mut query = overload_requests::Entity::find();
+
+        if let Some(student_id) = &filter.student_id {
+            query = query.filter(overload_requests::Column::StudentId.eq(student_id));
+        }
+        if let Some(status) = filter.status {
+            query = query.filter(overload_requests::Column::Status.eq(status.to_string()));
+        }
+
+        query
+            .order_by_desc(overload_requests::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_request)
+            .collect()
+    }
+}
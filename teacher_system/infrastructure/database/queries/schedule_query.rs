@@ -8,8 +8,8 @@ use crate::infrastructure::database::entities::{
 use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, JoinType, QueryFilter,
-    QuerySelect, RelationTrait, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, JoinType,
+    QueryFilter, QuerySelect, RelationTrait, Select, Set,
 };
 use shared::config::connect_to_supabase;
 use std::str::FromStr;
@@ -26,176 +26,184 @@ impl SupabaseScheduleRepository {
     }
 }
 
-#[async_trait]
-impl ScheduleRepository for SupabaseScheduleRepository {
-    async fn create_schedule(&self, schedule: &Schedule) -> Result<(), String> {
-        let schedule_model = course_schedules::ActiveModel {
-            id: Set(schedule.id.clone()),
-            course_id: Set(schedule.course_id.clone()),
-            day: Set(sea_orm_active_enums::to_db_daytype(&schedule.day)),
-            start_time: Set(schedule.start_time),
-            end_time: Set(schedule.end_time),
-            session_type: Set(sea_orm_active_enums::to_db_session(&schedule.session_type)),
-            location_detail: Set(schedule.location_detail.clone()),
-            facility_id: Set(schedule.facility_id.clone()),
-            created_at: Set(Some(Utc::now().naive_utc())),
-        };
+fn to_domain_schedule(s: course_schedules::Model) -> Result<Schedule, String> {
+    Ok(Schedule {
+        id: s.id,
+        course_id: s.course_id,
+        day: sea_orm_active_enums::to_domain_weekday(&s.day),
+        start_time: s.start_time,
+        end_time: s.end_time,
+        session_type: sea_orm_active_enums::to_domain_session(&s.session_type),
+        location_detail: s.location_detail,
+        created_at: s.created_at.map(|dt| dt.to_string()),
+        facility_id: s.facility_id,
+        required_equipment: sea_orm_active_enums::to_domain_equipment(s.required_equipment)?,
+        version: s.version,
+    })
+}
 
-        schedule_model
-            .insert(&self.db)
-            .await
-            .map_err(|e| e.to_string())?;
-        Ok(())
+/// Sesiones de cursos vigentes: las de un curso archivado no ocupan aula ni
+/// profesor, pero se conservan para cuando se restaure.
+fn active_schedules() -> Select<course_schedules::Entity> {
+    course_schedules::Entity::find()
+        .join(
+            JoinType::InnerJoin,
+            course_schedules::Relation::Courses.def(),
+        )
+        .filter(courses::Column::DeletedAt.is_null())
+}
+
+pub(crate) async fn update_schedule<C: ConnectionTrait>(
+    db: &C,
+    schedule: &Schedule,
+    expected_version: Option<i32>,
+) -> Result<(), String> {
+    let existing = course_schedules::Entity::find_by_id(&schedule.id)
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Schedule not found")?;
+
+    let current_version = expected_version.unwrap_or(existing.version);
+    let mut schedule_model: course_schedules::ActiveModel = existing.into();
+
+    schedule_model.course_id = Set(schedule.course_id.clone());
+    schedule_model.day = Set(sea_orm_active_enums::to_db_daytype(&schedule.day));
+    schedule_model.start_time = Set(schedule.start_time);
+    schedule_model.end_time = Set(schedule.end_time);
+    schedule_model.session_type = Set(sea_orm_active_enums::to_db_session(&schedule.session_type));
+    schedule_model.location_detail = Set(schedule.location_detail.clone());
+    schedule_model.facility_id = Set(schedule.facility_id.clone());
+    schedule_model.required_equipment = Set(sea_orm_active_enums::to_db_equipment(
+        &schedule.required_equipment,
+    ));
+    schedule_model.version = Set(current_version + 1);
+
+    // Solo se actualiza si nadie modificó la sesión desde que se leyó
+    let result = course_schedules::Entity::update_many()
+        .set(schedule_model)
+        .filter(course_schedules::Column::Id.eq(&schedule.id))
+        .filter(course_schedules::Column::Version.eq(current_version))
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if result.rows_affected == 0 {
+        return Err("Precondition failed: schedule was modified by another request".to_string());
     }
+    Ok(())
+}
 
-    async fn update_schedule(&self, schedule: &Schedule) -> Result<(), String> {
-        let mut schedule_model: course_schedules::ActiveModel =
-            course_schedules::Entity::find_by_id(&schedule.id)
-                .one(&self.db)
-                .await
-                .map_err(|e| e.to_string())?
-                .ok_or("Schedule not found")?
-                .into();
+pub(crate) async fn create_schedule<C: ConnectionTrait>(
+    db: &C,
+    schedule: &Schedule,
+) -> Result<(), String> {
+    let schedule_model = course_schedules::ActiveModel {
+        id: Set(schedule.id.clone()),
+        course_id: Set(schedule.course_id.clone()),
+        day: Set(sea_orm_active_enums::to_db_daytype(&schedule.day)),
+        start_time: Set(schedule.start_time),
+        end_time: Set(schedule.end_time),
+        session_type: Set(sea_orm_active_enums::to_db_session(&schedule.session_type)),
+        location_detail: Set(schedule.location_detail.clone()),
+        facility_id: Set(schedule.facility_id.clone()),
+        required_equipment: Set(sea_orm_active_enums::to_db_equipment(
+            &schedule.required_equipment,
+        )),
+        created_at: Set(Some(Utc::now().naive_utc())),
+        version: Set(1),
+    };
+
+    schedule_model.insert(db).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
 
-        schedule_model.course_id = Set(schedule.course_id.clone());
-        schedule_model.day = Set(sea_orm_active_enums::to_db_daytype(&schedule.day));
-        schedule_model.start_time = Set(schedule.start_time);
-        schedule_model.end_time = Set(schedule.end_time);
-        schedule_model.session_type =
-            Set(sea_orm_active_enums::to_db_session(&schedule.session_type));
-        schedule_model.location_detail = Set(schedule.location_detail.clone());
-        schedule_model.facility_id = Set(schedule.facility_id.clone());
+pub(crate) async fn delete_schedule<C: ConnectionTrait>(
+    db: &C,
+    schedule_id: &str,
+) -> Result<(), String> {
+    course_schedules::Entity::delete_by_id(schedule_id)
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
 
-        schedule_model
-            .update(&self.db)
-            .await
-            .map_err(|e| e.to_string())?;
-        Ok(())
+    Ok(())
+}
+
+#[async_trait]
+impl ScheduleRepository for SupabaseScheduleRepository {
+    async fn create_schedule(&self, schedule: &Schedule) -> Result<(), String> {
+        create_schedule(&self.db, schedule).await
+    }
+
+    async fn update_schedule(
+        &self,
+        schedule: &Schedule,
+        expected_version: Option<i32>,
+    ) -> Result<(), String> {
+        update_schedule(&self.db, schedule, expected_version).await
     }
 
     async fn get_schedule_by_id(&self, schedule_id: &str) -> Result<Option<Schedule>, String> {
-        let course_schedule = course_schedules::Entity::find_by_id(schedule_id)
+        course_schedules::Entity::find_by_id(schedule_id)
             .one(&self.db)
             .await
             .map_err(|e| e.to_string())?
-            .map(|s| Schedule {
-                id: s.id,
-                course_id: s.course_id,
-                day: sea_orm_active_enums::to_domain_weekday(&s.day),
-                start_time: s.start_time,
-                end_time: s.end_time,
-                session_type: sea_orm_active_enums::to_domain_session(&s.session_type),
-                location_detail: s.location_detail,
-                created_at: s.created_at.map(|dt| dt.to_string()),
-                facility_id: s.facility_id,
-            });
-
-        Ok(course_schedule)
+            .map(to_domain_schedule)
+            .transpose()
     }
 
     async fn get_all_schedules(&self) -> Result<Vec<Schedule>, String> {
-        let schedules = course_schedules::Entity::find()
+        active_schedules()
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|s| Schedule {
-                id: s.id,
-                course_id: s.course_id,
-                day: sea_orm_active_enums::to_domain_weekday(&s.day),
-                start_time: s.start_time,
-                end_time: s.end_time,
-                session_type: sea_orm_active_enums::to_domain_session(&s.session_type),
-                location_detail: s.location_detail,
-                created_at: s.created_at.map(|dt| dt.to_string()),
-                facility_id: s.facility_id,
-            })
-            .collect();
-
-        Ok(schedules)
+            .map(to_domain_schedule)
+            .collect()
     }
 
-    async fn get_schedules_by_course(&self, course_id: &str) -> Result<Option<Schedule>, String> {
-        let schedules = course_schedules::Entity::find()
+    async fn get_schedules_by_course(&self, course_id: &str) -> Result<Vec<Schedule>, String> {
+        course_schedules::Entity::find()
             .filter(course_schedules::Column::CourseId.eq(course_id))
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|s| Schedule {
-                id: s.id,
-                course_id: s.course_id,
-                day: sea_orm_active_enums::to_domain_weekday(&s.day),
-                start_time: s.start_time,
-                end_time: s.end_time,
-                session_type: sea_orm_active_enums::to_domain_session(&s.session_type),
-                location_detail: s.location_detail,
-                created_at: s.created_at.map(|dt| dt.to_string()),
-                facility_id: s.facility_id,
-            })
-            .next();
-
-        Ok(schedules)
+            .map(to_domain_schedule)
+            .collect()
     }
 
     async fn get_schedules_by_course_name(
         &self,
         name_course: &str,
     ) -> Result<Vec<Schedule>, String> {
-        let schedules = course_schedules::Entity::find()
-            .join(
-                JoinType::InnerJoin,
-                course_schedules::Relation::Courses.def(),
-            )
+        active_schedules()
             .filter(courses::Column::Name.eq(name_course))
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|s| Schedule {
-                id: s.id,
-                course_id: s.course_id,
-                day: sea_orm_active_enums::to_domain_weekday(&s.day),
-                start_time: s.start_time,
-                end_time: s.end_time,
-                session_type: sea_orm_active_enums::to_domain_session(&s.session_type),
-                location_detail: s.location_detail,
-                created_at: s.created_at.map(|dt| dt.to_string()),
-                facility_id: s.facility_id,
-            })
-            .collect();
-
-        Ok(schedules)
+            .map(to_domain_schedule)
+            .collect()
     }
 
     async fn get_schedules_by_facility(&self, facility_id: &str) -> Result<Vec<Schedule>, String> {
-        let schedules = course_schedules::Entity::find()
+        active_schedules()
             .filter(course_schedules::Column::FacilityId.eq(facility_id))
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|s| Schedule {
-                id: s.id,
-                course_id: s.course_id,
-                day: sea_orm_active_enums::to_domain_weekday(&s.day),
-                start_time: s.start_time,
-                end_time: s.end_time,
-                session_type: sea_orm_active_enums::to_domain_session(&s.session_type),
-                location_detail: s.location_detail,
-                created_at: s.created_at.map(|dt| dt.to_string()),
-                facility_id: s.facility_id,
-            })
-            .collect();
-
-        Ok(schedules)
+            .map(to_domain_schedule)
+            .collect()
     }
 
     async fn get_schedules_by_facility_name(
         &self,
         name_facility: &str,
     ) -> Result<Vec<Schedule>, String> {
-        let schedules = course_schedules::Entity::find()
+        active_schedules()
             .join(
                 JoinType::InnerJoin,
                 course_schedules::Relation::Facilities.def(),
@@ -205,80 +213,35 @@ impl ScheduleRepository for SupabaseScheduleRepository {
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|s| Schedule {
-                id: s.id,
-                course_id: s.course_id,
-                day: sea_orm_active_enums::to_domain_weekday(&s.day),
-                start_time: s.start_time,
-                end_time: s.end_time,
-                session_type: sea_orm_active_enums::to_domain_session(&s.session_type),
-                location_detail: s.location_detail,
-                created_at: s.created_at.map(|dt| dt.to_string()),
-                facility_id: s.facility_id,
-            })
-            .collect();
-
-        Ok(schedules)
+            .map(to_domain_schedule)
+            .collect()
     }
 
     async fn get_schedules_by_user(&self, user_id: &str) -> Result<Vec<Schedule>, String> {
-        let schedules = course_schedules::Entity::find()
-            .join(
-                JoinType::InnerJoin,
-                course_schedules::Relation::Courses.def(),
-            )
+        active_schedules()
             .filter(courses::Column::TeacherId.eq(user_id))
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|s| Schedule {
-                id: s.id,
-                course_id: s.course_id,
-                day: sea_orm_active_enums::to_domain_weekday(&s.day),
-                start_time: s.start_time,
-                end_time: s.end_time,
-                session_type: sea_orm_active_enums::to_domain_session(&s.session_type),
-                location_detail: s.location_detail,
-                created_at: s.created_at.map(|dt| dt.to_string()),
-                facility_id: s.facility_id,
-            })
-            .collect();
-
-        Ok(schedules)
+            .map(to_domain_schedule)
+            .collect()
     }
 
     async fn get_schedules_by_weekday(&self, weekday: &str) -> Result<Vec<Schedule>, String> {
         let day_enum = sea_orm_active_enums::to_db_daytype(&Weekday::from_str(weekday).unwrap());
 
-        let schedule = course_schedules::Entity::find()
+        active_schedules()
             .filter(course_schedules::Column::Day.eq(day_enum))
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
             .into_iter()
-            .map(|s| Schedule {
-                id: s.id,
-                course_id: s.course_id,
-                day: sea_orm_active_enums::to_domain_weekday(&s.day),
-                start_time: s.start_time,
-                end_time: s.end_time,
-                session_type: sea_orm_active_enums::to_domain_session(&s.session_type),
-                location_detail: s.location_detail,
-                created_at: s.created_at.map(|dt| dt.to_string()),
-                facility_id: s.facility_id,
-            })
-            .collect();
-
-        Ok(schedule)
+            .map(to_domain_schedule)
+            .collect()
     }
 
     async fn delete_schedule(&self, schedule_id: &str) -> Result<(), String> {
-        course_schedules::Entity::delete_by_id(schedule_id)
-            .exec(&self.db)
-            .await
-            .map_err(|e| e.to_string())?;
-
-        Ok(())
+        delete_schedule(&self.db, schedule_id).await
     }
 }
@@ -0,0 +1,139 @@
+use crate::domain::{
+    models::enums::ExceptionKind,
+    models::session_exception::{MakeUpSlot, SessionException},
+    repositories::session_exception_repository::SessionExceptionRepository,
+};
+use crate::infrastructure::database::entities::session_exceptions;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use shared::config::connect_to_supabase;
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub struct SupabaseSessionExceptionRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseSessionExceptionRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_exception(e: session_exceptions::Model) -> Result<SessionException, String> {
+    let make_up = match (
+        e.make_up_date,
+        e.make_up_start_time,
+        e.make_up_end_time,
+        e.make_up_facility_id,
+    ) {
+        (Some(date), Some(start_time), Some(end_time), Some(facility_id)) => Some(MakeUpSlot {
+            date,
+            start_time,
+            end_time,
+            facility_id,
+            booking_id: e.make_up_booking_id,
+        }),
+        _ => None,
+    };
+
+    Ok(SessionException {
+        id: e.id,
+        schedule_id: e.schedule_id,
+        original_date: e.original_date,
+        kind: ExceptionKind::from_str(&e.kind)?,
+        reason: e.reason,
+        make_up,
+        created_by: e.created_by,
+        created_at: Some(e.created_at.to_string()),
+    })
+}
+
+pub(crate) async fn create_exception<C: ConnectionTrait>(
+    db: &C,
+    exception: &SessionException,
+) -> Result<(), String> {
+    let slot = exception.make_up.as_ref();
+    let exception_model = session_exceptions::ActiveModel {
+        id: Set(exception.id.clone()),
+        schedule_id: Set(exception.schedule_id.clone()),
+        original_date: Set(exception.original_date),
+        kind: Set(exception.kind.to_string()),
+        reason: Set(exception.reason.clone()),
+        make_up_date: Set(slot.map(|s| s.date)),
+        make_up_start_time: Set(slot.map(|s| s.start_time)),
+        make_up_end_time: Set(slot.map(|s| s.end_time)),
+        make_up_facility_id: Set(slot.map(|s| s.facility_id.clone())),
+        make_up_booking_id: Set(slot.and_then(|s| s.booking_id.clone())),
+        created_by: Set(exception.created_by.clone()),
+        created_at: Set(Utc::now().naive_utc()),
+    };
+
+    exception_model
+        .insert(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[async_trait]
+impl SessionExceptionRepository for SupabaseSessionExceptionRepository {
+    async fn create_exception(&self, exception: &SessionException) -> Result<(), String> {
+        create_exception(&self.db, exception).await
+    }
+
+    async fn get_exceptions_by_schedule(
+        &self,
+        schedule_id: &str,
+    ) -> Result<Vec<SessionException>, String> {
+        session_exceptions::Entity::find()
+            .filter(session_exceptions::Column::ScheduleId.eq(schedule_id))
+            .order_by_asc(session_exceptions::Column::OriginalDate)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_exception)
+            .collect()
+    }
+
+    async fn get_exception_by_date(
+        &self,
+        schedule_id: &str,
+        date: NaiveDate,
+    ) -> Result<Option<SessionException>, String> {
+        session_exceptions::Entity::find()
+            .filter(session_exceptions::Column::ScheduleId.eq(schedule_id))
+            .filter(session_exceptions::Column::OriginalDate.eq(date))
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_exception)
+            .transpose()
+    }
+
+    async fn get_make_ups_by_date(
+        &self,
+        schedule_ids: &[String],
+        date: NaiveDate,
+    ) -> Result<Vec<SessionException>, String> {
+        if schedule_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        session_exceptions::Entity::find()
+            .filter(session_exceptions::Column::ScheduleId.is_in(schedule_ids.iter().cloned()))
+            .filter(session_exceptions::Column::MakeUpDate.eq(date))
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_exception)
+            .collect()
+    }
+}
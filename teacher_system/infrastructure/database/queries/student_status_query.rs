@@ -0,0 +1,81 @@
+use crate::domain::{
+    models::{enums::StudentStatus, student_status::StatusChange},
+    repositories::student_status_repository::StudentStatusRepository,
+};
+use crate::infrastructure::database::entities::student_status_history;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use shared::config::connect_to_supabase;
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub struct SupabaseStudentStatusRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseStudentStatusRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn parse_status(status: &str) -> Result<StudentStatus, String> {
+    StudentStatus::from_str(status).map_err(|_| format!("Unknown student status: {}", status))
+}
+
+fn to_domain_change(h: student_status_history::Model) -> Result<StatusChange, String> {
+    Ok(StatusChange {
+        id: h.id,
+        student_id: h.student_id,
+        previous_status: h.previous_status.as_deref().map(parse_status).transpose()?,
+        new_status: parse_status(&h.new_status)?,
+        reasons: serde_json::from_value(h.reasons).map_err(|e| e.to_string())?,
+        changed_by: h.changed_by,
+        changed_at: Some(h.changed_at.to_string()),
+    })
+}
+
+pub(crate) async fn record_change<C: ConnectionTrait>(
+    db: &C,
+    change: &StatusChange,
+) -> Result<(), String> {
+    let change_model = student_status_history::ActiveModel {
+        id: Set(change.id.clone()),
+        student_id: Set(change.student_id.clone()),
+        previous_status: Set(change.previous_status.as_ref().map(|s| s.to_string())),
+        new_status: Set(change.new_status.to_string()),
+        reasons: Set(serde_json::to_value(&change.reasons).map_err(|e| e.to_string())?),
+        changed_by: Set(change.changed_by.clone()),
+        changed_at: Set(Utc::now().naive_utc()),
+    };
+
+    change_model
+        .insert(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[async_trait]
+impl StudentStatusRepository for SupabaseStudentStatusRepository {
+    async fn record_change(&self, change: &StatusChange) -> Result<(), String> {
+        record_change(&self.db, change).await
+    }
+
+    async fn get_history(&self, student_id: &str) -> Result<Vec<StatusChange>, String> {
+        student_status_history::Entity::find()
+            .filter(student_status_history::Column::StudentId.eq(student_id))
+            .order_by_desc(student_status_history::Column::ChangedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_change)
+            .collect()
+    }
+}
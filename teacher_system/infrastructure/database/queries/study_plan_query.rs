@@ -0,0 +1,123 @@
+use crate::domain::{
+    models::study_plan::StudyPlan, repositories::study_plan_repository::StudyPlanRepository,
+};
+use crate::infrastructure::database::entities::study_plans;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait, sea_query::Expr,
+};
+use shared::config::connect_to_supabase;
+
+#[derive(Clone)]
+pub struct SupabaseStudyPlanRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseStudyPlanRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_plan(p: study_plans::Model) -> Result<StudyPlan, String> {
+    Ok(StudyPlan {
+        id: p.id,
+        program: p.program,
+        faculty: p.faculty,
+        version: p.version,
+        name: p.name,
+        active: p.active,
+        courses: serde_json::from_value(p.courses).map_err(|e| e.to_string())?,
+        elective_groups: serde_json::from_value(p.elective_groups).map_err(|e| e.to_string())?,
+        created_at: Some(p.created_at.to_string()),
+    })
+}
+
+#[async_trait]
+impl StudyPlanRepository for SupabaseStudyPlanRepository {
+    async fn create_plan(&self, plan: &StudyPlan) -> Result<(), String> {
+        let plan_model = study_plans::ActiveModel {
+            id: Set(plan.id.clone()),
+            program: Set(plan.program.clone()),
+            faculty: Set(plan.faculty.trim().to_string()),
+            version: Set(plan.version),
+            name: Set(plan.name.clone()),
+            active: Set(plan.active),
+            courses: Set(serde_json::to_value(&plan.courses).map_err(|e| e.to_string())?),
+            elective_groups: Set(
+                serde_json::to_value(&plan.elective_groups).map_err(|e| e.to_string())?,
+            ),
+            created_at: Set(Utc::now().naive_utc()),
+        };
+
+        plan_model.insert(&self.db).await.map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_plan_by_id(&self, plan_id: &str) -> Result<Option<StudyPlan>, String> {
+        study_plans::Entity::find_by_id(plan_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_plan)
+            .transpose()
+    }
+
+    async fn get_plans(&self, program: Option<&str>) -> Result<Vec<StudyPlan>, String> {
+        let mut query = study_plans::Entity::find();
+
+        if let Some(program) = program {
+            query = query.filter(study_plans::Column::Program.eq(program));
+        }
+
+        query
+            .order_by_asc(study_plans::Column::Program)
+            .order_by_desc(study_plans::Column::Version)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_plan)
+            .collect()
+    }
+
+    async fn get_active_plan(&self, program: &str) -> Result<Option<StudyPlan>, String> {
+        study_plans::Entity::find()
+            .filter(study_plans::Column::Program.eq(program))
+            .filter(study_plans::Column::Active.eq(true))
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_plan)
+            .transpose()
+    }
+
+    async fn activate_plan(&self, plan_id: &str) -> Result<(), String> {
+        let plan = study_plans::Entity::find_by_id(plan_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Study plan not found".to_string())?;
+
+        // Se desactiva la versión anterior y se activa la nueva a la vez
+        let txn = self.db.begin().await.map_err(|e| e.to_string())?;
+
+        study_plans::Entity::update_many()
+            .col_expr(study_plans::Column::Active, Expr::value(false))
+            .filter(study_plans::Column::Program.eq(&plan.program))
+            .filter(study_plans::Column::Id.ne(plan_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut plan: study_plans::ActiveModel = plan.into();
+        plan.active = Set(true);
+        plan.update(&txn).await.map_err(|e| e.to_string())?;
+
+        txn.commit().await.map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+use crate::domain::{
+    models::enums::AssignmentStatus,
+    models::teacher_assignment::{AssignmentFilter, AssignmentProposal},
+    repositories::teacher_assignment_repository::TeacherAssignmentRepository,
+};
+use crate::infrastructure::database::entities::teacher_assignments;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use shared::config::connect_to_supabase;
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub struct SupabaseTeacherAssignmentRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseTeacherAssignmentRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_proposal(a: teacher_assignments::Model) -> Result<AssignmentProposal, String> {
+    Ok(AssignmentProposal {
+        id: a.id,
+        course_id: a.course_id,
+        academic_year: a.academic_year,
+        semester: a.semester,
+        proposed_teacher_id: a.proposed_teacher_id,
+        candidates: serde_json::from_value(a.candidates).map_err(|e| e.to_string())?,
+        reasons: serde_json::from_value(a.reasons).map_err(|e| e.to_string())?,
+        status: AssignmentStatus::from_str(&a.status)?,
+        assigned_teacher_id: a.assigned_teacher_id,
+        decided_by: a.decided_by,
+        note: a.note,
+        created_at: Some(a.created_at.to_string()),
+    })
+}
+
+pub(crate) async fn create_proposals<C: ConnectionTrait>(
+    db: &C,
+    proposals: &[AssignmentProposal],
+) -> Result<(), String> {
+    if proposals.is_empty() {
+        return Ok(());
+    }
+
+    let mut models = Vec::new();
+    for p in proposals {
+        let candidates = serde_json::to_value(&p.candidates).map_err(|e| e.to_string())?;
+        let reasons = serde_json::to_value(&p.reasons).map_err(|e| e.to_string())?;
+        models.push(teacher_assignments::ActiveModel {
+            id: Set(p.id.clone()),
+            course_id: Set(p.course_id.clone()),
+            academic_year: Set(p.academic_year),
+            semester: Set(p.semester.clone()),
+            proposed_teacher_id: Set(p.proposed_teacher_id.clone()),
+            candidates: Set(candidates),
+            reasons: Set(reasons),
+            status: Set(p.status.to_string()),
+            assigned_teacher_id: Set(None),
+            decided_by: Set(None),
+            decided_at: Set(None),
+            note: Set(None),
+            created_at: Set(Utc::now().naive_utc()),
+        });
+    }
+
+    teacher_assignments::Entity::insert_many(models)
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub(crate) async fn delete_pending<C: ConnectionTrait>(
+    db: &C,
+    academic_year: i32,
+    semester: &str,
+) -> Result<(), String> {
+    teacher_assignments::Entity::delete_many()
+        .filter(teacher_assignments::Column::AcademicYear.eq(academic_year))
+        .filter(teacher_assignments::Column::Semester.eq(semester))
+        .filter(teacher_assignments::Column::Status.eq(AssignmentStatus::Proposed.to_string()))
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Solo cierra la propuesta si nadie la decidió desde que se leyó.
+pub(crate) async fn decide_proposal<C: ConnectionTrait>(
+    db: &C,
+    proposal_id: &str,
+    status: AssignmentStatus,
+    assigned_teacher_id: &str,
+    decided_by: &str,
+    note: Option<&str>,
+) -> Result<(), String> {
+    let proposal = teacher_assignments::ActiveModel {
+        status: Set(status.to_string()),
+        assigned_teacher_id: Set(Some(assigned_teacher_id.to_string())),
+        decided_by: Set(Some(decided_by.to_string())),
+        decided_at: Set(Some(Utc::now().naive_utc())),
+        note: Set(note.map(|n| n.to_string())),
+        ..Default::default()
+    };
+
+    let result = teacher_assignments::Entity::update_many()
+        .set(proposal)
+        .filter(teacher_assignments::Column::Id.eq(proposal_id))
+        .filter(teacher_assignments::Column::Status.eq(AssignmentStatus::Proposed.to_string()))
+        .exec(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if result.rows_affected == 0 {
+        return match teacher_assignments::Entity::find_by_id(proposal_id)
+            .one(db)
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            Some(current) => Err(format!(
+                "Conflict: assignment proposal is already {}",
+                current.status
+            )),
+            None => Err("Assignment proposal not found".to_string()),
+        };
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl TeacherAssignmentRepository for SupabaseTeacherAssignmentRepository {
+    async fn create_proposals(&self, proposals: &[AssignmentProposal]) -> Result<(), String> {
+        create_proposals(&self.db, proposals).await
+    }
+
+    async fn delete_pending(&self, academic_year: i32, semester: &str) -> Result<(), String> {
+        delete_pending(&self.db, academic_year, semester).await
+    }
+
+    async fn decide_proposal(
+        &self,
+        proposal_id: &str,
+        status: AssignmentStatus,
+        assigned_teacher_id: &str,
+        decided_by: &str,
+        note: Option<&str>,
+    ) -> Result<(), String> {
+        decide_proposal(
+            &self.db,
+            proposal_id,
+            status,
+            assigned_teacher_id,
+            decided_by,
+            note,
+        )
+        .await
+    }
+
+    async fn get_proposal_by_id(
+        &self,
+        proposal_id: &str,
+    ) -> Result<Option<AssignmentProposal>, String> {
+        teacher_assignments::Entity::find_by_id(proposal_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_proposal)
+            .transpose()
+    }
+
+    async fn get_proposals(
+        &self,
+        filter: &AssignmentFilter,
+    ) -> Result<Vec<AssignmentProposal>, String> {
+        let mut query = teacher_assignments::Entity::find();
+
+        if let Some(academic_year) = filter.academic_year {
+            query = query.filter(teacher_assignments::Column::AcademicYear.eq(academic_year));
+        }
+        if let Some(semester) = &filter.semester {
+            query = query.filter(teacher_assignments::Column::Semester.eq(semester));
+        }
+        if let Some(status) = filter.status {
+            query = query.filter(teacher_assignments::Column::Status.eq(status.to_string()));
+        }
+
+        query
+            .order_by_desc(teacher_assignments::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_proposal)
+            .collect()
+    }
+}
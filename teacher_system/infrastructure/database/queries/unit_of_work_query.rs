@@ -0,0 +1,125 @@
+use crate::domain::{
+    models::change_set::{Change, ChangeSet},
+    repositories::unit_of_work_repository::UnitOfWorkRepository,
+};
+use crate::infrastructure::database::queries::{
+    audit_query, booking_query, course_query, enrollment_query, event_outbox_query, facility_query,
+    grade_query, schedule_query, session_exception_query, student_status_query, teacher_assignment_query,
+    user_query,
+};
+use async_trait::async_trait;
+use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use shared::config::connect_to_supabase;
+
+/// Aplica cada `ChangeSet` dentro de una transacción de Postgres, reutilizando
+/// las mismas escrituras que los repositorios de cada tabla.
+#[derive(Clone)]
+pub struct SupabaseUnitOfWorkRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseUnitOfWorkRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+async fn apply(txn: &DatabaseTransaction, change: Change) -> Result<(), String> {
+    match change {
+        Change::CreateCourse(course) => course_query::create_course(txn, &course).await,
+        Change::UpdateCourse {
+            course,
+            expected_version,
+        } => course_query::update_course(txn, &course, expected_version).await,
+        Change::ArchiveCourse(course_id) => course_query::delete_course(txn, &course_id).await,
+        Change::RestoreCourse(course_id) => course_query::restore_course(txn, &course_id).await,
+        Change::CreateSchedule(schedule) => schedule_query::create_schedule(txn, &schedule).await,
+        Change::UpdateSchedule {
+            schedule,
+            expected_version,
+        } => schedule_query::update_schedule(txn, &schedule, expected_version).await,
+        Change::DeleteSchedule(schedule_id) => {
+            schedule_query::delete_schedule(txn, &schedule_id).await
+        }
+        Change::CreateFacility(facility) => facility_query::create_facility(txn, &facility).await,
+        Change::UpdateFacility(facility) => facility_query::update_facility(txn, &facility).await,
+        Change::ArchiveFacility(facility_id) => {
+            facility_query::archive_facility(txn, &facility_id).await
+        }
+        Change::RestoreFacility(facility_id) => {
+            facility_query::restore_facility(txn, &facility_id).await
+        }
+        Change::CreateUser(user) => user_query::create_user(txn, &user).await,
+        Change::UpdateUser(user) => user_query::update_user(txn, &user).await,
+        Change::ArchiveUser(user_id) => user_query::delete_user(txn, &user_id).await,
+        Change::RestoreUser(user_id) => user_query::restore_user(txn, &user_id).await,
+        Change::UpdateStudentStatus { user_id, status } => {
+            user_query::update_student_status(txn, &user_id, &status).await
+        }
+        Change::RecordStatusChange(change) => {
+            student_status_query::record_change(txn, &change).await
+        }
+        Change::CreateBooking(booking) => booking_query::create_booking(txn, &booking).await,
+        Change::CreateSessionException(exception) => {
+            session_exception_query::create_exception(txn, &exception).await
+        }
+        Change::ConfirmEnrollment {
+            enrollment_id,
+            max_credits,
+        } => enrollment_query::confirm_enrollment(txn, &enrollment_id, max_credits).await,
+        Change::MoveEnrollments {
+            enrollment_ids,
+            course_id,
+        } => enrollment_query::move_enrollments(txn, &enrollment_ids, &course_id).await,
+        Change::SaveGradingScheme(scheme) => grade_query::save_scheme(txn, &scheme).await,
+        Change::SaveGrades(grades) => grade_query::save_grades(txn, &grades).await,
+        Change::RecordEnrollmentResult {
+            enrollment_id,
+            status,
+            final_grade,
+        } => enrollment_query::record_result(txn, &enrollment_id, &status, final_grade).await,
+        Change::DeletePendingProposals {
+            academic_year,
+            semester,
+        } => teacher_assignment_query::delete_pending(txn, academic_year, &semester).await,
+        Change::CreateProposals(proposals) => {
+            teacher_assignment_query::create_proposals(txn, &proposals).await
+        }
+        Change::DecideProposal {
+            proposal_id,
+            status,
+            assigned_teacher_id,
+            decided_by,
+            note,
+        } => {
+            teacher_assignment_query::decide_proposal(
+                txn,
+                &proposal_id,
+                status,
+                &assigned_teacher_id,
+                &decided_by,
+                note.as_deref(),
+            )
+            .await
+        }
+        Change::RecordAudit(log) => audit_query::create_log(txn, &log).await,
+        Change::PublishEvent(event) => event_outbox_query::create_event(txn, &event).await,
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkRepository for SupabaseUnitOfWorkRepository {
+    async fn commit(&self, changes: ChangeSet) -> Result<(), String> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let txn = self.db.begin().await.map_err(|e| e.to_string())?;
+        for change in changes.into_changes() {
+            // Al soltar `txn` sin confirmar se deshace todo lo anterior
+            apply(&txn, change).await?;
+        }
+        txn.commit().await.map_err(|e| e.to_string())
+    }
+}
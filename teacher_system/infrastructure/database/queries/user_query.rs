@@ -1,11 +1,15 @@
-use crate::domain::{models::user::User, repositories::user_repository::UserRepository};
+use crate::domain::{
+    models::{enums::StudentStatus, user::User},
+    repositories::user_repository::UserRepository,
+};
 use crate::infrastructure::database::entities::{
     course_schedules, courses, enrollments, facilities, sea_orm_active_enums, users,
 };
 use async_trait::async_trait;
+use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, JoinType, QueryFilter,
-    QuerySelect, RelationTrait, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, JoinType,
+    QueryFilter, QuerySelect, RelationTrait, Set,
 };
 use shared::config::connect_to_supabase;
 
@@ -20,11 +24,172 @@ impl SupabaseUserRepository {
     }
 }
 
+pub(crate) async fn create_user<C: ConnectionTrait>(db: &C, user: &User) -> Result<(), String> {
+    let user_active_model = users::ActiveModel {
+        id: Set(user.id.clone()),
+        code: Set(user.code.clone()),
+        email: Set(user.email.clone()),
+        phone: Set(user.phone.clone()),
+        faculty: Set(user.faculty.clone()),
+        program: Set(Some(user.program.clone())),
+        specialty: Set(user.specialty.clone()),
+        role: Set(sea_orm_active_enums::UserRole::from_string(&user.role)
+            .ok_or_else(|| "Invalid user role".to_string())?),
+        student_status: Set(user
+            .student_status
+            .as_ref()
+            .map(sea_orm_active_enums::to_db_student_status)),
+        admission_date: user
+            .admission_date
+            .as_ref()
+            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .map(|d| Set(Some(d)))
+            .unwrap_or_default(),
+        contract_type: Set(user
+            .contract_type
+            .as_ref()
+            .map(sea_orm_active_enums::to_db_contract)),
+        max_hours_per_week: Set(user.max_hours_per_week),
+        hire_date: user
+            .hire_date
+            .as_ref()
+            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .map(|d| Set(Some(d)))
+            .unwrap_or_default(),
+        full_name: Set(Some(user.full_name.clone())),
+        password: Set("password".to_string()),
+        deleted_at: Set(None),
+    };
+
+    user_active_model
+        .insert(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+pub(crate) async fn update_user<C: ConnectionTrait>(db: &C, user: &User) -> Result<(), String> {
+    let mut user_active_model: users::ActiveModel = users::Entity::find()
+        .filter(users::Column::Id.eq(&user.id))
+        .filter(users::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "User not found".to_string())?
+        .into();
+
+    user_active_model.code = Set(user.code.clone());
+    user_active_model.email = Set(user.email.clone());
+    user_active_model.phone = Set(user.phone.clone());
+    user_active_model.faculty = Set(user.faculty.clone());
+    user_active_model.program = Set(Some(user.program.clone()));
+    user_active_model.specialty = Set(user.specialty.clone());
+    user_active_model.role = Set(sea_orm_active_enums::UserRole::from_string(&user.role)
+        .ok_or_else(|| "Invalid user role".to_string())?);
+    user_active_model.student_status = Set(user
+        .student_status
+        .as_ref()
+        .map(sea_orm_active_enums::to_db_student_status));
+    user_active_model.admission_date = user
+        .admission_date
+        .as_ref()
+        .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        .map(|d| Set(Some(d)))
+        .unwrap_or_default();
+    user_active_model.contract_type = Set(user
+        .contract_type
+        .as_ref()
+        .map(sea_orm_active_enums::to_db_contract));
+    user_active_model.max_hours_per_week = Set(user.max_hours_per_week);
+    user_active_model.hire_date = user
+        .hire_date
+        .as_ref()
+        .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        .map(|d| Set(Some(d)))
+        .unwrap_or_default();
+    user_active_model.full_name = Set(Some(user.full_name.clone()));
+    user_active_model.password = Set("password".to_string());
+
+    user_active_model
+        .update(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+pub(crate) async fn update_student_status<C: ConnectionTrait>(
+    db: &C,
+    user_id: &str,
+    status: &StudentStatus,
+) -> Result<(), String> {
+    let mut user_active_model: users::ActiveModel = users::Entity::find()
+        .filter(users::Column::Id.eq(user_id))
+        .filter(users::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "User not found".to_string())?
+        .into();
+
+    user_active_model.student_status =
+        Set(Some(sea_orm_active_enums::to_db_student_status(status)));
+
+    user_active_model
+        .update(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+pub(crate) async fn delete_user<C: ConnectionTrait>(db: &C, user_id: &str) -> Result<(), String> {
+    let mut user_active_model: users::ActiveModel = users::Entity::find()
+        .filter(users::Column::Id.eq(user_id))
+        .filter(users::Column::DeletedAt.is_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "User not found".to_string())?
+        .into();
+
+    user_active_model.deleted_at = Set(Some(Utc::now().naive_utc()));
+
+    user_active_model
+        .update(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+pub(crate) async fn restore_user<C: ConnectionTrait>(db: &C, user_id: &str) -> Result<(), String> {
+    let mut user_active_model: users::ActiveModel = users::Entity::find()
+        .filter(users::Column::Id.eq(user_id))
+        .filter(users::Column::DeletedAt.is_not_null())
+        .one(db)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Archived user not found".to_string())?
+        .into();
+
+    user_active_model.deleted_at = Set(None);
+
+    user_active_model
+        .update(db)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
 #[async_trait]
 impl UserRepository for SupabaseUserRepository {
     async fn get_user_by_id(&self, user_id: &str) -> Result<Option<User>, String> {
         users::Entity::find()
             .filter(users::Column::Id.eq(user_id))
+            .filter(users::Column::DeletedAt.is_null())
             .one(&self.db)
             .await
             .map_err(|e| e.to_string())?
@@ -56,6 +221,7 @@ impl UserRepository for SupabaseUserRepository {
     async fn get_user_by_email(&self, email: &str) -> Result<Option<User>, String> {
         users::Entity::find()
             .filter(users::Column::Email.eq(email))
+            .filter(users::Column::DeletedAt.is_null())
             .one(&self.db)
             .await
             .map_err(|e| e.to_string())?
@@ -86,6 +252,7 @@ impl UserRepository for SupabaseUserRepository {
 
     async fn get_all_users(&self) -> Result<Vec<User>, String> {
         users::Entity::find()
+            .filter(users::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
@@ -116,111 +283,34 @@ impl UserRepository for SupabaseUserRepository {
     }
 
     async fn create_user(&self, user: &User) -> Result<(), String> {
-        let user_active_model = users::ActiveModel {
-            id: Set(user.id.clone()),
-            code: Set(user.code.clone()),
-            email: Set(user.email.clone()),
-            phone: Set(user.phone.clone()),
-            faculty: Set(user.faculty.clone()),
-            program: Set(Some(user.program.clone())),
-            specialty: Set(user.specialty.clone()),
-            role: Set(sea_orm_active_enums::UserRole::from_string(&user.role)
-                .ok_or_else(|| "Invalid user role".to_string())?),
-            student_status: Set(user
-                .student_status
-                .as_ref()
-                .map(sea_orm_active_enums::to_db_student_status)),
-            admission_date: user
-                .admission_date
-                .as_ref()
-                .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
-                .map(|d| Set(Some(d)))
-                .unwrap_or_default(),
-            contract_type: Set(user
-                .contract_type
-                .as_ref()
-                .map(sea_orm_active_enums::to_db_contract)),
-            max_hours_per_week: Set(user.max_hours_per_week),
-            hire_date: user
-                .hire_date
-                .as_ref()
-                .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
-                .map(|d| Set(Some(d)))
-                .unwrap_or_default(),
-            full_name: Set(Some(user.full_name.clone())),
-            password: Set("password".to_string()),
-        };
-
-        user_active_model
-            .insert(&self.db)
-            .await
-            .map_err(|e| e.to_string())?;
-
-        Ok(())
+        create_user(&self.db, user).await
     }
 
     async fn update_user(&self, user: &User) -> Result<(), String> {
-        let mut user_active_model: users::ActiveModel = users::Entity::find()
-            .filter(users::Column::Id.eq(&user.id))
-            .one(&self.db)
-            .await
-            .map_err(|e| e.to_string())?
-            .ok_or_else(|| "User not found".to_string())?
-            .into();
-
-        user_active_model.code = Set(user.code.clone());
-        user_active_model.email = Set(user.email.clone());
-        user_active_model.phone = Set(user.phone.clone());
-        user_active_model.faculty = Set(user.faculty.clone());
-        user_active_model.program = Set(Some(user.program.clone()));
-        user_active_model.specialty = Set(user.specialty.clone());
-        user_active_model.role = Set(sea_orm_active_enums::UserRole::from_string(&user.role)
-            .ok_or_else(|| "Invalid user role".to_string())?);
-        user_active_model.student_status = Set(user
-            .student_status
-            .as_ref()
-            .map(sea_orm_active_enums::to_db_student_status));
-        user_active_model.admission_date = user
-            .admission_date
-            .as_ref()
-            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
-            .map(|d| Set(Some(d)))
-            .unwrap_or_default();
-        user_active_model.contract_type = Set(user
-            .contract_type
-            .as_ref()
-            .map(sea_orm_active_enums::to_db_contract));
-        user_active_model.max_hours_per_week = Set(user.max_hours_per_week);
-        user_active_model.hire_date = user
-            .hire_date
-            .as_ref()
-            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
-            .map(|d| Set(Some(d)))
-            .unwrap_or_default();
-        user_active_model.full_name = Set(Some(user.full_name.clone()));
-        user_active_model.password = Set("password".to_string());
-
-        user_active_model
-            .update(&self.db)
-            .await
-            .map_err(|e| e.to_string())?;
+        update_user(&self.db, user).await
+    }
 
-        Ok(())
+    async fn update_student_status(
+        &self,
+        user_id: &str,
+        status: &StudentStatus,
+    ) -> Result<(), String> {
+        update_student_status(&self.db, user_id, status).await
     }
 
     async fn delete_user(&self, user_id: &str) -> Result<(), String> {
-        users::Entity::delete_by_id(user_id)
-            .exec(&self.db)
-            .await
-            .map_err(|e| e.to_string())?;
+        delete_user(&self.db, user_id).await
+    }
 
-        Ok(())
+    async fn restore_user(&self, user_id: &str) -> Result<(), String> {
+        restore_user(&self.db, user_id).await
     }
 
     async fn get_users_by_course(&self, course_id: &str) -> Result<Vec<User>, String> {
         users::Entity::find()
             .join(JoinType::InnerJoin, users::Relation::Enrollments.def())
             .filter(enrollments::Column::CourseId.eq(course_id))
+            .filter(users::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
@@ -255,6 +345,7 @@ impl UserRepository for SupabaseUserRepository {
             .join(JoinType::InnerJoin, users::Relation::Enrollments.def())
             .join(JoinType::InnerJoin, enrollments::Relation::Courses.def())
             .filter(courses::Column::Name.eq(name_course))
+            .filter(users::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
@@ -295,6 +386,7 @@ impl UserRepository for SupabaseUserRepository {
             )
             .filter(course_schedules::Column::FacilityId.eq(facility_id))
             .filter(users::Column::Role.eq(sea_orm_active_enums::UserRole::Student))
+            .filter(users::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?;
@@ -308,6 +400,7 @@ impl UserRepository for SupabaseUserRepository {
             )
             .filter(course_schedules::Column::FacilityId.eq(facility_id))
             .filter(users::Column::Role.eq(sea_orm_active_enums::UserRole::Teacher))
+            .filter(users::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?;
@@ -357,6 +450,7 @@ impl UserRepository for SupabaseUserRepository {
             )
             .filter(facilities::Column::Name.eq(name_facility))
             .filter(users::Column::Role.eq(sea_orm_active_enums::UserRole::Student))
+            .filter(users::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?;
@@ -374,6 +468,7 @@ impl UserRepository for SupabaseUserRepository {
             )
             .filter(facilities::Column::Name.eq(name_facility))
             .filter(users::Column::Role.eq(sea_orm_active_enums::UserRole::Teacher))
+            .filter(users::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?;
@@ -424,6 +519,7 @@ impl UserRepository for SupabaseUserRepository {
     async fn get_users_by_name(&self, name: &str) -> Result<Vec<User>, String> {
         users::Entity::find()
             .filter(users::Column::FullName.like(format!("%{}%", name)))
+            .filter(users::Column::DeletedAt.is_null())
             .all(&self.db)
             .await
             .map_err(|e| e.to_string())?
@@ -0,0 +1,261 @@
+use crate::domain::{
+    models::enums::{DeliveryStatus, EventKind},
+    models::webhook::{DeliveryAttempt, OutboxMessage, WebhookSubscription},
+    repositories::webhook_repository::{OutboxRepository, WebhookRepository},
+};
+use crate::infrastructure::database::entities::{
+    webhook_deliveries, webhook_outbox, webhook_subscriptions,
+};
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+    sea_query::{Expr, LockBehavior, LockType, OnConflict},
+};
+use shared::config::connect_to_supabase;
+use std::str::FromStr;
+
+/// Tiempo que un envío tomado queda apartado; si el despachador cae antes de
+/// actualizarlo, vuelve a estar disponible.
+const CLAIM_TIMEOUT_MINUTES: i64 = 5;
+
+/// Suscripciones, bandeja de salida y registro de entregas comparten conexión.
+#[derive(Clone)]
+pub struct SupabaseWebhookRepository {
+    db: DatabaseConnection,
+}
+
+impl SupabaseWebhookRepository {
+    pub async fn new() -> Result<Self, String> {
+        let db = connect_to_supabase().await.map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+fn to_domain_subscription(s: webhook_subscriptions::Model) -> Result<WebhookSubscription, String> {
+    Ok(WebhookSubscription {
+        id: s.id,
+        target_url: s.target_url,
+        secret: s.secret,
+        events: serde_json::from_value(s.events).map_err(|e| e.to_string())?,
+        active: s.active,
+        created_by: s.created_by,
+        created_at: Some(s.created_at.to_string()),
+    })
+}
+
+fn to_domain_message(m: webhook_outbox::Model) -> Result<OutboxMessage, String> {
+    Ok(OutboxMessage {
+        id: m.id,
+        subscription_id: m.subscription_id,
+        event_id: m.event_id,
+        event_kind: EventKind::from_str(&m.event_kind)?,
+        payload: m.payload,
+        status: DeliveryStatus::from_str(&m.status)?,
+        attempts: m.attempts,
+        next_attempt_at: m.next_attempt_at,
+        last_error: m.last_error,
+        created_at: Some(m.created_at.to_string()),
+        delivered_at: m.delivered_at.map(|dt| dt.to_string()),
+    })
+}
+
+fn to_domain_attempt(d: webhook_deliveries::Model) -> Result<DeliveryAttempt, String> {
+    Ok(DeliveryAttempt {
+        id: d.id,
+        outbox_id: d.outbox_id,
+        subscription_id: d.subscription_id,
+        event_kind: EventKind::from_str(&d.event_kind)?,
+        attempt: d.attempt,
+        status_code: d.status_code,
+        error: d.error,
+        duration_ms: d.duration_ms,
+        attempted_at: Some(d.attempted_at.to_string()),
+    })
+}
+
+#[async_trait]
+impl WebhookRepository for SupabaseWebhookRepository {
+    async fn create_subscription(&self, subscription: &WebhookSubscription) -> Result<(), String> {
+        let subscription_model = webhook_subscriptions::ActiveModel {
+            id: Set(subscription.id.clone()),
+            target_url: Set(subscription.target_url.clone()),
+            secret: Set(subscription.secret.clone()),
+            events: Set(serde_json::to_value(&subscription.events).map_err(|e| e.to_string())?),
+            active: Set(subscription.active),
+            created_by: Set(subscription.created_by.clone()),
+            created_at: Set(Utc::now().naive_utc()),
+        };
+
+        subscription_model
+            .insert(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_subscriptions(&self) -> Result<Vec<WebhookSubscription>, String> {
+        webhook_subscriptions::Entity::find()
+            .order_by_asc(webhook_subscriptions::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_subscription)
+            .collect()
+    }
+
+    async fn get_subscription_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<WebhookSubscription>, String> {
+        webhook_subscriptions::Entity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(to_domain_subscription)
+            .transpose()
+    }
+
+    async fn set_subscription_active(&self, id: &str, active: bool) -> Result<(), String> {
+        let existing = webhook_subscriptions::Entity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or("Webhook not found")?;
+
+        let mut subscription_model: webhook_subscriptions::ActiveModel = existing.into();
+        subscription_model.active = Set(active);
+
+        subscription_model
+            .update(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for SupabaseWebhookRepository {
+    async fn enqueue_message(&self, message: &OutboxMessage) -> Result<(), String> {
+        let message_model = webhook_outbox::ActiveModel {
+            id: Set(message.id.clone()),
+            subscription_id: Set(message.subscription_id.clone()),
+            event_id: Set(message.event_id.clone()),
+            event_kind: Set(message.event_kind.to_string()),
+            payload: Set(message.payload.clone()),
+            status: Set(message.status.to_string()),
+            attempts: Set(message.attempts),
+            next_attempt_at: Set(message.next_attempt_at),
+            last_error: Set(message.last_error.clone()),
+            created_at: Set(Utc::now().naive_utc()),
+            delivered_at: Set(None),
+        };
+
+        // Un evento reintentado vuelve a encolar el mismo envío: se ignora
+        webhook_outbox::Entity::insert(message_model)
+            .on_conflict(
+                OnConflict::column(webhook_outbox::Column::Id)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn claim_due_messages(
+        &self,
+        now: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<OutboxMessage>, String> {
+        let txn = self.db.begin().await.map_err(|e| e.to_string())?;
+
+        // Las filas que otro despachador está tomando se saltan en vez de esperar
+        let due = webhook_outbox::Entity::find()
+            .filter(webhook_outbox::Column::Status.eq(DeliveryStatus::Pending.to_string()))
+            .filter(webhook_outbox::Column::NextAttemptAt.lte(now))
+            .order_by_asc(webhook_outbox::Column::NextAttemptAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !due.is_empty() {
+            webhook_outbox::Entity::update_many()
+                .col_expr(
+                    webhook_outbox::Column::NextAttemptAt,
+                    Expr::value(now + Duration::minutes(CLAIM_TIMEOUT_MINUTES)),
+                )
+                .filter(webhook_outbox::Column::Id.is_in(due.iter().map(|m| m.id.clone())))
+                .exec(&txn)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        txn.commit().await.map_err(|e| e.to_string())?;
+
+        due.into_iter().map(to_domain_message).collect()
+    }
+
+    async fn update_message(&self, message: &OutboxMessage) -> Result<(), String> {
+        let existing = webhook_outbox::Entity::find_by_id(&message.id)
+            .one(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or("Outbox message not found")?;
+
+        let mut message_model: webhook_outbox::ActiveModel = existing.into();
+        message_model.status = Set(message.status.to_string());
+        message_model.attempts = Set(message.attempts);
+        message_model.next_attempt_at = Set(message.next_attempt_at);
+        message_model.last_error = Set(message.last_error.clone());
+        message_model.delivered_at = Set(match message.status {
+            DeliveryStatus::Delivered => Some(Utc::now().naive_utc()),
+            _ => None,
+        });
+
+        message_model
+            .update(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn record_attempt(&self, attempt: &DeliveryAttempt) -> Result<(), String> {
+        let attempt_model = webhook_deliveries::ActiveModel {
+            id: Set(attempt.id.clone()),
+            outbox_id: Set(attempt.outbox_id.clone()),
+            subscription_id: Set(attempt.subscription_id.clone()),
+            event_kind: Set(attempt.event_kind.to_string()),
+            attempt: Set(attempt.attempt),
+            status_code: Set(attempt.status_code),
+            error: Set(attempt.error.clone()),
+            duration_ms: Set(attempt.duration_ms),
+            attempted_at: Set(Utc::now().naive_utc()),
+        };
+
+        attempt_model
+            .insert(&self.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn get_attempts_by_subscription(
+        &self,
+        subscription_id: &str,
+    ) -> Result<Vec<DeliveryAttempt>, String> {
+        webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::SubscriptionId.eq(subscription_id))
+            .order_by_desc(webhook_deliveries::Column::AttemptedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(to_domain_attempt)
+            .collect()
+    }
+}
@@ -0,0 +1,216 @@
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+
+/// Documento PDF de texto plano en A4 con Helvetica. Cubre lo que necesitan
+/// los reportes (títulos y tablas simples) sin depender de una librería.
+pub struct PdfDocument {
+    pages: Vec<Vec<u8>>,
+    y: f32,
+}
+
+impl Default for PdfDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Pasa el texto a WinAnsi (cubre las tildes y la eñe) y escapa los
+/// delimitadores de cadena de PDF.
+fn encode_text(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                bytes.push(b'\\');
+                bytes.push(c as u8);
+            }
+            ' '..='~' | '\u{a0}'..='\u{ff}' => bytes.push(c as u32 as u8),
+            _ => bytes.push(b'?'),
+        }
+    }
+    bytes
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        Self {
+            pages: vec![Vec::new()],
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    /// Escribe una línea; cada celda es un desplazamiento desde el margen
+    /// izquierdo y su texto. Salta de página si no queda espacio.
+    pub fn line(&mut self, cells: &[(f32, &str)], size: f32, bold: bool) {
+        let leading = size * 1.4;
+        if self.y - leading < MARGIN {
+            self.pages.push(Vec::new());
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+        self.y -= leading;
+
+        let font = if bold { "F2" } else { "F1" };
+        let page = self.pages.last_mut().expect("always at least one page");
+        for (offset, text) in cells {
+            page.extend_from_slice(
+                format!("BT /{} {} Tf {:.1} {:.1} Td (", font, size, MARGIN + offset, self.y)
+                    .as_bytes(),
+            );
+            page.extend_from_slice(&encode_text(text));
+            page.extend_from_slice(b") Tj ET\n");
+        }
+    }
+
+    pub fn space(&mut self, points: f32) {
+        self.y -= points;
+    }
+
+    /// Línea horizontal de margen a margen.
+    pub fn rule(&mut self) {
+        self.y -= 4.0;
+        let page = self.pages.last_mut().expect("always at least one page");
+        page.extend_from_slice(
+            format!(
+                "{:.1} {:.1} m {:.1} {:.1} l S\n",
+                MARGIN,
+                self.y,
+                PAGE_WIDTH - MARGIN,
+                self.y
+            )
+            .as_bytes(),
+        );
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        // 1: catálogo, 2: páginas, 3 y 4: fuentes, luego página y contenido
+        let page_count = self.pages.len();
+        let kids: Vec<String> = (0..page_count)
+            .map(|i| format!("{} 0 R", 5 + i * 2))
+            .collect();
+
+        let mut objects: Vec<Vec<u8>> = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids.join(" "),
+                page_count
+            )
+            .into_bytes(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+        ];
+
+        for (i, stream) in self.pages.into_iter().enumerate() {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                    PAGE_WIDTH,
+                    PAGE_HEIGHT,
+                    6 + i * 2
+                )
+                .into_bytes(),
+            );
+            let mut object = format!("<< /Length {} >>\nstream\n", stream.len()).into_bytes();
+            object.extend_from_slice(&stream);
+            object.extend_from_slice(b"\nendstream");
+            objects.push(object);
+        }
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+            pdf.extend_from_slice(object);
+            pdf.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref = pdf.len();
+        pdf.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+        for offset in offsets {
+            pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        pdf.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref
+            )
+            .as_bytes(),
+        );
+        pdf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_accents_and_escapes_delimiters() {
+        assert_eq!(encode_text("Año (1) \\ €"), b"A\xf1o \\(1\\) \\\\ ?");
+    }
+
+    #[test]
+    fn writes_a_one_page_document() {
+        let mut doc = PdfDocument::new();
+        doc.line(&[(0.0, "Año"), (100.0, "(1)")], 12.0, true);
+        doc.rule();
+
+        let expected: &[u8] = b"\
+            %PDF-1.4\n\
+            1 0 obj\n\
+            << /Type /Catalog /Pages 2 0 R >>\n\
+            endobj\n\
+            2 0 obj\n\
+            << /Type /Pages /Kids [5 0 R] /Count 1 >>\n\
+            endobj\n\
+            3 0 obj\n\
+            << /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>\n\
+            endobj\n\
+            4 0 obj\n\
+            << /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>\n\
+            endobj\n\
+            5 0 obj\n\
+            << /Type /Page /Parent 2 0 R /MediaBox [0 0 595 842] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents 6 0 R >>\n\
+            endobj\n\
+            6 0 obj\n\
+            << /Length 110 >>\n\
+            stream\n\
+            BT /F2 12 Tf 50.0 775.2 Td (A\xf1o) Tj ET\n\
+            BT /F2 12 Tf 150.0 775.2 Td (\\(1\\)) Tj ET\n\
+            50.0 771.2 m 545.0 771.2 l S\n\
+            \n\
+            endstream\n\
+            endobj\n\
+            xref\n\
+            0 7\n\
+            0000000000 65535 f \n\
+            0000000009 00000 n \n\
+            0000000058 00000 n \n\
+            0000000115 00000 n \n\
+            0000000212 00000 n \n\
+            0000000314 00000 n \n\
+            0000000450 00000 n \n\
+            trailer\n\
+            << /Size 7 /Root 1 0 R >>\n\
+            startxref\n\
+            611\n\
+            %%EOF\n";
+        assert_eq!(doc.into_bytes(), expected);
+    }
+
+    #[test]
+    fn breaks_pages_when_the_page_is_full() {
+        let mut doc = PdfDocument::new();
+        for _ in 0..60 {
+            doc.line(&[(0.0, "x")], 10.0, false);
+        }
+
+        let pdf = String::from_utf8_lossy(&doc.into_bytes()).into_owned();
+        assert!(pdf.contains("/Kids [5 0 R 7 0 R] /Count 2"));
+    }
+}
@@ -0,0 +1,119 @@
+use super::pdf::PdfDocument;
+use crate::domain::models::transcript::Transcript;
+
+/// Historial académico en PDF: datos del alumno, un bloque por semestre y el
+/// resumen de créditos y avance del programa.
+pub fn render_transcript(transcript: &Transcript) -> Vec<u8> {
+    let mut doc = PdfDocument::new();
+
+    doc.line(&[(0.0, "Historial académico")], 16.0, true);
+    doc.space(6.0);
+    doc.line(
+        &[(0.0, &format!("{} - {}", transcript.student_code, transcript.full_name))],
+        11.0,
+        false,
+    );
+    let program = match transcript.progress.plan_version {
+        Some(version) => format!("Programa: {} (plan v{})", transcript.progress.program, version),
+        None => format!("Programa: {}", transcript.progress.program),
+    };
+    doc.line(&[(0.0, &program)], 11.0, false);
+    if let Some(status) = &transcript.student_status {
+        doc.line(&[(0.0, &format!("Estado: {}", status.to_string()))], 11.0, false);
+    }
+    if let Some(admission_date) = &transcript.admission_date {
+        doc.line(&[(0.0, &format!("Ingreso: {}", admission_date))], 11.0, false);
+    }
+
+    for term in &transcript.terms {
+        doc.space(10.0);
+        doc.line(
+            &[(0.0, &format!("{} - {}", term.academic_year, term.semester))],
+            12.0,
+            true,
+        );
+        doc.line(
+            &[(0.0, "Código"), (80.0, "Curso"), (330.0, "Créditos"), (400.0, "Nota")],
+            10.0,
+            true,
+        );
+        doc.rule();
+        for entry in &term.entries {
+            let credits = entry.credits.to_string();
+            let grade = format!("{:.2}{}", entry.final_grade, if entry.passed { "" } else { " (D)" });
+            doc.line(
+                &[(0.0, &entry.code), (80.0, &entry.name), (330.0, &credits), (400.0, &grade)],
+                10.0,
+                false,
+            );
+        }
+        doc.line(
+            &[(
+                0.0,
+                &format!(
+                    "Créditos aprobados: {} de {}    Promedio ponderado: {:.2}",
+                    term.credits_earned, term.credits_attempted, term.weighted_average
+                ),
+            )],
+            10.0,
+            false,
+        );
+    }
+
+    doc.space(14.0);
+    doc.rule();
+    doc.line(&[(0.0, "Resumen")], 12.0, true);
+    doc.line(
+        &[(
+            0.0,
+            &format!(
+                "Créditos aprobados: {} de {} llevados",
+                transcript.credits_earned, transcript.credits_attempted
+            ),
+        )],
+        10.0,
+        false,
+    );
+    doc.line(
+        &[(0.0, &format!("Promedio ponderado acumulado: {:.2}", transcript.weighted_average))],
+        10.0,
+        false,
+    );
+    let progress = &transcript.progress;
+    doc.line(
+        &[(
+            0.0,
+            &format!(
+                "Avance del plan: {} de {} créditos requeridos ({:.1} %), {} créditos adicionales",
+                progress.earned_required_credits,
+                progress.required_credits,
+                progress.percentage,
+                progress.earned_elective_credits
+            ),
+        )],
+        10.0,
+        false,
+    );
+    if !progress.pending_courses.is_empty() {
+        doc.line(
+            &[(0.0, &format!("Pendientes: {}", progress.pending_courses.join(", ")))],
+            10.0,
+            false,
+        );
+    }
+    if !progress.pending_elective_groups.is_empty() {
+        doc.line(
+            &[(
+                0.0,
+                &format!(
+                    "Electivos pendientes: {}",
+                    progress.pending_elective_groups.join(", ")
+                ),
+            )],
+            10.0,
+            false,
+        );
+    }
+
+    doc.into_bytes()
+}
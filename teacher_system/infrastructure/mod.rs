@@ -1,2 +1,4 @@
 pub mod api_restful;
 pub mod database;
+pub mod documents;
+pub mod notifications;
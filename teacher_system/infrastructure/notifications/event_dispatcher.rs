@@ -0,0 +1,73 @@
+use crate::domain::{
+    repositories::event_outbox_repository::EventOutboxRepository,
+    services::event_bus::DefaultEventBus,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Eventos que se toman de la bandeja de salida en cada pasada.
+const BATCH_SIZE: u64 = 50;
+
+/// Reparte en segundo plano los eventos que los casos de uso dejaron en la
+/// bandeja de salida, así los avisos no alargan la petición que los produjo.
+/// Un evento cuyo manejador falla se reintenta solo con los manejadores que
+/// fallaron.
+#[derive(Clone)]
+pub struct EventDispatcher {
+    outbox_repo: Arc<dyn EventOutboxRepository + Send + Sync>,
+    event_bus: DefaultEventBus,
+}
+
+impl EventDispatcher {
+    pub fn new(
+        outbox_repo: Arc<dyn EventOutboxRepository + Send + Sync>,
+        event_bus: DefaultEventBus,
+    ) -> Self {
+        Self {
+            outbox_repo,
+            event_bus,
+        }
+    }
+
+    /// Revisa la bandeja de salida cada `interval` en segundo plano.
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.deliver_due().await {
+                    eprintln!("Error dispatching events: {}", e);
+                }
+            }
+        })
+    }
+
+    pub async fn deliver_due(&self) -> Result<(), String> {
+        let events = self
+            .outbox_repo
+            .claim_due_events(Utc::now().naive_utc(), BATCH_SIZE)
+            .await?;
+
+        // Un evento que falla no detiene a los demás de la pasada
+        for mut event in events {
+            match self.event_bus.dispatch(&mut event).await {
+                Ok(()) => event.record_success(),
+                Err(e) => {
+                    eprintln!(
+                        "Error handling event {} ({}): {}",
+                        event.envelope.id,
+                        event.envelope.event.kind(),
+                        e
+                    );
+                    event.record_failure(&e, Utc::now().naive_utc());
+                }
+            }
+
+            if let Err(e) = self.outbox_repo.update_event(&event).await {
+                eprintln!("Error updating event {}: {}", event.envelope.id, e);
+            }
+        }
+        Ok(())
+    }
+}
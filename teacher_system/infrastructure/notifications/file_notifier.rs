@@ -0,0 +1,36 @@
+use crate::domain::{models::notification::Notification, services::notifier::Notifier};
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tokio::io::AsyncWriteExt;
+
+/// Agrega cada notificación como una línea JSON al archivo indicado; sirve
+/// para revisar en local qué se habría enviado.
+#[derive(Clone)]
+pub struct FileNotifier {
+    path: PathBuf,
+}
+
+impl FileNotifier {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl Notifier for FileNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<(), String> {
+        let mut line = serde_json::to_string(notification).map_err(|e| e.to_string())?;
+        line.push('\n');
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        file.write_all(line.as_bytes())
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
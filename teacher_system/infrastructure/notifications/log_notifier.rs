@@ -0,0 +1,20 @@
+use crate::domain::{models::notification::Notification, services::notifier::Notifier};
+use async_trait::async_trait;
+
+/// Escribe las notificaciones en el log; útil en desarrollo local.
+#[derive(Clone, Default)]
+pub struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<(), String> {
+        log::info!(
+            "Notification to {} <{}>: {} - {}",
+            notification.recipient_id,
+            notification.recipient_email.as_deref().unwrap_or("no email"),
+            notification.subject,
+            notification.body
+        );
+        Ok(())
+    }
+}
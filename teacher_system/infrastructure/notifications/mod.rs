@@ -0,0 +1,6 @@
+pub mod event_dispatcher;
+pub mod file_notifier;
+pub mod log_notifier;
+pub mod smtp_notifier;
+pub mod webhook_dispatcher;
+pub mod webhook_notifier;
@@ -0,0 +1,199 @@
+use crate::domain::{models::notification::Notification, services::notifier::Notifier};
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, header::ContentType},
+    transport::smtp::authentication::Credentials,
+};
+use std::time::Duration;
+
+/// Tiempo máximo para entregar un correo al servidor SMTP.
+const SMTP_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Cómo se cifra la conexión con el servidor SMTP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// Conexión en claro que se sube a TLS con STARTTLS (puerto 587).
+    StartTls,
+    /// TLS desde el primer byte (puerto 465).
+    Tls,
+}
+
+impl std::str::FromStr for SmtpSecurity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "starttls" => Ok(SmtpSecurity::StartTls),
+            "tls" => Ok(SmtpSecurity::Tls),
+            _ => Err(format!("Invalid SMTP security: {}", s)),
+        }
+    }
+}
+
+/// Envía las notificaciones por correo; la conexión siempre va cifrada, así
+/// que las credenciales nunca viajan en claro.
+#[derive(Clone)]
+pub struct SmtpNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpNotifier {
+    pub fn new(
+        host: &str,
+        port: u16,
+        security: SmtpSecurity,
+        from: &str,
+        credentials: Option<(String, String)>,
+    ) -> Result<Self, String> {
+        let builder = match security {
+            SmtpSecurity::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host),
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(host),
+        }
+        .map_err(|e| e.to_string())?
+        .port(port)
+        .timeout(Some(SMTP_TIMEOUT));
+
+        let builder = match credentials {
+            Some((user, password)) => builder.credentials(Credentials::new(user, password)),
+            None => builder,
+        };
+
+        Ok(Self {
+            transport: builder.build(),
+            from: from
+                .parse()
+                .map_err(|e| format!("Invalid SMTP_FROM: {}", e))?,
+        })
+    }
+
+    /// Lee `SMTP_HOST`, `SMTP_PORT`, `SMTP_SECURITY` (`starttls` o `tls`),
+    /// `SMTP_FROM`, `SMTP_USER` y `SMTP_PASSWORD`; sin `SMTP_HOST` no hay
+    /// canal de correo.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        let Ok(host) = std::env::var("SMTP_HOST") else {
+            return Ok(None);
+        };
+        let security = match std::env::var("SMTP_SECURITY") {
+            Ok(value) => value.parse()?,
+            Err(_) => SmtpSecurity::StartTls,
+        };
+        let port = std::env::var("SMTP_PORT")
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(match security {
+                SmtpSecurity::StartTls => 587,
+                SmtpSecurity::Tls => 465,
+            });
+        let from = std::env::var("SMTP_FROM").unwrap_or_else(|_| format!("no-reply@{}", host));
+        let credentials = match (std::env::var("SMTP_USER"), std::env::var("SMTP_PASSWORD")) {
+            (Ok(user), Ok(password)) => Some((user, password)),
+            _ => None,
+        };
+
+        Self::new(&host, port, security, &from, credentials).map(Some)
+    }
+}
+
+/// Arma el correo; un destinatario o asunto con saltos de línea se rechaza en
+/// vez de acabar como otra cabecera u otro comando SMTP.
+fn build_message(from: &Mailbox, to: &str, notification: &Notification) -> Result<Message, String> {
+    if notification.subject.contains(['\r', '\n']) {
+        return Err("Invalid subject: line breaks are not allowed".to_string());
+    }
+    let to: Mailbox = to
+        .parse()
+        .map_err(|e| format!("Invalid recipient {:?}: {}", to, e))?;
+
+    Message::builder()
+        .from(from.clone())
+        .to(to)
+        .subject(notification.subject.as_str())
+        .header(ContentType::TEXT_PLAIN)
+        .body(notification.body.clone())
+        .map_err(|e| e.to_string())
+}
+
+#[async_trait]
+impl Notifier for SmtpNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<(), String> {
+        let to = notification.recipient_email.as_deref().ok_or_else(|| {
+            format!(
+                "Invalid recipient: user {} has no email",
+                notification.recipient_id
+            )
+        })?;
+
+        let message = build_message(&self.from, to, notification)?;
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(subject: &str) -> Notification {
+        Notification {
+            recipient_id: "u1".to_string(),
+            recipient_email: None,
+            subject: subject.to_string(),
+            body: "Body".to_string(),
+        }
+    }
+
+    fn from() -> Mailbox {
+        "no-reply@uni.edu".parse().unwrap()
+    }
+
+    #[test]
+    fn builds_a_plain_text_message() {
+        let message =
+            build_message(&from(), "ana@uni.edu", &notification("Cálculo cancelled")).unwrap();
+        let raw = String::from_utf8(message.formatted()).unwrap();
+
+        assert!(raw.contains("To: ana@uni.edu\r\n"));
+        assert!(raw.contains("Content-Type: text/plain; charset=utf-8\r\n"));
+        assert!(
+            !raw.contains("Cálculo"),
+            "non-ASCII subject must be encoded"
+        );
+    }
+
+    #[test]
+    fn rejects_line_breaks_in_the_recipient() {
+        let result = build_message(
+            &from(),
+            "ana@uni.edu>\r\nRCPT TO:<eve@evil.com",
+            &notification("Subject"),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_line_breaks_in_the_subject() {
+        let result = build_message(
+            &from(),
+            "ana@uni.edu",
+            &notification("Subject\r\nBcc: eve@evil.com"),
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid subject: line breaks are not allowed"
+        );
+    }
+
+    #[test]
+    fn parses_security_modes() {
+        assert_eq!("STARTTLS".parse(), Ok(SmtpSecurity::StartTls));
+        assert_eq!("tls".parse(), Ok(SmtpSecurity::Tls));
+        assert!("none".parse::<SmtpSecurity>().is_err());
+    }
+}
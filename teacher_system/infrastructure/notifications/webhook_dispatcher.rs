@@ -0,0 +1,177 @@
+use crate::domain::{
+    models::enums::DeliveryStatus,
+    models::webhook::{DeliveryAttempt, OutboxMessage},
+    repositories::webhook_repository::{OutboxRepository, WebhookRepository},
+};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Firma `sha256=<hex>` de `"{timestamp}.{body}"` con el secreto de la suscripción.
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+pub const EVENT_HEADER: &str = "X-Webhook-Event";
+/// Identificador estable entre reintentos para que el receptor descarte duplicados.
+pub const DELIVERY_HEADER: &str = "X-Webhook-Delivery";
+
+/// Envíos que se toman de la bandeja de salida en cada pasada.
+const BATCH_SIZE: u64 = 50;
+
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Entrega los envíos pendientes de la bandeja de salida. Como lee de la base
+/// de datos, lo que quedó pendiente antes de un reinicio se retoma solo; la
+/// entrega es "al menos una vez".
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    client: reqwest::Client,
+}
+
+impl WebhookDispatcher {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Result<Self, String> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            webhook_repo,
+            outbox_repo,
+            client,
+        })
+    }
+
+    /// Revisa la bandeja de salida cada `interval` en segundo plano.
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.deliver_due().await {
+                    eprintln!("Error delivering webhooks: {}", e);
+                }
+            }
+        })
+    }
+
+    pub async fn deliver_due(&self) -> Result<(), String> {
+        let messages = self
+            .outbox_repo
+            .claim_due_messages(Utc::now().naive_utc(), BATCH_SIZE)
+            .await?;
+
+        // Un envío que falla no detiene a los demás de la pasada
+        for message in messages {
+            let id = message.id.clone();
+            if let Err(e) = self.deliver(message).await {
+                eprintln!("Error delivering webhook message {}: {}", id, e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn deliver(&self, mut message: OutboxMessage) -> Result<(), String> {
+        let subscription = self
+            .webhook_repo
+            .get_subscription_by_id(&message.subscription_id)
+            .await?
+            .filter(|s| s.active);
+
+        // Si la suscripción se desactivó el envío ya no tiene destino
+        let Some(subscription) = subscription else {
+            message.status = DeliveryStatus::Failed;
+            message.last_error = Some("Subscription is no longer active".to_string());
+            return self.outbox_repo.update_message(&message).await;
+        };
+
+        let body = serde_json::to_string(&message.payload).map_err(|e| e.to_string())?;
+        let timestamp = Utc::now().timestamp();
+
+        let started = Instant::now();
+        let result = self
+            .client
+            .post(&subscription.target_url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(
+                SIGNATURE_HEADER,
+                sign_payload(&subscription.secret, timestamp, &body),
+            )
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(EVENT_HEADER, message.event_kind.to_string())
+            .header(DELIVERY_HEADER, message.id.as_str())
+            .body(body)
+            .send()
+            .await;
+        let duration_ms = started.elapsed().as_millis() as i64;
+
+        let (status_code, error) = match result {
+            Ok(response) if response.status().is_success() => {
+                (Some(response.status().as_u16() as i32), None)
+            }
+            Ok(response) => (
+                Some(response.status().as_u16() as i32),
+                Some(format!("Endpoint responded with {}", response.status())),
+            ),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        let now = Utc::now().naive_utc();
+        match &error {
+            None => message.record_success(now),
+            Some(e) => message.record_failure(e, now),
+        }
+
+        self.outbox_repo
+            .record_attempt(&DeliveryAttempt {
+                id: uuid::Uuid::new_v4().to_string(),
+                outbox_id: message.id.clone(),
+                subscription_id: message.subscription_id.clone(),
+                event_kind: message.event_kind,
+                attempt: message.attempts,
+                status_code,
+                error,
+                duration_ms,
+                attempted_at: None,
+            })
+            .await?;
+
+        self.outbox_repo.update_message(&message).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signs_timestamp_and_body_with_hmac_sha256() {
+        assert_eq!(
+            sign_payload("0123456789abcdef", 1_700_000_000, r#"{"id":"e1"}"#),
+            "sha256=35709ca2ecce8f3d15c806503243fd398425fec4a2629cd90ef9a7a8e7ce56b3"
+        );
+    }
+
+    #[test]
+    fn signature_depends_on_the_timestamp() {
+        let body = r#"{"id":"e1"}"#;
+
+        assert_ne!(
+            sign_payload("0123456789abcdef", 1_700_000_000, body),
+            sign_payload("0123456789abcdef", 1_700_000_001, body)
+        );
+    }
+}
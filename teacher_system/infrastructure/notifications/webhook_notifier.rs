@@ -0,0 +1,43 @@
+use crate::domain::{models::notification::Notification, services::notifier::Notifier};
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// Publica cada notificación como JSON en una URL fija (p. ej. la pasarela de
+/// mensajería del campus).
+#[derive(Clone)]
+pub struct WebhookNotifier {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: &str) -> Result<Self, String> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            url: url.to_string(),
+            client,
+        })
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<(), String> {
+        let response = self
+            .client
+            .post(&self.url)
+            .json(notification)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !response.status().is_success() {
+            return Err(format!("Webhook responded with {}", response.status()));
+        }
+        Ok(())
+    }
+}